@@ -1,4 +1,5 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
+#![allow(deprecated)]
 
 use criterion::{
     black_box, criterion_group, criterion_main, Bencher, Benchmark, Criterion, Throughput,
@@ -28,6 +28,10 @@ fn main() {
         out_dir: PathBuf::from(cargo_env("OUT_DIR")),
         rustc: cargo_env("RUSTC"),
     };
+    println!("cargo:rustc-check-cfg=cfg(track_caller)");
+    env.check_feature("track_caller", Optional(true), TRY_TRACK_CALLER, None);
+    println!("cargo:rustc-check-cfg=cfg(const_panic)");
+    env.check_feature("const_panic", Optional(true), TRY_CONST_PANIC, None);
 }
 
 #[derive(PartialEq)]
@@ -72,7 +76,7 @@ impl Environment {
             create_file_or_panic(&try_dir.join(&filename), file_contents);
             let mut cmd = Command::new(&self.rustc);
             cmd.current_dir(&try_dir)
-                .args(&[&filename, "--emit=dep-info,metadata"]);
+                .args([&filename, "--emit=dep-info,metadata"]);
             println!("$ {:?}", cmd);
             let status = cmd
                 .status()
@@ -95,6 +99,31 @@ impl Environment {
     }
 }
 
+const TRY_TRACK_CALLER: &str = r#"// try_track_caller.rs
+#[track_caller]
+fn tracked() {}
+fn main() {
+    tracked();
+}
+"#;
+
+const TRY_CONST_PANIC: &str = r#"// try_const_panic.rs
+const fn first_digit(bytes: &[u8]) -> u8 {
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'0'..=b'9' => return bytes[i] - b'0',
+            _ => i += 1,
+        }
+    }
+    panic!("no digits");
+}
+const DIGIT: u8 = first_digit("x1".as_bytes());
+fn main() {
+    let _ = DIGIT;
+}
+"#;
+
 fn cargo_env(name: &str) -> OsString {
     env::var_os(name)
         .unwrap_or_else(|| panic!("environment variable not found: {}, please use cargo", name))
@@ -15,13 +15,15 @@
 
 use crate::{
     helpers::IntHelper,
-    traits::ToFixed,
-    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
+    int256::{I256, U256},
+    traits::{Fixed, ToFixed},
+    types::extra::{self, LeEqU128, LeEqU16, LeEqU256, LeEqU32, LeEqU64, LeEqU8},
     wide_div::WideDivRem,
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    FixedI128, FixedI16, FixedI256, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU256,
+    FixedU32, FixedU64, FixedU8,
 };
 use core::{
+    cmp::Ordering,
     iter::{Product, Sum},
     ops::{
         Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
@@ -455,6 +457,105 @@ fixed_arith! { FixedI32(i32, LeEqU32, 32), Signed }
 fixed_arith! { FixedI64(i64, LeEqU64, 64), Signed }
 fixed_arith! { FixedI128(i128, LeEqU128, 128), Signed }
 
+trait MulWiden {
+    type Wide;
+    fn mul_widen(self, rhs: Self) -> Self::Wide;
+}
+
+macro_rules! mul_widen {
+    ($($Single:ty => $Double:ty),*) => { $(
+        impl MulWiden for $Single {
+            type Wide = $Double;
+            #[inline]
+            fn mul_widen(self, rhs: $Single) -> $Double {
+                <$Double>::from(self) * <$Double>::from(rhs)
+            }
+        }
+    )* };
+}
+
+mul_widen! { i8 => i16, i16 => i32, i32 => i64, i64 => i128 }
+mul_widen! { u8 => u16, u16 => u32, u32 => u64, u64 => u128 }
+
+impl MulWiden for i128 {
+    type Wide = I256;
+    #[inline]
+    fn mul_widen(self, rhs: i128) -> I256 {
+        let (lhs_neg, lhs_abs) = self.neg_abs();
+        let (rhs_neg, rhs_abs) = rhs.neg_abs();
+        let (hi, lo) = mul_wide(lhs_abs, rhs_abs);
+        I256::overflowing_from_neg_abs(lhs_neg != rhs_neg, U256::from_words(hi, lo)).0
+    }
+}
+
+impl MulWiden for u128 {
+    type Wide = U256;
+    #[inline]
+    fn mul_widen(self, rhs: u128) -> U256 {
+        let (hi, lo) = mul_wide(self, rhs);
+        U256::from_words(hi, lo)
+    }
+}
+
+macro_rules! fixed_wide_mul {
+    (
+        $Fixed:ident[$s_fixed:expr]($LeEqU:ident),
+        $Wider:ident[$s_wider:expr]($WiderLeEqU:ident)
+    ) => {
+        impl<Frac: $LeEqU> $Fixed<Frac> {
+            comment! {
+                "Widening multiplication. Returns the exact product as
+[`", $s_wider, "`], with as many fractional bits as the two operands
+together.
+
+The wider type has twice as many bits, so the product can never
+overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{
+    traits::FromFixed,
+    types::extra::{U2, U3, U5, U6},
+    ", $s_fixed, ", ", $s_wider, ",
+};
+let a = ", $s_fixed, "::<U3>::from_num(1.5);
+let b = ", $s_fixed, "::<U2>::from_num(2.75);
+let prod: ", $s_wider, "<U5> = a.wide_mul(b);
+assert_eq!(format!(\"{:.3}\", prod), \"4.125\");
+let max = ", $s_fixed, "::<U3>::max_value();
+// the product of the maxima is exact
+let max_wide = ", $s_wider, "::<U6>::from_fixed(max);
+assert_eq!(max.wide_mul(max), max_wide * max_wide);
+```
+
+[`", $s_wider, "`]: struct.", $s_wider, ".html
+";
+                #[inline]
+                pub fn wide_mul<RhsFrac>(self, rhs: $Fixed<RhsFrac>) -> $Wider<extra::Sum<Frac, RhsFrac>>
+                where
+                    RhsFrac: $LeEqU,
+                    Frac: Add<RhsFrac>,
+                    extra::Sum<Frac, RhsFrac>: $WiderLeEqU,
+                {
+                    $Wider::from_bits(self.to_bits().mul_widen(rhs.to_bits()))
+                }
+            }
+        }
+    };
+}
+
+fixed_wide_mul! { FixedI8["FixedI8"](LeEqU8), FixedI16["FixedI16"](LeEqU16) }
+fixed_wide_mul! { FixedI16["FixedI16"](LeEqU16), FixedI32["FixedI32"](LeEqU32) }
+fixed_wide_mul! { FixedI32["FixedI32"](LeEqU32), FixedI64["FixedI64"](LeEqU64) }
+fixed_wide_mul! { FixedI64["FixedI64"](LeEqU64), FixedI128["FixedI128"](LeEqU128) }
+fixed_wide_mul! { FixedI128["FixedI128"](LeEqU128), FixedI256["FixedI256"](LeEqU256) }
+fixed_wide_mul! { FixedU8["FixedU8"](LeEqU8), FixedU16["FixedU16"](LeEqU16) }
+fixed_wide_mul! { FixedU16["FixedU16"](LeEqU16), FixedU32["FixedU32"](LeEqU32) }
+fixed_wide_mul! { FixedU32["FixedU32"](LeEqU32), FixedU64["FixedU64"](LeEqU64) }
+fixed_wide_mul! { FixedU64["FixedU64"](LeEqU64), FixedU128["FixedU128"](LeEqU128) }
+fixed_wide_mul! { FixedU128["FixedU128"](LeEqU128), FixedU256["FixedU256"](LeEqU256) }
+
 pub(crate) trait MulDivOverflow: Sized {
     fn mul_overflow(self, rhs: Self, frac_nbits: u32) -> (Self, bool);
     fn div_overflow(self, rhs: Self, frac_nbits: u32) -> (Self, bool);
@@ -608,7 +709,7 @@ macro_rules! mul_div_fallback {
                     let col01 = ll_rl as <$Single as FallbackHelper>::Unsigned;
                     let (col01_hi, col01_lo) = col01.hi_lo();
                     let partial_col12 = lh_rl + col01_hi as $Single;
-                    let (col12, carry_col3) = partial_col12.carrying_add(ll_rh);
+                    let (col12, carry_col3) = FallbackHelper::carrying_add(partial_col12, ll_rh);
                     let (col12_hi, col12_lo) = col12.hi_lo();
                     let ans01 = col12_lo.shift_lo_up_unsigned() + col01_lo;
                     let ans23 = lh_rh + col12_hi + carry_col3.shift_lo_up();
@@ -648,6 +749,1131 @@ mul_div_widen! { i32, i64, Signed }
 mul_div_widen! { i64, i128, Signed }
 mul_div_fallback! { i128, u128, Signed }
 
+pub(crate) trait SqrtFrac: Sized {
+    // Returns the square root of the fixed-point number with frac_nbits
+    // fractional bits represented by self, rounded down, or None if self
+    // is negative or the root does not fit.
+    fn sqrt_frac(self, frac_nbits: u32) -> Option<Self>;
+}
+
+// Integer square root of the double word (hi, lo), rounded down.
+//
+// This works two bits at a time. The root fits in a single word, but the
+// remainder can grow to two bits more than that, so it is kept as a
+// double word.
+fn isqrt_double(hi: u128, lo: u128) -> u128 {
+    let nbits = if hi != 0 {
+        256 - hi.leading_zeros()
+    } else {
+        128 - lo.leading_zeros()
+    };
+    let mut root = 0u128;
+    let (mut rem_hi, mut rem_lo) = (0u128, 0u128);
+    let mut pairs = (nbits + 1) / 2;
+    while pairs > 0 {
+        pairs -= 1;
+        let shift = 2 * pairs;
+        let pair = if shift >= 128 {
+            (hi >> (shift - 128)) & 3
+        } else {
+            (lo >> shift) & 3
+        };
+        rem_hi = (rem_hi << 2) | (rem_lo >> 126);
+        rem_lo = (rem_lo << 2) | pair;
+        let (trial_hi, trial_lo) = (root >> 126, (root << 2) | 1);
+        if (rem_hi, rem_lo) >= (trial_hi, trial_lo) {
+            let (diff_lo, borrow) = rem_lo.overflowing_sub(trial_lo);
+            rem_hi = rem_hi - trial_hi - borrow as u128;
+            rem_lo = diff_lo;
+            root = (root << 1) | 1;
+        } else {
+            root <<= 1;
+        }
+    }
+    root
+}
+
+macro_rules! sqrt_frac {
+    ($Single:ty, $Uns:ty, $Signedness:tt) => {
+        impl SqrtFrac for $Single {
+            #[inline]
+            fn sqrt_frac(self, frac_nbits: u32) -> Option<$Single> {
+                if_signed! {
+                    $Signedness;
+                    if self < 0 {
+                        return None;
+                    }
+                }
+                // sqrt(bits / 2^f) = sqrt(bits * 2^f) / 2^f
+                let bits = self as $Uns as u128;
+                let (hi, lo) = match frac_nbits {
+                    0 => (0, bits),
+                    128 => (bits, 0),
+                    _ => (bits >> (128 - frac_nbits), bits << frac_nbits),
+                };
+                let root = isqrt_double(hi, lo);
+                if root > <$Single>::max_value() as u128 {
+                    None
+                } else {
+                    Some(root as $Single)
+                }
+            }
+        }
+    };
+}
+
+sqrt_frac! { u8, u8, Unsigned }
+sqrt_frac! { u16, u16, Unsigned }
+sqrt_frac! { u32, u32, Unsigned }
+sqrt_frac! { u64, u64, Unsigned }
+sqrt_frac! { u128, u128, Unsigned }
+sqrt_frac! { i8, u8, Signed }
+sqrt_frac! { i16, u16, Signed }
+sqrt_frac! { i32, u32, Signed }
+sqrt_frac! { i64, u64, Signed }
+sqrt_frac! { i128, u128, Signed }
+
+pub(crate) trait PowiFrac: Sized {
+    // Returns the fixed-point number with frac_nbits fractional bits
+    // represented by self raised to the integer power exp, rounded to
+    // the nearest, and whether an overflow occurred. On overflow, the
+    // wrapped value is returned. Returns None if self is zero and exp
+    // is negative.
+    fn powi_frac(self, exp: i32, frac_nbits: u32) -> Option<(Self, bool)>;
+}
+
+// Full 256-bit product of two u128 words as (hi, lo).
+pub(crate) fn mul_wide(lhs: u128, rhs: u128) -> (u128, u128) {
+    const LO: u128 = !0 >> 64;
+    let (lh, ll) = (lhs >> 64, lhs & LO);
+    let (rh, rl) = (rhs >> 64, rhs & LO);
+    let ll_rl = ll * rl;
+    let lh_rl = lh * rl;
+    let ll_rh = ll * rh;
+    let lh_rh = lh * rh;
+    let mid = (ll_rl >> 64) + (lh_rl & LO) + (ll_rh & LO);
+    let lo = (ll_rl & LO) | (mid << 64);
+    let hi = lh_rh + (lh_rl >> 64) + (ll_rh >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+// Values are handled as (mantissa, exponent) pairs representing
+// mantissa * 2^exponent, with the mantissa normalized so that its most
+// significant bit is set.
+pub(crate) type Mantissa = (u128, i64);
+
+pub(crate) fn mantissa_normalize(abs: u128) -> Mantissa {
+    debug_assert!(abs != 0);
+    let zeros = abs.leading_zeros();
+    (abs << zeros, -i64::from(zeros))
+}
+
+// Returns mantissa * 2^shift rounded to the nearest, wrapped to 128
+// bits, and whether it overflowed 128 bits.
+pub(crate) fn mantissa_to_int(val: Mantissa, shift: i64) -> (u128, bool) {
+    let m = val.0;
+    if shift >= 128 {
+        (0, true)
+    } else if shift > 0 {
+        (m << shift, true)
+    } else if shift == 0 {
+        (m, false)
+    } else if shift > -128 {
+        let rshift = (-shift) as u32;
+        ((m >> rshift) + ((m >> (rshift - 1)) & 1), false)
+    } else if shift == -128 {
+        // m >= 2^127, so this is at least one half
+        (1, false)
+    } else {
+        (0, false)
+    }
+}
+
+// Powers are computed on 256-bit mantissas as a lower and an upper
+// bound, with every operation rounded down for the lower bound and up
+// for the upper bound. Each operation has a relative error below
+// 2^-255, so the bounds of the nth power are at most a relative
+// n * 2^-253 apart, which is below 2^-222 for any i32 exponent. When
+// both bounds round to the same 128-bit number, that number is the
+// exact power correctly rounded; otherwise the exact power lies within
+// that distance of a tie and the lower bound is used, which can be one
+// unit in the last place off.
+type WideMantissa = (U256, i64);
+
+fn wide_normalize(abs: u128) -> WideMantissa {
+    let (m, exp) = mantissa_normalize(abs);
+    (U256::from_words(m, 0), exp - 128)
+}
+
+fn wide_round_up(m: U256, exp: i64, inexact: bool) -> WideMantissa {
+    if !inexact {
+        return (m, exp);
+    }
+    match m.overflowing_add(U256::ONE) {
+        (m, false) => (m, exp),
+        (_, true) => (U256::MSB, exp + 1),
+    }
+}
+
+fn wide_mul(lhs: WideMantissa, rhs: WideMantissa, up: bool) -> WideMantissa {
+    let (hi, lo) = lhs.0.mul_wide(rhs.0);
+    let exp = lhs.1 + rhs.1;
+    let (m, rest, exp) = if hi.leading_zeros() == 0 {
+        (hi, lo, exp + 256)
+    } else {
+        ((hi << 1) | (lo >> 255), lo << 1, exp + 255)
+    };
+    wide_round_up(m, exp, up && !rest.is_zero())
+}
+
+fn wide_recip(val: WideMantissa, up: bool) -> WideMantissa {
+    // 2^511 / m is in (2^255, 2^256]
+    let ((q1, q0), rem) = U256::div_rem_wide(U256::MSB, U256::ZERO, val.0);
+    if !q1.is_zero() {
+        return (U256::MSB, -510 - val.1);
+    }
+    wide_round_up(q0, -511 - val.1, up && !rem.is_zero())
+}
+
+fn wide_pow(base: WideMantissa, exp: u32, up: bool) -> WideMantissa {
+    debug_assert!(exp != 0);
+    // left to right, so that every intermediate power is at most the
+    // final power and only the mantissa needs to be multiplied
+    let mut acc = base;
+    let mut bit = 31 - exp.leading_zeros();
+    while bit > 0 {
+        bit -= 1;
+        acc = wide_mul(acc, acc, up);
+        if exp & (1 << bit) != 0 {
+            acc = wide_mul(acc, base, up);
+        }
+    }
+    acc
+}
+
+// Returns mantissa * 2^shift rounded to the nearest, wrapped to 128
+// bits, and whether it overflowed 128 bits.
+fn wide_to_int(val: WideMantissa, shift: i64) -> (u128, bool) {
+    let m = val.0;
+    if shift >= 0 {
+        let lo = if shift >= 128 {
+            0
+        } else {
+            m.to_words().1 << shift
+        };
+        (lo, true)
+    } else if shift > -256 {
+        let rshift = (-shift) as u32;
+        let (hi, lo) = (m >> rshift).to_words();
+        let round = (m >> (rshift - 1)).to_words().1 & 1;
+        let (int, carry) = lo.overflowing_add(round);
+        (int, hi != 0 || carry)
+    } else if shift == -256 {
+        // m >= 2^255, so this is at least one half
+        (1, false)
+    } else {
+        (0, false)
+    }
+}
+
+// On overflow, the bounds above only approximate the wrapped value, so
+// the power is formed exactly as a big integer of up to BIG_WORDS
+// 128-bit words, least significant first. Powers without fractional
+// bits need only their low 256 bits, whatever the exponent; others need
+// every bit down to the rounding bit, and beyond 8192 bits the wrapped
+// value is left approximate.
+const BIG_WORDS: usize = 64;
+type Big = [u128; BIG_WORDS];
+
+fn big_from_u256(val: U256) -> Big {
+    let mut big = [0; BIG_WORDS];
+    let (hi, lo) = val.to_words();
+    big[0] = lo;
+    big[1] = hi;
+    big
+}
+
+// lhs * rhs modulo 2^(128 len)
+fn big_mul(lhs: &Big, rhs: &Big, len: usize) -> Big {
+    let mut ans = [0; BIG_WORDS];
+    for i in 0..len {
+        if lhs[i] == 0 {
+            continue;
+        }
+        let mut carry = 0;
+        for j in 0..len - i {
+            // lhs[i] * rhs[j] + ans[i + j] + carry < 2^256
+            let (hi, lo) = mul_wide(lhs[i], rhs[j]);
+            let (sum, c0) = ans[i + j].overflowing_add(lo);
+            let (sum, c1) = sum.overflowing_add(carry);
+            ans[i + j] = sum;
+            carry = hi + u128::from(c0) + u128::from(c1);
+        }
+    }
+    ans
+}
+
+// base^exp modulo 2^(128 len), for exp > 0
+fn big_pow(base: U256, exp: u32, len: usize) -> Big {
+    let base = big_from_u256(base);
+    let mut acc = base;
+    let mut bit = 31 - exp.leading_zeros();
+    while bit > 0 {
+        bit -= 1;
+        acc = big_mul(&acc, &acc, len);
+        if exp & (1 << bit) != 0 {
+            acc = big_mul(&acc, &base, len);
+        }
+    }
+    acc
+}
+
+fn big_bit(val: &Big, pos: usize) -> bool {
+    let word = val.get(pos / 128).copied().unwrap_or(0);
+    (word >> (pos % 128)) & 1 != 0
+}
+
+// Returns val / 2^shift rounded to the nearest, wrapped to 256 bits.
+fn big_shr_round(val: &Big, shift: usize) -> U256 {
+    let word = |i: usize| val.get(i).copied().unwrap_or(0);
+    let bits_at = |pos: usize| {
+        let (i, bit) = (pos / 128, pos % 128);
+        if bit == 0 {
+            word(i)
+        } else {
+            (word(i) >> bit) | (word(i + 1) << (128 - bit))
+        }
+    };
+    let int = U256::from_words(bits_at(shift + 128), bits_at(shift));
+    if shift > 0 && big_bit(val, shift - 1) {
+        int.wrapping_add(U256::ONE)
+    } else {
+        int
+    }
+}
+
+// Returns 2^shift / divisor rounded to the nearest, wrapped to 256 bits,
+// computed one bit at a time. The divisor must have fewer than len
+// words, so that twice the remainder fits in len words.
+fn big_recip_round(divisor: &Big, shift: usize, len: usize) -> U256 {
+    let mut rem = [0u128; BIG_WORDS];
+    let mut q = U256::ZERO;
+    let ge = |lhs: &Big, rhs: &Big| {
+        for i in (0..len).rev() {
+            if lhs[i] != rhs[i] {
+                return lhs[i] > rhs[i];
+            }
+        }
+        true
+    };
+    let double = |val: &mut Big, low: bool| {
+        let mut carry = low;
+        for word in val.iter_mut().take(len) {
+            let next = *word >> 127 != 0;
+            *word = (*word << 1) | u128::from(carry);
+            carry = next;
+        }
+    };
+    let sub = |lhs: &mut Big, rhs: &Big| {
+        let mut borrow = false;
+        for i in 0..len {
+            let (diff, b0) = lhs[i].overflowing_sub(rhs[i]);
+            let (diff, b1) = diff.overflowing_sub(u128::from(borrow));
+            lhs[i] = diff;
+            borrow = b0 || b1;
+        }
+    };
+    for pos in (0..=shift).rev() {
+        double(&mut rem, pos == shift);
+        q = q << 1;
+        if ge(&rem, divisor) {
+            sub(&mut rem, divisor);
+            q = q | U256::ONE;
+        }
+    }
+    double(&mut rem, false);
+    if ge(&rem, divisor) {
+        q.wrapping_add(U256::ONE)
+    } else {
+        q
+    }
+}
+
+// Returns |bits / 2^frac_nbits|^exp * 2^frac_nbits rounded to the
+// nearest and wrapped to 256 bits, or None if that needs a big integer
+// of more than BIG_WORDS words. abs and exp must not be zero.
+fn powi_wrapped(abs: U256, exp: i32, frac_nbits: u32) -> Option<U256> {
+    let zeros = abs.trailing_zeros();
+    let odd = abs >> zeros;
+    let n = exp.wrapping_abs() as u32;
+    let (frac_nbits, zeros, n64) = (i64::from(frac_nbits), i64::from(zeros), i64::from(n));
+    let max_bits = (BIG_WORDS * 128) as i64;
+    if exp > 0 {
+        // odd^n * 2^shift with shift = zeros n - frac_nbits (n - 1)
+        let shift = zeros * n64 - frac_nbits * (n64 - 1);
+        if shift >= 0 {
+            if shift >= 256 {
+                return Some(U256::ZERO);
+            }
+            let mut pow = U256::ONE;
+            let mut bit = 32 - n.leading_zeros();
+            while bit > 0 {
+                bit -= 1;
+                pow = pow.wrapping_mul(pow);
+                if n & (1 << bit) != 0 {
+                    pow = pow.wrapping_mul(odd);
+                }
+            }
+            return Some(pow << shift as u32);
+        }
+        // the rounding bit and the 256 bits above it
+        let nbits = 256 - shift;
+        if nbits > max_bits {
+            return None;
+        }
+        let len = ((nbits + 127) / 128) as usize;
+        Some(big_shr_round(&big_pow(odd, n, len), (-shift) as usize))
+    } else {
+        // 2^shift / odd^n with shift = frac_nbits (n + 1) - zeros n
+        let shift = frac_nbits * (n64 + 1) - zeros * n64;
+        if shift < 0 {
+            return None;
+        }
+        if odd == U256::ONE {
+            return Some(if shift >= 256 {
+                U256::ZERO
+            } else {
+                U256::ONE << shift as u32
+            });
+        }
+        let pow_nbits = i64::from(256 - odd.leading_zeros()) * n64;
+        if pow_nbits.max(shift) + 1 > max_bits - 128 {
+            return None;
+        }
+        let len = ((pow_nbits.max(shift) + 1 + 127) / 128) as usize + 1;
+        let divisor = big_pow(odd, n, len);
+        Some(big_recip_round(&divisor, shift as usize, len))
+    }
+}
+
+// Returns |bits / 2^frac_nbits|^exp * 2^frac_nbits rounded to the
+// nearest, wrapped to 128 bits, and whether it overflowed 128 bits.
+fn powi_abs(abs: u128, exp: i32, frac_nbits: u32) -> Option<(u128, bool)> {
+    let frac_nbits = i64::from(frac_nbits);
+    if exp == 0 {
+        return Some(mantissa_to_int((1 << 127, 0), frac_nbits - 127));
+    }
+    if abs == 0 {
+        return if exp < 0 { None } else { Some((0, false)) };
+    }
+    let n = exp.wrapping_abs() as u32;
+    let base = wide_normalize(abs);
+    let (lower, upper) = (wide_pow(base, n, false), wide_pow(base, n, true));
+    let n = i64::from(n);
+    let (lower, upper) = if exp > 0 {
+        // abs^n / 2^(f n) * 2^f
+        let shift = -frac_nbits * (n - 1);
+        (
+            wide_to_int(lower, lower.1 + shift),
+            wide_to_int(upper, upper.1 + shift),
+        )
+    } else {
+        // 2^(f n) / abs^n * 2^f, where the larger power gives the lower bound
+        let shift = frac_nbits * (n + 1);
+        let (lower, upper) = (wide_recip(upper, false), wide_recip(lower, true));
+        (
+            wide_to_int(lower, lower.1 + shift),
+            wide_to_int(upper, upper.1 + shift),
+        )
+    };
+    if lower.1 || upper.1 {
+        let wrapped = powi_wrapped(U256::from(abs), exp, frac_nbits as u32);
+        return Some((wrapped.map_or(lower.0, |w| w.to_words().1), true));
+    }
+    debug_assert!(lower == upper || lower.0.wrapping_add(1) == upper.0);
+    Some(lower)
+}
+
+macro_rules! powi_frac {
+    ($Single:ty, $Uns:ty, $Signedness:tt) => {
+        impl PowiFrac for $Single {
+            #[inline]
+            fn powi_frac(self, exp: i32, frac_nbits: u32) -> Option<($Single, bool)> {
+                let neg = if_signed_unsigned! {
+                    $Signedness,
+                    self < 0 && exp & 1 != 0,
+                    false
+                };
+                let abs = if_signed_unsigned! {
+                    $Signedness,
+                    if self < 0 {
+                        (self as $Uns).wrapping_neg()
+                    } else {
+                        self as $Uns
+                    },
+                    self
+                };
+                let (abs, overflow128) = powi_abs(u128::from(abs), exp, frac_nbits)?;
+                let max_abs = if_signed_unsigned! {
+                    $Signedness,
+                    <$Single>::max_value() as u128 + if neg { 1 } else { 0 },
+                    <$Single>::max_value() as u128
+                };
+                let overflow = overflow128 || abs > max_abs;
+                let bits = abs as $Uns;
+                let bits = if neg { bits.wrapping_neg() } else { bits };
+                Some((bits as $Single, overflow))
+            }
+        }
+    };
+}
+
+powi_frac! { u8, u8, Unsigned }
+powi_frac! { u16, u16, Unsigned }
+powi_frac! { u32, u32, Unsigned }
+powi_frac! { u64, u64, Unsigned }
+powi_frac! { u128, u128, Unsigned }
+powi_frac! { i8, u8, Signed }
+powi_frac! { i16, u16, Signed }
+powi_frac! { i32, u32, Signed }
+powi_frac! { i64, u64, Signed }
+powi_frac! { i128, u128, Signed }
+
+/// The rounding mode used by [`mul_round`], [`div_round`] and their
+/// checked, saturating, wrapping and overflowing variants.
+///
+/// The plain multiplication operator rounds toward −∞ and the plain
+/// division operator rounds toward zero. An explicit rounding mode
+/// makes it possible to choose the direction, for example to always
+/// round a fee up.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::{types::extra::U4, FixedI8, RoundingMode};
+/// type Fix = FixedI8<U4>;
+/// let a = Fix::from_bits(-0b101);
+/// let half = Fix::from_num(0.5);
+/// // the exact product is −2.5 ulps
+/// assert_eq!(a.mul_round(half, RoundingMode::Floor), Fix::from_bits(-3));
+/// assert_eq!(a.mul_round(half, RoundingMode::Ceil), Fix::from_bits(-2));
+/// assert_eq!(a.mul_round(half, RoundingMode::TowardZero), Fix::from_bits(-2));
+/// assert_eq!(a.mul_round(half, RoundingMode::HalfUp), Fix::from_bits(-3));
+/// assert_eq!(a.mul_round(half, RoundingMode::HalfEven), Fix::from_bits(-2));
+/// ```
+///
+/// [`div_round`]: struct.FixedI32.html#method.div_round
+/// [`mul_round`]: struct.FixedI32.html#method.mul_round
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RoundingMode {
+    /// Round toward −∞.
+    Floor,
+    /// Round toward +∞.
+    Ceil,
+    /// Round toward zero.
+    TowardZero,
+    /// Round to the nearest, with ties rounded away from zero.
+    HalfUp,
+    /// Round to the nearest, with ties rounded to even.
+    HalfEven,
+}
+
+pub(crate) trait MulDivRound: Sized {
+    // Returns the product rounded using mode, and whether an overflow
+    // occurred. On overflow, the wrapped value is returned.
+    fn mul_round(self, rhs: Self, frac_nbits: u32, mode: RoundingMode) -> (Self, bool);
+    // Like mul_round, but for the quotient. Panics if rhs is zero.
+    fn div_round(self, rhs: Self, frac_nbits: u32, mode: RoundingMode) -> (Self, bool);
+    // Returns self * mul / div rounded using mode, keeping the whole
+    // double-width product. The fractional bits cancel out, so no
+    // frac_nbits is needed. Panics if div is zero.
+    fn mul_div_round(self, mul: Self, div: Self, mode: RoundingMode) -> (Self, bool);
+}
+
+// Returns whether the truncated absolute value has to be incremented,
+// where half is the ordering of the discarded part relative to one
+// half of the last kept bit.
+fn round_up_abs(mode: RoundingMode, neg: bool, odd: bool, inexact: bool, half: Ordering) -> bool {
+    match mode {
+        RoundingMode::Floor => neg && inexact,
+        RoundingMode::Ceil => !neg && inexact,
+        RoundingMode::TowardZero => false,
+        RoundingMode::HalfUp => half != Ordering::Less,
+        RoundingMode::HalfEven => half == Ordering::Greater || (half == Ordering::Equal && odd),
+    }
+}
+
+// Returns |lhs * rhs| / 2^frac_nbits rounded using mode, wrapped to
+// 128 bits, and whether it overflowed 128 bits.
+fn mul_round_abs(
+    lhs: u128,
+    rhs: u128,
+    frac_nbits: u32,
+    neg: bool,
+    mode: RoundingMode,
+) -> (u128, bool) {
+    shr_round_abs(mul_wide(lhs, rhs), frac_nbits as i32, neg, mode)
+}
+
+// Returns the 256-bit (hi, lo) shifted right by shift, which is in the
+// range −128 ≤ shift ≤ 256, rounded using mode, wrapped to 128 bits,
+// and whether it overflowed 128 bits.
+fn shr_round_abs(val: (u128, u128), shift: i32, neg: bool, mode: RoundingMode) -> (u128, bool) {
+    let (hi, lo) = val;
+    if shift <= 0 {
+        let lshift = (-shift) as u32;
+        return match lshift {
+            0 => (lo, hi != 0),
+            128 => (0, hi != 0 || lo != 0),
+            _ => (lo << lshift, hi != 0 || lo >> (128 - lshift) != 0),
+        };
+    }
+    let shift = shift as u32;
+    // rem and half are 256-bit (hi, lo) pairs, so they compare correctly
+    let (q1, q0, rem, half) = if shift < 128 {
+        (
+            hi >> shift,
+            (hi << (128 - shift)) | (lo >> shift),
+            (0, lo & (!0 >> (128 - shift))),
+            (0, 1 << (shift - 1)),
+        )
+    } else if shift == 128 {
+        (0, hi, (0, lo), (0, 1 << 127))
+    } else if shift < 256 {
+        (
+            0,
+            hi >> (shift - 128),
+            (hi & (!0 >> (256 - shift)), lo),
+            (1 << (shift - 129), 0),
+        )
+    } else {
+        (0, 0, (hi, lo), (1 << 127, 0))
+    };
+    let inexact = rem != (0, 0);
+    let half = if inexact {
+        rem.cmp(&half)
+    } else {
+        Ordering::Less
+    };
+    let up = round_up_abs(mode, neg, q0 & 1 != 0, inexact, half);
+    let (q0, carry) = q0.overflowing_add(if up { 1 } else { 0 });
+    (q0, q1 != 0 || carry)
+}
+
+// Like shr_round_abs, but for the 512-bit (hi, lo) of two U256 words,
+// with −256 ≤ shift ≤ 256, wrapping to 256 bits.
+pub(crate) fn shr_round_abs_256(
+    val: (U256, U256),
+    shift: i32,
+    neg: bool,
+    mode: RoundingMode,
+) -> (U256, bool) {
+    let (hi, lo) = val;
+    if shift <= 0 {
+        let lshift = (-shift) as u32;
+        return match lshift {
+            0 => (lo, !hi.is_zero()),
+            256 => (U256::ZERO, !hi.is_zero() || !lo.is_zero()),
+            _ => (
+                lo << lshift,
+                !hi.is_zero() || !(lo >> (256 - lshift)).is_zero(),
+            ),
+        };
+    }
+    let shift = shift as u32;
+    let (q1, q0, rem, half) = if shift < 256 {
+        (
+            hi >> shift,
+            (hi << (256 - shift)) | (lo >> shift),
+            lo & (U256::max_value() >> (256 - shift)),
+            U256::ONE << (shift - 1),
+        )
+    } else {
+        (U256::ZERO, hi, lo, U256::MSB)
+    };
+    let inexact = !rem.is_zero();
+    let half = if inexact {
+        rem.cmp(&half)
+    } else {
+        Ordering::Less
+    };
+    let up = round_up_abs(mode, neg, q0.is_odd(), inexact, half);
+    let (q0, carry) = q0.overflowing_add(if up { U256::ONE } else { U256::ZERO });
+    (q0, !q1.is_zero() || carry)
+}
+
+// Returns lhs * rhs converted to Dst, where the operands are given as
+// sign and magnitude and frac_nbits is the sum of their fractional
+// bits, rounded to the nearest with ties rounded to even, wrapping on
+// overflow.
+pub(crate) fn overflowing_mul_to<Dst>(
+    lhs: (bool, U256),
+    rhs: (bool, U256),
+    frac_nbits: u32,
+) -> (Dst, bool)
+where
+    Dst: Fixed,
+{
+    let neg = lhs.0 != rhs.0;
+    let shift = frac_nbits as i32 - Dst::frac_nbits() as i32;
+    let prod = lhs.1.mul_wide(rhs.1);
+    let (abs, overflow256) = shr_round_abs_256(prod, shift, neg, RoundingMode::HalfEven);
+    let (ans, overflow) = Dst::private_overflowing_from_neg_abs(neg, abs);
+    (ans, overflow256 || overflow)
+}
+
+// Returns |lhs| * 2^frac_nbits / |rhs| rounded using mode, wrapped to
+// 128 bits, and whether it overflowed 128 bits.
+fn div_round_abs(
+    lhs: u128,
+    rhs: u128,
+    frac_nbits: u32,
+    neg: bool,
+    mode: RoundingMode,
+) -> (u128, bool) {
+    let dividend = match frac_nbits {
+        0 => (0, lhs),
+        128 => (lhs, 0),
+        _ => (lhs >> (128 - frac_nbits), lhs << frac_nbits),
+    };
+    div_wide_round_abs(dividend, rhs, neg, mode)
+}
+
+// Returns the 256-bit dividend divided by divisor rounded using mode,
+// wrapped to 128 bits, and whether it overflowed 128 bits.
+fn div_wide_round_abs(
+    dividend: (u128, u128),
+    divisor: u128,
+    neg: bool,
+    mode: RoundingMode,
+) -> (u128, bool) {
+    let ((q1, q0), rem) = divisor.div_rem_from(dividend);
+    let inexact = rem != 0;
+    let half = if inexact {
+        rem.cmp(&(divisor - rem))
+    } else {
+        Ordering::Less
+    };
+    let up = round_up_abs(mode, neg, q0 & 1 != 0, inexact, half);
+    let (q0, carry) = q0.overflowing_add(if up { 1 } else { 0 });
+    (q0, q1 != 0 || carry)
+}
+
+macro_rules! mul_div_round {
+    ($Single:ty, $Uns:ty, $Signedness:tt) => {
+        impl MulDivRound for $Single {
+            #[inline]
+            fn mul_round(
+                self,
+                rhs: $Single,
+                frac_nbits: u32,
+                mode: RoundingMode,
+            ) -> ($Single, bool) {
+                let (lhs_neg, lhs_abs) = self.neg_abs();
+                let (rhs_neg, rhs_abs) = rhs.neg_abs();
+                let neg = lhs_neg != rhs_neg;
+                let (abs, overflow128) = mul_round_abs(
+                    u128::from(lhs_abs),
+                    u128::from(rhs_abs),
+                    frac_nbits,
+                    neg,
+                    mode,
+                );
+                mul_div_round!(@from_abs $Single, $Uns, $Signedness, neg, abs, overflow128)
+            }
+
+            #[inline]
+            fn div_round(
+                self,
+                rhs: $Single,
+                frac_nbits: u32,
+                mode: RoundingMode,
+            ) -> ($Single, bool) {
+                if rhs == 0 {
+                    panic!("division by zero");
+                }
+                let (lhs_neg, lhs_abs) = self.neg_abs();
+                let (rhs_neg, rhs_abs) = rhs.neg_abs();
+                let neg = lhs_neg != rhs_neg;
+                let (abs, overflow128) = div_round_abs(
+                    u128::from(lhs_abs),
+                    u128::from(rhs_abs),
+                    frac_nbits,
+                    neg,
+                    mode,
+                );
+                mul_div_round!(@from_abs $Single, $Uns, $Signedness, neg, abs, overflow128)
+            }
+
+            #[inline]
+            fn mul_div_round(
+                self,
+                mul: $Single,
+                div: $Single,
+                mode: RoundingMode,
+            ) -> ($Single, bool) {
+                if div == 0 {
+                    panic!("division by zero");
+                }
+                let (lhs_neg, lhs_abs) = self.neg_abs();
+                let (mul_neg, mul_abs) = mul.neg_abs();
+                let (div_neg, div_abs) = div.neg_abs();
+                let neg = (lhs_neg != mul_neg) != div_neg;
+                let prod = mul_wide(u128::from(lhs_abs), u128::from(mul_abs));
+                let (abs, overflow128) = div_wide_round_abs(prod, u128::from(div_abs), neg, mode);
+                mul_div_round!(@from_abs $Single, $Uns, $Signedness, neg, abs, overflow128)
+            }
+        }
+    };
+    (@from_abs $Single:ty, $Uns:ty, $Signedness:tt, $neg:ident, $abs:ident, $overflow128:ident) => {{
+        let max_abs = if_signed_unsigned! {
+            $Signedness,
+            <$Single>::max_value() as u128 + if $neg { 1 } else { 0 },
+            <$Single>::max_value() as u128
+        };
+        let overflow = $overflow128 || $abs > max_abs;
+        let bits = $abs as $Uns;
+        let bits = if $neg { bits.wrapping_neg() } else { bits };
+        (bits as $Single, overflow)
+    }};
+}
+
+mul_div_round! { u8, u8, Unsigned }
+mul_div_round! { u16, u16, Unsigned }
+mul_div_round! { u32, u32, Unsigned }
+mul_div_round! { u64, u64, Unsigned }
+mul_div_round! { u128, u128, Unsigned }
+mul_div_round! { i8, u8, Signed }
+mul_div_round! { i16, u16, Signed }
+mul_div_round! { i32, u32, Signed }
+mul_div_round! { i64, u64, Signed }
+mul_div_round! { i128, u128, Signed }
+
+// The 256-bit types work on 512-bit intermediate values stored as
+// (hi, lo) pairs of U256 words, otherwise following the narrower types.
+
+// Returns the 512-bit dividend divided by divisor rounded using mode,
+// wrapped to 256 bits, and whether it overflowed 256 bits.
+fn div_wide_round_abs_256(
+    dividend: (U256, U256),
+    divisor: U256,
+    neg: bool,
+    mode: RoundingMode,
+) -> (U256, bool) {
+    let ((q1, q0), rem) = U256::div_rem_wide(dividend.0, dividend.1, divisor);
+    let inexact = !rem.is_zero();
+    let half = if inexact {
+        rem.cmp(&divisor.wrapping_sub(rem))
+    } else {
+        Ordering::Less
+    };
+    let up = round_up_abs(mode, neg, q0.is_odd(), inexact, half);
+    let (q0, carry) = q0.overflowing_add(if up { U256::ONE } else { U256::ZERO });
+    (q0, !q1.is_zero() || carry)
+}
+
+// Returns abs * 2^frac_nbits as a 512-bit (hi, lo) pair.
+fn shl_wide_256(abs: U256, frac_nbits: u32) -> (U256, U256) {
+    match frac_nbits {
+        0 => (U256::ZERO, abs),
+        256 => (abs, U256::ZERO),
+        _ => (abs >> (256 - frac_nbits), abs << frac_nbits),
+    }
+}
+
+// Like isqrt_double, but for the 512-bit (hi, lo).
+fn isqrt_double_256(hi: U256, lo: U256) -> U256 {
+    let nbits = if !hi.is_zero() {
+        512 - hi.leading_zeros()
+    } else {
+        256 - lo.leading_zeros()
+    };
+    let three = U256::from(3u8);
+    let mut root = U256::ZERO;
+    let (mut rem_hi, mut rem_lo) = (U256::ZERO, U256::ZERO);
+    let mut pairs = (nbits + 1) / 2;
+    while pairs > 0 {
+        pairs -= 1;
+        let shift = 2 * pairs;
+        let pair = if shift >= 256 {
+            (hi >> (shift - 256)) & three
+        } else {
+            (lo >> shift) & three
+        };
+        rem_hi = (rem_hi << 2) | (rem_lo >> 254);
+        rem_lo = (rem_lo << 2) | pair;
+        let (trial_hi, trial_lo) = (root >> 254, (root << 2) | U256::ONE);
+        if (rem_hi, rem_lo) >= (trial_hi, trial_lo) {
+            let (diff_lo, borrow) = rem_lo.overflowing_sub(trial_lo);
+            rem_hi = rem_hi.wrapping_sub(trial_hi);
+            if borrow {
+                rem_hi = rem_hi.wrapping_sub(U256::ONE);
+            }
+            rem_lo = diff_lo;
+            root = (root << 1) | U256::ONE;
+        } else {
+            root = root << 1;
+        }
+    }
+    root
+}
+
+// Powers of 256-bit numbers use 512-bit mantissas for the bounds, so
+// that the error analysis of WideMantissa carries over.
+type Mantissa512 = ((U256, U256), i64);
+
+fn mantissa512_normalize(abs: U256) -> Mantissa512 {
+    debug_assert!(!abs.is_zero());
+    let zeros = abs.leading_zeros();
+    ((abs << zeros, U256::ZERO), -i64::from(zeros) - 256)
+}
+
+fn mantissa512_round_up(m: (U256, U256), exp: i64, inexact: bool) -> Mantissa512 {
+    if !inexact {
+        return (m, exp);
+    }
+    let (lo, carry) = m.1.overflowing_add(U256::ONE);
+    if !carry {
+        return ((m.0, lo), exp);
+    }
+    match m.0.overflowing_add(U256::ONE) {
+        (hi, false) => ((hi, lo), exp),
+        (_, true) => ((U256::MSB, U256::ZERO), exp + 1),
+    }
+}
+
+fn add_count_carry(lhs: U256, rhs: U256, carries: &mut u8) -> U256 {
+    let (sum, carry) = lhs.overflowing_add(rhs);
+    if carry {
+        *carries += 1;
+    }
+    sum
+}
+
+fn mantissa512_mul(lhs: Mantissa512, rhs: Mantissa512, up: bool) -> Mantissa512 {
+    let ((ah, al), (bh, bl)) = (lhs.0, rhs.0);
+    let (p0h, w0) = al.mul_wide(bl);
+    let (p1h, p1l) = ah.mul_wide(bl);
+    let (p2h, p2l) = al.mul_wide(bh);
+    let (p3h, p3l) = ah.mul_wide(bh);
+    // the 1024-bit product is (w3, w2, w1, w0)
+    let mut c1 = 0;
+    let w1 = add_count_carry(p0h, p1l, &mut c1);
+    let w1 = add_count_carry(w1, p2l, &mut c1);
+    let mut c2 = 0;
+    let w2 = add_count_carry(p1h, p2h, &mut c2);
+    let w2 = add_count_carry(w2, p3l, &mut c2);
+    let w2 = add_count_carry(w2, U256::from(c1), &mut c2);
+    let w3 = p3h.wrapping_add(U256::from(c2));
+    let exp = lhs.1 + rhs.1;
+    let (m, rest_is_zero, exp) = if w3.leading_zeros() == 0 {
+        ((w3, w2), w1.is_zero() && w0.is_zero(), exp + 512)
+    } else {
+        (
+            ((w3 << 1) | (w2 >> 255), (w2 << 1) | (w1 >> 255)),
+            (w1 << 1).is_zero() && w0.is_zero(),
+            exp + 511,
+        )
+    };
+    mantissa512_round_up(m, exp, up && !rest_is_zero)
+}
+
+fn mantissa512_recip(val: Mantissa512, up: bool) -> Mantissa512 {
+    let m = val.0;
+    if m == (U256::MSB, U256::ZERO) {
+        return ((U256::MSB, U256::ZERO), -1022 - val.1);
+    }
+    // 2^1023 / m is in (2^511, 2^512), computed one bit at a time
+    let mut rem = (U256::MSB, U256::ZERO);
+    let mut q = (U256::ZERO, U256::ZERO);
+    for _ in 0..512 {
+        let carry = rem.0.leading_zeros() == 0;
+        rem = ((rem.0 << 1) | (rem.1 >> 255), rem.1 << 1);
+        q = ((q.0 << 1) | (q.1 >> 255), q.1 << 1);
+        if carry || rem >= m {
+            let (lo, borrow) = rem.1.overflowing_sub(m.1);
+            let mut hi = rem.0.wrapping_sub(m.0);
+            if borrow {
+                hi = hi.wrapping_sub(U256::ONE);
+            }
+            rem = (hi, lo);
+            q.1 = q.1 | U256::ONE;
+        }
+    }
+    let inexact = !rem.0.is_zero() || !rem.1.is_zero();
+    mantissa512_round_up(q, -1023 - val.1, up && inexact)
+}
+
+fn mantissa512_pow(base: Mantissa512, exp: u32, up: bool) -> Mantissa512 {
+    debug_assert!(exp != 0);
+    let mut acc = base;
+    let mut bit = 31 - exp.leading_zeros();
+    while bit > 0 {
+        bit -= 1;
+        acc = mantissa512_mul(acc, acc, up);
+        if exp & (1 << bit) != 0 {
+            acc = mantissa512_mul(acc, base, up);
+        }
+    }
+    acc
+}
+
+// Returns mantissa * 2^shift rounded to the nearest, wrapped to 256
+// bits, and whether it overflowed 256 bits.
+fn mantissa512_to_int(val: Mantissa512, shift: i64) -> (U256, bool) {
+    let (hi, lo) = val.0;
+    if shift >= 0 {
+        let lo = if shift >= 256 {
+            U256::ZERO
+        } else {
+            lo << shift as u32
+        };
+        (lo, true)
+    } else if shift > -512 {
+        let rshift = (-shift) as u32;
+        let (q1, q0) = if rshift < 256 {
+            (hi >> rshift, (lo >> rshift) | (hi << (256 - rshift)))
+        } else if rshift == 256 {
+            (U256::ZERO, hi)
+        } else {
+            (U256::ZERO, hi >> (rshift - 256))
+        };
+        let round = if rshift - 1 < 256 {
+            (lo >> (rshift - 1)).is_odd()
+        } else {
+            (hi >> (rshift - 257)).is_odd()
+        };
+        let (int, carry) = q0.overflowing_add(if round { U256::ONE } else { U256::ZERO });
+        (int, !q1.is_zero() || carry)
+    } else if shift == -512 {
+        // the mantissa is at least 2^511, so this is at least one half
+        (U256::ONE, false)
+    } else {
+        (U256::ZERO, false)
+    }
+}
+
+// Like powi_abs, but for 256-bit magnitudes.
+fn powi_abs_256(abs: U256, exp: i32, frac_nbits: u32) -> Option<(U256, bool)> {
+    let frac_nbits = i64::from(frac_nbits);
+    if exp == 0 {
+        let one = ((U256::MSB, U256::ZERO), 0);
+        return Some(mantissa512_to_int(one, frac_nbits - 511));
+    }
+    if abs.is_zero() {
+        return if exp < 0 {
+            None
+        } else {
+            Some((U256::ZERO, false))
+        };
+    }
+    let n = exp.wrapping_abs() as u32;
+    let base = mantissa512_normalize(abs);
+    let (lower, upper) = (
+        mantissa512_pow(base, n, false),
+        mantissa512_pow(base, n, true),
+    );
+    let n = i64::from(n);
+    let (lower, upper) = if exp > 0 {
+        let shift = -frac_nbits * (n - 1);
+        (
+            mantissa512_to_int(lower, lower.1 + shift),
+            mantissa512_to_int(upper, upper.1 + shift),
+        )
+    } else {
+        let shift = frac_nbits * (n + 1);
+        let (lower, upper) = (
+            mantissa512_recip(upper, false),
+            mantissa512_recip(lower, true),
+        );
+        (
+            mantissa512_to_int(lower, lower.1 + shift),
+            mantissa512_to_int(upper, upper.1 + shift),
+        )
+    };
+    if lower.1 || upper.1 {
+        let wrapped = powi_wrapped(abs, exp, frac_nbits as u32);
+        return Some((wrapped.unwrap_or(lower.0), true));
+    }
+    debug_assert!(lower == upper || lower.0.wrapping_add(U256::ONE) == upper.0);
+    Some(lower)
+}
+
+macro_rules! frac_256 {
+    ($Int:ident) => {
+        impl SqrtFrac for $Int {
+            #[inline]
+            fn sqrt_frac(self, frac_nbits: u32) -> Option<$Int> {
+                let (neg, abs) = self.neg_abs();
+                if neg {
+                    return None;
+                }
+                let (hi, lo) = shl_wide_256(abs, frac_nbits);
+                match $Int::overflowing_from_neg_abs(false, isqrt_double_256(hi, lo)) {
+                    (root, false) => Some(root),
+                    (_, true) => None,
+                }
+            }
+        }
+
+        impl PowiFrac for $Int {
+            #[inline]
+            fn powi_frac(self, exp: i32, frac_nbits: u32) -> Option<($Int, bool)> {
+                let (neg, abs) = self.neg_abs();
+                let neg = neg && exp & 1 != 0;
+                let (abs, overflow256) = powi_abs_256(abs, exp, frac_nbits)?;
+                let (ans, overflow) = $Int::overflowing_from_neg_abs(neg, abs);
+                Some((ans, overflow256 || overflow))
+            }
+        }
+
+        impl MulDivRound for $Int {
+            #[inline]
+            fn mul_round(self, rhs: $Int, frac_nbits: u32, mode: RoundingMode) -> ($Int, bool) {
+                let (lhs_neg, lhs_abs) = self.neg_abs();
+                let (rhs_neg, rhs_abs) = rhs.neg_abs();
+                let neg = lhs_neg != rhs_neg;
+                let prod = lhs_abs.mul_wide(rhs_abs);
+                let (abs, overflow256) = shr_round_abs_256(prod, frac_nbits as i32, neg, mode);
+                let (ans, overflow) = $Int::overflowing_from_neg_abs(neg, abs);
+                (ans, overflow256 || overflow)
+            }
+
+            #[inline]
+            fn div_round(self, rhs: $Int, frac_nbits: u32, mode: RoundingMode) -> ($Int, bool) {
+                let (lhs_neg, lhs_abs) = self.neg_abs();
+                let (rhs_neg, rhs_abs) = rhs.neg_abs();
+                if rhs_abs.is_zero() {
+                    panic!("division by zero");
+                }
+                let neg = lhs_neg != rhs_neg;
+                let dividend = shl_wide_256(lhs_abs, frac_nbits);
+                let (abs, overflow256) = div_wide_round_abs_256(dividend, rhs_abs, neg, mode);
+                let (ans, overflow) = $Int::overflowing_from_neg_abs(neg, abs);
+                (ans, overflow256 || overflow)
+            }
+
+            #[inline]
+            fn mul_div_round(self, mul: $Int, div: $Int, mode: RoundingMode) -> ($Int, bool) {
+                let (lhs_neg, lhs_abs) = self.neg_abs();
+                let (mul_neg, mul_abs) = mul.neg_abs();
+                let (div_neg, div_abs) = div.neg_abs();
+                if div_abs.is_zero() {
+                    panic!("division by zero");
+                }
+                let neg = (lhs_neg != mul_neg) != div_neg;
+                let prod = lhs_abs.mul_wide(mul_abs);
+                let (abs, overflow256) = div_wide_round_abs_256(prod, div_abs, neg, mode);
+                let (ans, overflow) = $Int::overflowing_from_neg_abs(neg, abs);
+                (ans, overflow256 || overflow)
+            }
+        }
+    };
+}
+
+frac_256! { U256 }
+frac_256! { I256 }
+
 #[cfg(test)]
 #[allow(clippy::cognitive_complexity)]
 mod tests {
@@ -888,4 +2114,652 @@ mod tests {
         assert_eq!(i0(0.25) % 1, i0(0.25));
         assert_eq!(i0(0.25).rem_euclid_int(1), i0(0.25));
     }
+
+    #[test]
+    fn sqrt_exhaustive_16() {
+        use crate::types::extra::{U0, U16, U7};
+        for bits in 0..=0xffff_u16 {
+            for &frac in &[0, 7, 16] {
+                let wide = u64::from(bits) << frac;
+                let root = match frac {
+                    0 => FixedU16::<U0>::from_bits(bits).sqrt().to_bits(),
+                    7 => FixedU16::<U7>::from_bits(bits).sqrt().to_bits(),
+                    _ => FixedU16::<U16>::from_bits(bits).sqrt().to_bits(),
+                };
+                let root = u64::from(root);
+                assert!(root * root <= wide && (root + 1) * (root + 1) > wide);
+            }
+            let signed = FixedI16::<U7>::from_bits(bits as i16);
+            if signed < 0 {
+                assert_eq!(signed.checked_sqrt(), None);
+            } else {
+                let root = FixedU16::<U7>::from_bits(bits).sqrt();
+                assert_eq!(signed.sqrt().to_bits() as u16, root.to_bits());
+            }
+        }
+        // no integer bits, so sqrt(0.25) = 0.5 does not fit
+        assert_eq!(FixedI16::<U16>::from_num(0.25).checked_sqrt(), None);
+        assert!(FixedI16::<U16>::from_num(0.2).checked_sqrt().is_some());
+    }
+
+    #[test]
+    fn sqrt_64() {
+        use crate::types::extra::{U32, U64};
+        let mut bits = 1u64;
+        while bits != 0 {
+            for &x in &[bits, bits - 1, bits.wrapping_mul(0x9e37_79b9_7f4a_7c15)] {
+                let root = FixedU64::<U32>::from_bits(x).sqrt().to_bits();
+                let wide = u128::from(x) << 32;
+                let (root, next) = (u128::from(root), u128::from(root) + 1);
+                assert!(root * root <= wide && next * next > wide);
+                let root = FixedU64::<U64>::from_bits(x).sqrt().to_bits();
+                let wide = u128::from(x) << 64;
+                let (root, next) = (u128::from(root), u128::from(root) + 1);
+                assert!(root * root <= wide);
+                if let Some(square) = next.checked_mul(next) {
+                    assert!(square > wide);
+                }
+            }
+            bits <<= 1;
+        }
+    }
+
+    #[test]
+    fn sqrt_128() {
+        use crate::types::extra::{U0, U125, U126, U127, U128, U64};
+        // the constants are rounded down, so their truncations are the floor roots
+        assert_eq!(
+            FixedU128::<U64>::from_num(2).sqrt(),
+            FixedU128::<U64>::from_num(consts::SQRT_2)
+        );
+        assert_eq!(
+            FixedU128::<U126>::from_num(2).sqrt(),
+            FixedU128::<U126>::from_num(consts::SQRT_2)
+        );
+        assert_eq!(
+            FixedU128::<U128>::from_num(0.5).sqrt(),
+            FixedU128::<U128>::from_num(consts::FRAC_1_SQRT_2)
+        );
+        assert_eq!(
+            FixedI128::<U125>::from_num(2).sqrt(),
+            FixedI128::<U125>::from_num(consts::SQRT_2)
+        );
+        assert_eq!(FixedI128::<U125>::from_num(-2).checked_sqrt(), None);
+        // I1F127 cannot hold 2, but holds sqrt(0.5)
+        assert_eq!(
+            FixedI128::<U127>::from_num(0.5).sqrt(),
+            FixedI128::<U127>::from_num(consts::FRAC_1_SQRT_2)
+        );
+        assert_eq!(
+            FixedU128::<U0>::max_value().sqrt(),
+            FixedU128::<U0>::from_bits(u128::from(!0u64))
+        );
+        assert_eq!(
+            FixedU128::<U128>::max_value().sqrt(),
+            FixedU128::<U128>::max_value()
+        );
+        let square = FixedU128::<U64>::from_bits(0x1234_5678_9abc_def0_0000_0000);
+        assert_eq!((square * square).sqrt(), square);
+    }
+
+    #[test]
+    fn powi_exhaustive_8() {
+        use crate::types::extra::U4;
+        for bits in 0..=0xff_u8 {
+            let signed = FixedI8::<U4>::from_bits(bits as i8);
+            let unsigned = FixedU8::<U4>::from_bits(bits);
+            for exp in -5..=5 {
+                if bits == 0 && exp < 0 {
+                    assert_eq!(signed.checked_powi(exp), None);
+                    assert_eq!(unsigned.checked_powi(exp), None);
+                    continue;
+                }
+                // exact in f64, so rounding once gives the expected bits
+                let expected = (f64::from(bits as i8) / 16.0).powi(exp) * 16.0;
+                let expected = expected.round() as i64;
+                let fits = (-0x80..0x80).contains(&expected);
+                assert_eq!(
+                    signed.overflowing_powi(exp),
+                    (FixedI8::from_bits(expected as i8), !fits)
+                );
+                let expected = (f64::from(bits) / 16.0).powi(exp) * 16.0;
+                let expected = expected.round() as i64;
+                let fits = expected < 0x100;
+                assert_eq!(
+                    unsigned.overflowing_powi(exp),
+                    (FixedU8::from_bits(expected as u8), !fits)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn powi_large_exponents() {
+        use crate::types::extra::{U0, U127, U128, U32, U64};
+        type I = FixedI64<U32>;
+        let max = 0x7fff_ffff;
+        let min = -max - 1;
+        assert_eq!(I::from_num(1).checked_powi(min), Some(I::from_num(1)));
+        assert_eq!(I::from_num(-1).checked_powi(max), Some(I::from_num(-1)));
+        assert_eq!(I::from_num(-1).checked_powi(min), Some(I::from_num(1)));
+        assert_eq!(I::from_num(0.5).checked_powi(max), Some(I::from_num(0)));
+        assert_eq!(I::from_num(2).checked_powi(min), Some(I::from_num(0)));
+        assert_eq!(I::from_num(2).checked_powi(max), None);
+        assert_eq!(I::from_num(-2).saturating_powi(max), I::min_value());
+        assert_eq!(I::from_num(-2).saturating_powi(min + 1), I::from_num(0));
+        assert_eq!(I::from_num(0).checked_powi(max), Some(I::from_num(0)));
+        assert_eq!(I::from_num(0).checked_powi(0), Some(I::from_num(1)));
+
+        // 3^40 < 2^64, so all the bits are significant
+        let three = FixedU128::<U64>::from_num(3);
+        let pow = 3u128.pow(40);
+        assert_eq!(
+            three.checked_powi(40),
+            Some(FixedU128::from_bits(pow << 64))
+        );
+        assert_eq!(three.checked_powi(41), None);
+        let pow = FixedI128::<U64>::from_num(-3).checked_powi(39).unwrap();
+        assert_eq!(pow.to_bits(), -((3i128.pow(39)) << 64));
+        // 1/3 is 0x5555...5.55..., which rounds down
+        assert_eq!(
+            three.checked_powi(-1).map(|x| x.to_bits()),
+            Some((!0 / 3) >> 64)
+        );
+        // 2^64/6 is 0x2aaa...a.aa..., which rounds up
+        let six = FixedU128::<U64>::from_num(6);
+        assert_eq!(
+            six.checked_powi(-1).map(|x| x.to_bits()),
+            Some(((!0 / 6) >> 64) + 1)
+        );
+
+        // the result of 1 does not fit without integer bits
+        assert_eq!(FixedU128::<U128>::from_num(0.5).checked_powi(0), None);
+        assert_eq!(FixedI128::<U127>::from_num(-0.5).checked_powi(0), None);
+        assert_eq!(
+            FixedI128::<U127>::from_num(-0.5).checked_powi(3),
+            Some(FixedI128::from_num(-0.125))
+        );
+        assert_eq!(
+            FixedU128::<U0>::max_value().checked_powi(1),
+            Some(FixedU128::max_value())
+        );
+        assert_eq!(
+            FixedU128::<U0>::max_value().checked_powi(-1),
+            Some(FixedU128::from_num(0))
+        );
+    }
+
+    // |bits|^exp / 2^(frac_nbits (exp - 1)) rounded to the nearest with
+    // ties up, computed exactly on little-endian 32-bit limbs
+    fn powi_ref(abs: u128, exp: u32, frac_nbits: u32) -> Option<u128> {
+        match powi_ref_wrapping(abs, exp, frac_nbits) {
+            (int, false) => Some(int),
+            (_, true) => None,
+        }
+    }
+
+    // Like powi_ref, but wrapped to 128 bits, with an overflow flag.
+    fn powi_ref_wrapping(abs: u128, exp: u32, frac_nbits: u32) -> (u128, bool) {
+        use std::vec;
+        let mut limbs = vec![1u32];
+        for _ in 0..exp {
+            let mut acc = vec![0u32; limbs.len() + 4];
+            for (i, &l) in limbs.iter().enumerate() {
+                let mut carry = 0u64;
+                for j in 0..4 {
+                    let r = (abs >> (32 * j)) as u32;
+                    let t = u64::from(l) * u64::from(r) + u64::from(acc[i + j]) + carry;
+                    acc[i + j] = t as u32;
+                    carry = t >> 32;
+                }
+                let mut k = i + 4;
+                while carry != 0 {
+                    let t = u64::from(acc[k]) + carry;
+                    acc[k] = t as u32;
+                    carry = t >> 32;
+                    k += 1;
+                }
+            }
+            limbs = acc;
+        }
+        let shift = (frac_nbits * (exp - 1)) as usize;
+        let bit = |i: usize| limbs.get(i / 32).map_or(0, |l| (l >> (i % 32)) & 1);
+        let round = if shift == 0 { 0 } else { bit(shift - 1) };
+        let high = (shift + 128..limbs.len() * 32).any(|i| bit(i) != 0);
+        let int = (0..128).fold(0u128, |acc, i| acc | (u128::from(bit(shift + i)) << i));
+        let (int, carry) = int.overflowing_add(u128::from(round));
+        (int, high || carry)
+    }
+
+    #[test]
+    fn powi_wrapping_exact() {
+        use crate::int256::U256;
+        use crate::types::extra::{U32, U64};
+        use crate::types::{I32F32, I64F64};
+        use rand::{RngCore, SeedableRng};
+        use rand_xoshiro::Xoshiro256PlusPlus;
+
+        // both bounds overflow, so the wrapped bits come from the exact power
+        assert_eq!(
+            I32F32::from_num(3).wrapping_powi(200).to_bits(),
+            -6_126_953_087_501_336_576
+        );
+        assert_eq!(
+            I64F64::from_num(1.5).overflowing_powi(1000),
+            (
+                I64F64::from_bits(51_642_950_469_390_997_107_899_645_015_103_873_100),
+                true
+            )
+        );
+        let (abs, overflow) = powi_ref_wrapping(3 << 63, 1000, 64);
+        assert!(overflow);
+        assert_eq!(abs, 51_642_950_469_390_997_107_899_645_015_103_873_100);
+
+        // powers of up to 64 * 120 bits fit in the big integers
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(0x7772_6170);
+        for i in 0..300 {
+            let exp = (rng.next_u32() % 120 + 2) as i32;
+            let frac_nbits = i % 65;
+            let bits = u128::from(rng.next_u64()) >> (i % 62);
+            let expected = powi_ref_wrapping(bits, exp as u32, frac_nbits);
+            let (int, overflow) = bits.powi_frac(exp, frac_nbits).unwrap();
+            assert_eq!(
+                (int, overflow),
+                expected,
+                "{}^{} >> {}",
+                bits,
+                exp,
+                frac_nbits
+            );
+            let (int, overflow) = (bits as i128).powi_frac(exp, frac_nbits).unwrap();
+            let fits = !expected.1 && expected.0 <= i128::MAX as u128;
+            assert_eq!((int as u128, overflow), (expected.0, !fits));
+        }
+
+        // negative exponents of tiny operands: 2^96 / 9 and 2^192 / 3^5
+        let (pow, overflow) = I32F32::from_bits(3).overflowing_powi(-2);
+        // 2^96 = 1 (mod 9), so the quotient rounds down
+        let q = (1u128 << 96) / 9;
+        assert_eq!((pow.to_bits(), overflow), (q as i64, true));
+        let (pow, overflow) = FixedU128::<U64>::from_bits(3).overflowing_powi(-2);
+        let expected = U256::div_rem_wide(U256::ZERO, U256::ONE << 192, U256::from(9u8));
+        let (q, rem) = (expected.0 .1, expected.1);
+        let q = if rem.to_words().1 * 2 >= 9 {
+            q.wrapping_add(U256::ONE)
+        } else {
+            q
+        };
+        assert_eq!((pow.to_bits(), overflow), (q.to_words().1, true));
+
+        // 256-bit types wrap the exact power too
+        let mut expected = U256::ONE;
+        for _ in 0..300 {
+            expected = expected.wrapping_mul(U256::from(3u8));
+        }
+        let pow = FixedU256::<U32>::from_num(3).wrapping_powi(300);
+        assert_eq!(pow.to_bits(), expected << 32);
+        let (pow, overflow) = FixedU256::<U64>::from_num(1.5).overflowing_powi(1000);
+        assert!(overflow);
+        let (hi, lo) = pow.to_bits().to_words();
+        assert_eq!(lo, 51_642_950_469_390_997_107_899_645_015_103_873_100);
+        assert_ne!(hi, 0);
+    }
+
+    #[test]
+    fn powi_exact_128() {
+        use crate::types::extra::{U127, U128, U64};
+        use rand::{RngCore, SeedableRng};
+        use rand_xoshiro::Xoshiro256PlusPlus;
+
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(0x706f_7769);
+        let mut random = || u128::from(rng.next_u64()) << 64 | u128::from(rng.next_u64());
+        let unsigned =
+            FixedU128::<U128>::from_bits(330_749_823_229_634_191_392_319_164_229_873_206_057);
+        assert_eq!(
+            unsigned.checked_powi(6).map(FixedU128::to_bits),
+            powi_ref(unsigned.to_bits(), 6, 128)
+        );
+        for i in 0..3000 {
+            let bits = random();
+            let exp = i % 7 + 2;
+            let unsigned = FixedU128::<U128>::from_bits(bits);
+            assert_eq!(
+                unsigned.checked_powi(exp as i32).map(FixedU128::to_bits),
+                powi_ref(bits, exp, 128),
+                "{}^{}",
+                unsigned,
+                exp
+            );
+            let signed = FixedI128::<U127>::from_bits(bits as i128);
+            let neg = signed < 0 && exp & 1 != 0;
+            let expected = match powi_ref((bits as i128).wrapping_abs() as u128, exp, 127) {
+                Some(abs) if neg && abs <= 1 << 127 => Some((abs as i128).wrapping_neg()),
+                Some(abs) if !neg && abs < 1 << 127 => Some(abs as i128),
+                _ => None,
+            };
+            assert_eq!(
+                signed.checked_powi(exp as i32).map(FixedI128::to_bits),
+                expected,
+                "{}^{}",
+                signed,
+                exp
+            );
+            let mixed = FixedU128::<U64>::from_bits(bits >> (i % 64 + 64));
+            assert_eq!(
+                mixed.checked_powi(exp as i32).map(FixedU128::to_bits),
+                powi_ref(mixed.to_bits(), exp, 64),
+                "{}^{}",
+                mixed,
+                exp
+            );
+        }
+    }
+
+    fn round_ref(num: i64, den: i64, mode: RoundingMode) -> i64 {
+        let floor = num.div_euclid(den);
+        let rem = num - floor * den;
+        let up = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => rem != 0,
+            RoundingMode::TowardZero => num < 0 && rem != 0,
+            RoundingMode::HalfUp => 2 * rem > den || (2 * rem == den && num >= 0),
+            RoundingMode::HalfEven => 2 * rem > den || (2 * rem == den && floor & 1 != 0),
+        };
+        if up {
+            floor + 1
+        } else {
+            floor
+        }
+    }
+
+    fn check_round_8<Frac: LeEqU8>() {
+        let modes = [
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::TowardZero,
+            RoundingMode::HalfUp,
+            RoundingMode::HalfEven,
+        ];
+        let frac_nbits = Frac::U32;
+        for a in 0..=0xff_u8 {
+            for b in 0..=0xff_u8 {
+                let (sa, sb) = (i64::from(a as i8), i64::from(b as i8));
+                let (ua, ub) = (i64::from(a), i64::from(b));
+                let (sfa, sfb) = (
+                    FixedI8::<Frac>::from_bits(a as i8),
+                    FixedI8::from_bits(b as i8),
+                );
+                let (ufa, ufb) = (FixedU8::<Frac>::from_bits(a), FixedU8::from_bits(b));
+                for &mode in &modes {
+                    let expected = round_ref(sa * sb, 1 << frac_nbits, mode);
+                    let fits = (-0x80..0x80).contains(&expected);
+                    assert_eq!(
+                        sfa.overflowing_mul_round(sfb, mode),
+                        (FixedI8::from_bits(expected as i8), !fits)
+                    );
+                    let expected = round_ref(ua * ub, 1 << frac_nbits, mode);
+                    assert_eq!(
+                        ufa.overflowing_mul_round(ufb, mode),
+                        (FixedU8::from_bits(expected as u8), expected > 0xff)
+                    );
+                    if b == 0 {
+                        assert_eq!(sfa.checked_div_round(sfb, mode), None);
+                        assert_eq!(ufa.checked_div_round(ufb, mode), None);
+                        continue;
+                    }
+                    let (num, den) = if sb < 0 { (-sa, -sb) } else { (sa, sb) };
+                    let expected = round_ref(num << frac_nbits, den, mode);
+                    let fits = (-0x80..0x80).contains(&expected);
+                    assert_eq!(
+                        sfa.overflowing_div_round(sfb, mode),
+                        (FixedI8::from_bits(expected as i8), !fits)
+                    );
+                    let expected = round_ref(ua << frac_nbits, ub, mode);
+                    assert_eq!(
+                        ufa.overflowing_div_round(ufb, mode),
+                        (FixedU8::from_bits(expected as u8), expected > 0xff)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_exhaustive_8() {
+        use crate::types::extra::{U0, U3, U7, U8};
+        check_round_8::<U0>();
+        check_round_8::<U3>();
+        check_round_8::<U7>();
+        check_round_8::<U8>();
+    }
+
+    #[test]
+    fn round_128() {
+        use crate::types::extra::{U0, U128, U64};
+        type U = FixedU128<U128>;
+        // 3 ulp * 0.5 = 1.5 ulp
+        let (three, half) = (U::from_bits(3), U::from_bits(1 << 127));
+        assert_eq!(three.mul_round(half, RoundingMode::Floor), U::from_bits(1));
+        assert_eq!(three.mul_round(half, RoundingMode::Ceil), U::from_bits(2));
+        assert_eq!(
+            three.mul_round(half, RoundingMode::TowardZero),
+            U::from_bits(1)
+        );
+        assert_eq!(three.mul_round(half, RoundingMode::HalfUp), U::from_bits(2));
+        assert_eq!(
+            three.mul_round(half, RoundingMode::HalfEven),
+            U::from_bits(2)
+        );
+        assert_eq!(half.checked_div_round(three, RoundingMode::Floor), None);
+        assert_eq!(
+            half.checked_div_round(U::from_bits(3 << 126), RoundingMode::HalfEven),
+            Some(U::from_bits(0xaaaa_aaaa_aaaa_aaaa_aaaa_aaaa_aaaa_aaab))
+        );
+
+        type I = FixedI128<U64>;
+        let (one, three) = (I::from_num(1), I::from_num(3));
+        let third = I::from_bits(0x5555_5555_5555_5555);
+        assert_eq!(one.div_round(three, RoundingMode::Floor), third);
+        assert_eq!(
+            one.div_round(three, RoundingMode::Ceil),
+            third + I::from_bits(1)
+        );
+        assert_eq!(
+            (-one).div_round(three, RoundingMode::Floor),
+            -third - I::from_bits(1)
+        );
+        assert_eq!((-one).div_round(three, RoundingMode::TowardZero), -third);
+        assert_eq!((-one).div_round(three, RoundingMode::HalfUp), -third);
+        let small = I::from_bits(-1);
+        assert_eq!(small.mul_round(small, RoundingMode::Ceil), I::from_bits(1));
+        assert_eq!(small.mul_round(-small, RoundingMode::Ceil), I::from_num(0));
+        assert_eq!(
+            I::max_value().saturating_mul_round(-three, RoundingMode::Floor),
+            I::min_value()
+        );
+
+        type I0 = FixedI128<U0>;
+        let minus_one = I0::from_num(-1);
+        assert_eq!(
+            I0::min_value().overflowing_div_round(minus_one, RoundingMode::Floor),
+            (I0::min_value(), true)
+        );
+        assert_eq!(
+            I0::min_value().overflowing_mul_round(minus_one, RoundingMode::Ceil),
+            (I0::min_value(), true)
+        );
+        assert_eq!(
+            I0::max_value().wrapping_mul_round(I0::max_value(), RoundingMode::Floor),
+            I0::from_num(1)
+        );
+        assert_eq!(
+            I0::max_value().saturating_div_round(I0::from_num(2), RoundingMode::HalfEven),
+            I0::from_bits(1 << 126)
+        );
+    }
+
+    #[test]
+    fn mul_div_8() {
+        use crate::types::extra::U4;
+        let modes = [
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::TowardZero,
+            RoundingMode::HalfUp,
+            RoundingMode::HalfEven,
+        ];
+        let divs = [1_u8, 0xff, 3, 0xf9, 16, 100, 0x7f, 0x80];
+        for a in 0..=0xff_u8 {
+            for b in 0..=0xff_u8 {
+                for &c in &divs {
+                    let (sa, sb, sc) = (i64::from(a as i8), i64::from(b as i8), i64::from(c as i8));
+                    let (ua, ub, uc) = (i64::from(a), i64::from(b), i64::from(c));
+                    let sf = |x: u8| FixedI8::<U4>::from_bits(x as i8);
+                    let uf = FixedU8::<U4>::from_bits;
+                    let (num, den) = if sc < 0 {
+                        (-sa * sb, -sc)
+                    } else {
+                        (sa * sb, sc)
+                    };
+                    for &mode in &modes {
+                        let expected = round_ref(num, den, mode);
+                        let fits = (-0x80..0x80).contains(&expected);
+                        let ans = MulDivRound::mul_div_round(a as i8, b as i8, c as i8, mode);
+                        assert_eq!(ans, (expected as i8, !fits));
+                        let expected = round_ref(ua * ub, uc, mode);
+                        let ans = MulDivRound::mul_div_round(a, b, c, mode);
+                        assert_eq!(ans, (expected as u8, expected > 0xff));
+                    }
+                    let expected = round_ref(num, den, RoundingMode::TowardZero);
+                    let fits = (-0x80..0x80).contains(&expected);
+                    let checked = sf(a).checked_mul_div(sf(b), sf(c));
+                    assert_eq!(checked, if fits { Some(sf(expected as u8)) } else { None });
+                    let saturated = if fits {
+                        sf(expected as u8)
+                    } else if expected < 0 {
+                        FixedI8::min_value()
+                    } else {
+                        FixedI8::max_value()
+                    };
+                    assert_eq!(sf(a).saturating_mul_div(sf(b), sf(c)), saturated);
+                    let expected = round_ref(ua * ub, uc, RoundingMode::TowardZero);
+                    let checked = uf(a).checked_mul_div(uf(b), uf(c));
+                    let fits = expected <= 0xff;
+                    assert_eq!(checked, if fits { Some(uf(expected as u8)) } else { None });
+                    let saturated = if fits {
+                        uf(expected as u8)
+                    } else {
+                        FixedU8::max_value()
+                    };
+                    assert_eq!(uf(a).saturating_mul_div(uf(b), uf(c)), saturated);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mul_div_128() {
+        use crate::types::extra::{U0, U64};
+        type U = FixedU128<U64>;
+        let max = U::max_value();
+        let two = U::from_num(2);
+        assert_eq!(max.checked_mul(max), None);
+        assert_eq!(max.checked_mul_div(max, max), Some(max));
+        assert_eq!(max.checked_mul_div(two, two), Some(max));
+        assert_eq!(max.checked_mul_div(two, U::from_num(1.5)), None);
+        assert_eq!(max.checked_mul_div(two, U::from_num(0)), None);
+        assert_eq!(max.saturating_mul_div(two, U::from_num(1)), max);
+        let third = U::from_bits(0x5555_5555_5555_5555);
+        let one = U::from_num(1);
+        assert_eq!(one.checked_mul_div(one, U::from_num(3)), Some(third));
+        assert_eq!(
+            one.mul_div_round(one, U::from_num(3), RoundingMode::Ceil),
+            third + U::from_bits(1)
+        );
+
+        type I = FixedI128<U0>;
+        let (min, max) = (I::min_value(), I::max_value());
+        assert_eq!(min.checked_mul_div(min, min), Some(min));
+        assert_eq!(min.checked_mul_div(max, max), Some(min));
+        assert_eq!(min.checked_mul_div(max, min), Some(max));
+        assert_eq!(min.checked_mul_div(min, max), None);
+        assert_eq!(min.saturating_mul_div(min, max), max);
+        assert_eq!(
+            min.saturating_mul_div(I::from_num(-1), I::from_num(-1)),
+            min
+        );
+        assert_eq!(
+            max.mul_div_round(I::from_num(-3), I::from_num(6), RoundingMode::Floor),
+            I::from_bits(-(1 << 126))
+        );
+        assert_eq!(
+            max.mul_div_round(I::from_num(-3), I::from_num(6), RoundingMode::TowardZero),
+            I::from_bits(-(1 << 126) + 1)
+        );
+    }
+
+    #[test]
+    fn wide_mul_and_mul_to_8() {
+        use crate::types::extra::{U0, U10, U3, U4, U5, U8};
+        for a in 0..=0xff_u8 {
+            for b in 0..=0xff_u8 {
+                let (sa, sb) = (
+                    FixedI8::<U3>::from_bits(a as i8),
+                    FixedI8::<U5>::from_bits(b as i8),
+                );
+                let prod = i32::from(a as i8) * i32::from(b as i8);
+                assert_eq!(sa.wide_mul(sb), FixedI16::<U8>::from_bits(prod as i16));
+                let expected = round_ref(i64::from(prod), 16, RoundingMode::HalfEven);
+                let fits = (-0x80..0x80).contains(&expected);
+                let checked = sa.checked_mul_to::<FixedI8<U4>, _>(sb);
+                let expected = FixedI8::from_bits(expected as i8);
+                assert_eq!(checked, if fits { Some(expected) } else { None });
+                let wide = sa.mul_to::<FixedI32<U10>, _>(sb);
+                assert_eq!(wide, FixedI32::<U10>::from_bits(prod << 2));
+                let unsigned = sa.checked_mul_to::<FixedU16<U0>, _>(sb);
+                let expected = round_ref(i64::from(prod), 256, RoundingMode::HalfEven);
+                let fits = expected >= 0;
+                let expected = FixedU16::from_bits(expected as u16);
+                assert_eq!(unsigned, if fits { Some(expected) } else { None });
+
+                let (ua, ub) = (FixedU8::<U3>::from_bits(a), FixedU8::<U5>::from_bits(b));
+                let prod = u32::from(a) * u32::from(b);
+                assert_eq!(ua.wide_mul(ub), FixedU16::<U8>::from_bits(prod as u16));
+                let expected = round_ref(i64::from(prod), 16, RoundingMode::HalfEven);
+                let checked = ua.checked_mul_to::<FixedU8<U4>, _>(ub);
+                let fits = expected <= 0xff;
+                let expected = FixedU8::from_bits(expected as u8);
+                assert_eq!(checked, if fits { Some(expected) } else { None });
+            }
+        }
+    }
+
+    #[test]
+    fn mul_to_128() {
+        use crate::types::extra::{U0, U1, U128, U64};
+        type U = FixedU128<U128>;
+        let (half, max) = (U::from_num(0.5), U::max_value());
+        assert_eq!(half.mul_to::<U, _>(half), U::from_num(0.25));
+        assert_eq!(max.mul_to::<FixedU8<U0>, _>(max), 1);
+        assert_eq!(max.mul_to::<FixedI8<U0>, _>(max), 1);
+        assert_eq!(half.mul_to::<FixedU8<U0>, _>(half), 0);
+        // 0.75 × 0.5 = 0.375, which is 0.5 when rounded to one fractional
+        // bit; 0.5 × 0.5 = 0.25 is a tie and is rounded to even
+        let three_quarters = U::from_num(0.75);
+        assert_eq!(three_quarters.mul_to::<FixedU8<U1>, _>(half), 0.5);
+        assert_eq!(half.mul_to::<FixedU8<U1>, _>(half), 0);
+
+        type I = FixedI128<U64>;
+        let (min, max) = (I::min_value(), I::max_value());
+        assert_eq!(min.checked_mul_to::<I, _>(min), None);
+        assert_eq!(
+            min.mul_to::<FixedI128<U0>, _>(I::from_num(-0.5)),
+            FixedI128::<U0>::from_bits(1 << 62)
+        );
+        assert_eq!(max.checked_mul_to::<I, _>(I::from_num(2)), None);
+        assert_eq!(
+            FixedI64::<U64>::min_value().wide_mul(FixedI64::<U0>::min_value()),
+            FixedI128::<U64>::from_bits(1 << 126)
+        );
+    }
 }
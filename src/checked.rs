@@ -0,0 +1,1165 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+#![allow(clippy::suspicious_op_assign_impl)]
+
+use crate::{
+    from_str::ParseFixedError,
+    traits::{Fixed, FixedSigned, FixedUnsigned, FromFixed, ToFixed},
+    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::{Product, Sum},
+    mem,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
+        SubAssign,
+    },
+    str::FromStr,
+};
+
+/// Provides checked arithmetic on fixed-point numbers.
+///
+/// The underlying value can be retrieved through the `.0` index. It
+/// is [`None`] if any operation leading to it overflowed or divided
+/// by zero, and once [`None`], it stays [`None`] through any further
+/// operations.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::{types::I16F16, Checked};
+/// let max = Checked::<I16F16>::max_value();
+/// let delta = Checked::<I16F16>::from_bits(1);
+/// assert_eq!((max - delta).0, Some(I16F16::from_bits(0x7FFF_FFFE)));
+/// // max + delta overflows, and the result is poisoned for later operations
+/// let poisoned = max + delta;
+/// assert_eq!(poisoned.0, None);
+/// assert_eq!((poisoned - delta * 2).0, None);
+/// ```
+///
+/// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+#[repr(transparent)]
+#[derive(Clone, Copy, Hash, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Checked<F>(pub Option<F>);
+
+impl<F: Fixed> Checked<F> {
+    /// Returns the smallest value that can be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// assert_eq!(Checked::<I16F16>::min_value(), Checked(Some(I16F16::min_value())));
+    /// ```
+    #[inline]
+    pub fn min_value() -> Checked<F> {
+        Checked(Some(F::min_value()))
+    }
+
+    /// Returns the largest value that can be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// assert_eq!(Checked::<I16F16>::max_value(), Checked(Some(I16F16::max_value())));
+    /// ```
+    #[inline]
+    pub fn max_value() -> Checked<F> {
+        Checked(Some(F::max_value()))
+    }
+
+    /// Returns the number of integer bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// assert_eq!(Checked::<I16F16>::int_nbits(), I16F16::int_nbits());
+    /// ```
+    #[inline]
+    pub fn int_nbits() -> u32 {
+        F::int_nbits()
+    }
+
+    /// Returns the number of fractional bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// assert_eq!(Checked::<I16F16>::frac_nbits(), I16F16::frac_nbits());
+    /// ```
+    #[inline]
+    pub fn frac_nbits() -> u32 {
+        F::frac_nbits()
+    }
+
+    /// Creates a fixed-point number that has a bitwise representation
+    /// identical to the given integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// assert_eq!(Checked::<I16F16>::from_bits(0x1C), Checked(Some(I16F16::from_bits(0x1C))));
+    /// ```
+    #[inline]
+    pub fn from_bits(bits: F::Bits) -> Checked<F> {
+        Checked(Some(F::from_bits(bits)))
+    }
+
+    /// Creates an integer that has a bitwise representation identical
+    /// to the given fixed-point number, or [`None`] if the value has
+    /// overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let w = Checked(Some(I16F16::from_bits(0x1C)));
+    /// assert_eq!(w.to_bits(), Some(0x1C));
+    /// assert_eq!(Checked::<I16F16>(None).to_bits(), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn to_bits(self) -> Option<F::Bits> {
+        self.0.map(Fixed::to_bits)
+    }
+
+    /// Checked conversion from another number.
+    ///
+    /// The other number can be:
+    ///
+    ///   * A fixed-point number. Any extra fractional bits are truncated.
+    ///   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    ///     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    ///     [`usize`].
+    ///   * A floating-point number of type [`f32`] or [`f64`]. If the
+    ///     [`f16` feature] is enabled, it can also be of type [`f16`]
+    ///     or [`bf16`]. For this conversion, the method rounds to the
+    ///     nearest, with ties rounding to even.
+    ///   * Any other number `src` for which [`ToFixed`] is implemented, in
+    ///     which case this method returns
+    ///     <code>[Checked][`Checked`]([src.checked_to_fixed()][`checked_to_fixed`])</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{
+    ///     types::{I4F4, I16F16},
+    ///     Checked,
+    /// };
+    ///
+    /// // 1.75 fits
+    /// let src = I16F16::from_num(1.75);
+    /// assert_eq!(Checked::<I4F4>::from_num(src), Checked(Some(I4F4::from_num(1.75))));
+    ///
+    /// // 0x1234 overflows
+    /// assert_eq!(Checked::<I4F4>::from_num(0x1234_i32), Checked(None));
+    ///
+    /// // infinity cannot be converted
+    /// assert_eq!(Checked::<I4F4>::from_num(std::f64::INFINITY), Checked(None));
+    /// ```
+    ///
+    /// [`Checked`]: struct.Checked.html
+    /// [`ToFixed`]: traits/trait.ToFixed.html
+    /// [`bf16`]: https://docs.rs/half/^1.2/half/struct.bf16.html
+    /// [`checked_to_fixed`]: traits/trait.ToFixed.html#tymethod.checked_to_fixed
+    /// [`f16` feature]: index.html#optional-features
+    /// [`f16`]: https://docs.rs/half/^1.2/half/struct.f16.html
+    /// [`f32`]: https://doc.rust-lang.org/nightly/std/primitive.f32.html
+    /// [`f64`]: https://doc.rust-lang.org/nightly/std/primitive.f64.html
+    /// [`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+    /// [`i16`]: https://doc.rust-lang.org/nightly/std/primitive.i16.html
+    /// [`i32`]: https://doc.rust-lang.org/nightly/std/primitive.i32.html
+    /// [`i64`]: https://doc.rust-lang.org/nightly/std/primitive.i64.html
+    /// [`i8`]: https://doc.rust-lang.org/nightly/std/primitive.i8.html
+    /// [`isize`]: https://doc.rust-lang.org/nightly/std/primitive.isize.html
+    /// [`u128`]: https://doc.rust-lang.org/nightly/std/primitive.u128.html
+    /// [`u16`]: https://doc.rust-lang.org/nightly/std/primitive.u16.html
+    /// [`u32`]: https://doc.rust-lang.org/nightly/std/primitive.u32.html
+    /// [`u64`]: https://doc.rust-lang.org/nightly/std/primitive.u64.html
+    /// [`u8`]: https://doc.rust-lang.org/nightly/std/primitive.u8.html
+    /// [`usize`]: https://doc.rust-lang.org/nightly/std/primitive.usize.html
+    #[inline]
+    pub fn from_num<Src: ToFixed>(src: Src) -> Checked<F> {
+        Checked(src.checked_to_fixed())
+    }
+
+    /// Converts a fixed-point number to another number, returning
+    /// [`None`] on overflow or if the value has already overflowed.
+    ///
+    /// The other number can be:
+    ///
+    ///   * Another fixed-point number. Any extra fractional bits are truncated.
+    ///   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    ///     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    ///     [`usize`]. Any fractional bits are truncated.
+    ///   * A floating-point number of type [`f32`] or [`f64`]. If the
+    ///     [`f16` feature] is enabled, it can also be of type [`f16`]
+    ///     or [`bf16`]. For this conversion, the method rounds to the
+    ///     nearest, with ties rounding to even.
+    ///   * Any other type `Dst` for which [`FromFixed`] is implemented, in
+    ///     which case this method returns
+    ///     [`Dst::checked_from_fixed`][`checked_from_fixed`] applied to
+    ///     the inner value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{
+    ///     types::{I16F16, I2F6, I4F4},
+    ///     Checked,
+    /// };
+    ///
+    /// // conversion that fits
+    /// let src = Checked(Some(I4F4::from_num(1.75)));
+    /// assert_eq!(src.to_num::<I16F16>(), Some(I16F16::from_num(1.75)));
+    ///
+    /// // conversion that overflows
+    /// let src = Checked(Some(I4F4::max_value()));
+    /// assert_eq!(src.to_num::<I2F6>(), None);
+    /// ```
+    ///
+    /// [`FromFixed`]: traits/trait.FromFixed.html
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    /// [`bf16`]: https://docs.rs/half/^1.2/half/struct.bf16.html
+    /// [`checked_from_fixed`]: traits/trait.FromFixed.html#tymethod.checked_from_fixed
+    /// [`f16` feature]: index.html#optional-features
+    /// [`f16`]: https://docs.rs/half/^1.2/half/struct.f16.html
+    /// [`f32`]: https://doc.rust-lang.org/nightly/std/primitive.f32.html
+    /// [`f64`]: https://doc.rust-lang.org/nightly/std/primitive.f64.html
+    /// [`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+    /// [`i16`]: https://doc.rust-lang.org/nightly/std/primitive.i16.html
+    /// [`i32`]: https://doc.rust-lang.org/nightly/std/primitive.i32.html
+    /// [`i64`]: https://doc.rust-lang.org/nightly/std/primitive.i64.html
+    /// [`i8`]: https://doc.rust-lang.org/nightly/std/primitive.i8.html
+    /// [`isize`]: https://doc.rust-lang.org/nightly/std/primitive.isize.html
+    /// [`u128`]: https://doc.rust-lang.org/nightly/std/primitive.u128.html
+    /// [`u16`]: https://doc.rust-lang.org/nightly/std/primitive.u16.html
+    /// [`u32`]: https://doc.rust-lang.org/nightly/std/primitive.u32.html
+    /// [`u64`]: https://doc.rust-lang.org/nightly/std/primitive.u64.html
+    /// [`u8`]: https://doc.rust-lang.org/nightly/std/primitive.u8.html
+    /// [`usize`]: https://doc.rust-lang.org/nightly/std/primitive.usize.html
+    #[inline]
+    pub fn to_num<Dst: FromFixed>(self) -> Option<Dst> {
+        self.0.and_then(Dst::checked_from_fixed)
+    }
+
+    /// Parses a string slice containing binary digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even. If the
+    /// value does not fit, the returned number is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I8F8, Checked};
+    /// let check = Checked(Some(I8F8::from_bits(0b11100011 << (8 - 1))));
+    /// assert_eq!(Checked::<I8F8>::from_str_binary("1110001.1"), Ok(check));
+    /// assert_eq!(Checked::<I8F8>::from_str_binary("101100111000.1"), Ok(Checked(None)));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn from_str_binary(src: &str) -> Result<Checked<F>, ParseFixedError> {
+        F::overflowing_from_str_binary(src).map(from_overflowing)
+    }
+
+    /// Parses a string slice containing octal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even. If the
+    /// value does not fit, the returned number is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I8F8, Checked};
+    /// let check = Checked(Some(I8F8::from_bits(0o1654 << (8 - 3))));
+    /// assert_eq!(Checked::<I8F8>::from_str_octal("165.4"), Ok(check));
+    /// assert_eq!(Checked::<I8F8>::from_str_octal("7165.4"), Ok(Checked(None)));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn from_str_octal(src: &str) -> Result<Checked<F>, ParseFixedError> {
+        F::overflowing_from_str_octal(src).map(from_overflowing)
+    }
+
+    /// Parses a string slice containing hexadecimal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even. If the
+    /// value does not fit, the returned number is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I8F8, Checked};
+    /// let check = Checked(Some(I8F8::from_bits(0xFFE)));
+    /// assert_eq!(Checked::<I8F8>::from_str_hex("F.FE"), Ok(check));
+    /// assert_eq!(Checked::<I8F8>::from_str_hex("C0F.FE"), Ok(Checked(None)));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn from_str_hex(src: &str) -> Result<Checked<F>, ParseFixedError> {
+        F::overflowing_from_str_hex(src).map(from_overflowing)
+    }
+
+    /// Parses a string slice containing digits in the given radix to
+    /// return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even. If the
+    /// value does not fit, the returned number is [`None`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range 2 to 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I8F8, Checked};
+    /// // 12.1 in base 3 is 16/3
+    /// let check = Checked(Some(I8F8::from_bits(1365)));
+    /// assert_eq!(Checked::<I8F8>::from_str_radix("12.1", 3), Ok(check));
+    /// assert_eq!(Checked::<I8F8>::from_str_radix("100200.1", 3), Ok(Checked(None)));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Checked<F>, ParseFixedError> {
+        F::overflowing_from_str_radix(src, radix).map(from_overflowing)
+    }
+
+    /// Returns the integer part.
+    ///
+    /// Note that since the numbers are stored in two’s complement,
+    /// negative numbers with non-zero fractional parts will be
+    /// rounded towards −∞, except in the case where there are no
+    /// integer bits, for example for the type
+    /// <code>[Checked][`Checked`]&lt;[I0F16][`I0F16`]&gt;</code>,
+    /// where the return value is always zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let int = Checked::<I16F16>::from_num(-12.25).int();
+    /// assert_eq!(int, Checked::from_num(-13));
+    /// ```
+    ///
+    /// [`Checked`]: struct.Checked.html
+    /// [`I0F16`]: types/type.I0F16.html
+    #[inline]
+    pub fn int(self) -> Checked<F> {
+        Checked(self.0.map(Fixed::int))
+    }
+
+    /// Returns the fractional part.
+    ///
+    /// Note that since the numbers are stored in two’s complement,
+    /// the returned fraction will be non-negative for negative
+    /// numbers, except in the case where there are no integer bits,
+    /// for example for the type
+    /// <code>[Checked][`Checked`]&lt;[I0F16][`I0F16`]&gt;</code>,
+    /// where the return value is always equal to `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let frac = Checked::<I16F16>::from_num(-12.25).frac();
+    /// assert_eq!(frac, Checked::from_num(0.75));
+    /// ```
+    ///
+    /// [`Checked`]: struct.Checked.html
+    /// [`I0F16`]: types/type.I0F16.html
+    #[inline]
+    pub fn frac(self) -> Checked<F> {
+        Checked(self.0.map(Fixed::frac))
+    }
+
+    /// Rounds to the next integer towards 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let three = Checked::<I16F16>::from_num(3);
+    /// assert_eq!(Checked::<I16F16>::from_num(3.9).round_to_zero(), three);
+    /// assert_eq!(Checked::<I16F16>::from_num(-3.9).round_to_zero(), -three);
+    /// ```
+    #[inline]
+    pub fn round_to_zero(self) -> Checked<F> {
+        Checked(self.0.map(Fixed::round_to_zero))
+    }
+
+    /// Checked ceil. Rounds to the next integer towards +∞, returning
+    /// [`None`] on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let two_half = Checked::<I16F16>::from_num(2.5);
+    /// assert_eq!(two_half.ceil(), Checked::from_num(3));
+    /// assert_eq!(Checked::<I16F16>::max_value().ceil(), Checked(None));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn ceil(self) -> Checked<F> {
+        Checked(self.0.and_then(Fixed::checked_ceil))
+    }
+
+    /// Checked floor. Rounds to the next integer towards −∞,
+    /// returning [`None`] on overflow.
+    ///
+    /// Overflow can only occur for signed numbers with zero integer
+    /// bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{
+    ///     types::{I0F32, I16F16},
+    ///     Checked,
+    /// };
+    /// let two_half = Checked::<I16F16>::from_num(2.5);
+    /// assert_eq!(two_half.floor(), Checked::from_num(2));
+    /// assert_eq!(Checked::<I0F32>::min_value().floor(), Checked(None));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn floor(self) -> Checked<F> {
+        Checked(self.0.and_then(Fixed::checked_floor))
+    }
+
+    /// Checked round. Rounds to the next integer to the nearest,
+    /// with ties rounded away from zero, and returning [`None`] on
+    /// overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let two_half = Checked::<I16F16>::from_num(2.5);
+    /// assert_eq!(two_half.round(), Checked::from_num(3));
+    /// assert_eq!((-two_half).round(), Checked::from_num(-3));
+    /// assert_eq!(Checked::<I16F16>::max_value().round(), Checked(None));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn round(self) -> Checked<F> {
+        Checked(self.0.and_then(Fixed::checked_round))
+    }
+
+    /// Checked round. Rounds to the next integer to the nearest,
+    /// with ties rounded to even, and returning [`None`] on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let two_half = Checked::<I16F16>::from_num(2.5);
+    /// assert_eq!(two_half.round_ties_to_even(), Checked::from_num(2));
+    /// let three_half = Checked::<I16F16>::from_num(3.5);
+    /// assert_eq!(three_half.round_ties_to_even(), Checked::from_num(4));
+    /// let max = Checked::<I16F16>::max_value();
+    /// assert_eq!(max.round_ties_to_even(), Checked(None));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn round_ties_to_even(self) -> Checked<F> {
+        Checked(self.0.and_then(Fixed::checked_round_ties_to_even))
+    }
+
+    /// Returns the number of ones in the binary representation, or
+    /// [`None`] if the value has overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let w = Checked::<I16F16>::from_bits(0x00FF_FF00);
+    /// assert_eq!(w.count_ones(), Some(16));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn count_ones(self) -> Option<u32> {
+        self.0.map(Fixed::count_ones)
+    }
+
+    /// Returns the number of zeros in the binary representation, or
+    /// [`None`] if the value has overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let w = Checked::<I16F16>::from_bits(0x00FF_FF00);
+    /// assert_eq!(w.count_zeros(), Some(16));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn count_zeros(self) -> Option<u32> {
+        self.0.map(Fixed::count_zeros)
+    }
+
+    /// Returns the number of leading zeros in the binary
+    /// representation, or [`None`] if the value has overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let w = Checked::<I16F16>::from_bits(0x00FF_FF00);
+    /// assert_eq!(w.leading_zeros(), Some(8));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn leading_zeros(self) -> Option<u32> {
+        self.0.map(Fixed::leading_zeros)
+    }
+
+    /// Returns the number of trailing zeros in the binary
+    /// representation, or [`None`] if the value has overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let w = Checked::<I16F16>::from_bits(0x00FF_FF00);
+    /// assert_eq!(w.trailing_zeros(), Some(8));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn trailing_zeros(self) -> Option<u32> {
+        self.0.map(Fixed::trailing_zeros)
+    }
+
+    /// Shifts to the left by `n` bits, wrapping the truncated bits to the right end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let i = I16F16::from_bits(0x00FF_FF00);
+    /// assert_eq!(Checked(Some(i)).rotate_left(12), Checked(Some(i.rotate_left(12))));
+    /// ```
+    #[inline]
+    pub fn rotate_left(self, n: u32) -> Checked<F> {
+        Checked(self.0.map(|x| x.rotate_left(n)))
+    }
+
+    /// Shifts to the right by `n` bits, wrapping the truncated bits to the left end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let i = I16F16::from_bits(0x00FF_FF00);
+    /// assert_eq!(Checked(Some(i)).rotate_right(12), Checked(Some(i.rotate_right(12))));
+    /// ```
+    #[inline]
+    pub fn rotate_right(self, n: u32) -> Checked<F> {
+        Checked(self.0.map(|x| x.rotate_right(n)))
+    }
+
+    /// Euclidean division, returning [`None`] if the divisor is zero
+    /// or on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let num = Checked::<I16F16>::from_num(7.5);
+    /// let den = Checked::<I16F16>::from_num(2);
+    /// assert_eq!(num.div_euclid(den), Checked::from_num(3));
+    /// let quarter = Checked::<I16F16>::from_num(0.25);
+    /// assert_eq!(Checked::max_value().div_euclid(quarter), Checked(None));
+    /// assert_eq!(num.div_euclid(Checked::from_num(0)), Checked(None));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn div_euclid(self, divisor: Checked<F>) -> Checked<F> {
+        Checked(both(self.0, divisor.0).and_then(|(a, b)| a.checked_div_euclid(b)))
+    }
+
+    /// Remainder for Euclidean division, returning [`None`] if the
+    /// divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let num = Checked::<I16F16>::from_num(7.5);
+    /// let den = Checked::<I16F16>::from_num(2);
+    /// assert_eq!(num.rem_euclid(den), Checked::from_num(1.5));
+    /// assert_eq!((-num).rem_euclid(den), Checked::from_num(0.5));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn rem_euclid(self, divisor: Checked<F>) -> Checked<F> {
+        Checked(both(self.0, divisor.0).and_then(|(a, b)| a.checked_rem_euclid(b)))
+    }
+
+    /// Euclidean division by an integer, returning [`None`] if the
+    /// divisor is zero or on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let num = Checked::<I16F16>::from_num(7.5);
+    /// assert_eq!(num.div_euclid_int(2), Checked::from_num(3));
+    /// let min = Checked::<I16F16>::min_value();
+    /// assert_eq!(min.div_euclid_int(-1), Checked(None));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn div_euclid_int(self, divisor: F::Bits) -> Checked<F> {
+        Checked(self.0.and_then(|a| a.checked_div_euclid_int(divisor)))
+    }
+
+    /// Remainder for Euclidean division by an integer, returning
+    /// [`None`] if the divisor is zero or on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// let num = Checked::<I16F16>::from_num(7.5);
+    /// assert_eq!(num.rem_euclid_int(2), Checked::from_num(1.5));
+    /// assert_eq!((-num).rem_euclid_int(2), Checked::from_num(0.5));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn rem_euclid_int(self, divisor: F::Bits) -> Checked<F> {
+        Checked(self.0.and_then(|a| a.checked_rem_euclid_int(divisor)))
+    }
+}
+
+impl<F: FixedSigned> Checked<F> {
+    /// Returns [`true`][`bool`] if the number is > 0, or [`None`] if
+    /// the value has overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// assert_eq!(Checked::<I16F16>::from_num(4.3).is_positive(), Some(true));
+    /// assert_eq!(Checked::<I16F16>::from_num(-4.3).is_positive(), Some(false));
+    /// assert_eq!(Checked::<I16F16>(None).is_positive(), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    #[inline]
+    pub fn is_positive(self) -> Option<bool> {
+        self.0.map(FixedSigned::is_positive)
+    }
+
+    /// Returns [`true`][`bool`] if the number is < 0, or [`None`] if
+    /// the value has overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// assert_eq!(Checked::<I16F16>::from_num(4.3).is_negative(), Some(false));
+    /// assert_eq!(Checked::<I16F16>::from_num(-4.3).is_negative(), Some(true));
+    /// assert_eq!(Checked::<I16F16>(None).is_negative(), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    #[inline]
+    pub fn is_negative(self) -> Option<bool> {
+        self.0.map(FixedSigned::is_negative)
+    }
+
+    /// Checked absolute value. Returns the absolute value, or
+    /// [`None`] on overflow.
+    ///
+    /// Overflow can only occur when trying to find the absolute value
+    /// of the minimum value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Checked};
+    /// assert_eq!(Checked::<I16F16>::from_num(-5).abs(), Checked::from_num(5));
+    /// assert_eq!(Checked::<I16F16>::min_value().abs(), Checked(None));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn abs(self) -> Checked<F> {
+        Checked(self.0.and_then(FixedSigned::checked_abs))
+    }
+
+    /// Returns a number representing the sign of `self`, or [`None`]
+    /// if the result cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{
+    ///     types::{I1F15, I16F16},
+    ///     Checked,
+    /// };
+    /// assert_eq!(Checked::<I16F16>::from_num(-3.9).signum(), Checked::from_num(-1));
+    /// assert_eq!(Checked::<I16F16>::from_num(0).signum(), Checked::from_num(0));
+    /// assert_eq!(Checked::<I16F16>::from_num(3.9).signum(), Checked::from_num(1));
+    /// // +1 does not fit in I1F15
+    /// assert_eq!(Checked::<I1F15>::from_num(0.5).signum(), Checked(None));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn signum(self) -> Checked<F> {
+        match self.0 {
+            None => Checked(None),
+            Some(x) if x.is_positive() => Self::from_num(1),
+            Some(x) if x.is_negative() => Self::from_num(-1),
+            Some(_) => Self::from_num(0),
+        }
+    }
+}
+
+impl<F: FixedUnsigned> Checked<F> {
+    /// Returns [`true`][`bool`] if the fixed-point number is
+    /// 2<sup><i>k</i></sup> for some integer <i>k</i>, or [`None`] if
+    /// the value has overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::U16F16, Checked};
+    /// assert_eq!(Checked::<U16F16>::from_num(0.5).is_power_of_two(), Some(true));
+    /// assert_eq!(Checked::<U16F16>::from_num(5).is_power_of_two(), Some(false));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    #[inline]
+    pub fn is_power_of_two(self) -> Option<bool> {
+        self.0.map(FixedUnsigned::is_power_of_two)
+    }
+
+    /// Returns the smallest power of two that is ≥ `self`, or
+    /// [`None`] if the next power of two is too large to fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::U16F16, Checked};
+    /// let half = Checked::<U16F16>::from_num(0.5);
+    /// assert_eq!(Checked::<U16F16>::from_num(0.3).next_power_of_two(), half);
+    /// assert_eq!(Checked::<U16F16>::max_value().next_power_of_two(), Checked(None));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    pub fn next_power_of_two(self) -> Checked<F> {
+        Checked(self.0.and_then(FixedUnsigned::checked_next_power_of_two))
+    }
+}
+
+#[inline]
+fn both<F>(a: Option<F>, b: Option<F>) -> Option<(F, F)> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some((a, b)),
+        _ => None,
+    }
+}
+
+#[inline]
+fn from_overflowing<F>((value, overflow): (F, bool)) -> Checked<F> {
+    Checked(if overflow { None } else { Some(value) })
+}
+
+impl<F: Default> Default for Checked<F> {
+    #[inline]
+    fn default() -> Checked<F> {
+        Checked(Some(F::default()))
+    }
+}
+
+impl<F: Fixed> Display for Checked<F> {
+    /// Formats the inner value, or writes `overflow` if the value has
+    /// overflowed.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self.0 {
+            Some(ref val) => Display::fmt(val, f),
+            None => f.pad("overflow"),
+        }
+    }
+}
+
+impl<F: Fixed> From<F> for Checked<F> {
+    /// Wraps a fixed-point number for checked arithmetic.
+    #[inline]
+    fn from(src: F) -> Checked<F> {
+        Checked(Some(src))
+    }
+}
+
+impl<F: Fixed> FromStr for Checked<F> {
+    type Err = ParseFixedError;
+    /// Parses a string slice containing decimal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even. If the
+    /// value does not fit, the returned number is [`None`].
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        F::overflowing_from_str(s).map(from_overflowing)
+    }
+}
+
+macro_rules! op {
+    ($checked:ident, $Op:ident $op:ident, $OpAssign:ident $op_assign:ident) => {
+        impl<F: Fixed> $Op<Checked<F>> for Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: Checked<F>) -> Checked<F> {
+                Checked(both(self.0, other.0).and_then(|(a, b)| a.$checked(b)))
+            }
+        }
+        impl<'a, F: Fixed> $Op<Checked<F>> for &'a Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: Checked<F>) -> Checked<F> {
+                Checked(both(self.0, other.0).and_then(|(a, b)| a.$checked(b)))
+            }
+        }
+        impl<'a, F: Fixed> $Op<&'a Checked<F>> for Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: &Checked<F>) -> Checked<F> {
+                Checked(both(self.0, other.0).and_then(|(a, b)| a.$checked(b)))
+            }
+        }
+        impl<'a, 'b, F: Fixed> $Op<&'a Checked<F>> for &'b Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: &Checked<F>) -> Checked<F> {
+                Checked(both(self.0, other.0).and_then(|(a, b)| a.$checked(b)))
+            }
+        }
+        impl<F: Fixed> $OpAssign<Checked<F>> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: Checked<F>) {
+                self.0 = both(self.0, other.0).and_then(|(a, b)| a.$checked(b));
+            }
+        }
+        impl<'a, F: Fixed> $OpAssign<&'a Checked<F>> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: &Checked<F>) {
+                self.0 = both(self.0, other.0).and_then(|(a, b)| a.$checked(b));
+            }
+        }
+    };
+}
+
+macro_rules! op_bitwise {
+    ($Op:ident $op:ident, $OpAssign:ident $op_assign:ident) => {
+        impl<F: Fixed> $Op<Checked<F>> for Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: Checked<F>) -> Checked<F> {
+                Checked(both(self.0, other.0).map(|(a, b)| a.$op(b)))
+            }
+        }
+        impl<'a, F: Fixed> $Op<Checked<F>> for &'a Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: Checked<F>) -> Checked<F> {
+                Checked(both(self.0, other.0).map(|(a, b)| a.$op(b)))
+            }
+        }
+        impl<'a, F: Fixed> $Op<&'a Checked<F>> for Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: &'a Checked<F>) -> Checked<F> {
+                Checked(both(self.0, other.0).map(|(a, b)| a.$op(b)))
+            }
+        }
+        impl<'a, 'b, F: Fixed> $Op<&'a Checked<F>> for &'b Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: &'a Checked<F>) -> Checked<F> {
+                Checked(both(self.0, other.0).map(|(a, b)| a.$op(b)))
+            }
+        }
+        impl<F: Fixed> $OpAssign<Checked<F>> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: Checked<F>) {
+                self.0 = both(self.0, other.0).map(|(a, b)| a.$op(b));
+            }
+        }
+        impl<'a, F: Fixed> $OpAssign<&'a Checked<F>> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: &'a Checked<F>) {
+                self.0 = both(self.0, other.0).map(|(a, b)| a.$op(b));
+            }
+        }
+    };
+}
+
+// Shifting by a negative amount or by at least the number of bits
+// gives None.
+macro_rules! op_shift {
+    (
+        $Op:ident $op:ident, $OpAssign:ident $op_assign:ident;
+        $($Rhs:ident),*
+    ) => { $(
+        impl<F: Fixed> $Op<$Rhs> for Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: $Rhs) -> Checked<F> {
+                Checked(shift_amount::<F>(other as i128).and_then(|n| self.0.map(|a| a.$op(n))))
+            }
+        }
+        impl<'a, F: Fixed> $Op<$Rhs> for &'a Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: $Rhs) -> Checked<F> {
+                Checked(shift_amount::<F>(other as i128).and_then(|n| self.0.map(|a| a.$op(n))))
+            }
+        }
+        impl<'a, F: Fixed> $Op<&'a $Rhs> for Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: &$Rhs) -> Checked<F> {
+                Checked(shift_amount::<F>(*other as i128).and_then(|n| self.0.map(|a| a.$op(n))))
+            }
+        }
+        impl<'a, 'b, F: Fixed> $Op<&'a $Rhs> for &'b Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: &$Rhs) -> Checked<F> {
+                Checked(shift_amount::<F>(*other as i128).and_then(|n| self.0.map(|a| a.$op(n))))
+            }
+        }
+        impl<F: Fixed> $OpAssign<$Rhs> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: $Rhs) {
+                self.0 = shift_amount::<F>(other as i128).and_then(|n| self.0.map(|a| a.$op(n)));
+            }
+        }
+        impl<'a, F: Fixed> $OpAssign<&'a $Rhs> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: &$Rhs) {
+                self.0 = shift_amount::<F>(*other as i128).and_then(|n| self.0.map(|a| a.$op(n)));
+            }
+        }
+    )* };
+}
+
+#[inline]
+fn shift_amount<F>(amount: i128) -> Option<u32> {
+    let nbits = mem::size_of::<F>() as i128 * 8;
+    if 0 <= amount && amount < nbits {
+        Some(amount as u32)
+    } else {
+        None
+    }
+}
+
+impl<F: Fixed> Neg for Checked<F> {
+    type Output = Checked<F>;
+    #[inline]
+    fn neg(self) -> Checked<F> {
+        Checked(self.0.and_then(Fixed::checked_neg))
+    }
+}
+
+impl<F: Fixed> Neg for &Checked<F> {
+    type Output = Checked<F>;
+    #[inline]
+    fn neg(self) -> Checked<F> {
+        Checked(self.0.and_then(Fixed::checked_neg))
+    }
+}
+op! { checked_add, Add add, AddAssign add_assign }
+op! { checked_sub, Sub sub, SubAssign sub_assign }
+op! { checked_mul, Mul mul, MulAssign mul_assign }
+op! { checked_div, Div div, DivAssign div_assign }
+op! { checked_rem, Rem rem, RemAssign rem_assign }
+
+impl<F: Fixed> Not for Checked<F> {
+    type Output = Checked<F>;
+    #[inline]
+    fn not(self) -> Checked<F> {
+        Checked(self.0.map(Not::not))
+    }
+}
+impl<F: Fixed> Not for &Checked<F> {
+    type Output = Checked<F>;
+    #[inline]
+    fn not(self) -> Checked<F> {
+        Checked(self.0.map(Not::not))
+    }
+}
+op_bitwise! { BitAnd bitand, BitAndAssign bitand_assign }
+op_bitwise! { BitOr bitor, BitOrAssign bitor_assign }
+op_bitwise! { BitXor bitxor, BitXorAssign bitxor_assign }
+
+op_shift! {
+    Shl shl, ShlAssign shl_assign;
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+}
+op_shift! {
+    Shr shr, ShrAssign shr_assign;
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+}
+
+impl<F: Fixed> Sum<Checked<F>> for Checked<F> {
+    fn sum<I>(iter: I) -> Checked<F>
+    where
+        I: Iterator<Item = Checked<F>>,
+    {
+        iter.fold(Checked(Some(F::from_num(0))), Add::add)
+    }
+}
+
+impl<'a, F: 'a + Fixed> Sum<&'a Checked<F>> for Checked<F> {
+    fn sum<I>(iter: I) -> Checked<F>
+    where
+        I: Iterator<Item = &'a Checked<F>>,
+    {
+        iter.fold(Checked(Some(F::from_num(0))), Add::add)
+    }
+}
+
+impl<F: Fixed> Product<Checked<F>> for Checked<F> {
+    fn product<I>(mut iter: I) -> Checked<F>
+    where
+        I: Iterator<Item = Checked<F>>,
+    {
+        match iter.next() {
+            None => Checked(1.checked_to_fixed()),
+            Some(first) => iter.fold(first, Mul::mul),
+        }
+    }
+}
+
+impl<'a, F: 'a + Fixed> Product<&'a Checked<F>> for Checked<F> {
+    fn product<I>(mut iter: I) -> Checked<F>
+    where
+        I: Iterator<Item = &'a Checked<F>>,
+    {
+        match iter.next() {
+            None => Checked(1.checked_to_fixed()),
+            Some(first) => iter.fold(*first, Mul::mul),
+        }
+    }
+}
+
+// The following cannot be implemented for Checked<F> where F: Fixed,
+// otherwise there will be a conflicting implementation error, so
+// implementations are provided for each concrete type, as is done
+// for Wrapping<F>.
+
+macro_rules! op_bits {
+    (
+        $Fixed:ident($Bits:ident $(, $LeEqU:ident)*)::$checked:ident,
+        $Op:ident $op:ident,
+        $OpAssign:ident $op_assign:ident
+    ) => {
+        impl<Frac $(: $LeEqU)*> $Op<$Bits> for Checked<$Fixed<Frac>> {
+            type Output = Checked<$Fixed<Frac>>;
+            #[inline]
+            fn $op(self, other: $Bits) -> Checked<$Fixed<Frac>> {
+                Checked(self.0.and_then(|a| a.$checked(other)))
+            }
+        }
+        impl<'a, Frac $(: $LeEqU)*> $Op<$Bits> for &'a Checked<$Fixed<Frac>> {
+            type Output = Checked<$Fixed<Frac>>;
+            #[inline]
+            fn $op(self, other: $Bits) -> Checked<$Fixed<Frac>> {
+                Checked(self.0.and_then(|a| a.$checked(other)))
+            }
+        }
+        impl<'a, Frac $(: $LeEqU)*> $Op<&'a $Bits> for Checked<$Fixed<Frac>> {
+            type Output = Checked<$Fixed<Frac>>;
+            #[inline]
+            fn $op(self, other: &$Bits) -> Checked<$Fixed<Frac>> {
+                Checked(self.0.and_then(|a| a.$checked(*other)))
+            }
+        }
+        impl<'a, 'b, Frac $(: $LeEqU)*> $Op<&'a $Bits> for &'b Checked<$Fixed<Frac>> {
+            type Output = Checked<$Fixed<Frac>>;
+            #[inline]
+            fn $op(self, other: &$Bits) -> Checked<$Fixed<Frac>> {
+                Checked(self.0.and_then(|a| a.$checked(*other)))
+            }
+        }
+        impl<Frac $(: $LeEqU)*> $OpAssign<$Bits> for Checked<$Fixed<Frac>> {
+            #[inline]
+            fn $op_assign(&mut self, other: $Bits) {
+                self.0 = self.0.and_then(|a| a.$checked(other));
+            }
+        }
+        impl<'a, Frac $(: $LeEqU)*> $OpAssign<&'a $Bits> for Checked<$Fixed<Frac>> {
+            #[inline]
+            fn $op_assign(&mut self, other: &$Bits) {
+                self.0 = self.0.and_then(|a| a.$checked(*other));
+            }
+        }
+    };
+}
+
+macro_rules! ops {
+    ($Fixed:ident($Bits:ident, $LeEqU:ident)) => {
+        op_bits! { $Fixed($Bits, $LeEqU)::checked_mul_int, Mul mul, MulAssign mul_assign }
+        op_bits! { $Fixed($Bits, $LeEqU)::checked_div_int, Div div, DivAssign div_assign }
+        op_bits! { $Fixed($Bits, $LeEqU)::checked_rem_int, Rem rem, RemAssign rem_assign }
+    };
+}
+ops! { FixedI8(i8, LeEqU8) }
+ops! { FixedI16(i16, LeEqU16) }
+ops! { FixedI32(i32, LeEqU32) }
+ops! { FixedI64(i64, LeEqU64) }
+ops! { FixedI128(i128, LeEqU128) }
+ops! { FixedU8(u8, LeEqU8) }
+ops! { FixedU16(u16, LeEqU16) }
+ops! { FixedU32(u32, LeEqU32) }
+ops! { FixedU64(u64, LeEqU64) }
+ops! { FixedU128(u128, LeEqU128) }
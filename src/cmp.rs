@@ -15,10 +15,11 @@
 
 use crate::{
     helpers::{FloatHelper, FloatKind, IntHelper, Widest},
+    int256::U256,
     traits::Fixed,
-    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    types::extra::{LeEqU128, LeEqU16, LeEqU256, LeEqU32, LeEqU64, LeEqU8},
+    FixedI128, FixedI16, FixedI256, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU256,
+    FixedU32, FixedU64, FixedU8,
 };
 use core::cmp::Ordering;
 #[cfg(feature = "f16")]
@@ -383,6 +384,170 @@ fixed_cmp_all! { FixedU32(LeEqU32) }
 fixed_cmp_all! { FixedU64(LeEqU64) }
 fixed_cmp_all! { FixedU128(LeEqU128) }
 
+// Compares lhs with rhs × 2^shift.
+fn cmp_abs_shl(lhs: U256, rhs: U256, shift: u32) -> Ordering {
+    if rhs.is_zero() {
+        return if lhs.is_zero() {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        };
+    }
+    if shift >= 256 || rhs.leading_zeros() < shift {
+        // rhs × 2^shift ≥ 2^256 > lhs
+        return Ordering::Less;
+    }
+    lhs.cmp(&(rhs << shift))
+}
+
+// Compares two numbers given as a sign and a magnitude with the given
+// number of fractional bits. Negative zero is equal to zero.
+fn cmp_neg_abs(lhs: (bool, U256), lhs_frac: i32, rhs: (bool, U256), rhs_frac: i32) -> Ordering {
+    let lhs_neg = lhs.0 && !lhs.1.is_zero();
+    let rhs_neg = rhs.0 && !rhs.1.is_zero();
+    let abs_cmp = if lhs_frac >= rhs_frac {
+        cmp_abs_shl(lhs.1, rhs.1, (lhs_frac - rhs_frac) as u32)
+    } else {
+        cmp_abs_shl(rhs.1, lhs.1, (rhs_frac - lhs_frac) as u32).reverse()
+    };
+    match (lhs_neg, rhs_neg) {
+        (false, false) => abs_cmp,
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+        (true, true) => abs_cmp.reverse(),
+    }
+}
+
+#[inline]
+fn cmp_fixed<Lhs: Fixed, Rhs: Fixed>(lhs: Lhs, rhs: Rhs) -> Ordering {
+    cmp_neg_abs(
+        lhs.private_neg_abs(),
+        Lhs::frac_nbits() as i32,
+        rhs.private_neg_abs(),
+        Rhs::frac_nbits() as i32,
+    )
+}
+
+#[inline]
+fn cmp_fixed_float<Lhs: Fixed, Rhs: FloatHelper>(lhs: Lhs, rhs: Rhs) -> Option<Ordering> {
+    if rhs.is_nan() {
+        return None;
+    }
+    if !rhs.is_finite() {
+        return Some(if rhs.parts().0 {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        });
+    }
+    let (rhs_neg, rhs_abs, rhs_exp) = rhs.neg_abs_exp();
+    Some(cmp_neg_abs(
+        lhs.private_neg_abs(),
+        Lhs::frac_nbits() as i32,
+        (rhs_neg, U256::from(rhs_abs)),
+        -rhs_exp,
+    ))
+}
+
+// Comparisons involving the 256-bit numbers, which are done on the
+// magnitudes as their bits do not fit in the narrower helpers.
+macro_rules! fixed_cmp_wide {
+    ($Lhs:ident($LhsLeEqU:ident), $Rhs:ident($RhsLeEqU:ident)) => {
+        impl<FracLhs: $LhsLeEqU, FracRhs: $RhsLeEqU> PartialEq<$Rhs<FracRhs>> for $Lhs<FracLhs> {
+            #[inline]
+            fn eq(&self, rhs: &$Rhs<FracRhs>) -> bool {
+                cmp_fixed(*self, *rhs) == Ordering::Equal
+            }
+        }
+
+        impl<FracLhs: $LhsLeEqU, FracRhs: $RhsLeEqU> PartialOrd<$Rhs<FracRhs>> for $Lhs<FracLhs> {
+            #[inline]
+            fn partial_cmp(&self, rhs: &$Rhs<FracRhs>) -> Option<Ordering> {
+                Some(cmp_fixed(*self, *rhs))
+            }
+        }
+    };
+    ($Fix:ident; $($Narrow:ident($NarrowLeEqU:ident)),*) => { $(
+        fixed_cmp_wide! { $Fix(LeEqU256), $Narrow($NarrowLeEqU) }
+        fixed_cmp_wide! { $Narrow($NarrowLeEqU), $Fix(LeEqU256) }
+    )* };
+}
+
+macro_rules! fixed_cmp_float_wide {
+    ($Fix:ident($LeEqU:ident), $Float:ident) => {
+        impl<Frac: $LeEqU> PartialEq<$Float> for $Fix<Frac> {
+            #[inline]
+            fn eq(&self, rhs: &$Float) -> bool {
+                cmp_fixed_float(*self, *rhs) == Some(Ordering::Equal)
+            }
+        }
+
+        impl<Frac: $LeEqU> PartialEq<$Fix<Frac>> for $Float {
+            #[inline]
+            fn eq(&self, rhs: &$Fix<Frac>) -> bool {
+                rhs.eq(self)
+            }
+        }
+
+        impl<Frac: $LeEqU> PartialOrd<$Float> for $Fix<Frac> {
+            #[inline]
+            fn partial_cmp(&self, rhs: &$Float) -> Option<Ordering> {
+                cmp_fixed_float(*self, *rhs)
+            }
+        }
+
+        impl<Frac: $LeEqU> PartialOrd<$Fix<Frac>> for $Float {
+            #[inline]
+            fn partial_cmp(&self, rhs: &$Fix<Frac>) -> Option<Ordering> {
+                cmp_fixed_float(*rhs, *self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+
+macro_rules! fixed_cmp_wide_all {
+    ($Fix:ident) => {
+        impl<Frac: LeEqU256> Eq for $Fix<Frac> {}
+
+        impl<Frac: LeEqU256> Ord for $Fix<Frac> {
+            #[inline]
+            fn cmp(&self, rhs: &$Fix<Frac>) -> Ordering {
+                self.to_bits().cmp(&rhs.to_bits())
+            }
+        }
+
+        fixed_cmp_wide! { $Fix(LeEqU256), FixedI256(LeEqU256) }
+        fixed_cmp_wide! { $Fix(LeEqU256), FixedU256(LeEqU256) }
+        fixed_cmp_wide! {
+            $Fix;
+            FixedI8(LeEqU8), FixedI16(LeEqU16), FixedI32(LeEqU32), FixedI64(LeEqU64),
+            FixedI128(LeEqU128), FixedU8(LeEqU8), FixedU16(LeEqU16), FixedU32(LeEqU32),
+            FixedU64(LeEqU64), FixedU128(LeEqU128)
+        }
+        fixed_cmp_int! { $Fix(LeEqU256), i8 }
+        fixed_cmp_int! { $Fix(LeEqU256), i16 }
+        fixed_cmp_int! { $Fix(LeEqU256), i32 }
+        fixed_cmp_int! { $Fix(LeEqU256), i64 }
+        fixed_cmp_int! { $Fix(LeEqU256), i128 }
+        fixed_cmp_int! { $Fix(LeEqU256), isize }
+        fixed_cmp_int! { $Fix(LeEqU256), u8 }
+        fixed_cmp_int! { $Fix(LeEqU256), u16 }
+        fixed_cmp_int! { $Fix(LeEqU256), u32 }
+        fixed_cmp_int! { $Fix(LeEqU256), u64 }
+        fixed_cmp_int! { $Fix(LeEqU256), u128 }
+        fixed_cmp_int! { $Fix(LeEqU256), usize }
+        #[cfg(feature = "f16")]
+        fixed_cmp_float_wide! { $Fix(LeEqU256), f16 }
+        #[cfg(feature = "f16")]
+        fixed_cmp_float_wide! { $Fix(LeEqU256), bf16 }
+        fixed_cmp_float_wide! { $Fix(LeEqU256), f32 }
+        fixed_cmp_float_wide! { $Fix(LeEqU256), f64 }
+    };
+}
+
+fixed_cmp_wide_all! { FixedI256 }
+fixed_cmp_wide_all! { FixedU256 }
+
 macro_rules! fixed_cmp {
     ($Fixed:ident($Inner:ty, $Len:ty, $bits_count:expr)) => {};
 }
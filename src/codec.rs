@@ -0,0 +1,491 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    from_str::{ParseErrorKind, ParseFixedError},
+    int256::{I256, U256},
+    types::extra::{LeEqU128, LeEqU16, LeEqU256, LeEqU32, LeEqU64, LeEqU8},
+    Checked, FixedI128, FixedI16, FixedI256, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16,
+    FixedU256, FixedU32, FixedU64, FixedU8, Saturating, Unwrapped, Wrapping,
+};
+use core::{char, convert::TryFrom};
+use parity_scale_codec::{
+    Compact, CompactAs, Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output,
+};
+use scale_info::{build::Fields, Path, Type, TypeInfo, TypeParameter};
+
+macro_rules! frac_docs {
+    ($($n:literal)*) => {
+        [$(concat!("Fixed-point number with ", stringify!($n), " fractional bits.")),*]
+    };
+}
+
+// FRAC_DOCS[n] documents a fixed-point number with n fractional bits.
+static FRAC_DOCS: [&str; 257] = frac_docs![
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+    33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62
+    63 64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80 81 82 83 84 85 86 87 88 89 90 91 92
+    93 94 95 96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111 112 113 114 115 116
+    117 118 119 120 121 122 123 124 125 126 127 128 129 130 131 132 133 134 135 136 137 138
+    139 140 141 142 143 144 145 146 147 148 149 150 151 152 153 154 155 156 157 158 159 160
+    161 162 163 164 165 166 167 168 169 170 171 172 173 174 175 176 177 178 179 180 181 182
+    183 184 185 186 187 188 189 190 191 192 193 194 195 196 197 198 199 200 201 202 203 204
+    205 206 207 208 209 210 211 212 213 214 215 216 217 218 219 220 221 222 223 224 225 226
+    227 228 229 230 231 232 233 234 235 236 237 238 239 240 241 242 243 244 245 246 247 248
+    249 250 251 252 253 254 255 256
+];
+
+macro_rules! codec_fixed {
+    ($Fixed:ident($LeEqU:ident) is $Inner:ident) => {
+        impl<Frac: $LeEqU> Encode for $Fixed<Frac> {
+            #[inline]
+            fn size_hint(&self) -> usize {
+                self.to_bits().size_hint()
+            }
+
+            #[inline]
+            fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+                self.to_bits().encode_to(dest)
+            }
+
+            #[inline]
+            fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+                self.to_bits().using_encoded(f)
+            }
+
+            #[inline]
+            fn encoded_size(&self) -> usize {
+                self.to_bits().encoded_size()
+            }
+        }
+
+        impl<Frac: $LeEqU> EncodeLike for $Fixed<Frac> {}
+
+        impl<Frac: $LeEqU> Decode for $Fixed<Frac> {
+            #[inline]
+            fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+                $Inner::decode(input).map(Self::from_bits)
+            }
+        }
+
+        impl<Frac: $LeEqU> MaxEncodedLen for $Fixed<Frac> {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                $Inner::max_encoded_len()
+            }
+        }
+
+        impl<Frac: $LeEqU + 'static> TypeInfo for $Fixed<Frac> {
+            type Identity = Self;
+
+            fn type_info() -> Type {
+                Type::builder()
+                    .path(Path::new(stringify!($Fixed), "substrate_fixed"))
+                    .type_params(Some(TypeParameter::new("Frac", None)))
+                    .docs_always(&[FRAC_DOCS[Frac::USIZE]])
+                    .composite(
+                        Fields::named()
+                            .field(|f| f.ty::<$Inner>().name("bits").type_name(stringify!($Inner))),
+                    )
+            }
+        }
+    };
+}
+
+codec_fixed! { FixedI8(LeEqU8) is i8 }
+codec_fixed! { FixedI16(LeEqU16) is i16 }
+codec_fixed! { FixedI32(LeEqU32) is i32 }
+codec_fixed! { FixedI64(LeEqU64) is i64 }
+codec_fixed! { FixedI128(LeEqU128) is i128 }
+codec_fixed! { FixedI256(LeEqU256) is I256 }
+codec_fixed! { FixedU8(LeEqU8) is u8 }
+codec_fixed! { FixedU16(LeEqU16) is u16 }
+codec_fixed! { FixedU32(LeEqU32) is u32 }
+codec_fixed! { FixedU64(LeEqU64) is u64 }
+codec_fixed! { FixedU128(LeEqU128) is u128 }
+codec_fixed! { FixedU256(LeEqU256) is U256 }
+
+// With #[codec(compact)], the bits of the unsigned fixed-point numbers
+// are encoded as a compact integer. There is no compact encoding for
+// 256-bit integers, so FixedU256 is not included.
+macro_rules! compact_fixed {
+    ($Fixed:ident($LeEqU:ident) is $Inner:ident) => {
+        impl<Frac: $LeEqU> CompactAs for $Fixed<Frac> {
+            type As = $Inner;
+
+            #[inline]
+            fn encode_as(&self) -> &$Inner {
+                &self.bits
+            }
+
+            #[inline]
+            fn decode_from(bits: $Inner) -> Result<Self, Error> {
+                Ok(Self::from_bits(bits))
+            }
+        }
+
+        impl<Frac: $LeEqU> From<Compact<$Fixed<Frac>>> for $Fixed<Frac> {
+            #[inline]
+            fn from(compact: Compact<$Fixed<Frac>>) -> Self {
+                compact.0
+            }
+        }
+    };
+}
+
+compact_fixed! { FixedU8(LeEqU8) is u8 }
+compact_fixed! { FixedU16(LeEqU16) is u16 }
+compact_fixed! { FixedU32(LeEqU32) is u32 }
+compact_fixed! { FixedU64(LeEqU64) is u64 }
+compact_fixed! { FixedU128(LeEqU128) is u128 }
+
+// The wrappers are encoded exactly like the value they wrap.
+macro_rules! codec_wrapper {
+    ($Wrapper:ident($Inner:ty) in $module:ident) => {
+        impl<F: Encode> Encode for $Wrapper<F> {
+            #[inline]
+            fn size_hint(&self) -> usize {
+                self.0.size_hint()
+            }
+
+            #[inline]
+            fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+                self.0.encode_to(dest)
+            }
+
+            #[inline]
+            fn using_encoded<R, G: FnOnce(&[u8]) -> R>(&self, f: G) -> R {
+                self.0.using_encoded(f)
+            }
+
+            #[inline]
+            fn encoded_size(&self) -> usize {
+                self.0.encoded_size()
+            }
+        }
+
+        impl<F: Encode> EncodeLike for $Wrapper<F> {}
+
+        impl<F: Decode> Decode for $Wrapper<F> {
+            #[inline]
+            fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+                <$Inner>::decode(input).map($Wrapper)
+            }
+        }
+
+        impl<F: MaxEncodedLen> MaxEncodedLen for $Wrapper<F> {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                <$Inner>::max_encoded_len()
+            }
+        }
+
+        impl<F: TypeInfo + 'static> TypeInfo for $Wrapper<F> {
+            type Identity = Self;
+
+            fn type_info() -> Type {
+                Type::builder()
+                    .path(Path::new(
+                        stringify!($Wrapper),
+                        concat!("substrate_fixed::", stringify!($module)),
+                    ))
+                    .type_params(Some(TypeParameter::new(
+                        "F",
+                        Some(scale_info::meta_type::<F>()),
+                    )))
+                    .composite(
+                        Fields::unnamed().field(|f| f.ty::<$Inner>().type_name(stringify!($Inner))),
+                    )
+            }
+        }
+    };
+}
+
+codec_wrapper! { Wrapping(F) in wrapping }
+codec_wrapper! { Saturating(F) in saturating }
+codec_wrapper! { Unwrapped(F) in unwrapped }
+codec_wrapper! { Checked(Option<F>) in checked }
+
+// The 256-bit integers are encoded as the low word followed by the
+// high word, which is the 32-byte little-endian representation.
+macro_rules! codec_int256 {
+    ($Int:ident($Hi:ident)) => {
+        impl Encode for $Int {
+            #[inline]
+            fn size_hint(&self) -> usize {
+                32
+            }
+
+            #[inline]
+            fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+                let (hi, lo) = self.to_words();
+                lo.encode_to(dest);
+                hi.encode_to(dest);
+            }
+
+            #[inline]
+            fn encoded_size(&self) -> usize {
+                32
+            }
+        }
+
+        impl EncodeLike for $Int {}
+
+        impl Decode for $Int {
+            #[inline]
+            fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+                let lo = u128::decode(input)?;
+                let hi = $Hi::decode(input)?;
+                Ok($Int::from_words(hi, lo))
+            }
+        }
+
+        impl MaxEncodedLen for $Int {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                32
+            }
+        }
+
+        impl TypeInfo for $Int {
+            type Identity = Self;
+
+            fn type_info() -> Type {
+                Type::builder()
+                    .path(Path::new(stringify!($Int), "substrate_fixed::int256"))
+                    .composite(
+                        Fields::named()
+                            .field(|f| f.ty::<u128>().name("lo").type_name("u128"))
+                            .field(|f| f.ty::<$Hi>().name("hi").type_name(stringify!($Hi))),
+                    )
+            }
+        }
+    };
+}
+
+codec_int256! { U256(u128) }
+codec_int256! { I256(i128) }
+
+// SCALE has no usize or char, so the position is encoded as a u64 and
+// the character as a u32.
+impl Encode for ParseFixedError {
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        self.kind().encode_to(dest);
+        self.position().map(|p| p as u64).encode_to(dest);
+        self.character().map(u32::from).encode_to(dest);
+    }
+}
+
+impl EncodeLike for ParseFixedError {}
+
+impl Decode for ParseFixedError {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let kind = ParseErrorKind::decode(input)?;
+        let position = match Option::<u64>::decode(input)? {
+            Some(p) => Some(usize::try_from(p).map_err(|_| "position out of range")?),
+            None => None,
+        };
+        let character = match Option::<u32>::decode(input)? {
+            Some(c) => Some(char::from_u32(c).ok_or("invalid character")?),
+            None => None,
+        };
+        Ok(ParseFixedError::from_parts(kind, position, character))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        from_str::ParseFixedError,
+        int256::I256,
+        transcendental::TranscendentalError,
+        types::{
+            extra::{U200, U3},
+            I16F16, I8F8, U0F8, U64F64, U8F8,
+        },
+        Checked, FixedI256, FixedU256, Saturating, Unwrapped, Wrapping,
+    };
+    use parity_scale_codec::{Compact, Decode, Encode, MaxEncodedLen};
+    use scale_info::{meta_type, TypeDef, TypeInfo};
+
+    #[test]
+    fn max_encoded_len() {
+        assert_eq!(U0F8::max_encoded_len(), 1);
+        assert_eq!(I16F16::max_encoded_len(), 4);
+        assert_eq!(FixedU256::<U200>::max_encoded_len(), 32);
+        assert_eq!(Wrapping::<I16F16>::max_encoded_len(), 4);
+        assert_eq!(Saturating::<I16F16>::max_encoded_len(), 4);
+        assert_eq!(Unwrapped::<I16F16>::max_encoded_len(), 4);
+        assert_eq!(Checked::<I8F8>::max_encoded_len(), 3);
+    }
+
+    #[test]
+    fn wrappers_encode_like_inner() {
+        let a = I16F16::from_num(-3.5);
+        assert_eq!(Wrapping(a).encode(), a.encode());
+        assert_eq!(Saturating(a).encode(), a.encode());
+        assert_eq!(Unwrapped(a).encode(), a.encode());
+        let encoded = Wrapping(a).encode();
+        assert_eq!(
+            Wrapping::<I16F16>::decode(&mut &encoded[..]),
+            Ok(Wrapping(a))
+        );
+
+        let some = Checked(Some(a)).encode();
+        assert_eq!(some, Some(a).encode());
+        assert_eq!(
+            Checked::<I16F16>::decode(&mut &some[..]),
+            Ok(Checked(Some(a)))
+        );
+        let none = Checked::<I16F16>(None).encode();
+        assert_eq!(none, [0]);
+        assert_eq!(Checked::<I16F16>::decode(&mut &none[..]), Ok(Checked(None)));
+    }
+
+    #[test]
+    fn type_info() {
+        let ty = I16F16::type_info();
+        assert_eq!(ty.path().segments(), ["substrate_fixed", "FixedI32"]);
+        assert_eq!(ty.docs(), ["Fixed-point number with 16 fractional bits."]);
+        assert_eq!(ty.type_params().len(), 1);
+        assert_eq!(ty.type_params()[0].name(), &"Frac");
+        match ty.type_def() {
+            TypeDef::Composite(composite) => {
+                let fields = composite.fields();
+                assert_eq!(fields.len(), 1);
+                assert_eq!(fields[0].name(), Some(&"bits"));
+                assert_eq!(fields[0].type_name(), Some(&"i32"));
+                assert_eq!(fields[0].ty(), &meta_type::<i32>());
+            }
+            _ => panic!("expected composite"),
+        }
+
+        let ty = FixedU256::<U200>::type_info();
+        assert_eq!(ty.path().segments(), ["substrate_fixed", "FixedU256"]);
+        assert_eq!(ty.docs(), ["Fixed-point number with 200 fractional bits."]);
+
+        let ty = Wrapping::<I8F8>::type_info();
+        assert_eq!(
+            ty.path().segments(),
+            ["substrate_fixed", "wrapping", "Wrapping"]
+        );
+    }
+
+    #[test]
+    fn fixed256() {
+        let val = FixedI256::<U3>::from_bits(I256::from_words(-2, 5));
+        let encoded = val.encode();
+        let mut expected = [0u8; 32];
+        expected[0] = 5;
+        for b in &mut expected[16..] {
+            *b = 0xff;
+        }
+        expected[16] = 0xfe;
+        assert_eq!(encoded, expected.to_vec());
+        assert_eq!(val.to_le_bytes().to_vec(), encoded);
+        assert_eq!(FixedI256::<U3>::decode(&mut &encoded[..]), Ok(val));
+        assert_eq!(I256::max_encoded_len(), 32);
+    }
+
+    #[test]
+    fn transcendental_error() {
+        for &error in &[
+            TranscendentalError::Domain,
+            TranscendentalError::Overflow,
+            TranscendentalError::Underflow,
+            TranscendentalError::DivisionByZero,
+        ] {
+            let encoded = error.encode();
+            assert_eq!(
+                TranscendentalError::decode(&mut &encoded[..]).ok(),
+                Some(error)
+            );
+        }
+    }
+
+    #[test]
+    fn compact() {
+        use core::convert::TryFrom;
+
+        #[derive(Debug, PartialEq, Encode, Decode)]
+        struct Balance {
+            #[codec(compact)]
+            free: U64F64,
+        }
+
+        macro_rules! check {
+            ($Fixed:ident, $bits:expr, $len:expr) => {
+                let val = $Fixed::from_bits($bits);
+                let encoded = Compact(val).encode();
+                assert_eq!(encoded, Compact(u128::from($bits)).encode());
+                assert_eq!(encoded.len(), $len);
+                assert_eq!(
+                    Compact::<$Fixed>::decode(&mut &encoded[..]),
+                    Ok(Compact(val))
+                );
+                let plain = val.encode();
+                assert_eq!($Fixed::decode(&mut &plain[..]), Ok(val));
+            };
+        }
+
+        // boundaries of the single-byte, two-byte, four-byte and big-integer modes
+        for &(bits, len) in &[
+            (0, 1),
+            (0x3f, 1),
+            (0x40, 2),
+            (0x3fff, 2),
+            (0x4000, 4),
+            (0x3fff_ffff, 4),
+            (0x4000_0000, 5),
+            (u128::from(!0u64), 9),
+            (!0u128, 17),
+        ] {
+            check!(U64F64, bits, len);
+            if let Ok(bits16) = u16::try_from(bits) {
+                check!(U8F8, bits16, len);
+            }
+        }
+        check!(U0F8, !0u8, 2);
+
+        let balance = Balance {
+            free: U64F64::from_num(1),
+        };
+        let encoded = balance.encode();
+        assert_eq!(encoded, Compact(1u128 << 64).encode());
+        assert!(encoded.len() < U64F64::from_num(1).encode().len());
+        assert_eq!(Balance::decode(&mut &encoded[..]), Ok(balance));
+    }
+
+    #[test]
+    fn parse_fixed_error() {
+        let errors = [
+            "12\u{e9}".parse::<I16F16>().unwrap_err(),
+            "1.2.3".parse::<I16F16>().unwrap_err(),
+            "".parse::<I16F16>().unwrap_err(),
+            "99999".parse::<I16F16>().unwrap_err(),
+        ];
+        for error in &errors {
+            let encoded = error.encode();
+            assert_eq!(ParseFixedError::decode(&mut &encoded[..]), Ok(*error));
+        }
+        // kind InvalidDigit, position Some(2), character Some('é')
+        assert_eq!(
+            errors[0].encode(),
+            [0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0xe9, 0, 0, 0]
+        );
+        let bad_char = [0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0xd8, 0, 0];
+        assert!(ParseFixedError::decode(&mut &bad_char[..]).is_err());
+    }
+}
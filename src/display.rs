@@ -14,10 +14,12 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::{
+    from_str::check_radix,
     helpers::IntHelper,
-    types::extra::{False, LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    int256::U256,
+    types::extra::{LeEqU128, LeEqU16, LeEqU256, LeEqU32, LeEqU64, LeEqU8},
+    FixedI128, FixedI16, FixedI256, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU256,
+    FixedU32, FixedU64, FixedU8,
 };
 use core::{
     cmp::{self, Ordering},
@@ -25,10 +27,12 @@ use core::{
         Alignment, Binary, Debug, Display, Formatter, LowerHex, Octal, Result as FmtResult,
         UpperHex,
     },
-    mem, str,
+    mem,
+    ops::{Not, Shl, Shr},
+    str,
 };
 
-// We need 130 bytes: 128 digits, one radix point, one leading zero.
+// We need 258 bytes: 256 digits, one radix point, one leading zero.
 //
 // The leading zero has two purposes:
 //
@@ -43,7 +47,7 @@ use core::{
 struct Buffer {
     int_digits: usize,
     frac_digits: usize,
-    data: [u8; 130],
+    data: [u8; 258],
 }
 
 impl Buffer {
@@ -51,14 +55,14 @@ impl Buffer {
         Buffer {
             int_digits: 0,
             frac_digits: 0,
-            data: [0; 130],
+            data: [0; 258],
         }
     }
 
     // Do not combine with new to avoid copying data, otherwise the
     // buffer will be created, modified with the '.', then copied.
     fn set_len(&mut self, int_digits: u32, frac_digits: u32) {
-        assert!(int_digits + frac_digits < 130, "out of bounds");
+        assert!(int_digits + frac_digits < 258, "out of bounds");
         self.int_digits = int_digits as usize;
         self.frac_digits = frac_digits as usize;
         self.data[1 + self.int_digits] = b'.';
@@ -129,7 +133,7 @@ impl Buffer {
         for digit in self.data[..self.int_digits + self.frac_digits + 2].iter_mut() {
             if *digit < 10 {
                 *digit += b'0';
-            } else if *digit < 16 {
+            } else if *digit < 36 {
                 *digit += if upper { b'A' - 10 } else { b'a' - 10 };
             }
         }
@@ -218,6 +222,7 @@ enum Radix {
     LowHex,
     UpHex,
     Dec,
+    Any(u8),
 }
 impl Radix {
     fn digit_bits(self) -> u32 {
@@ -227,6 +232,7 @@ impl Radix {
             Radix::LowHex => 4,
             Radix::UpHex => 4,
             Radix::Dec => 4,
+            Radix::Any(_) => unreachable!(),
         }
     }
     fn max(self) -> u8 {
@@ -236,6 +242,7 @@ impl Radix {
             Radix::LowHex => 15,
             Radix::UpHex => 15,
             Radix::Dec => 9,
+            Radix::Any(radix) => radix - 1,
         }
     }
     fn prefix(self) -> &'static str {
@@ -245,22 +252,41 @@ impl Radix {
             Radix::LowHex => "0x",
             Radix::UpHex => "0x",
             Radix::Dec => "",
+            Radix::Any(_) => "",
         }
     }
 }
 
-trait FmtHelper: IntHelper<IsSigned = False> {
+trait FmtHelper: Copy + Not<Output = Self> + Shl<u32, Output = Self> + Shr<u32, Output = Self> {
+    const NBITS: u32;
+    const ZERO: Self;
+    fn leading_zeros(self) -> u32;
+    fn trailing_zeros(self) -> u32;
     fn write_int(self, radix: Radix, nbits: u32, buf: &mut Buffer);
     fn write_frac(self, radix: Radix, nbits: u32, buf: &mut Buffer) -> Ordering;
     fn write_int_dec(self, nbits: u32, buf: &mut Buffer);
     fn write_frac_dec(self, nbits: u32, auto_prec: bool, buf: &mut Buffer) -> Ordering;
+    fn radix_digits(self, radix: u8) -> u32;
+    fn write_int_radix(self, radix: u8, buf: &mut Buffer);
+    fn write_frac_radix(self, radix: u8, nbits: u32, auto_prec: bool, buf: &mut Buffer)
+        -> Ordering;
 }
 
 macro_rules! impl_radix_helper {
     ($U:ident, $H:ident, $attempt_half:expr) => {
         impl FmtHelper for $U {
+            const NBITS: u32 = <$U as IntHelper>::NBITS;
+            const ZERO: $U = 0;
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                IntHelper::leading_zeros(self)
+            }
+            #[inline]
+            fn trailing_zeros(self) -> u32 {
+                IntHelper::trailing_zeros(self)
+            }
             fn write_int(mut self, radix: Radix, nbits: u32, buf: &mut Buffer) {
-                if $attempt_half && nbits < $U::NBITS / 2 {
+                if $attempt_half && nbits < <$U as IntHelper>::NBITS / 2 {
                     return (self as $H).write_int(radix, nbits, buf);
                 }
                 let digit_bits = radix.digit_bits();
@@ -273,11 +299,12 @@ macro_rules! impl_radix_helper {
                 debug_assert!(self == 0);
             }
             fn write_frac(mut self, radix: Radix, nbits: u32, buf: &mut Buffer) -> Ordering {
-                if $attempt_half && nbits < $U::NBITS / 2 {
-                    return ((self >> ($U::NBITS / 2)) as $H).write_frac(radix, nbits, buf);
+                if $attempt_half && nbits < <$U as IntHelper>::NBITS / 2 {
+                    return ((self >> (<$U as IntHelper>::NBITS / 2)) as $H)
+                        .write_frac(radix, nbits, buf);
                 }
                 let digit_bits = radix.digit_bits();
-                let compl_digit_bits = $U::NBITS - digit_bits;
+                let compl_digit_bits = <$U as IntHelper>::NBITS - digit_bits;
                 for b in buf.frac().iter_mut() {
                     debug_assert!(self != 0);
                     *b = (self >> compl_digit_bits).lower_byte();
@@ -286,7 +313,7 @@ macro_rules! impl_radix_helper {
                 self.cmp(&$U::MSB)
             }
             fn write_int_dec(mut self, nbits: u32, buf: &mut Buffer) {
-                if $attempt_half && nbits < $U::NBITS / 2 {
+                if $attempt_half && nbits < <$U as IntHelper>::NBITS / 2 {
                     return (self as $H).write_int_dec(nbits, buf);
                 }
                 for b in buf.int().iter_mut().rev() {
@@ -296,12 +323,13 @@ macro_rules! impl_radix_helper {
                 debug_assert!(self == 0);
             }
             fn write_frac_dec(mut self, nbits: u32, auto_prec: bool, buf: &mut Buffer) -> Ordering {
-                if $attempt_half && nbits < $U::NBITS / 2 {
-                    return ((self >> ($U::NBITS / 2)) as $H).write_frac_dec(nbits, auto_prec, buf);
+                if $attempt_half && nbits < <$U as IntHelper>::NBITS / 2 {
+                    return ((self >> (<$U as IntHelper>::NBITS / 2)) as $H)
+                        .write_frac_dec(nbits, auto_prec, buf);
                 }
 
                 // add_5 is to add rounding when all bits are used
-                let (mut tie, mut add_5) = if nbits == $U::NBITS {
+                let (mut tie, mut add_5) = if nbits == <$U as IntHelper>::NBITS {
                     (0, true)
                 } else {
                     ($U::MSB >> nbits, false)
@@ -336,6 +364,68 @@ macro_rules! impl_radix_helper {
                 }
                 self.cmp(&$U::MSB)
             }
+            fn radix_digits(mut self, radix: u8) -> u32 {
+                let mut digits = 0;
+                while self != 0 {
+                    self /= $U::from(radix);
+                    digits += 1;
+                }
+                digits
+            }
+            fn write_int_radix(mut self, radix: u8, buf: &mut Buffer) {
+                for b in buf.int().iter_mut().rev() {
+                    *b = (self % $U::from(radix)).lower_byte();
+                    self /= $U::from(radix);
+                }
+                debug_assert!(self == 0);
+            }
+            fn write_frac_radix(
+                mut self,
+                radix: u8,
+                nbits: u32,
+                auto_prec: bool,
+                buf: &mut Buffer,
+            ) -> Ordering {
+                if $attempt_half && nbits < <$U as IntHelper>::NBITS / 2 {
+                    return ((self >> (<$U as IntHelper>::NBITS / 2)) as $H)
+                        .write_frac_radix(radix, nbits, auto_prec, buf);
+                }
+
+                // add_half is like add_5 in write_frac_dec; for odd
+                // radices the tie is rounded down, which can only keep
+                // a digit that could have been trimmed
+                let (mut tie, mut add_half) = if nbits == <$U as IntHelper>::NBITS {
+                    (0, true)
+                } else {
+                    ($U::MSB >> nbits, false)
+                };
+                let mut trim_to = None;
+                for (i, b) in buf.frac().iter_mut().enumerate() {
+                    *b = self.mul_radix_assign(radix);
+
+                    // Check if very close to zero, like in write_frac_dec.
+                    if self < $U::from(radix) || self.wrapping_neg() < $U::from(radix) {
+                        trim_to = Some(i + 1);
+                        break;
+                    }
+
+                    if auto_prec {
+                        tie.mul_radix_assign(radix);
+                        if add_half {
+                            tie += $U::from(radix / 2);
+                            add_half = false;
+                        }
+                        if self < tie || self.wrapping_neg() < tie {
+                            trim_to = Some(i + 1);
+                            break;
+                        }
+                    }
+                }
+                if let Some(trim_to) = trim_to {
+                    buf.frac_digits = trim_to;
+                }
+                self.cmp(&$U::MSB)
+            }
         }
     };
 }
@@ -346,6 +436,161 @@ impl_radix_helper! { u32, u16, true }
 impl_radix_helper! { u64, u32, true }
 impl_radix_helper! { u128, u64, true }
 
+impl FmtHelper for U256 {
+    const NBITS: u32 = 256;
+    const ZERO: U256 = U256::ZERO;
+    #[inline]
+    fn leading_zeros(self) -> u32 {
+        U256::leading_zeros(self)
+    }
+    #[inline]
+    fn trailing_zeros(self) -> u32 {
+        U256::trailing_zeros(self)
+    }
+    fn write_int(mut self, radix: Radix, nbits: u32, buf: &mut Buffer) {
+        if nbits < 128 {
+            return self.to_words().1.write_int(radix, nbits, buf);
+        }
+        let digit_bits = radix.digit_bits();
+        let mask = radix.max();
+        for b in buf.int().iter_mut().rev() {
+            debug_assert!(!self.is_zero());
+            *b = self.lower_byte() & mask;
+            self = self >> digit_bits;
+        }
+        debug_assert!(self.is_zero());
+    }
+    fn write_frac(mut self, radix: Radix, nbits: u32, buf: &mut Buffer) -> Ordering {
+        if nbits < 128 {
+            return self.to_words().0.write_frac(radix, nbits, buf);
+        }
+        let digit_bits = radix.digit_bits();
+        let compl_digit_bits = 256 - digit_bits;
+        for b in buf.frac().iter_mut() {
+            debug_assert!(!self.is_zero());
+            *b = (self >> compl_digit_bits).lower_byte();
+            self = self << digit_bits;
+        }
+        self.cmp(&U256::MSB)
+    }
+    fn write_int_dec(mut self, nbits: u32, buf: &mut Buffer) {
+        if nbits < 128 {
+            return self.to_words().1.write_int_dec(nbits, buf);
+        }
+        for b in buf.int().iter_mut().rev() {
+            let (q, r) = self.div_rem_u128(10);
+            *b = r as u8;
+            self = q;
+        }
+        debug_assert!(self.is_zero());
+    }
+    fn write_frac_dec(mut self, nbits: u32, auto_prec: bool, buf: &mut Buffer) -> Ordering {
+        if nbits < 128 {
+            return self.to_words().0.write_frac_dec(nbits, auto_prec, buf);
+        }
+
+        let ten = U256::from(10u8);
+        // add_5 is to add rounding when all bits are used
+        let (mut tie, mut add_5) = if nbits == 256 {
+            (U256::ZERO, true)
+        } else {
+            (U256::MSB >> nbits, false)
+        };
+        let mut trim_to = None;
+        for (i, b) in buf.frac().iter_mut().enumerate() {
+            *b = self.mul10_assign();
+
+            // Check if very close to zero, to avoid things like 0.19999999 and 0.20000001.
+            // This takes place even if we have a precision.
+            if self < ten || self.wrapping_neg() < ten {
+                trim_to = Some(i + 1);
+                break;
+            }
+
+            if auto_prec {
+                // tie might overflow in last iteration when i = frac_digits - 1,
+                // but it has no effect as all it can do is set trim_to = Some(i + 1)
+                tie.mul10_assign();
+                if add_5 {
+                    tie = tie.wrapping_add(U256::from(5u8));
+                    add_5 = false;
+                }
+                if self < tie || self.wrapping_neg() < tie {
+                    trim_to = Some(i + 1);
+                    break;
+                }
+            }
+        }
+        if let Some(trim_to) = trim_to {
+            buf.frac_digits = trim_to;
+        }
+        self.cmp(&U256::MSB)
+    }
+    fn radix_digits(mut self, radix: u8) -> u32 {
+        let mut digits = 0;
+        while self.to_words().0 != 0 {
+            self = self.div_rem_u128(u128::from(radix)).0;
+            digits += 1;
+        }
+        digits + self.to_words().1.radix_digits(radix)
+    }
+    fn write_int_radix(mut self, radix: u8, buf: &mut Buffer) {
+        for b in buf.int().iter_mut().rev() {
+            let (q, r) = self.div_rem_u128(u128::from(radix));
+            *b = r as u8;
+            self = q;
+        }
+        debug_assert!(self.is_zero());
+    }
+    fn write_frac_radix(
+        mut self,
+        radix: u8,
+        nbits: u32,
+        auto_prec: bool,
+        buf: &mut Buffer,
+    ) -> Ordering {
+        if nbits < 128 {
+            return self
+                .to_words()
+                .0
+                .write_frac_radix(radix, nbits, auto_prec, buf);
+        }
+        let radix_u256 = U256::from(radix);
+        // add_half is like add_5 in write_frac_dec
+        let (mut tie, mut add_half) = if nbits == 256 {
+            (U256::ZERO, true)
+        } else {
+            (U256::MSB >> nbits, false)
+        };
+        let mut trim_to = None;
+        for (i, b) in buf.frac().iter_mut().enumerate() {
+            *b = self.mul_radix_assign(radix);
+
+            // Check if very close to zero, like in write_frac_dec.
+            if self < radix_u256 || self.wrapping_neg() < radix_u256 {
+                trim_to = Some(i + 1);
+                break;
+            }
+
+            if auto_prec {
+                tie.mul_radix_assign(radix);
+                if add_half {
+                    tie = tie.wrapping_add(U256::from(radix / 2));
+                    add_half = false;
+                }
+                if self < tie || self.wrapping_neg() < tie {
+                    trim_to = Some(i + 1);
+                    break;
+                }
+            }
+        }
+        if let Some(trim_to) = trim_to {
+            buf.frac_digits = trim_to;
+        }
+        self.cmp(&U256::MSB)
+    }
+}
+
 fn fmt_dec<U: FmtHelper>((neg, abs): (bool, U), frac_nbits: u32, fmt: &mut Formatter) -> FmtResult {
     let (int, frac) = if frac_nbits == 0 {
         (abs, U::ZERO)
@@ -402,6 +647,95 @@ fn fmt_radix2<U: FmtHelper>(
     buf.finish(radix, neg, frac_rem_cmp_msb, fmt)
 }
 
+fn fmt_radix<U: FmtHelper>(
+    (neg, abs): (bool, U),
+    frac_nbits: u32,
+    radix: u8,
+    fmt: &mut Formatter,
+) -> FmtResult {
+    let (int, frac) = if frac_nbits == 0 {
+        (abs, U::ZERO)
+    } else if frac_nbits == U::NBITS {
+        (U::ZERO, abs)
+    } else {
+        (abs >> frac_nbits, abs << (U::NBITS - frac_nbits))
+    };
+    let int_digits = int.radix_digits(radix);
+    let frac_used_nbits = U::NBITS - frac.trailing_zeros();
+    let (frac_digits, auto_prec) = if let Some(precision) = fmt.precision() {
+        // A fraction with n bits has at most n digits in an even
+        // radix, but it never terminates in an odd radix, so then the
+        // digits are limited to what fits in the buffer.
+        let max_digits = if radix & 1 == 0 {
+            frac_used_nbits
+        } else {
+            256 - int_digits
+        };
+        // max_digits fits in usize, but precision might wrap to 0 in u32
+        (cmp::min(max_digits as usize, precision) as u32, false)
+    } else if frac_nbits == 0 {
+        (0, true)
+    } else {
+        // enough digits to tell apart all 2^frac_nbits fractions
+        let max_frac = !U::ZERO >> (U::NBITS - frac_nbits);
+        (max_frac.radix_digits(radix), true)
+    };
+
+    let mut buf = Buffer::new();
+    buf.set_len(int_digits, frac_digits);
+    int.write_int_radix(radix, &mut buf);
+    let frac_rem_cmp_msb = frac.write_frac_radix(radix, frac_nbits, auto_prec, &mut buf);
+    buf.finish(Radix::Any(radix), neg, frac_rem_cmp_msb, fmt)
+}
+
+/// A wrapper that formats a fixed-point number in any radix from 2 to
+/// 36, returned by methods such as [`FixedI32::display_radix`].
+///
+/// Digits above 9 are written as lowercase letters. For radices 2, 8,
+/// 10 and 16, the output is the same as for the [`Binary`], [`Octal`],
+/// [`Display`] and [`LowerHex`] implementations, including the prefix
+/// for the alternate form. No prefix is written for other radices.
+///
+/// Without a precision, the fractional part is written using the
+/// fewest digits that identify the number exactly, like in
+/// [`Display`]. Otherwise, rounding is to the nearest, with ties
+/// rounded to an even last digit. In odd radices, the expansion of a
+/// binary fraction does not terminate, and precision beyond the
+/// digits that can be computed, which are always more than enough to
+/// identify the number, is padded with zeros.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::types::I16F16;
+/// let x = I16F16::from_num(-35.5);
+/// assert_eq!(format!("{}", x.display_radix(36)), "-z.i");
+/// // 0.5 is 0.111… in base 3, so rounding it is always a tie
+/// assert_eq!(format!("{:.3}", I16F16::from_num(0.5).display_radix(3)), "0.112");
+/// ```
+///
+/// [`Binary`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Binary.html
+/// [`Display`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Display.html
+/// [`FixedI32::display_radix`]: struct.FixedI32.html#method.display_radix
+/// [`LowerHex`]: https://doc.rust-lang.org/nightly/core/fmt/trait.LowerHex.html
+/// [`Octal`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Octal.html
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayRadix<F> {
+    fixed: F,
+    radix: u8,
+}
+
+impl<F> DisplayRadix<F> {
+    // Panics if the radix is not in the range 2 to 36 inclusive.
+    pub(crate) fn new(fixed: F, radix: u32) -> DisplayRadix<F> {
+        check_radix(radix);
+        DisplayRadix {
+            fixed,
+            radix: radix as u8,
+        }
+    }
+}
+
 macro_rules! impl_fmt {
     ($Fixed:ident($LeEqU:ident)) => {
         impl<Frac: $LeEqU> Display for $Fixed<Frac> {
@@ -439,6 +773,20 @@ macro_rules! impl_fmt {
                 fmt_radix2(self.to_bits().neg_abs(), Self::FRAC_NBITS, Radix::UpHex, f)
             }
         }
+
+        impl<Frac: $LeEqU> Display for DisplayRadix<$Fixed<Frac>> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                let neg_abs = self.fixed.to_bits().neg_abs();
+                let frac_nbits = $Fixed::<Frac>::FRAC_NBITS;
+                match self.radix {
+                    2 => fmt_radix2(neg_abs, frac_nbits, Radix::Bin, f),
+                    8 => fmt_radix2(neg_abs, frac_nbits, Radix::Oct, f),
+                    10 => fmt_dec(neg_abs, frac_nbits, f),
+                    16 => fmt_radix2(neg_abs, frac_nbits, Radix::LowHex, f),
+                    radix => fmt_radix(neg_abs, frac_nbits, radix, f),
+                }
+            }
+        }
     };
 }
 
@@ -447,11 +795,13 @@ impl_fmt! { FixedU16(LeEqU16) }
 impl_fmt! { FixedU32(LeEqU32) }
 impl_fmt! { FixedU64(LeEqU64) }
 impl_fmt! { FixedU128(LeEqU128) }
+impl_fmt! { FixedU256(LeEqU256) }
 impl_fmt! { FixedI8(LeEqU8) }
 impl_fmt! { FixedI16(LeEqU16) }
 impl_fmt! { FixedI32(LeEqU32) }
 impl_fmt! { FixedI64(LeEqU64) }
 impl_fmt! { FixedI128(LeEqU128) }
+impl_fmt! { FixedI256(LeEqU256) }
 
 // ceil(i × log_10 2), works for input < 112_816
 fn ceil_log10_2_times(int_bits: u32) -> u32 {
@@ -496,6 +846,49 @@ impl Mul10 for u128 {
     }
 }
 
+trait MulRadix: Sized {
+    fn mul_radix_assign(&mut self, radix: u8) -> u8;
+}
+macro_rules! mul_radix_widen {
+    ($Single:ty, $Double:ty) => {
+        impl MulRadix for $Single {
+            #[inline]
+            fn mul_radix_assign(&mut self, radix: u8) -> u8 {
+                const NBITS: usize = 8 * mem::size_of::<$Single>();
+                let prod = <$Double>::from(*self) * <$Double>::from(radix);
+                *self = prod as $Single;
+                (prod >> NBITS) as u8
+            }
+        }
+    };
+}
+mul_radix_widen! { u8, u16 }
+mul_radix_widen! { u16, u32 }
+mul_radix_widen! { u32, u64 }
+mul_radix_widen! { u64, u128 }
+impl MulRadix for u128 {
+    #[inline]
+    fn mul_radix_assign(&mut self, radix: u8) -> u8 {
+        const LO_MASK: u128 = !(!0 << 64);
+        let hi = (*self >> 64) * u128::from(radix);
+        let lo = (*self & LO_MASK) * u128::from(radix);
+        // same workaround as in mul10_assign
+        let (hi_lo, hi_hi) = (hi as u64, (hi >> 64) as u64);
+        let (lo_lo, lo_hi) = (lo as u64, (lo >> 64) as u64);
+        let (wrapped, overflow) = hi_lo.overflowing_add(lo_hi);
+        *self = (u128::from(wrapped) << 64) | u128::from(lo_lo);
+        hi_hi as u8 + u8::from(overflow)
+    }
+}
+impl MulRadix for U256 {
+    #[inline]
+    fn mul_radix_assign(&mut self, radix: u8) -> u8 {
+        let (hi, lo) = self.mul_wide(U256::from(radix));
+        *self = lo;
+        hi.lower_byte()
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::cognitive_complexity, clippy::float_cmp)]
 mod tests {
@@ -822,4 +1215,123 @@ mod tests {
             }
         }
     }
+
+    fn radix_int_string(mut int: u128, radix: u32) -> String {
+        let mut s = String::new();
+        loop {
+            let digit = std::char::from_digit((int % u128::from(radix)) as u32, radix).unwrap();
+            s.insert(0, digit);
+            int /= u128::from(radix);
+            if int == 0 {
+                return s;
+            }
+        }
+    }
+
+    // Rounds to prec digits with ties to an even last digit.
+    fn radix_reference(bits: u128, frac_nbits: u32, radix: u32, prec: u32) -> String {
+        let radix = u128::from(radix);
+        let (mut int, frac) = (bits >> frac_nbits, bits & !(!0 << frac_nbits));
+        let scale = radix.pow(prec);
+        let prod = frac * scale;
+        let (mut digits, rem) = (prod >> frac_nbits, prod & !(!0 << frac_nbits));
+        let half = 1u128 << (frac_nbits - 1);
+        if rem > half || (rem == half && (digits % radix) % 2 != 0) {
+            digits += 1;
+            if digits == scale {
+                digits = 0;
+                int += 1;
+            }
+        }
+        let mut s = radix_int_string(int, radix as u32);
+        if prec > 0 {
+            let frac_str = radix_int_string(digits, radix as u32);
+            s.push('.');
+            for _ in frac_str.len()..prec as usize {
+                s.push('0');
+            }
+            s.push_str(&frac_str);
+        }
+        s
+    }
+
+    #[test]
+    fn radix() {
+        use crate::{
+            int256::{I256, U256},
+            FixedI256, FixedU256,
+        };
+
+        let x = I16F16::from_bits(-0x1234_5678);
+        assert_eq!(format!("{:#}", x.display_radix(2)), format!("{:#b}", x));
+        assert_eq!(format!("{:#.3}", x.display_radix(8)), format!("{:#.3o}", x));
+        assert_eq!(format!("{:+}", x.display_radix(10)), format!("{:+}", x));
+        assert_eq!(
+            format!("{:.40}", x.display_radix(16)),
+            format!("{:.40x}", x)
+        );
+
+        for radix in 2..=36 {
+            for i in 0..200u32 {
+                let bits = i.wrapping_mul(0x9E37_79B9) >> 8;
+                let fix = U16F16::from_bits(bits);
+                for prec in 0..4 {
+                    let expected = radix_reference(u128::from(bits), 16, radix, prec);
+                    let s = format!("{:.*}", prec as usize, fix.display_radix(radix));
+                    assert_eq!(s, expected, "{} radix {}", fix, radix);
+                    let neg = format!(
+                        "{:.*}",
+                        prec as usize,
+                        (-I16F16::from_bits(bits as i32)).display_radix(radix)
+                    );
+                    if bits != 0 {
+                        assert_eq!(neg, format!("-{}", expected), "-{} radix {}", fix, radix);
+                    }
+                }
+
+                // without a precision, parsing gives back the same number
+                let s = fix.display_radix(radix).to_string();
+                assert_eq!(U16F16::from_str_radix(&s, radix), Ok(fix), "{}", s);
+                let wide = I64F64::from_bits(-(i128::from(bits) << 40) - i128::from(i));
+                let s = wide.display_radix(radix).to_string();
+                assert_eq!(I64F64::from_str_radix(&s, radix), Ok(wide), "{}", s);
+                let tiny = U0F128::from_bits(u128::from(bits) << 100 | u128::from(i));
+                let s = tiny.display_radix(radix).to_string();
+                assert_eq!(U0F128::from_str_radix(&s, radix), Ok(tiny), "{}", s);
+                let huge = FixedI256::<extra::U100>::from_bits(I256::from_words(
+                    wide.to_bits(),
+                    tiny.to_bits(),
+                ));
+                let s = huge.display_radix(radix).to_string();
+                assert_eq!(FixedI256::from_str_radix(&s, radix), Ok(huge), "{}", s);
+                let all_frac = FixedU256::<extra::U256>::from_bits(U256::from_words(
+                    tiny.to_bits(),
+                    u128::from(i),
+                ));
+                let s = all_frac.display_radix(radix).to_string();
+                assert_eq!(FixedU256::from_str_radix(&s, radix), Ok(all_frac), "{}", s);
+            }
+        }
+
+        // one half has no finite expansion in odd radices
+        let half = U0F8::from_num(0.5);
+        assert_eq!(format!("{}", half.display_radix(3)), "0.111112");
+        assert_eq!(format!("{:.0}", half.display_radix(3)), "0");
+        assert_eq!(format!("{:.0}", U1F7::from_num(1.5).display_radix(3)), "2");
+        let long = format!(
+            "{:.300}",
+            FixedU256::<extra::U256>::from_bits(U256::MSB).display_radix(3)
+        );
+        // 256 digits fit, and they end in an exact tie rounded to even
+        let expected = format!("0.{:1>255}2{:0>44}", "", "");
+        assert_eq!(long, expected);
+
+        assert_eq!(
+            format!("{:>8}", I8F8::from_num(-35.5).display_radix(36)),
+            "    -z.i"
+        );
+        assert_eq!(format!("{:#}", U8F8::from_num(35).display_radix(36)), "z");
+        assert_eq!(format!("{}", U8F8::from_num(0).display_radix(7)), "0");
+        assert_eq!(format!("{:.2}", U8F8::from_num(0).display_radix(7)), "0.00");
+    }
 }
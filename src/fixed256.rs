@@ -0,0 +1,3427 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::{
+    arith::{MulDivRound, PowiFrac, RoundingMode, SqrtFrac},
+    display::DisplayRadix,
+    from_str::{check_radix, FromStrRadix, ParseFixedError, ParseOptions},
+    helpers::{FloatHelper, Sealed, ToFixedHelper, Widest},
+    int256::{I256, U256},
+    traits::{Fixed, FromFixed, ToFixed},
+    types::extra::LeEqU256,
+};
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
+        SubAssign,
+    },
+};
+
+// Shifts abs left by shift, or right by −shift, rounding towards −∞
+// if neg. Returns the wrapped absolute value and whether bits were
+// shifted out on the left.
+fn shl_floor(neg: bool, abs: U256, shift: i32) -> (U256, bool) {
+    if shift >= 0 {
+        let shift = shift as u32;
+        if shift >= 256 {
+            (U256::ZERO, !abs.is_zero())
+        } else {
+            (abs << shift, abs.leading_zeros() < shift)
+        }
+    } else {
+        let shift = (-shift) as u32;
+        let (shifted, inexact) = if shift >= 256 {
+            (U256::ZERO, !abs.is_zero())
+        } else {
+            (abs >> shift, !(abs << (256 - shift)).is_zero())
+        };
+        if neg && inexact {
+            (shifted.wrapping_add(U256::ONE), false)
+        } else {
+            (shifted, false)
+        }
+    }
+}
+
+// Converts a finite float to the absolute value of the bits of a
+// fixed-point number with frac_nbits fractional bits, rounding to the
+// nearest with ties to even. Returns the sign, the wrapped absolute
+// value and whether bits were shifted out on the left.
+fn float_to_neg_abs<Float: FloatHelper>(src: Float, frac_nbits: u32) -> (bool, U256, bool) {
+    let (neg, abs, exp) = src.neg_abs_exp();
+    let abs = U256::from(abs);
+    let shift = exp + frac_nbits as i32;
+    if shift >= 0 {
+        let (abs, overflow) = shl_floor(false, abs, shift);
+        return (neg, abs, overflow);
+    }
+    let shift = (-shift) as u32;
+    if shift >= 256 {
+        return (neg, U256::ZERO, false);
+    }
+    let kept = abs >> shift;
+    let removed = abs & !(U256::max_value() << shift);
+    let tie = U256::ONE << (shift - 1);
+    if removed > tie || (removed == tie && kept.is_odd()) {
+        (neg, kept.wrapping_add(U256::ONE), false)
+    } else {
+        (neg, kept, false)
+    }
+}
+
+// Returns the absolute product, wrapped, rounded towards −∞ if neg.
+fn mul_abs(lhs: U256, rhs: U256, frac_nbits: u32, neg: bool) -> (U256, bool) {
+    let (hi, lo) = lhs.mul_wide(rhs);
+    let (shifted, mut overflow, inexact) = match frac_nbits {
+        0 => (lo, !hi.is_zero(), false),
+        256 => (hi, false, !lo.is_zero()),
+        _ => (
+            (lo >> frac_nbits) | (hi << (256 - frac_nbits)),
+            !(hi >> frac_nbits).is_zero(),
+            !(lo << (256 - frac_nbits)).is_zero(),
+        ),
+    };
+    let abs = if neg && inexact {
+        let (abs, carry) = shifted.overflowing_add(U256::ONE);
+        overflow = overflow || carry;
+        abs
+    } else {
+        shifted
+    };
+    (abs, overflow)
+}
+
+// Returns the absolute quotient, wrapped, truncated.
+fn div_abs(lhs: U256, rhs: U256, frac_nbits: u32) -> (U256, bool) {
+    let (hi, lo) = match frac_nbits {
+        0 => (U256::ZERO, lhs),
+        256 => (lhs, U256::ZERO),
+        _ => (lhs >> (256 - frac_nbits), lhs << frac_nbits),
+    };
+    let ((q1, q0), _) = U256::div_rem_wide(hi, lo, rhs);
+    (q0, !q1.is_zero())
+}
+
+macro_rules! fixed256 {
+    (
+        $description:expr,
+        $Fixed:ident[$s_fixed:expr]($Inner:ident[$s_inner:expr]),
+        $Signedness:tt
+    ) => {
+        comment! {
+            $description,
+            " number with `Frac` fractional bits.
+
+The number is backed by a [`", $s_inner, "`], which stores two
+128-bit words, so `Frac` can be anything from [`U0`] to [`U256`].
+
+Arithmetic, comparisons, parsing and formatting work like for the
+narrower fixed-point numbers. Conversions to and from the other
+fixed-point numbers are provided by the [`FromFixed`] and
+[`ToFixed`] traits.
+
+# Examples
+
+```rust
+use substrate_fixed::{
+    traits::FromFixed,
+    types::{extra::U3, I16F16},
+    FixedI16, ", $s_fixed, ",
+};
+type Fix = ", $s_fixed, "<U3>;
+let eleven: Fix = \"11\".parse().unwrap();
+assert_eq!(eleven.to_string(), \"11\");
+let two_point_75 = eleven / \"4\".parse::<Fix>().unwrap();
+assert_eq!(two_point_75.to_string(), \"2.8\");
+assert_eq!(Fix::from_fixed(I16F16::from_num(2.75)), two_point_75);
+assert_eq!(FixedI16::<U3>::from_num(two_point_75), 2.75);
+```
+
+[`", $s_inner, "`]: int256/struct.", $s_inner, ".html
+[`FromFixed`]: traits/trait.FromFixed.html
+[`ToFixed`]: traits/trait.ToFixed.html
+[`U0`]: types/extra/type.U0.html
+[`U256`]: types/extra/type.U256.html
+";
+            #[repr(transparent)]
+            pub struct $Fixed<Frac> {
+                bits: $Inner,
+                phantom: PhantomData<Frac>,
+            }
+        }
+
+        impl<Frac> Clone for $Fixed<Frac> {
+            #[inline]
+            fn clone(&self) -> $Fixed<Frac> {
+                *self
+            }
+        }
+
+        impl<Frac> Copy for $Fixed<Frac> {}
+
+        impl<Frac> Default for $Fixed<Frac> {
+            #[inline]
+            fn default() -> Self {
+                $Fixed {
+                    bits: Default::default(),
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<Frac> Hash for $Fixed<Frac> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.bits.hash(state);
+            }
+        }
+
+        impl<Frac> $Fixed<Frac> {
+            /// Returns the smallest value that can be represented.
+            #[inline]
+            pub const fn min_value() -> $Fixed<Frac> {
+                Self::from_bits($Inner::min_value())
+            }
+
+            /// Returns the largest value that can be represented.
+            #[inline]
+            pub const fn max_value() -> $Fixed<Frac> {
+                Self::from_bits($Inner::max_value())
+            }
+
+            comment! {
+                "Creates a fixed-point number that has a bitwise
+representation identical to the given integer.
+
+# Examples
+
+```rust
+use substrate_fixed::{int256::", $s_inner, ", types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 0010.0000 == 2
+let two = Fix::from_bits(", $s_inner, "::from(0b10_0000u8));
+assert_eq!(two.to_string(), \"2\");
+```
+";
+                #[inline]
+                pub const fn from_bits(bits: $Inner) -> $Fixed<Frac> {
+                    $Fixed {
+                        bits,
+                        phantom: PhantomData,
+                    }
+                }
+            }
+
+            /// Creates an integer that has a bitwise representation
+            /// identical to the given fixed-point number.
+            #[inline]
+            pub const fn to_bits(self) -> $Inner {
+                self.bits
+            }
+
+            // Used by the fixed! macro, which passes FRAC_NBITS as it
+            // cannot be used in a const fn here without Frac bounds.
+            #[cfg(const_panic)]
+            #[doc(hidden)]
+            #[inline]
+            pub const fn __from_lit(src: &str, frac_nbits: u32) -> $Fixed<Frac> {
+                let signed = if_signed_unsigned!($Signedness, true, false);
+                let (hi, lo) = crate::lit::parse(src.as_bytes(), 256, frac_nbits, signed);
+                Self::from_bits($Inner::from_words(hi as _, lo))
+            }
+
+            /// Creates a fixed-point number from its representation
+            /// as a byte array in big endian.
+            #[inline]
+            pub fn from_be_bytes(bytes: [u8; 32]) -> $Fixed<Frac> {
+                $Fixed::from_bits($Inner::from_be_bytes(bytes))
+            }
+
+            /// Creates a fixed-point number from its representation
+            /// as a byte array in little endian.
+            #[inline]
+            pub fn from_le_bytes(bytes: [u8; 32]) -> $Fixed<Frac> {
+                $Fixed::from_bits($Inner::from_le_bytes(bytes))
+            }
+
+            /// Creates a fixed-point number from its representation
+            /// as a byte array in native endian.
+            #[inline]
+            pub fn from_ne_bytes(bytes: [u8; 32]) -> $Fixed<Frac> {
+                $Fixed::from_bits($Inner::from_ne_bytes(bytes))
+            }
+
+            /// Returns the memory representation of this fixed-point
+            /// number as a byte array in big-endian byte order.
+            #[inline]
+            pub fn to_be_bytes(self) -> [u8; 32] {
+                self.to_bits().to_be_bytes()
+            }
+
+            /// Returns the memory representation of this fixed-point
+            /// number as a byte array in little-endian byte order.
+            #[inline]
+            pub fn to_le_bytes(self) -> [u8; 32] {
+                self.to_bits().to_le_bytes()
+            }
+
+            /// Returns the memory representation of this fixed-point
+            /// number as a byte array in native byte order.
+            #[inline]
+            pub fn to_ne_bytes(self) -> [u8; 32] {
+                self.to_bits().to_ne_bytes()
+            }
+        }
+
+        impl<Frac: LeEqU256> $Fixed<Frac> {
+            /// The number of integer bits.
+            pub const INT_NBITS: u32 = 256 - Frac::U32;
+
+            /// The number of fractional bits.
+            pub const FRAC_NBITS: u32 = Frac::U32;
+
+            /// Returns the number of integer bits.
+            #[inline]
+            pub fn int_nbits() -> u32 {
+                Self::INT_NBITS
+            }
+
+            /// Returns the number of fractional bits.
+            #[inline]
+            pub fn frac_nbits() -> u32 {
+                Self::FRAC_NBITS
+            }
+
+            comment! {
+                "Returns the integer part.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "Note that since the numbers are stored in two’s
+complement, negative numbers with non-zero fractional parts will be
+rounded towards −∞, except in the case where there are no integer
+bits, that is `", $s_fixed, "<U256>`, where the return value is always
+zero.
+
+",
+                },
+                "# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let val: Fix = \"12.25\".parse().unwrap();
+assert_eq!(val.int().to_string(), \"12\");
+```
+";
+                #[inline]
+                pub fn int(self) -> $Fixed<Frac> {
+                    match Self::FRAC_NBITS {
+                        0 => self,
+                        256 => Self::default(),
+                        f => Self::from_bits(self.to_bits() >> f << f),
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the fractional part.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "Note that since the numbers are stored in two’s
+complement, the returned fraction will be non-negative for negative
+numbers, except in the case where there are no integer bits, that is
+`", $s_fixed, "<U256>` where the return value is always equal to
+`self`.
+
+",
+                },
+                "# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let val: Fix = \"12.25\".parse().unwrap();
+assert_eq!(val.frac().to_string(), \"0.25\");
+```
+";
+                #[inline]
+                pub fn frac(self) -> $Fixed<Frac> {
+                    self ^ self.int()
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+
+                /// Returns [`true`][`bool`] if the number is > 0.
+                ///
+                /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+                #[inline]
+                pub fn is_positive(self) -> bool {
+                    self.to_bits() > $Inner::default()
+                }
+
+                /// Returns [`true`][`bool`] if the number is < 0.
+                ///
+                /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+                #[inline]
+                pub fn is_negative(self) -> bool {
+                    self.to_bits().is_negative()
+                }
+
+                /// Returns the absolute value.
+                ///
+                /// # Panics
+                ///
+                /// When debug assertions are enabled, panics if the
+                /// value is the minimum value.
+                #[inline]
+                pub fn abs(self) -> $Fixed<Frac> {
+                    if self.is_negative() {
+                        -self
+                    } else {
+                        self
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing binary digits to return a fixed-point number.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.75 is 1.11 in binary
+let f = Fix::from_str_binary(\"1.11\");
+assert_eq!(f.map(|x| x.to_string()), Ok(\"1.75\".into()));
+```
+";
+                #[inline]
+                pub fn from_str_binary(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                    FromStrRadix::from_str_radix(src, 2)
+                }
+            }
+
+            /// Parses a string slice containing octal digits to
+            /// return a fixed-point number.
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            #[inline]
+            pub fn from_str_octal(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_str_radix(src, 8)
+            }
+
+            /// Parses a string slice containing hexadecimal digits to
+            /// return a fixed-point number.
+            ///
+            /// The digits can be followed by a binary exponent
+            /// introduced by `p` or `P`, for example `1.8p4` is 24.
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            #[inline]
+            pub fn from_str_hex(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_str_radix(src, 16)
+            }
+
+            /// Parses a string slice containing digits in the given
+            /// radix to return a fixed-point number.
+            ///
+            /// Digits above 9 are the letters `a` to `z`, which can be
+            /// uppercase or lowercase.
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the radix is not in the range 2 to 36
+            /// inclusive.
+            #[inline]
+            pub fn from_str_radix(src: &str, radix: u32) -> Result<$Fixed<Frac>, ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::from_str_radix(src, radix)
+            }
+
+            /// Parses a string slice containing digits in the given
+            /// radix to return a fixed-point number, saturating on
+            /// overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the radix is not in the range 2 to 36
+            /// inclusive.
+            #[inline]
+            pub fn saturating_from_str_radix(
+                src: &str,
+                radix: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::saturating_from_str_radix(src, radix)
+            }
+
+            /// Parses a string slice containing digits in the given
+            /// radix to return a fixed-point number, wrapping on
+            /// overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the radix is not in the range 2 to 36
+            /// inclusive.
+            #[inline]
+            pub fn wrapping_from_str_radix(
+                src: &str,
+                radix: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::wrapping_from_str_radix(src, radix)
+            }
+
+            /// Parses a string slice containing digits in the given
+            /// radix to return a fixed-point number and whether an
+            /// overflow has occurred, in which case the wrapped value
+            /// is returned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the radix is not in the range 2 to 36
+            /// inclusive.
+            #[inline]
+            pub fn overflowing_from_str_radix(
+                src: &str,
+                radix: u32,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::overflowing_from_str_radix(src, radix)
+            }
+
+            /// Parses a string slice to return a fixed-point number,
+            /// using the given [`ParseOptions`].
+            ///
+            /// [`ParseOptions`]: struct.ParseOptions.html
+            #[inline]
+            pub fn from_str_with(
+                src: &str,
+                options: ParseOptions,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_bytes(src.as_bytes(), options)
+            }
+
+            /// Parses a string slice to return a fixed-point number,
+            /// using the given [`ParseOptions`] and saturating on
+            /// overflow.
+            ///
+            /// [`ParseOptions`]: struct.ParseOptions.html
+            #[inline]
+            pub fn saturating_from_str_with(
+                src: &str,
+                options: ParseOptions,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_bytes(src.as_bytes(), options)
+            }
+
+            /// Parses a string slice to return a fixed-point number,
+            /// using the given [`ParseOptions`] and wrapping on
+            /// overflow.
+            ///
+            /// [`ParseOptions`]: struct.ParseOptions.html
+            #[inline]
+            pub fn wrapping_from_str_with(
+                src: &str,
+                options: ParseOptions,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_bytes(src.as_bytes(), options)
+            }
+
+            /// Parses a string slice to return a fixed-point number
+            /// and whether an overflow has occurred, using the given
+            /// [`ParseOptions`]. On overflow, the wrapped value is
+            /// returned.
+            ///
+            /// [`ParseOptions`]: struct.ParseOptions.html
+            #[inline]
+            pub fn overflowing_from_str_with(
+                src: &str,
+                options: ParseOptions,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                FromStrRadix::overflowing_from_bytes(src.as_bytes(), options)
+            }
+
+            /// Parses a byte slice containing ASCII decimal digits to
+            /// return a fixed-point number.
+            ///
+            /// This accepts the same syntax as [`FromStr`], but it
+            /// does not require the bytes to be valid UTF-8.
+            ///
+            /// [`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+            #[inline]
+            pub fn from_ascii(src: &[u8]) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_bytes(src, ParseOptions::new())
+            }
+
+            /// Parses a byte slice containing ASCII decimal digits to
+            /// return a fixed-point number, saturating on overflow.
+            #[inline]
+            pub fn saturating_from_ascii(src: &[u8]) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_bytes(src, ParseOptions::new())
+            }
+
+            /// Parses a byte slice containing ASCII decimal digits to
+            /// return a fixed-point number, wrapping on overflow.
+            #[inline]
+            pub fn wrapping_from_ascii(src: &[u8]) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_bytes(src, ParseOptions::new())
+            }
+
+            /// Parses a byte slice containing ASCII decimal digits to
+            /// return a fixed-point number and whether an overflow has
+            /// occurred, in which case the wrapped value is returned.
+            #[inline]
+            pub fn overflowing_from_ascii(
+                src: &[u8],
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                FromStrRadix::overflowing_from_bytes(src, ParseOptions::new())
+            }
+
+            /// Parses a byte slice containing ASCII digits in the given
+            /// radix to return a fixed-point number.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the radix is not in the range 2 to 36
+            /// inclusive.
+            #[inline]
+            pub fn from_ascii_radix(
+                src: &[u8],
+                radix: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::from_bytes(src, ParseOptions::strict(radix))
+            }
+
+            /// Parses a byte slice containing ASCII digits in the given
+            /// radix to return a fixed-point number, saturating on
+            /// overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the radix is not in the range 2 to 36
+            /// inclusive.
+            #[inline]
+            pub fn saturating_from_ascii_radix(
+                src: &[u8],
+                radix: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::saturating_from_bytes(src, ParseOptions::strict(radix))
+            }
+
+            /// Parses a byte slice containing ASCII digits in the given
+            /// radix to return a fixed-point number, wrapping on
+            /// overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the radix is not in the range 2 to 36
+            /// inclusive.
+            #[inline]
+            pub fn wrapping_from_ascii_radix(
+                src: &[u8],
+                radix: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::wrapping_from_bytes(src, ParseOptions::strict(radix))
+            }
+
+            /// Parses a byte slice containing ASCII digits in the given
+            /// radix to return a fixed-point number and whether an
+            /// overflow has occurred, in which case the wrapped value
+            /// is returned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the radix is not in the range 2 to 36
+            /// inclusive.
+            #[inline]
+            pub fn overflowing_from_ascii_radix(
+                src: &[u8],
+                radix: u32,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::overflowing_from_bytes(src, ParseOptions::strict(radix))
+            }
+
+            /// Returns a wrapper that formats the fixed-point number
+            /// in the given radix.
+            ///
+            /// See [`DisplayRadix`] for details.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the radix is not in the range 2 to 36
+            /// inclusive.
+            ///
+            /// [`DisplayRadix`]: struct.DisplayRadix.html
+            #[inline]
+            pub fn display_radix(self, radix: u32) -> DisplayRadix<$Fixed<Frac>> {
+                DisplayRadix::new(self, radix)
+            }
+
+            /// Formats the fixed-point number as a string in the
+            /// given radix.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the radix is not in the range 2 to 36
+            /// inclusive.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn to_string_radix(self, radix: u32) -> String {
+                self.display_radix(radix).to_string()
+            }
+
+            /// Parses a string slice containing decimal digits to
+            /// return a fixed-point number, saturating on overflow.
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            #[inline]
+            pub fn saturating_from_str(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_str_radix(src, 10)
+            }
+
+            /// Parses a string slice containing decimal digits to
+            /// return a fixed-point number, wrapping on overflow.
+            ///
+            /// Rounding is to the nearest, with ties rounded to even.
+            #[inline]
+            pub fn wrapping_from_str(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_str_radix(src, 10)
+            }
+
+            comment! {
+                "Parses a string slice containing decimal digits to return a fixed-point number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (f, o) = Fix::overflowing_from_str(\"1.75\").unwrap();
+assert_eq!((f.to_string().as_str(), o), (\"1.75\", false));
+let max = Fix::max_value().to_string();
+let (f, o) = Fix::overflowing_from_str(&max).unwrap();
+assert_eq!((f, o), (Fix::max_value(), false));
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+                #[inline]
+                pub fn overflowing_from_str(
+                    src: &str,
+                ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                    FromStrRadix::overflowing_from_str_radix(src, 10)
+                }
+            }
+
+            comment! {
+                "Overflowing negation.
+
+Returns a [tuple] of the negated value and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+",
+                if_signed_unsigned! {
+                    $Signedness,
+                    "Overflow can only happen when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                },
+                "
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+                #[inline]
+                pub fn overflowing_neg(self) -> ($Fixed<Frac>, bool) {
+                    let (ans, o) = self.to_bits().overflowing_neg();
+                    (Self::from_bits(ans), o)
+                }
+            }
+
+            /// Overflowing addition.
+            ///
+            /// Returns a [tuple] of the sum and a [`bool`] indicating
+            /// whether an overflow has occurred. On overflow, the
+            /// wrapped value is returned.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_add(self, rhs: $Fixed<Frac>) -> ($Fixed<Frac>, bool) {
+                let (ans, o) = self.to_bits().overflowing_add(rhs.to_bits());
+                (Self::from_bits(ans), o)
+            }
+
+            /// Overflowing subtraction.
+            ///
+            /// Returns a [tuple] of the difference and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_sub(self, rhs: $Fixed<Frac>) -> ($Fixed<Frac>, bool) {
+                let (ans, o) = self.to_bits().overflowing_sub(rhs.to_bits());
+                (Self::from_bits(ans), o)
+            }
+
+            comment! {
+                "Overflowing multiplication.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+The full 512-bit product is computed before it is shifted back, so the
+intermediate result cannot overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let a: Fix = \"1.5\".parse().unwrap();
+let b: Fix = \"2.25\".parse().unwrap();
+let (prod, o) = a.overflowing_mul(b);
+assert_eq!((format!(\"{:.3}\", prod), o), (\"3.375\".into(), false));
+let (_, o) = Fix::max_value().overflowing_mul(b);
+assert!(o);
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+                #[inline]
+                pub fn overflowing_mul(self, rhs: $Fixed<Frac>) -> ($Fixed<Frac>, bool) {
+                    let (lhs_neg, lhs_abs) = self.to_bits().neg_abs();
+                    let (rhs_neg, rhs_abs) = rhs.to_bits().neg_abs();
+                    let neg = lhs_neg != rhs_neg;
+                    let (abs, overflow1) = mul_abs(lhs_abs, rhs_abs, Self::FRAC_NBITS, neg);
+                    let (ans, overflow2) = $Inner::overflowing_from_neg_abs(neg, abs);
+                    (Self::from_bits(ans), overflow1 || overflow2)
+                }
+            }
+
+            /// Overflowing division.
+            ///
+            /// Returns a [tuple] of the quotient and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_div(self, rhs: $Fixed<Frac>) -> ($Fixed<Frac>, bool) {
+                let (lhs_neg, lhs_abs) = self.to_bits().neg_abs();
+                let (rhs_neg, rhs_abs) = rhs.to_bits().neg_abs();
+                let neg = lhs_neg != rhs_neg;
+                let (abs, overflow1) = div_abs(lhs_abs, rhs_abs, Self::FRAC_NBITS);
+                let (ans, overflow2) = $Inner::overflowing_from_neg_abs(neg, abs);
+                (Self::from_bits(ans), overflow1 || overflow2)
+            }
+
+            /// Checked negation. Returns the negated value, or [`None`] on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_neg(self) -> Option<$Fixed<Frac>> {
+                match self.overflowing_neg() {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked addition. Returns the sum, or [`None`] on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_add(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                match self.overflowing_add(rhs) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked subtraction. Returns the difference, or [`None`] on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_sub(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                match self.overflowing_sub(rhs) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked multiplication. Returns the product, or [`None`] on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_mul(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                match self.overflowing_mul(rhs) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked division. Returns the quotient, or [`None`] if
+            /// the divisor is zero or on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_div(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                if rhs.to_bits() == $Inner::default() {
+                    return None;
+                }
+                match self.overflowing_div(rhs) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked remainder. Returns the remainder, or [`None`] if
+            /// the divisor is zero.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_rem(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                if rhs.to_bits() == $Inner::default() {
+                    None
+                } else {
+                    Some(self % rhs)
+                }
+            }
+
+            /// Saturating negation. Returns the negated value, saturating on overflow.
+            #[inline]
+            pub fn saturating_neg(self) -> $Fixed<Frac> {
+                match self.overflowing_neg() {
+                    (ans, false) => ans,
+                    (_, true) => if_signed_unsigned! {
+                        $Signedness,
+                        Self::max_value(),
+                        Self::min_value(),
+                    },
+                }
+            }
+
+            /// Saturating addition. Returns the sum, saturating on overflow.
+            #[inline]
+            pub fn saturating_add(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                match self.overflowing_add(rhs) {
+                    (ans, false) => ans,
+                    (_, true) => if_signed_unsigned! {
+                        $Signedness,
+                        if rhs.is_negative() {
+                            Self::min_value()
+                        } else {
+                            Self::max_value()
+                        },
+                        Self::max_value(),
+                    },
+                }
+            }
+
+            /// Saturating subtraction. Returns the difference, saturating on overflow.
+            #[inline]
+            pub fn saturating_sub(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                match self.overflowing_sub(rhs) {
+                    (ans, false) => ans,
+                    (_, true) => if_signed_unsigned! {
+                        $Signedness,
+                        if rhs.is_negative() {
+                            Self::max_value()
+                        } else {
+                            Self::min_value()
+                        },
+                        Self::min_value(),
+                    },
+                }
+            }
+
+            /// Saturating multiplication. Returns the product, saturating on overflow.
+            #[inline]
+            pub fn saturating_mul(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                match self.overflowing_mul(rhs) {
+                    (ans, false) => ans,
+                    (_, true) => {
+                        let zero = $Inner::default();
+                        if (self.to_bits() < zero) != (rhs.to_bits() < zero) {
+                            Self::min_value()
+                        } else {
+                            Self::max_value()
+                        }
+                    }
+                }
+            }
+
+            /// Saturating division. Returns the quotient, saturating on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn saturating_div(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                match self.overflowing_div(rhs) {
+                    (ans, false) => ans,
+                    (_, true) => {
+                        let zero = $Inner::default();
+                        if (self.to_bits() < zero) != (rhs.to_bits() < zero) {
+                            Self::min_value()
+                        } else {
+                            Self::max_value()
+                        }
+                    }
+                }
+            }
+
+            /// Wrapping negation. Returns the negated value, wrapping on overflow.
+            #[inline]
+            pub fn wrapping_neg(self) -> $Fixed<Frac> {
+                self.overflowing_neg().0
+            }
+
+            /// Wrapping addition. Returns the sum, wrapping on overflow.
+            #[inline]
+            pub fn wrapping_add(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                self.overflowing_add(rhs).0
+            }
+
+            /// Wrapping subtraction. Returns the difference, wrapping on overflow.
+            #[inline]
+            pub fn wrapping_sub(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                self.overflowing_sub(rhs).0
+            }
+
+            /// Wrapping multiplication. Returns the product, wrapping on overflow.
+            #[inline]
+            pub fn wrapping_mul(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                self.overflowing_mul(rhs).0
+            }
+
+            /// Wrapping division. Returns the quotient, wrapping on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn wrapping_div(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                self.overflowing_div(rhs).0
+            }
+
+            // The integer lsb, or zero if there are no integer bits.
+            #[inline]
+            fn int_lsb() -> $Inner {
+                match Self::FRAC_NBITS {
+                    256 => $Inner::default(),
+                    f => $Inner::from(1u8) << f,
+                }
+            }
+
+            // The fractional msb, or zero if there are no fractional bits.
+            #[inline]
+            fn frac_msb() -> $Inner {
+                match Self::FRAC_NBITS {
+                    0 => $Inner::default(),
+                    f => $Inner::from(1u8) << (f - 1),
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from another number.
+
+The other number can be a fixed-point number, an integer or a
+floating-point number. Rounding is like for [`ToFixed::to_fixed`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, types::I8F8, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(3).to_string(), \"3\");
+assert_eq!(Fix::from_num(I8F8::from_num(1.75)).to_string(), \"1.75\");
+assert_eq!(Fix::from_num(1.75f64), Fix::from_num(7) >> 2);
+```
+
+[`ToFixed::to_fixed`]: traits/trait.ToFixed.html#tymethod.to_fixed
+";
+                #[inline]
+                pub fn from_num<Src: ToFixed>(src: Src) -> $Fixed<Frac> {
+                    src.to_fixed()
+                }
+            }
+
+            comment! {
+                "Converts a fixed-point number to another number.
+
+The other number can be a fixed-point number, an integer or a
+floating-point number. Rounding is like for [`FromFixed::from_fixed`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, types::I8F8, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let val: Fix = \"6.5\".parse().unwrap();
+assert_eq!(val.to_num::<i32>(), 6);
+assert_eq!(val.to_num::<f64>(), 6.5);
+assert_eq!(val.to_num::<I8F8>(), 6.5);
+```
+
+[`FromFixed::from_fixed`]: traits/trait.FromFixed.html#tymethod.from_fixed
+";
+                #[inline]
+                pub fn to_num<Dst: FromFixed>(self) -> Dst {
+                    Dst::from_fixed(self)
+                }
+            }
+
+            /// Creates a fixed-point number from another number if it
+            /// fits, otherwise returns [`None`].
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_from_num<Src: ToFixed>(src: Src) -> Option<$Fixed<Frac>> {
+                src.checked_to_fixed()
+            }
+
+            /// Converts a fixed-point number to another number if it
+            /// fits, otherwise returns [`None`].
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst> {
+                Dst::checked_from_fixed(self)
+            }
+
+            /// Creates a fixed-point number from another number,
+            /// saturating the value if it does not fit.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the source is a floating-point NaN.
+            #[inline]
+            pub fn saturating_from_num<Src: ToFixed>(src: Src) -> $Fixed<Frac> {
+                src.saturating_to_fixed()
+            }
+
+            /// Converts a fixed-point number to another number,
+            /// saturating the value if it does not fit.
+            #[inline]
+            pub fn saturating_to_num<Dst: FromFixed>(self) -> Dst {
+                Dst::saturating_from_fixed(self)
+            }
+
+            /// Creates a fixed-point number from another number,
+            /// wrapping the value on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the source is a floating-point NaN or
+            /// infinity.
+            #[inline]
+            pub fn wrapping_from_num<Src: ToFixed>(src: Src) -> $Fixed<Frac> {
+                src.wrapping_to_fixed()
+            }
+
+            /// Converts a fixed-point number to another number,
+            /// wrapping the value on overflow.
+            #[inline]
+            pub fn wrapping_to_num<Dst: FromFixed>(self) -> Dst {
+                Dst::wrapping_from_fixed(self)
+            }
+
+            /// Creates a fixed-point number from another number.
+            ///
+            /// Returns a [tuple] of the fixed-point number and a
+            /// [`bool`] indicating whether an overflow has occurred.
+            /// On overflow, the wrapped value is returned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the source is a floating-point NaN or
+            /// infinity.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_from_num<Src: ToFixed>(src: Src) -> ($Fixed<Frac>, bool) {
+                src.overflowing_to_fixed()
+            }
+
+            /// Converts a fixed-point number to another number.
+            ///
+            /// Returns a [tuple] of the number and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool) {
+                Dst::overflowing_from_fixed(self)
+            }
+
+            /// Parses a string slice containing binary digits to
+            /// return a fixed-point number, saturating on overflow.
+            #[inline]
+            pub fn saturating_from_str_binary(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_str_radix(src, 2)
+            }
+
+            /// Parses a string slice containing octal digits to
+            /// return a fixed-point number, saturating on overflow.
+            #[inline]
+            pub fn saturating_from_str_octal(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_str_radix(src, 8)
+            }
+
+            /// Parses a string slice containing hexadecimal digits to
+            /// return a fixed-point number, saturating on overflow.
+            #[inline]
+            pub fn saturating_from_str_hex(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_str_radix(src, 16)
+            }
+
+            /// Parses a string slice containing binary digits to
+            /// return a fixed-point number, wrapping on overflow.
+            #[inline]
+            pub fn wrapping_from_str_binary(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_str_radix(src, 2)
+            }
+
+            /// Parses a string slice containing octal digits to
+            /// return a fixed-point number, wrapping on overflow.
+            #[inline]
+            pub fn wrapping_from_str_octal(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_str_radix(src, 8)
+            }
+
+            /// Parses a string slice containing hexadecimal digits to
+            /// return a fixed-point number, wrapping on overflow.
+            #[inline]
+            pub fn wrapping_from_str_hex(src: &str) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_str_radix(src, 16)
+            }
+
+            /// Parses a string slice containing binary digits to
+            /// return a fixed-point number and whether an overflow has
+            /// occurred, in which case the wrapped value is returned.
+            #[inline]
+            pub fn overflowing_from_str_binary(
+                src: &str,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                FromStrRadix::overflowing_from_str_radix(src, 2)
+            }
+
+            /// Parses a string slice containing octal digits to
+            /// return a fixed-point number and whether an overflow has
+            /// occurred, in which case the wrapped value is returned.
+            #[inline]
+            pub fn overflowing_from_str_octal(
+                src: &str,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                FromStrRadix::overflowing_from_str_radix(src, 8)
+            }
+
+            /// Parses a string slice containing hexadecimal digits to
+            /// return a fixed-point number and whether an overflow has
+            /// occurred, in which case the wrapped value is returned.
+            #[inline]
+            pub fn overflowing_from_str_hex(
+                src: &str,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                FromStrRadix::overflowing_from_str_radix(src, 16)
+            }
+
+            /// Rounds to the next integer towards 0.
+            #[inline]
+            pub fn round_to_zero(self) -> $Fixed<Frac> {
+                if_signed! {
+                    $Signedness;
+                    if self.is_negative() && !self.frac().to_bits().is_zero() {
+                        let int = self.int();
+                        let increment = Self::from_bits(Self::int_lsb());
+                        if Self::INT_NBITS == 1 {
+                            // increment is -1, so subtract it
+                            return int - increment;
+                        }
+                        return int + increment;
+                    }
+                }
+                self.int()
+            }
+
+            comment! {
+                "Rounds to the next integer towards +∞.
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(2.5).ceil(), Fix::from_num(3));
+assert_eq!(Fix::from_num(2.5).floor(), Fix::from_num(2));
+assert_eq!(Fix::from_num(2.5).round(), Fix::from_num(3));
+assert_eq!(Fix::from_num(2.5).round_ties_to_even(), Fix::from_num(2));
+```
+";
+                #[inline]
+                pub fn ceil(self) -> $Fixed<Frac> {
+                    let (ceil, overflow) = self.overflowing_ceil();
+                    debug_assert!(!overflow, "overflow");
+                    ceil
+                }
+            }
+
+            /// Rounds to the next integer towards −∞.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the result
+            /// does not fit, which can only happen when there are zero
+            /// integer bits.
+            #[inline]
+            pub fn floor(self) -> $Fixed<Frac> {
+                let (floor, overflow) = self.overflowing_floor();
+                debug_assert!(!overflow, "overflow");
+                floor
+            }
+
+            /// Rounds to the nearest integer, with ties rounded away
+            /// from zero.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the result
+            /// does not fit.
+            #[inline]
+            pub fn round(self) -> $Fixed<Frac> {
+                let (round, overflow) = self.overflowing_round();
+                debug_assert!(!overflow, "overflow");
+                round
+            }
+
+            /// Rounds to the nearest integer, with ties rounded to
+            /// even.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the result
+            /// does not fit.
+            #[inline]
+            pub fn round_ties_to_even(self) -> $Fixed<Frac> {
+                let (round, overflow) = self.overflowing_round_ties_to_even();
+                debug_assert!(!overflow, "overflow");
+                round
+            }
+
+            /// Checked ceil. Rounds to the next integer towards +∞,
+            /// returning [`None`] on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_ceil(self) -> Option<$Fixed<Frac>> {
+                match self.overflowing_ceil() {
+                    (ceil, false) => Some(ceil),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked floor. Rounds to the next integer towards −∞,
+            /// returning [`None`] on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_floor(self) -> Option<$Fixed<Frac>> {
+                match self.overflowing_floor() {
+                    (floor, false) => Some(floor),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked round. Rounds to the nearest integer, with ties
+            /// rounded away from zero, returning [`None`] on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_round(self) -> Option<$Fixed<Frac>> {
+                match self.overflowing_round() {
+                    (round, false) => Some(round),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked round. Rounds to the nearest integer, with ties
+            /// rounded to even, returning [`None`] on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_round_ties_to_even(self) -> Option<$Fixed<Frac>> {
+                match self.overflowing_round_ties_to_even() {
+                    (round, false) => Some(round),
+                    (_, true) => None,
+                }
+            }
+
+            /// Saturating ceil. Rounds to the next integer towards +∞,
+            /// saturating on overflow.
+            #[inline]
+            pub fn saturating_ceil(self) -> $Fixed<Frac> {
+                match self.overflowing_ceil() {
+                    (ceil, false) => ceil,
+                    (_, true) => Self::max_value(),
+                }
+            }
+
+            /// Saturating floor. Rounds to the next integer towards
+            /// −∞, saturating on overflow.
+            #[inline]
+            pub fn saturating_floor(self) -> $Fixed<Frac> {
+                match self.overflowing_floor() {
+                    (floor, false) => floor,
+                    (_, true) => Self::min_value(),
+                }
+            }
+
+            /// Saturating round. Rounds to the nearest integer, with
+            /// ties rounded away from zero, and saturating on overflow.
+            #[inline]
+            pub fn saturating_round(self) -> $Fixed<Frac> {
+                match self.overflowing_round() {
+                    (round, false) => round,
+                    (_, true) => self.saturated_round(),
+                }
+            }
+
+            /// Saturating round. Rounds to the nearest integer, with
+            /// ties rounded to even, and saturating on overflow.
+            #[inline]
+            pub fn saturating_round_ties_to_even(self) -> $Fixed<Frac> {
+                match self.overflowing_round_ties_to_even() {
+                    (round, false) => round,
+                    (_, true) => self.saturated_round(),
+                }
+            }
+
+            // The saturated value when rounding overflows.
+            #[inline]
+            fn saturated_round(self) -> $Fixed<Frac> {
+                if self.to_bits() > $Inner::default() {
+                    Self::max_value()
+                } else {
+                    Self::min_value()
+                }
+            }
+
+            /// Wrapping ceil. Rounds to the next integer towards +∞,
+            /// wrapping on overflow.
+            #[inline]
+            pub fn wrapping_ceil(self) -> $Fixed<Frac> {
+                self.overflowing_ceil().0
+            }
+
+            /// Wrapping floor. Rounds to the next integer towards −∞,
+            /// wrapping on overflow.
+            #[inline]
+            pub fn wrapping_floor(self) -> $Fixed<Frac> {
+                self.overflowing_floor().0
+            }
+
+            /// Wrapping round. Rounds to the next integer to the
+            /// nearest, with ties rounded away from zero, and wrapping
+            /// on overflow.
+            #[inline]
+            pub fn wrapping_round(self) -> $Fixed<Frac> {
+                self.overflowing_round().0
+            }
+
+            /// Wrapping round. Rounds to the next integer to the
+            /// nearest, with ties rounded to even, and wrapping on
+            /// overflow.
+            #[inline]
+            pub fn wrapping_round_ties_to_even(self) -> $Fixed<Frac> {
+                self.overflowing_round_ties_to_even().0
+            }
+
+            /// Overflowing ceil. Rounds to the next integer towards +∞.
+            ///
+            /// Returns a [tuple] of the fixed-point number and a
+            /// [`bool`], indicating whether an overflow has occurred.
+            /// On overflow, the wrapped value is returned.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_ceil(self) -> ($Fixed<Frac>, bool) {
+                let int = self.int();
+                if self.frac().to_bits().is_zero() {
+                    return (int, false);
+                }
+                if Self::INT_NBITS == 0 {
+                    return (int, self.to_bits() > $Inner::default());
+                }
+                let increment = Self::from_bits(Self::int_lsb());
+                if_signed! {
+                    $Signedness;
+                    if Self::INT_NBITS == 1 {
+                        // increment is -1, so subtract it
+                        return int.overflowing_sub(increment);
+                    }
+                }
+                int.overflowing_add(increment)
+            }
+
+            /// Overflowing floor. Rounds to the next integer towards −∞.
+            ///
+            /// Returns a [tuple] of the fixed-point number and a
+            /// [`bool`], indicating whether an overflow has occurred.
+            /// On overflow, the wrapped value is returned. Overflow
+            /// can only occur for signed numbers with zero integer
+            /// bits.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_floor(self) -> ($Fixed<Frac>, bool) {
+                let int = self.int();
+                if_signed! {
+                    $Signedness;
+                    if Self::INT_NBITS == 0 {
+                        return (int, self.is_negative());
+                    }
+                }
+                (int, false)
+            }
+
+            /// Overflowing round. Rounds to the next integer to the
+            /// nearest, with ties rounded away from zero.
+            ///
+            /// Returns a [tuple] of the fixed-point number and a
+            /// [`bool`], indicating whether an overflow has occurred.
+            /// On overflow, the wrapped value is returned.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_round(self) -> ($Fixed<Frac>, bool) {
+                let int = self.int();
+                if (self.to_bits() & Self::frac_msb()).is_zero() {
+                    return (int, false);
+                }
+                let increment = Self::from_bits(Self::int_lsb());
+                if_signed! {
+                    $Signedness;
+                    let tie = self.frac().to_bits() == Self::frac_msb();
+                    if Self::INT_NBITS == 0 {
+                        // −0.5 overflows, −0.5 < self < 0 rounds to 0
+                        return (int, tie);
+                    }
+                    // A negative tie is already rounded away from zero
+                    // by truncating towards −∞.
+                    if tie && self.is_negative() {
+                        return (int, false);
+                    }
+                    if Self::INT_NBITS == 1 {
+                        // increment is -1, so subtract it
+                        return int.overflowing_sub(increment);
+                    }
+                    int.overflowing_add(increment)
+                }
+                if_unsigned! {
+                    $Signedness;
+                    if Self::INT_NBITS == 0 {
+                        return (int, true);
+                    }
+                    int.overflowing_add(increment)
+                }
+            }
+
+            /// Overflowing round. Rounds to the next integer to the
+            /// nearest, with ties rounded to even.
+            ///
+            /// Returns a [tuple] of the fixed-point number and a
+            /// [`bool`], indicating whether an overflow has occurred.
+            /// On overflow, the wrapped value is returned.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_round_ties_to_even(self) -> ($Fixed<Frac>, bool) {
+                let int = self.int();
+                if (self.to_bits() & Self::frac_msb()).is_zero() {
+                    return (int, false);
+                }
+                if self.frac().to_bits() == Self::frac_msb()
+                    && (int.to_bits() & Self::int_lsb()).is_zero()
+                {
+                    return (int, false);
+                }
+                let increment = Self::from_bits(Self::int_lsb());
+                if_signed! {
+                    $Signedness;
+                    // If INT_NBITS is 0, increment is zero, and
+                    // −0.5 ≤ self < 0.5, so 0 + 0 is fine.
+                    if Self::INT_NBITS == 1 {
+                        // increment is -1, so subtract it
+                        int.overflowing_sub(increment)
+                    } else {
+                        int.overflowing_add(increment)
+                    }
+                }
+                if_unsigned! {
+                    $Signedness;
+                    if Self::INT_NBITS == 0 {
+                        return (int, true);
+                    }
+                    int.overflowing_add(increment)
+                }
+            }
+
+            /// Returns the number of ones in the binary representation.
+            #[inline]
+            pub fn count_ones(self) -> u32 {
+                self.to_bits().count_ones()
+            }
+
+            /// Returns the number of zeros in the binary representation.
+            #[inline]
+            pub fn count_zeros(self) -> u32 {
+                (!self.to_bits()).count_ones()
+            }
+
+            /// Returns the number of leading zeros in the binary
+            /// representation.
+            #[inline]
+            pub fn leading_zeros(self) -> u32 {
+                self.to_bits().leading_zeros()
+            }
+
+            /// Returns the number of trailing zeros in the binary
+            /// representation.
+            #[inline]
+            pub fn trailing_zeros(self) -> u32 {
+                self.to_bits().trailing_zeros()
+            }
+
+            /// Shifts to the left by `n` bits, wrapping the truncated
+            /// bits to the right end.
+            #[inline]
+            pub fn rotate_left(self, n: u32) -> $Fixed<Frac> {
+                Self::from_bits(self.to_bits().rotate_left(n))
+            }
+
+            /// Shifts to the right by `n` bits, wrapping the truncated
+            /// bits to the left end.
+            #[inline]
+            pub fn rotate_right(self, n: u32) -> $Fixed<Frac> {
+                Self::from_bits(self.to_bits().rotate_right(n))
+            }
+
+            /// Euclidean division.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            ///
+            /// When debug assertions are enabled, panics if the result
+            /// does not fit.
+            #[inline]
+            pub fn div_euclid(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                let (ans, overflow) = self.overflowing_div_euclid(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+
+            /// Remainder for Euclidean division.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn rem_euclid(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                self.checked_rem_euclid(rhs).expect("division by zero")
+            }
+
+            /// Euclidean division by an integer.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            ///
+            /// When debug assertions are enabled, panics if the result
+            /// does not fit.
+            #[inline]
+            pub fn div_euclid_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                let (ans, overflow) = self.overflowing_div_euclid_int(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+
+            /// Remainder for Euclidean division by an integer.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            ///
+            /// When debug assertions are enabled, panics if the result
+            /// does not fit.
+            #[inline]
+            pub fn rem_euclid_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                let (ans, overflow) = self.overflowing_rem_euclid_int(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+
+            comment! {
+                "Square root, rounded down.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "# Panics
+
+Panics if the number is negative, or if the root does not fit, which
+can only happen when there are no integer bits.
+
+",
+                },
+                "# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(6.25).sqrt(), Fix::from_num(2.5));
+```
+";
+                #[inline]
+                pub fn sqrt(self) -> $Fixed<Frac> {
+                    if_signed! {
+                        $Signedness;
+                        assert!(!self.is_negative(), "square root of negative number");
+                    }
+                    let root = self.to_bits().sqrt_frac(Frac::U32);
+                    Self::from_bits(root.expect("overflow"))
+                }
+            }
+
+            /// Multiplication with the given rounding mode.
+            ///
+            /// # Panics
+            ///
+            /// When debug assertions are enabled, panics if the result
+            /// does not fit.
+            #[inline]
+            pub fn mul_round(self, rhs: $Fixed<Frac>, mode: RoundingMode) -> $Fixed<Frac> {
+                let (ans, overflow) = self.overflowing_mul_round(rhs, mode);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+
+            /// Division with the given rounding mode.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            ///
+            /// When debug assertions are enabled, panics if the result
+            /// does not fit.
+            #[inline]
+            pub fn div_round(self, rhs: $Fixed<Frac>, mode: RoundingMode) -> $Fixed<Frac> {
+                let (ans, overflow) = self.overflowing_div_round(rhs, mode);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+
+            /// Computes `self` × `mul` ÷ `div` with the given rounding
+            /// mode, using the full 512-bit intermediate product.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `div` is zero.
+            ///
+            /// When debug assertions are enabled, panics if the result
+            /// does not fit.
+            #[inline]
+            pub fn mul_div_round(
+                self,
+                mul: $Fixed<Frac>,
+                div: $Fixed<Frac>,
+                mode: RoundingMode,
+            ) -> $Fixed<Frac> {
+                let (ans, overflow) =
+                    MulDivRound::mul_div_round(self.to_bits(), mul.to_bits(), div.to_bits(), mode);
+                debug_assert!(!overflow, "overflow");
+                Self::from_bits(ans)
+            }
+
+            /// Checked Euclidean division. Returns the quotient, or
+            /// [`None`] if the divisor is zero or on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_div_euclid(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                let q = self.checked_div(rhs)?.round_to_zero();
+                if_signed! {
+                    $Signedness;
+                    if (self % rhs).is_negative() {
+                        return if rhs.is_positive() {
+                            q.checked_add(Self::checked_from_num(-1)?)
+                        } else {
+                            q.checked_add(Self::checked_from_num(1)?)
+                        };
+                    }
+                }
+                Some(q)
+            }
+
+            /// Checked remainder for Euclidean division. Returns the
+            /// remainder, or [`None`] if the divisor is zero.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_rem_euclid(self, rhs: $Fixed<Frac>) -> Option<$Fixed<Frac>> {
+                let rem = self.checked_rem(rhs)?;
+                if_signed! {
+                    $Signedness;
+                    if rem.is_negative() {
+                        // |rhs| − |rem| is less than |rhs|, so it fits.
+                        let (_, rhs_abs) = rhs.to_bits().neg_abs();
+                        let (_, rem_abs) = rem.to_bits().neg_abs();
+                        let abs = rhs_abs.wrapping_sub(rem_abs);
+                        return Some(Self::from_bits($Inner::overflowing_from_neg_abs(false, abs).0));
+                    }
+                }
+                Some(rem)
+            }
+
+            /// Checked multiplication by an integer. Returns the
+            /// product, or [`None`] on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_mul_int(self, rhs: $Inner) -> Option<$Fixed<Frac>> {
+                match self.overflowing_mul_int(rhs) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked division by an integer. Returns the quotient, or
+            /// [`None`] if the divisor is zero or on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_div_int(self, rhs: $Inner) -> Option<$Fixed<Frac>> {
+                if rhs.is_zero() {
+                    return None;
+                }
+                match self.overflowing_div_int(rhs) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked fixed-point remainder for division by an
+            /// integer. Returns the remainder, or [`None`] if the
+            /// divisor is zero.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_rem_int(self, rhs: $Inner) -> Option<$Fixed<Frac>> {
+                let (neg, abs) = self.to_bits().neg_abs();
+                let (_, rhs_abs) = rhs.neg_abs();
+                if rhs_abs.is_zero() {
+                    return None;
+                }
+                // If rhs does not fit as a fixed-point number, |rhs| > |self|.
+                let rem = match shl_floor(false, rhs_abs, Self::FRAC_NBITS as i32) {
+                    (rhs_abs, false) => U256::div_rem_wide(U256::ZERO, abs, rhs_abs).1,
+                    (_, true) => abs,
+                };
+                Some(Self::from_bits($Inner::overflowing_from_neg_abs(neg, rem).0))
+            }
+
+            /// Checked Euclidean division by an integer. Returns the
+            /// quotient, or [`None`] if the divisor is zero or on
+            /// overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_div_euclid_int(self, rhs: $Inner) -> Option<$Fixed<Frac>> {
+                let q = self.checked_div_int(rhs)?.round_to_zero();
+                if_signed! {
+                    $Signedness;
+                    if (self % rhs).is_negative() {
+                        return if rhs.is_negative() {
+                            q.checked_add(Self::checked_from_num(1)?)
+                        } else {
+                            q.checked_add(Self::checked_from_num(-1)?)
+                        };
+                    }
+                }
+                Some(q)
+            }
+
+            /// Checked remainder for Euclidean division by an integer.
+            /// Returns the remainder, or [`None`] if the divisor is
+            /// zero or if the remainder results in overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_rem_euclid_int(self, rhs: $Inner) -> Option<$Fixed<Frac>> {
+                if rhs.is_zero() {
+                    return None;
+                }
+                match self.overflowing_rem_euclid_int(rhs) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked square root. Returns [`None`] if the number is
+            /// negative or if the root does not fit.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_sqrt(self) -> Option<$Fixed<Frac>> {
+                self.to_bits().sqrt_frac(Frac::U32).map(Self::from_bits)
+            }
+
+            /// Checked integer power. Returns [`None`] on overflow, or
+            /// if `self` is zero and `exp` is negative.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_powi(self, exp: i32) -> Option<$Fixed<Frac>> {
+                match self.to_bits().powi_frac(exp, Frac::U32) {
+                    Some((ans, false)) => Some(Self::from_bits(ans)),
+                    _ => None,
+                }
+            }
+
+            /// Checked multiplication with the given rounding mode.
+            /// Returns the product, or [`None`] on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_mul_round(
+                self,
+                rhs: $Fixed<Frac>,
+                mode: RoundingMode,
+            ) -> Option<$Fixed<Frac>> {
+                match self.overflowing_mul_round(rhs, mode) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked division with the given rounding mode. Returns
+            /// the quotient, or [`None`] if the divisor is zero or on
+            /// overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_div_round(
+                self,
+                rhs: $Fixed<Frac>,
+                mode: RoundingMode,
+            ) -> Option<$Fixed<Frac>> {
+                if rhs.to_bits().is_zero() {
+                    return None;
+                }
+                match self.overflowing_div_round(rhs, mode) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked `self` × `mul` ÷ `div`, rounded towards zero.
+            /// Returns [`None`] if `div` is zero or on overflow.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_mul_div(
+                self,
+                mul: $Fixed<Frac>,
+                div: $Fixed<Frac>,
+            ) -> Option<$Fixed<Frac>> {
+                if div.to_bits().is_zero() {
+                    return None;
+                }
+                let mode = RoundingMode::TowardZero;
+                match MulDivRound::mul_div_round(self.to_bits(), mul.to_bits(), div.to_bits(), mode) {
+                    (ans, false) => Some(Self::from_bits(ans)),
+                    (_, true) => None,
+                }
+            }
+
+            /// Checked shift left. Returns the shifted number, or
+            /// [`None`] if `rhs` ≥ 256.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_shl(self, rhs: u32) -> Option<$Fixed<Frac>> {
+                if rhs < 256 {
+                    Some(self << rhs)
+                } else {
+                    None
+                }
+            }
+
+            /// Checked shift right. Returns the shifted number, or
+            /// [`None`] if `rhs` ≥ 256.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            pub fn checked_shr(self, rhs: u32) -> Option<$Fixed<Frac>> {
+                if rhs < 256 {
+                    Some(self >> rhs)
+                } else {
+                    None
+                }
+            }
+
+            /// Saturating Euclidean division. Returns the quotient,
+            /// saturating on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn saturating_div_euclid(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                if rhs.to_bits().is_zero() {
+                    panic!("division by zero");
+                }
+                self.checked_div_euclid(rhs).unwrap_or_else(|| {
+                    let zero = $Inner::default();
+                    if (self.to_bits() > zero) == (rhs.to_bits() > zero) {
+                        Self::max_value()
+                    } else {
+                        Self::min_value()
+                    }
+                })
+            }
+
+            /// Saturating integer power. Returns the power, saturating
+            /// on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `self` is zero and `exp` is negative.
+            #[inline]
+            pub fn saturating_powi(self, exp: i32) -> $Fixed<Frac> {
+                match self.overflowing_powi(exp) {
+                    (ans, false) => ans,
+                    (_, true) => {
+                        if self.to_bits().is_negative() && exp & 1 != 0 {
+                            Self::min_value()
+                        } else {
+                            Self::max_value()
+                        }
+                    }
+                }
+            }
+
+            /// Saturating multiplication with the given rounding mode.
+            /// Returns the product, saturating on overflow.
+            #[inline]
+            pub fn saturating_mul_round(
+                self,
+                rhs: $Fixed<Frac>,
+                mode: RoundingMode,
+            ) -> $Fixed<Frac> {
+                match self.overflowing_mul_round(rhs, mode) {
+                    (ans, false) => ans,
+                    (_, true) => {
+                        if self.to_bits().is_negative() != rhs.to_bits().is_negative() {
+                            Self::min_value()
+                        } else {
+                            Self::max_value()
+                        }
+                    }
+                }
+            }
+
+            /// Saturating division with the given rounding mode.
+            /// Returns the quotient, saturating on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn saturating_div_round(
+                self,
+                rhs: $Fixed<Frac>,
+                mode: RoundingMode,
+            ) -> $Fixed<Frac> {
+                match self.overflowing_div_round(rhs, mode) {
+                    (ans, false) => ans,
+                    (_, true) => {
+                        if self.to_bits().is_negative() != rhs.to_bits().is_negative() {
+                            Self::min_value()
+                        } else {
+                            Self::max_value()
+                        }
+                    }
+                }
+            }
+
+            /// Saturating `self` × `mul` ÷ `div`, rounded towards
+            /// zero, and saturating on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `div` is zero.
+            #[inline]
+            pub fn saturating_mul_div(
+                self,
+                mul: $Fixed<Frac>,
+                div: $Fixed<Frac>,
+            ) -> $Fixed<Frac> {
+                let mode = RoundingMode::TowardZero;
+                match MulDivRound::mul_div_round(self.to_bits(), mul.to_bits(), div.to_bits(), mode) {
+                    (ans, false) => Self::from_bits(ans),
+                    (_, true) => {
+                        let neg = (self.to_bits().is_negative() != mul.to_bits().is_negative())
+                            != div.to_bits().is_negative();
+                        if neg {
+                            Self::min_value()
+                        } else {
+                            Self::max_value()
+                        }
+                    }
+                }
+            }
+
+            /// Saturating multiplication by an integer. Returns the
+            /// product, saturating on overflow.
+            #[inline]
+            pub fn saturating_mul_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                match self.overflowing_mul_int(rhs) {
+                    (ans, false) => ans,
+                    (_, true) => {
+                        if self.to_bits().is_negative() != rhs.is_negative() {
+                            Self::min_value()
+                        } else {
+                            Self::max_value()
+                        }
+                    }
+                }
+            }
+
+            /// Wrapping Euclidean division. Returns the quotient,
+            /// wrapping on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn wrapping_div_euclid(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                self.overflowing_div_euclid(rhs).0
+            }
+
+            /// Wrapping multiplication by an integer. Returns the
+            /// product, wrapping on overflow.
+            #[inline]
+            pub fn wrapping_mul_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                self.overflowing_mul_int(rhs).0
+            }
+
+            /// Wrapping division by an integer. Returns the quotient,
+            /// wrapping on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn wrapping_div_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                self.overflowing_div_int(rhs).0
+            }
+
+            /// Wrapping fixed-point remainder for division by an
+            /// integer. Returns the remainder, which cannot overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn wrapping_rem_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                self % rhs
+            }
+
+            /// Wrapping Euclidean division by an integer. Returns the
+            /// quotient, wrapping on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn wrapping_div_euclid_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                self.overflowing_div_euclid_int(rhs).0
+            }
+
+            /// Wrapping remainder for Euclidean division by an integer.
+            /// Returns the remainder, wrapping on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn wrapping_rem_euclid_int(self, rhs: $Inner) -> $Fixed<Frac> {
+                self.overflowing_rem_euclid_int(rhs).0
+            }
+
+            /// Wrapping integer power. Returns the power, wrapping on
+            /// overflow.
+            ///
+            /// The exact power is wrapped when it has no fractional
+            /// bits, or when it can be formed with 8192 bits down to the
+            /// rounding bit; beyond that the wrapped value is
+            /// unspecified.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `self` is zero and `exp` is negative.
+            #[inline]
+            pub fn wrapping_powi(self, exp: i32) -> $Fixed<Frac> {
+                self.overflowing_powi(exp).0
+            }
+
+            /// Wrapping multiplication with the given rounding mode.
+            /// Returns the product, wrapping on overflow.
+            #[inline]
+            pub fn wrapping_mul_round(self, rhs: $Fixed<Frac>, mode: RoundingMode) -> $Fixed<Frac> {
+                self.overflowing_mul_round(rhs, mode).0
+            }
+
+            /// Wrapping division with the given rounding mode. Returns
+            /// the quotient, wrapping on overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            #[inline]
+            pub fn wrapping_div_round(self, rhs: $Fixed<Frac>, mode: RoundingMode) -> $Fixed<Frac> {
+                self.overflowing_div_round(rhs, mode).0
+            }
+
+            /// Wrapping shift left. Wraps `rhs` if `rhs` ≥ 256, then
+            /// shifts and returns the number.
+            #[inline]
+            pub fn wrapping_shl(self, rhs: u32) -> $Fixed<Frac> {
+                self << (rhs % 256)
+            }
+
+            /// Wrapping shift right. Wraps `rhs` if `rhs` ≥ 256, then
+            /// shifts and returns the number.
+            #[inline]
+            pub fn wrapping_shr(self, rhs: u32) -> $Fixed<Frac> {
+                self >> (rhs % 256)
+            }
+
+            /// Overflowing Euclidean division.
+            ///
+            /// Returns a [tuple] of the quotient and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_div_euclid(self, rhs: $Fixed<Frac>) -> ($Fixed<Frac>, bool) {
+                let (mut q, overflow) = self.overflowing_div(rhs);
+                q = q.round_to_zero();
+                if_signed! {
+                    $Signedness;
+                    if (self % rhs).is_negative() {
+                        let (q, overflow2) = if rhs.is_positive() {
+                            let minus_one = match Self::checked_from_num(-1) {
+                                None => return (q, true),
+                                Some(s) => s,
+                            };
+                            q.overflowing_add(minus_one)
+                        } else {
+                            let one = match Self::checked_from_num(1) {
+                                None => return (q, true),
+                                Some(s) => s,
+                            };
+                            q.overflowing_add(one)
+                        };
+                        return (q, overflow | overflow2);
+                    }
+                }
+                (q, overflow)
+            }
+
+            /// Overflowing multiplication by an integer.
+            ///
+            /// Returns a [tuple] of the product and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_mul_int(self, rhs: $Inner) -> ($Fixed<Frac>, bool) {
+                let (lhs_neg, lhs_abs) = self.to_bits().neg_abs();
+                let (rhs_neg, rhs_abs) = rhs.neg_abs();
+                let neg = lhs_neg != rhs_neg;
+                let (abs, overflow1) = lhs_abs.overflowing_mul(rhs_abs);
+                let (ans, overflow2) = $Inner::overflowing_from_neg_abs(neg, abs);
+                (Self::from_bits(ans), overflow1 || overflow2)
+            }
+
+            /// Overflowing division by an integer.
+            ///
+            /// Returns a [tuple] of the quotient and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_div_int(self, rhs: $Inner) -> ($Fixed<Frac>, bool) {
+                let (lhs_neg, lhs_abs) = self.to_bits().neg_abs();
+                let (rhs_neg, rhs_abs) = rhs.neg_abs();
+                if rhs_abs.is_zero() {
+                    panic!("division by zero");
+                }
+                let ((_, abs), _) = U256::div_rem_wide(U256::ZERO, lhs_abs, rhs_abs);
+                let (ans, overflow) = $Inner::overflowing_from_neg_abs(lhs_neg != rhs_neg, abs);
+                (Self::from_bits(ans), overflow)
+            }
+
+            /// Overflowing fixed-point remainder for division by an
+            /// integer.
+            ///
+            /// Returns a [tuple] of the remainder and [`false`][`bool`],
+            /// as the division can never overflow.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_rem_int(self, rhs: $Inner) -> ($Fixed<Frac>, bool) {
+                (self % rhs, false)
+            }
+
+            /// Overflowing Euclidean division by an integer.
+            ///
+            /// Returns a [tuple] of the quotient and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_div_euclid_int(self, rhs: $Inner) -> ($Fixed<Frac>, bool) {
+                let (mut q, overflow) = self.overflowing_div_int(rhs);
+                q = q.round_to_zero();
+                if_signed! {
+                    $Signedness;
+                    if (self % rhs).is_negative() {
+                        let (q, overflow2) = if rhs.is_negative() {
+                            let one = match Self::checked_from_num(1) {
+                                None => return (q, true),
+                                Some(s) => s,
+                            };
+                            q.overflowing_add(one)
+                        } else {
+                            let minus_one = match Self::checked_from_num(-1) {
+                                None => return (q, true),
+                                Some(s) => s,
+                            };
+                            q.overflowing_add(minus_one)
+                        };
+                        return (q, overflow | overflow2);
+                    }
+                }
+                (q, overflow)
+            }
+
+            /// Overflowing remainder for Euclidean division by an
+            /// integer.
+            ///
+            /// Returns a [tuple] of the remainder and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_rem_euclid_int(self, rhs: $Inner) -> ($Fixed<Frac>, bool) {
+                let rem = self % rhs;
+                if_signed! {
+                    $Signedness;
+                    if rem.is_negative() {
+                        // INT_NBITS == 0 is a special case, as then
+                        // −0.5 ≤ rem < 0, and the answer would be in
+                        // the range 0.5 ≤ answer < 1, which does not fit.
+                        if Self::INT_NBITS == 0 {
+                            return (rem, true);
+                        }
+                        // The answer is |rhs| − |rem|, but |rhs| may
+                        // overflow when converted to fixed point.
+                        let (_, rhs_abs) = rhs.neg_abs();
+                        let (rhs_abs, overflow1) =
+                            shl_floor(false, rhs_abs, Self::FRAC_NBITS as i32);
+                        let (_, rem_abs) = rem.to_bits().neg_abs();
+                        let abs = rhs_abs.wrapping_sub(rem_abs);
+                        let (ans, overflow2) = $Inner::overflowing_from_neg_abs(false, abs);
+                        return (Self::from_bits(ans), overflow1 || overflow2);
+                    }
+                }
+                (rem, false)
+            }
+
+            /// Overflowing integer power.
+            ///
+            /// Returns a [tuple] of the power and a [`bool`] indicating
+            /// whether an overflow has occurred. On overflow, the
+            /// wrapped value is returned. The exact power is wrapped
+            /// when it has no fractional bits, or when it can be formed
+            /// with 8192 bits down to the rounding bit; beyond that the
+            /// wrapped value is unspecified.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `self` is zero and `exp` is negative.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_powi(self, exp: i32) -> ($Fixed<Frac>, bool) {
+                let (ans, overflow) = self
+                    .to_bits()
+                    .powi_frac(exp, Frac::U32)
+                    .expect("division by zero");
+                (Self::from_bits(ans), overflow)
+            }
+
+            /// Overflowing multiplication with the given rounding mode.
+            ///
+            /// Returns a [tuple] of the product and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_mul_round(
+                self,
+                rhs: $Fixed<Frac>,
+                mode: RoundingMode,
+            ) -> ($Fixed<Frac>, bool) {
+                let (ans, overflow) =
+                    MulDivRound::mul_round(self.to_bits(), rhs.to_bits(), Frac::U32, mode);
+                (Self::from_bits(ans), overflow)
+            }
+
+            /// Overflowing division with the given rounding mode.
+            ///
+            /// Returns a [tuple] of the quotient and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the divisor is zero.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_div_round(
+                self,
+                rhs: $Fixed<Frac>,
+                mode: RoundingMode,
+            ) -> ($Fixed<Frac>, bool) {
+                let (ans, overflow) =
+                    MulDivRound::div_round(self.to_bits(), rhs.to_bits(), Frac::U32, mode);
+                (Self::from_bits(ans), overflow)
+            }
+
+            /// Overflowing shift left.
+            ///
+            /// Returns a [tuple] of the shifted value and a [`bool`]
+            /// indicating whether `rhs` ≥ 256, in which case `rhs` is
+            /// wrapped before shifting.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_shl(self, rhs: u32) -> ($Fixed<Frac>, bool) {
+                (self << (rhs % 256), rhs >= 256)
+            }
+
+            /// Overflowing shift right.
+            ///
+            /// Returns a [tuple] of the shifted value and a [`bool`]
+            /// indicating whether `rhs` ≥ 256, in which case `rhs` is
+            /// wrapped before shifting.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            pub fn overflowing_shr(self, rhs: u32) -> ($Fixed<Frac>, bool) {
+                (self >> (rhs % 256), rhs >= 256)
+            }
+
+            if_signed! {
+                $Signedness;
+
+                /// Returns a number representing the sign of `self`.
+                ///
+                /// # Panics
+                ///
+                /// When debug assertions are enabled, panics if the
+                /// value is positive and the fixed-point number has
+                /// zero or one integer bits such that it cannot hold
+                /// the value 1, or if the value is negative and the
+                /// fixed-point number has zero integer bits such that
+                /// it cannot hold the value −1.
+                #[inline]
+                pub fn signum(self) -> $Fixed<Frac> {
+                    match self.to_bits().cmp(&$Inner::default()) {
+                        Ordering::Equal => Self::default(),
+                        Ordering::Greater => Self::from_num(1),
+                        Ordering::Less => Self::from_num(-1),
+                    }
+                }
+
+                /// Checked absolute value. Returns the absolute value,
+                /// or [`None`] on overflow.
+                ///
+                /// Overflow can only occur when trying to find the
+                /// absolute value of the minimum value.
+                ///
+                /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+                #[inline]
+                pub fn checked_abs(self) -> Option<$Fixed<Frac>> {
+                    match self.overflowing_abs() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+
+                /// Saturating absolute value. Returns the absolute
+                /// value, saturating on overflow.
+                #[inline]
+                pub fn saturating_abs(self) -> $Fixed<Frac> {
+                    match self.overflowing_abs() {
+                        (ans, false) => ans,
+                        (_, true) => Self::max_value(),
+                    }
+                }
+
+                /// Wrapping absolute value. Returns the absolute value,
+                /// wrapping on overflow.
+                #[inline]
+                pub fn wrapping_abs(self) -> $Fixed<Frac> {
+                    self.overflowing_abs().0
+                }
+
+                /// Overflowing absolute value.
+                ///
+                /// Returns a [tuple] of the absolute value and a
+                /// [`bool`], indicating whether an overflow has
+                /// occurred. On overflow, the wrapped value is
+                /// returned.
+                ///
+                /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+                /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+                #[inline]
+                pub fn overflowing_abs(self) -> ($Fixed<Frac>, bool) {
+                    if self.is_negative() {
+                        self.overflowing_neg()
+                    } else {
+                        (self, false)
+                    }
+                }
+            }
+
+            if_unsigned! {
+                $Signedness;
+
+                /// Returns [`true`][`bool`] if the fixed-point number
+                /// is 2<sup><i>k</i></sup> for some integer <i>k</i>.
+                ///
+                /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+                #[inline]
+                pub fn is_power_of_two(self) -> bool {
+                    self.to_bits().count_ones() == 1
+                }
+
+                /// Returns the smallest power of two that is ≥ `self`.
+                ///
+                /// # Panics
+                ///
+                /// When debug assertions are enabled, panics if the
+                /// next power of two is too large to represent.
+                #[inline]
+                pub fn next_power_of_two(self) -> $Fixed<Frac> {
+                    let ans = self.checked_next_power_of_two();
+                    debug_assert!(ans.is_some(), "overflow");
+                    ans.unwrap_or_default()
+                }
+
+                /// Returns the smallest power of two that is ≥ `self`,
+                /// or [`None`] if the next power of two is too large
+                /// to represent.
+                ///
+                /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+                #[inline]
+                pub fn checked_next_power_of_two(self) -> Option<$Fixed<Frac>> {
+                    let bits = self.to_bits();
+                    if bits.count_ones() <= 1 {
+                        // zero maps to the smallest positive value
+                        return Some(Self::from_bits(if bits.is_zero() { U256::ONE } else { bits }));
+                    }
+                    match bits.leading_zeros() {
+                        0 => None,
+                        zeros => Some(Self::from_bits(U256::ONE << (256 - zeros))),
+                    }
+                }
+            }
+        }
+
+        if_signed! {
+            $Signedness;
+            impl<Frac: LeEqU256> Neg for $Fixed<Frac> {
+                type Output = $Fixed<Frac>;
+                #[inline]
+                fn neg(self) -> $Fixed<Frac> {
+                    let (ans, o) = self.overflowing_neg();
+                    debug_assert!(!o, "attempt to negate with overflow");
+                    ans
+                }
+            }
+        }
+
+        impl<Frac: LeEqU256> Add for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn add(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                let (ans, o) = self.overflowing_add(rhs);
+                debug_assert!(!o, "attempt to add with overflow");
+                ans
+            }
+        }
+
+        impl<Frac: LeEqU256> Sub for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn sub(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                let (ans, o) = self.overflowing_sub(rhs);
+                debug_assert!(!o, "attempt to subtract with overflow");
+                ans
+            }
+        }
+
+        impl<Frac: LeEqU256> Mul for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn mul(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                let (ans, o) = self.overflowing_mul(rhs);
+                debug_assert!(!o, "overflow");
+                ans
+            }
+        }
+
+        impl<Frac: LeEqU256> Div for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn div(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                let (ans, o) = self.overflowing_div(rhs);
+                debug_assert!(!o, "overflow");
+                ans
+            }
+        }
+
+        impl<Frac: LeEqU256> Rem for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn rem(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                let (lhs_neg, lhs_abs) = self.to_bits().neg_abs();
+                let (_, rhs_abs) = rhs.to_bits().neg_abs();
+                let (_, rem) = U256::div_rem_wide(U256::ZERO, lhs_abs, rhs_abs);
+                Self::from_bits($Inner::overflowing_from_neg_abs(lhs_neg, rem).0)
+            }
+        }
+
+        impl<Frac: LeEqU256> Mul<$Inner> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn mul(self, rhs: $Inner) -> $Fixed<Frac> {
+                let (ans, o) = self.overflowing_mul_int(rhs);
+                debug_assert!(!o, "attempt to multiply with overflow");
+                ans
+            }
+        }
+
+        impl<Frac: LeEqU256> Mul<$Fixed<Frac>> for $Inner {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn mul(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                rhs * self
+            }
+        }
+
+        impl<Frac: LeEqU256> Div<$Inner> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn div(self, rhs: $Inner) -> $Fixed<Frac> {
+                let (ans, o) = self.overflowing_div_int(rhs);
+                debug_assert!(!o, "attempt to divide with overflow");
+                ans
+            }
+        }
+
+        impl<Frac: LeEqU256> Rem<$Inner> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn rem(self, rhs: $Inner) -> $Fixed<Frac> {
+                self.checked_rem_int(rhs).expect("division by zero")
+            }
+        }
+
+        impl<Frac> Not for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn not(self) -> $Fixed<Frac> {
+                Self::from_bits(!self.to_bits())
+            }
+        }
+
+        fixed256_bit_ops! {
+            $Fixed;
+            BitAnd bitand, BitAndAssign bitand_assign, &;
+            BitOr bitor, BitOrAssign bitor_assign, |;
+            BitXor bitxor, BitXorAssign bitxor_assign, ^;
+        }
+
+        fixed256_assign_ops! {
+            $Fixed;
+            AddAssign add_assign, +;
+            SubAssign sub_assign, -;
+            MulAssign mul_assign, *;
+            DivAssign div_assign, /;
+            RemAssign rem_assign, %;
+        }
+
+        fixed256_assign_ops! {
+            $Fixed, $Inner;
+            MulAssign mul_assign, *;
+            DivAssign div_assign, /;
+            RemAssign rem_assign, %;
+        }
+
+        impl<Frac> Shl<u32> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn shl(self, rhs: u32) -> $Fixed<Frac> {
+                Self::from_bits(self.to_bits() << rhs)
+            }
+        }
+
+        impl<Frac> ShlAssign<u32> for $Fixed<Frac> {
+            #[inline]
+            fn shl_assign(&mut self, rhs: u32) {
+                *self = *self << rhs;
+            }
+        }
+
+        impl<Frac> Shr<u32> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn shr(self, rhs: u32) -> $Fixed<Frac> {
+                Self::from_bits(self.to_bits() >> rhs)
+            }
+        }
+
+        impl<Frac> ShrAssign<u32> for $Fixed<Frac> {
+            #[inline]
+            fn shr_assign(&mut self, rhs: u32) {
+                *self = *self >> rhs;
+            }
+        }
+
+        impl<Frac: LeEqU256> FromFixed for $Fixed<Frac> {
+            /// Converts a fixed-point number.
+            ///
+            /// Any extra fractional bits are truncated.
+            #[inline]
+            fn from_fixed<F: Fixed>(src: F) -> Self {
+                let (wrapped, overflow) = FromFixed::overflowing_from_fixed(src);
+                debug_assert!(!overflow, "{} overflows", src);
+                let _ = overflow;
+                wrapped
+            }
+
+            /// Converts a fixed-point number if it fits, otherwise returns [`None`].
+            ///
+            /// Any extra fractional bits are truncated.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            fn checked_from_fixed<F: Fixed>(src: F) -> Option<Self> {
+                match FromFixed::overflowing_from_fixed(src) {
+                    (_, true) => None,
+                    (wrapped, false) => Some(wrapped),
+                }
+            }
+
+            /// Converts a fixed-point number, saturating if it does not fit.
+            ///
+            /// Any extra fractional bits are truncated.
+            #[inline]
+            fn saturating_from_fixed<F: Fixed>(src: F) -> Self {
+                match FromFixed::overflowing_from_fixed(src) {
+                    (wrapped, false) => wrapped,
+                    (_, true) => {
+                        if src.private_neg_abs().0 {
+                            Self::min_value()
+                        } else {
+                            Self::max_value()
+                        }
+                    }
+                }
+            }
+
+            /// Converts a fixed-point number, wrapping if it does not fit.
+            ///
+            /// Any extra fractional bits are truncated.
+            #[inline]
+            fn wrapping_from_fixed<F: Fixed>(src: F) -> Self {
+                let (wrapped, _) = FromFixed::overflowing_from_fixed(src);
+                wrapped
+            }
+
+            /// Converts a fixed-point number.
+            ///
+            /// Returns a [tuple] of the value and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// Any extra fractional bits are truncated.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            fn overflowing_from_fixed<F: Fixed>(src: F) -> (Self, bool) {
+                let (neg, abs) = src.private_neg_abs();
+                let shift = Self::FRAC_NBITS as i32 - F::frac_nbits() as i32;
+                let (abs, overflow1) = shl_floor(neg, abs, shift);
+                let (bits, overflow2) = $Inner::overflowing_from_neg_abs(neg, abs);
+                (Self::from_bits(bits), overflow1 || overflow2)
+            }
+        }
+
+        impl<Frac: LeEqU256> ToFixed for $Fixed<Frac> {
+            /// Converts a fixed-point number.
+            ///
+            /// Any extra fractional bits are truncated.
+            #[inline]
+            fn to_fixed<F: Fixed>(self) -> F {
+                let (wrapped, overflow) = ToFixed::overflowing_to_fixed(self);
+                debug_assert!(!overflow, "{} overflows", self);
+                let _ = overflow;
+                wrapped
+            }
+
+            /// Converts a fixed-point number if it fits, otherwise returns [`None`].
+            ///
+            /// Any extra fractional bits are truncated.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            fn checked_to_fixed<F: Fixed>(self) -> Option<F> {
+                match ToFixed::overflowing_to_fixed(self) {
+                    (_, true) => None,
+                    (wrapped, false) => Some(wrapped),
+                }
+            }
+
+            /// Converts a fixed-point number, saturating if it does not fit.
+            ///
+            /// Any extra fractional bits are truncated.
+            #[inline]
+            fn saturating_to_fixed<F: Fixed>(self) -> F {
+                match ToFixed::overflowing_to_fixed(self) {
+                    (wrapped, false) => wrapped,
+                    (_, true) => {
+                        if self.to_bits().neg_abs().0 {
+                            F::min_value()
+                        } else {
+                            F::max_value()
+                        }
+                    }
+                }
+            }
+
+            /// Converts a fixed-point number, wrapping if it does not fit.
+            ///
+            /// Any extra fractional bits are truncated.
+            #[inline]
+            fn wrapping_to_fixed<F: Fixed>(self) -> F {
+                let (wrapped, _) = ToFixed::overflowing_to_fixed(self);
+                wrapped
+            }
+
+            /// Converts a fixed-point number.
+            ///
+            /// Returns a [tuple] of the value and a [`bool`]
+            /// indicating whether an overflow has occurred. On
+            /// overflow, the wrapped value is returned.
+            ///
+            /// Any extra fractional bits are truncated.
+            ///
+            /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+            /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+            #[inline]
+            fn overflowing_to_fixed<F: Fixed>(self) -> (F, bool) {
+                let (neg, abs) = self.to_bits().neg_abs();
+                let shift = F::frac_nbits() as i32 - Self::FRAC_NBITS as i32;
+                let (abs, overflow1) = shl_floor(neg, abs, shift);
+                let (ans, overflow2) = F::private_overflowing_from_neg_abs(neg, abs);
+                (ans, overflow1 || overflow2)
+            }
+        }
+
+        impl<Frac: LeEqU256> Sealed for $Fixed<Frac> {
+            #[inline]
+            fn private_to_fixed_helper(
+                self,
+                dst_frac_nbits: u32,
+                dst_int_nbits: u32,
+            ) -> ToFixedHelper {
+                let bits = self.to_bits();
+                if bits.is_zero() {
+                    return ToFixedHelper {
+                        bits: Widest::Unsigned(0),
+                        dir: Ordering::Equal,
+                        overflow: false,
+                    };
+                }
+                let dst_bits = (dst_frac_nbits + dst_int_nbits) as i32;
+                let need_to_shr = Self::FRAC_NBITS as i32 - dst_frac_nbits as i32;
+                let leading = if bits.is_negative() {
+                    (!bits).leading_zeros() - 1
+                } else {
+                    bits.leading_zeros()
+                };
+                let overflow = 256 - dst_bits > need_to_shr + leading as i32;
+                let (shifted, lost_bits) = match need_to_shr {
+                    -255..=-1 => (bits << (-need_to_shr) as u32, false),
+                    0 => (bits, false),
+                    1..=255 => {
+                        let shifted = bits >> need_to_shr as u32;
+                        (shifted, shifted << need_to_shr as u32 != bits)
+                    }
+                    _ if need_to_shr < 0 => ($Inner::default(), false),
+                    _ if bits.is_negative() => (!$Inner::default(), true),
+                    _ => ($Inner::default(), true),
+                };
+                let dir = if lost_bits {
+                    Ordering::Less
+                } else {
+                    Ordering::Equal
+                };
+                // Only the lower word is needed as the destination
+                // has at most 128 bits.
+                let (_, lo) = shifted.to_words();
+                let bits = if bits.is_negative() {
+                    Widest::Negative(lo as i128)
+                } else {
+                    Widest::Unsigned(lo)
+                };
+                ToFixedHelper {
+                    bits,
+                    dir,
+                    overflow,
+                }
+            }
+
+            #[inline]
+            fn private_neg_abs(self) -> (bool, U256) {
+                self.to_bits().neg_abs()
+            }
+
+            #[inline]
+            fn private_overflowing_from_neg_abs(neg: bool, abs: U256) -> (Self, bool) {
+                let (bits, overflow) = $Inner::overflowing_from_neg_abs(neg, abs);
+                (Self::from_bits(bits), overflow)
+            }
+
+            #[inline]
+            fn private_to_float<Float: FloatHelper>(self) -> Float {
+                let (neg, abs) = self.to_bits().neg_abs();
+                Float::from_neg_abs(neg, abs, Self::FRAC_NBITS)
+            }
+
+            #[inline]
+            fn private_saturating_from_float<Float: FloatHelper>(src: Float) -> Self {
+                if src.is_nan() {
+                    panic!("NaN");
+                }
+                let saturated = if src.parts().0 {
+                    Self::min_value()
+                } else {
+                    Self::max_value()
+                };
+                if !src.is_finite() {
+                    return saturated;
+                }
+                match Self::private_overflowing_from_float(src) {
+                    (ans, false) => ans,
+                    (_, true) => saturated,
+                }
+            }
+
+            #[inline]
+            fn private_overflowing_from_float<Float: FloatHelper>(src: Float) -> (Self, bool) {
+                if src.is_nan() {
+                    panic!("NaN");
+                }
+                if !src.is_finite() {
+                    panic!("infinite");
+                }
+                let (neg, abs, overflow1) = float_to_neg_abs(src, Self::FRAC_NBITS);
+                let (bits, overflow2) = $Inner::overflowing_from_neg_abs(neg, abs);
+                (Self::from_bits(bits), overflow1 || overflow2)
+            }
+        }
+    };
+}
+
+macro_rules! fixed256_bit_ops {
+    ($Fixed:ident; $($Imp:ident $method:ident, $ImpAssign:ident $method_assign:ident, $op:tt;)*) => { $(
+        impl<Frac> $Imp for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn $method(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                Self::from_bits(self.to_bits() $op rhs.to_bits())
+            }
+        }
+
+        impl<Frac> $ImpAssign for $Fixed<Frac> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: $Fixed<Frac>) {
+                *self = *self $op rhs;
+            }
+        }
+    )* };
+}
+
+macro_rules! fixed256_assign_ops {
+    ($Fixed:ident; $($ImpAssign:ident $method_assign:ident, $op:tt;)*) => {
+        fixed256_assign_ops! { $Fixed, $Fixed<Frac>; $($ImpAssign $method_assign, $op;)* }
+    };
+    ($Fixed:ident, $Rhs:ty; $($ImpAssign:ident $method_assign:ident, $op:tt;)*) => { $(
+        impl<Frac: LeEqU256> $ImpAssign<$Rhs> for $Fixed<Frac> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: $Rhs) {
+                *self = *self $op rhs;
+            }
+        }
+    )* };
+}
+
+fixed256! {
+    "A 256-bit fixed-point unsigned",
+    FixedU256["FixedU256"](U256["U256"]),
+    Unsigned
+}
+fixed256! {
+    "A 256-bit fixed-point signed",
+    FixedI256["FixedI256"](I256["I256"]),
+    Signed
+}
+
+#[cfg(test)]
+#[allow(clippy::cognitive_complexity)]
+mod tests {
+    use crate::{
+        int256::{I256, U256},
+        traits::{FromFixed, ToFixed},
+        types::{
+            extra::{U0, U128, U256 as F256, U3, U64},
+            I64F64, I8F8, U64F64,
+        },
+        FixedI256, FixedU256,
+    };
+    use std::{format, string::ToString};
+
+    #[test]
+    fn arith_matches_128() {
+        type I = FixedI256<U64>;
+        type U = FixedU256<U64>;
+        let vals = [
+            0i128,
+            1,
+            -1,
+            3,
+            -7,
+            1 << 64,
+            -(1 << 64),
+            (1 << 64) + (1 << 63),
+            0x1234_5678_9abc_def0_1234_5678,
+            -0x1234_5678_9abc_def0_1234_5678,
+            0x7fff_ffff_ffff,
+        ];
+        for &a in &vals {
+            for &b in &vals {
+                let (fa, fb) = (I64F64::from_bits(a), I64F64::from_bits(b));
+                let (wa, wb) = (I::from_fixed(fa), I::from_fixed(fb));
+                assert_eq!(wa + wb, I::from_fixed(fa + fb));
+                assert_eq!(wa - wb, I::from_fixed(fa - fb));
+                if let Some(prod) = fa.checked_mul(fb) {
+                    assert_eq!(wa * wb, I::from_fixed(prod));
+                }
+                if b != 0 {
+                    if let Some(quot) = fa.checked_div(fb) {
+                        assert_eq!(wa / wb, I::from_fixed(quot));
+                    }
+                    assert_eq!(wa % wb, I::from_fixed(fa % fb));
+                }
+                assert_eq!(wa.cmp(&wb), fa.cmp(&fb));
+                assert_eq!(wa.to_string(), fa.to_string());
+                assert_eq!(format!("{:x}", wa), format!("{:x}", fa));
+                assert_eq!(wa.to_string().parse::<I>(), Ok(wa));
+                assert_eq!(wa.to_fixed::<I64F64>(), fa);
+
+                let (ua, ub) = (a as u128, b as u128);
+                let (fa, fb) = (U64F64::from_bits(ua), U64F64::from_bits(ub));
+                let (wa, wb) = (U::from_fixed(fa), U::from_fixed(fb));
+                assert_eq!(
+                    wa.wrapping_add(wb),
+                    U::from_fixed(fa.wrapping_add(fb)) + {
+                        let carry = fa.overflowing_add(fb).1;
+                        if carry {
+                            U::from_bits(U256::from_words(1, 0))
+                        } else {
+                            U::default()
+                        }
+                    }
+                );
+                if let Some(prod) = fa.checked_mul(fb) {
+                    assert_eq!(wa * wb, U::from_fixed(prod));
+                }
+                if ub != 0 {
+                    if let Some(quot) = fa.checked_div(fb) {
+                        assert_eq!(wa / wb, U::from_fixed(quot));
+                    }
+                    assert_eq!(wa % wb, U::from_fixed(fa % fb));
+                }
+                assert_eq!(wa.to_string(), fa.to_string());
+                assert_eq!(wa.to_string().parse::<U>(), Ok(wa));
+            }
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        type I = FixedI256<U3>;
+        type U = FixedU256<U3>;
+        let delta = I::from_bits(I256::from(1u8));
+        assert_eq!(
+            I::max_value().overflowing_add(delta),
+            (I::min_value(), true)
+        );
+        assert_eq!(I::max_value().saturating_add(delta), I::max_value());
+        assert_eq!(I::min_value().saturating_sub(delta), I::min_value());
+        assert_eq!(I::min_value().checked_neg(), None);
+        assert_eq!(I::min_value().saturating_neg(), I::max_value());
+        assert_eq!(
+            I::max_value().saturating_mul(-I::max_value()),
+            I::min_value()
+        );
+        assert_eq!(I::max_value().checked_mul(I::max_value()), None);
+        let two: I = "2".parse().unwrap();
+        let half: I = "0.5".parse().unwrap();
+        assert_eq!(I::max_value().checked_div(half), None);
+        assert_eq!(I::max_value().checked_div(I::default()), None);
+        assert_eq!(I::max_value().checked_rem(I::default()), None);
+        assert_eq!(
+            (I::min_value() / two).to_string(),
+            (I::min_value() >> 1).to_string()
+        );
+        let neg_eighth: I = "-0.125".parse().unwrap();
+        assert_eq!(neg_eighth * half, neg_eighth);
+        assert_eq!(-neg_eighth * half, I::default());
+
+        let one: U = "1".parse().unwrap();
+        assert_eq!(U::default().checked_neg(), Some(U::default()));
+        assert_eq!(one.checked_neg(), None);
+        assert_eq!(one.saturating_neg(), U::default());
+        assert_eq!(U::default().saturating_sub(one), U::default());
+        assert_eq!(U::max_value().saturating_add(one), U::max_value());
+        assert_eq!(U::max_value().wrapping_mul(one), U::max_value());
+    }
+
+    #[test]
+    fn parse_and_display() {
+        type I0 = FixedI256<U0>;
+        type U1 = FixedU256<F256>;
+        let max = I256::max_value().to_string();
+        assert_eq!(I0::max_value().to_string(), max);
+        assert_eq!(max.parse::<I0>(), Ok(I0::max_value()));
+        let min = I256::min_value().to_string();
+        assert_eq!(min.parse::<I0>(), Ok(I0::min_value()));
+        assert!(I0::from_str_hex(&format!("8{:0>63}", "")).is_err());
+        assert_eq!(
+            I0::overflowing_from_str(&format!("1{}", max)).map(|x| x.1),
+            Ok(true)
+        );
+        assert_eq!(
+            I0::saturating_from_str(&format!("-1{}", max)),
+            Ok(I0::min_value())
+        );
+
+        let half: U1 = "0.5".parse().unwrap();
+        assert_eq!(half.to_bits(), U256::from_words(1 << 127, 0));
+        assert_eq!(half.to_string(), "0.5");
+        assert_eq!(U1::from_str_binary("0.1"), Ok(half));
+        assert_eq!(U1::from_str_octal("0.4"), Ok(half));
+        assert_eq!(format!("{:x}", U1::max_value()), format!("0.{:f>64}", ""));
+        assert_eq!(U1::overflowing_from_str("1").map(|x| x.1), Ok(true));
+
+        // ties to even with no fractional bits
+        assert_eq!("2.5".parse::<I0>(), Ok(I0::from_bits(I256::from(2u8))));
+        assert_eq!("3.5".parse::<I0>(), Ok(I0::from_bits(I256::from(4u8))));
+        assert_eq!("-3.5".parse::<I0>(), Ok(I0::from_bits(I256::from(-4i8))));
+        assert_eq!(
+            "2.50000000000000000001".parse::<I0>(),
+            Ok(I0::from_bits(I256::from(3u8)))
+        );
+
+        let x: FixedI256<U3> = "-12.375".parse().unwrap();
+        assert_eq!(x.to_string(), "-12.4");
+        assert_eq!(format!("{:.3}", x), "-12.375");
+        assert_eq!(x.int().to_string(), "-13");
+        assert_eq!(x.frac().to_bits(), I256::from(5u8));
+        assert_eq!(x.abs().to_bits(), I256::from(99u8));
+    }
+
+    #[test]
+    fn conversions() {
+        type I = FixedI256<U3>;
+        let x = I::from_fixed(I8F8::from_num(-1.5625));
+        assert_eq!(x.to_bits(), I256::from(-13i8));
+        assert_eq!(x.to_fixed::<I8F8>(), -1.625);
+        assert_eq!(I8F8::from_num(x), -1.625);
+        let big: I = "1000.5".parse().unwrap();
+        assert_eq!(big.checked_to_fixed::<I8F8>(), None);
+        assert_eq!(big.saturating_to_fixed::<I8F8>(), I8F8::max_value());
+        assert_eq!((-big).saturating_to_fixed::<I8F8>(), I8F8::min_value());
+        assert_eq!(
+            big.overflowing_to_fixed::<I8F8>(),
+            (I8F8::from_bits(0x3e880u32 as i16), true)
+        );
+        assert_eq!(
+            FixedU256::<U3>::checked_from_fixed(I8F8::from_num(-1)),
+            None
+        );
+        assert_eq!(
+            FixedU256::<U3>::saturating_from_fixed(I8F8::from_num(-1)),
+            FixedU256::<U3>::default()
+        );
+
+        let a = I64F64::from_num(-1.5);
+        let prod = a.wide_mul(a);
+        assert_eq!(prod.to_string(), "2.25");
+        let max = U64F64::max_value();
+        assert_eq!(
+            max.wide_mul(max),
+            FixedU256::<U128>::from_fixed(max) * FixedU256::<U128>::from_fixed(max)
+        );
+    }
+
+    #[test]
+    fn num_conversions() {
+        type I = FixedI256<U3>;
+        type U = FixedU256<U128>;
+        assert_eq!(I::from_num(-12.375f64).to_string(), "-12.4");
+        assert_eq!(I::from_num(-12.375f64).to_num::<f32>(), -12.375);
+        assert_eq!(I::from_num(-7i8).to_num::<i128>(), -7);
+        assert_eq!(I::from_num(I8F8::from_num(1.5)), 1.5);
+        assert_eq!(I::from_num(2.5).to_num::<I8F8>(), 2.5);
+        assert_eq!(I::from_num(2.5).to_num::<i32>(), 2);
+        assert_eq!(I::from_num(-2.5).to_num::<i32>(), -3);
+        assert_eq!(I::from_num(u128::MAX), u128::MAX);
+        assert_eq!(I::from_num(1e70f64).to_num::<f64>(), 1e70);
+        assert_eq!(I::checked_from_num(1e80f64), None);
+        assert_eq!(I::saturating_from_num(-1e80f64), I::min_value());
+        assert_eq!(I::saturating_from_num(f64::INFINITY), I::max_value());
+        assert_eq!(I::checked_from_num(f64::NAN), None);
+        assert_eq!(I::from_num(300).checked_to_num::<u8>(), None);
+        assert_eq!(I::from_num(300).saturating_to_num::<u8>(), 255);
+        assert_eq!(I::from_num(300).wrapping_to_num::<u8>(), 44);
+        assert_eq!(
+            I::from_num(-1).overflowing_to_num::<u32>(),
+            (u32::MAX, true)
+        );
+
+        assert_eq!(U::checked_from_num(-1), None);
+        assert_eq!(U::saturating_from_num(-1.5), 0);
+        assert_eq!(U::from_num(0.1f64).to_num::<f64>(), 0.1);
+        assert_eq!(U::from_num(3).wrapping_to_num::<I8F8>(), 3);
+        assert_eq!(U::max_value().to_num::<f64>(), 2f64.powi(128));
+        assert_eq!(U::max_value().checked_to_num::<u128>(), Some(u128::MAX));
+    }
+
+    #[test]
+    fn comparisons() {
+        type I = FixedI256<U3>;
+        type U = FixedU256<F256>;
+        let x = I::from_num(-1.5);
+        assert!(x < 0 && x > -2 && x == -1.5f32 && x < -1i128);
+        assert!(x == I8F8::from_num(-1.5) && I8F8::from_num(-1.5) == x);
+        assert!(x < U64F64::from_num(0) && U64F64::from_num(0) > x);
+        assert!(x > f64::NEG_INFINITY && x < f64::INFINITY);
+        assert_eq!(x.partial_cmp(&f64::NAN), None);
+        assert_ne!(x, f64::NAN);
+        assert!(I::max_value() > u128::MAX);
+        assert!(I::min_value() < i128::MIN);
+        assert!(I::max_value() > U::max_value() && U::max_value() < I::max_value());
+        assert!(I::from_num(0.125) > U::from_bits(U256::from(1u8)));
+        assert_eq!(FixedI256::<U0>::from_num(1), I::from_num(1));
+        assert!(U::max_value() < 1 && U::max_value() > 0.99);
+        assert!(U::from_bits(U256::from(1u8)) > 0f64);
+        assert!(U::from_bits(U256::from(1u8)) > f64::MIN_POSITIVE);
+        assert!(-0.0f64 == FixedU256::<U3>::from_num(0));
+    }
+
+    #[test]
+    fn rounding_and_int_ops() {
+        type I = FixedI256<U3>;
+        type U = FixedU256<U3>;
+        let x = I::from_num(-2.5);
+        assert_eq!(x.ceil(), -2);
+        assert_eq!(x.floor(), -3);
+        assert_eq!(x.round(), -3);
+        assert_eq!(x.round_ties_to_even(), -2);
+        assert_eq!(x.round_to_zero(), -2);
+        assert_eq!(I::max_value().checked_ceil(), None);
+        assert_eq!(I::max_value().saturating_round(), I::max_value());
+        assert_eq!(
+            I::max_value().overflowing_floor(),
+            (I::max_value().floor(), false)
+        );
+        assert_eq!(U::from_num(2.5).round(), 3);
+        assert_eq!(U::from_num(2.5).round_ties_to_even(), 2);
+
+        assert_eq!(x * I256::from(-3i8), 7.5);
+        assert_eq!(I256::from(4u8) * x, -10);
+        assert_eq!(x / I256::from(2u8), -1.25);
+        assert_eq!(x % I256::from(2u8), -0.5);
+        assert_eq!(x.div_euclid_int(I256::from(2u8)), -2);
+        assert_eq!(x.rem_euclid_int(I256::from(2u8)), 1.5);
+        assert_eq!(x.div_euclid(I::from_num(2)), -2);
+        assert_eq!(x.rem_euclid(I::from_num(2)), 1.5);
+        assert_eq!(x.checked_div_int(I256::from(0u8)), None);
+        assert_eq!(x.checked_rem_int(I256::from(0u8)), None);
+        assert_eq!(I::max_value().checked_mul_int(I256::from(2u8)), None);
+        assert_eq!(
+            I::max_value().saturating_mul_int(I256::from(-2i8)),
+            I::min_value()
+        );
+        assert_eq!(
+            I::min_value().overflowing_div_int(I256::from(-1i8)),
+            (I::min_value(), true)
+        );
+        assert_eq!(I::min_value().wrapping_rem_int(I256::from(-1i8)), 0);
+        let mut y = x;
+        y *= I256::from(2u8);
+        y /= I256::from(5u8);
+        assert_eq!(y, -1);
+        let mut u = U::from_num(7.5);
+        u %= U256::from(2u8);
+        assert_eq!(u, 1.5);
+
+        assert_eq!(x.signum(), -1);
+        assert_eq!(x.abs(), 2.5);
+        assert_eq!(I::min_value().checked_abs(), None);
+        assert_eq!(I::min_value().saturating_abs(), I::max_value());
+        assert_eq!(I::from_num(9).sqrt(), 3);
+        assert_eq!(I::from_num(-2).saturating_powi(3), -8);
+        assert_eq!(I::from_num(2).checked_powi(300), None);
+        assert_eq!(U::from_num(3).next_power_of_two(), 4);
+        assert_eq!(U::from_num(0.375).next_power_of_two(), 0.5);
+        assert!(U::from_num(0.25).is_power_of_two());
+        assert_eq!(U::max_value().checked_next_power_of_two(), None);
+        assert_eq!(x.count_ones(), 253);
+        assert_eq!(U::from_num(1).leading_zeros(), 252);
+        assert_eq!(U::from_num(1).trailing_zeros(), 3);
+        assert_eq!(
+            U::from_bits(U256::from(1u8)).rotate_right(1),
+            U::from_bits(U256::MSB)
+        );
+    }
+}
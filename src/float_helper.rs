@@ -13,7 +13,10 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
-use crate::helpers::{FloatKind, IntHelper, ToFixedHelper, ToFloatHelper, Widest};
+use crate::{
+    helpers::{FloatKind, IntHelper, ToFixedHelper, ToFloatHelper, Widest},
+    int256::U256,
+};
 use core::cmp::Ordering;
 #[cfg(feature = "f16")]
 use half::{bf16, f16};
@@ -30,13 +33,22 @@ pub trait FloatHelper: Copy {
     const MANT_MASK: Self::Bits;
 
     fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
 
+    #[allow(dead_code)]
     fn bits_from_parts(neg: bool, exp: i32, mant: Self::Bits) -> Self::Bits;
+    #[allow(dead_code)]
     fn from_parts(neg: bool, exp: i32, mant: Self::Bits) -> Self;
     fn parts(self) -> (bool, i32, Self::Bits);
+    // For finite values, returns the sign and (abs, exp) such that the
+    // magnitude is abs × 2^exp.
+    fn neg_abs_exp(self) -> (bool, u128, i32);
 
     fn from_to_float_helper(val: ToFloatHelper, frac_bits: u32, int_bits: u32) -> Self;
     fn to_float_kind(self, dst_frac_bits: u32, dst_int_bits: u32) -> FloatKind;
+    // Converts abs × 2^−frac_bits, rounding to the nearest with ties
+    // to even.
+    fn from_neg_abs(neg: bool, abs: U256, frac_bits: u32) -> Self;
 }
 
 macro_rules! sealed_float {
@@ -53,6 +65,11 @@ macro_rules! sealed_float {
                 (self.to_bits() & !Self::SIGN_MASK) > Self::EXP_MASK
             }
 
+            #[inline]
+            fn is_finite(self) -> bool {
+                (self.to_bits() & !Self::SIGN_MASK) < Self::EXP_MASK
+            }
+
             #[inline]
             fn bits_from_parts(neg: bool, exp: i32, mant: Self::Bits) -> Self::Bits {
                 let sign = if neg { Self::SIGN_MASK } else { 0 };
@@ -77,6 +94,19 @@ macro_rules! sealed_float {
                 (neg, exp, mant)
             }
 
+            #[inline]
+            #[allow(clippy::cast_lossless)]
+            fn neg_abs_exp(self) -> (bool, u128, i32) {
+                let (neg, exp, mant) = self.parts();
+                let prec = Self::PREC as i32;
+                if exp >= Self::EXP_MIN {
+                    let mant = mant | (1 << (prec - 1));
+                    (neg, mant as u128, exp - (prec - 1))
+                } else {
+                    (neg, mant as u128, Self::EXP_MIN - (prec - 1))
+                }
+            }
+
             #[inline]
             fn from_to_float_helper(val: ToFloatHelper, frac_bits: u32, int_bits: u32) -> $Float {
                 let fix_bits = frac_bits + int_bits;
@@ -201,6 +231,43 @@ macro_rules! sealed_float {
                 conv.dir = dir;
                 FloatKind::Finite { neg, conv }
             }
+
+            #[inline]
+            fn from_neg_abs(neg: bool, abs: U256, frac_bits: u32) -> $Float {
+                let prec = Self::PREC as i32;
+                let bits_sign = if neg { Self::SIGN_MASK } else { 0 };
+                if abs.is_zero() {
+                    return Self::from_bits(bits_sign);
+                }
+                let exp = 255 - abs.leading_zeros() as i32 - frac_bits as i32;
+                if exp > Self::EXP_MAX {
+                    return Self::from_bits(Self::EXP_MASK | bits_sign);
+                }
+                // position of the lsb of the mantissa within abs
+                let shift = exp.max(Self::EXP_MIN) - (prec - 1) + frac_bits as i32;
+                let mant = if shift <= 0 {
+                    abs << (-shift) as u32
+                } else {
+                    let shift = shift as u32;
+                    let kept = abs >> shift;
+                    let removed = abs & (U256::max_value() >> (256 - shift));
+                    let tie = U256::ONE << (shift - 1);
+                    if removed > tie || (removed == tie && kept.is_odd()) {
+                        kept.wrapping_add(U256::ONE)
+                    } else {
+                        kept
+                    }
+                };
+                let mant = mant.to_words().1 as $Bits;
+                // the implicit one of normal numbers is added to the
+                // exponent, and a carry from rounding increments it
+                let bits_exp_mant = if exp >= Self::EXP_MIN {
+                    (((exp + Self::EXP_BIAS - 1) as $Bits) << (prec - 1)) + mant
+                } else {
+                    mant
+                };
+                Self::from_bits(bits_sign | bits_exp_mant)
+            }
         }
     };
 }
@@ -16,17 +16,21 @@
 use crate::{
     display::Mul10,
     helpers::IntHelper,
-    types::extra::{False, LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
+    int256::{I256, U256},
+    types::extra::{False, LeEqU128, LeEqU16, LeEqU256, LeEqU32, LeEqU64, LeEqU8},
     wide_div::WideDivRem,
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8,
+    FixedI128, FixedI16, FixedI256, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU256,
+    FixedU32, FixedU64, FixedU8,
 };
 use core::{
+    char::REPLACEMENT_CHARACTER,
     cmp::Ordering,
     fmt::{Display, Formatter, Result as FmtResult},
     ops::{Add, Mul, Shl, Shr},
     str::FromStr,
 };
+#[cfg(feature = "codec")]
+use parity_scale_codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use std::error::Error;
 
@@ -141,6 +145,18 @@ fn unchecked_hex_digit(byte: u8) -> u8 {
     (byte & 0x0f) + if byte >= 0x40 { 9 } else { 0 }
 }
 
+fn unchecked_digit(byte: u8) -> u8 {
+    // We know that byte is a valid digit in some radix up to 36:
+    //   * b'0'..=b'9' => 0..=9
+    //   * b'A'..=b'Z' => 10..=35
+    //   * b'a'..=b'z' => 10..=35
+    match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'a'..=b'z' => byte - b'a' + 10,
+        _ => byte - b'A' + 10,
+    }
+}
+
 fn hex_str_int_to_bin<I>(bytes: &[u8]) -> (I, bool)
 where
     I: IntHelper<IsSigned = False> + From<u8>,
@@ -217,6 +233,92 @@ where
     (acc, overflow)
 }
 
+fn radix_str_int_to_bin<I>(bytes: &[u8], radix: u32) -> (I, bool)
+where
+    I: IntHelper<IsSigned = False> + From<u8>,
+{
+    // For odd radices every digit affects the wrapped value, so no
+    // digits can be skipped like in dec_str_int_to_bin.
+    let mut acc = I::from(0);
+    let mut overflow = false;
+    for &byte in bytes {
+        if byte == b'_' {
+            continue;
+        }
+        let (mul, mul_overflow) = acc.overflowing_mul(I::from(radix as u8));
+        let (add, add_overflow) = mul.overflowing_add(I::from(unchecked_digit(byte)));
+        acc = add;
+        overflow = overflow || mul_overflow || add_overflow;
+    }
+    (acc, overflow)
+}
+
+// Decode fractional digits in any radix into nbits ≤ 256 fractional
+// bits, rounding to the nearest with ties rounded to even. If rounding
+// results in more than nbits bits, returns None.
+//
+// The digits are consumed from the least significant one using
+//
+//     floor((d + x) / r) = floor((d + floor(x)) / r)
+//
+// for integer d, so that with m = nbits + 1, acc = floor(2^m × frac)
+// is computed exactly in 320 bits, as 2^m × d + acc < 2^263. A sticky
+// flag records whether any division had a nonzero remainder. Then the
+// least significant bit of acc is the half bit used for rounding.
+//
+// This is exact for every radix, so it is also used for odd radices
+// in the 256-bit path, where truncating the digits is not possible.
+fn radix_str_frac_to_bin(bytes: &[u8], radix: u32, nbits: u32) -> Option<U256> {
+    debug_assert!(nbits <= 256);
+    let m = nbits as usize + 1;
+    let mut acc = [0u64; 5];
+    let mut sticky = false;
+    for &byte in bytes.iter().rev() {
+        if byte == b'_' {
+            continue;
+        }
+        // acc < 2^m, so adding d × 2^m is a bitwise or
+        let digit = u128::from(unchecked_digit(byte)) << (m % 64);
+        acc[m / 64] |= digit as u64;
+        if m / 64 < 4 {
+            acc[m / 64 + 1] |= (digit >> 64) as u64;
+        }
+        let mut rem = 0u128;
+        for limb in acc.iter_mut().rev() {
+            let cur = (rem << 64) | u128::from(*limb);
+            *limb = (cur / u128::from(radix)) as u64;
+            rem = cur % u128::from(radix);
+        }
+        if rem != 0 {
+            sticky = true;
+        }
+    }
+    let half = acc[0] & 1 != 0;
+    let mut val = [0u64; 5];
+    for i in 0..5 {
+        val[i] = acc[i] >> 1;
+        if i < 4 {
+            val[i] |= acc[i + 1] << 63;
+        }
+    }
+    if half && (sticky || val[0] & 1 != 0) {
+        for limb in val.iter_mut() {
+            let (sum, carry) = limb.overflowing_add(1);
+            *limb = sum;
+            if !carry {
+                break;
+            }
+        }
+    }
+    // val ≤ 2^nbits, so it has too many bits only if bit nbits is set
+    if val[nbits as usize / 64] >> (nbits % 64) & 1 != 0 {
+        return None;
+    }
+    let hi = u128::from(val[2]) | u128::from(val[3]) << 64;
+    let lo = u128::from(val[0]) | u128::from(val[1]) << 64;
+    Some(U256::from_words(hi, lo))
+}
+
 enum Round {
     Nearest,
     Floor,
@@ -468,6 +570,10 @@ where
             break;
         }
     }
+    // if the digits ran out before the boundary did, they are below it
+    if tie && boundary != I::ZERO {
+        return Some(floor);
+    }
     if tie && !floor.is_odd() {
         return Some(floor);
     }
@@ -515,7 +621,7 @@ An error which can be returned when parsing a fixed-point number.
 # Examples
 
 ```rust
-use substrate_fixed::{types::I16F16, ParseFixedError};
+use substrate_fixed::{types::I16F16, ParseErrorKind, ParseFixedError};
 // This string is not a fixed-point number.
 let s = "something completely different (_!_!_)";
 let error: ParseFixedError = match s.parse::<I16F16>() {
@@ -523,29 +629,146 @@ let error: ParseFixedError = match s.parse::<I16F16>() {
     Err(error) => error,
 };
 println!("Parse error: {}", error);
+// The error has a kind, and the offending character and its byte index.
+assert_eq!(error.kind(), ParseErrorKind::InvalidDigit);
+assert_eq!(error.character(), Some('s'));
+assert_eq!(error.position(), Some(0));
 ```
+
+If the `codec` feature is enabled, the error implements [`Encode`]
+and [`Decode`], and if the `serde` feature is enabled, it implements
+[`Serialize`] and [`Deserialize`].
+
+[`Decode`]: https://docs.rs/parity-scale-codec/^2/parity_scale_codec/trait.Decode.html
+[`Deserialize`]: https://docs.rs/serde/^1/serde/de/trait.Deserialize.html
+[`Encode`]: https://docs.rs/parity-scale-codec/^2/parity_scale_codec/trait.Encode.html
+[`Serialize`]: https://docs.rs/serde/^1/serde/ser/trait.Serialize.html
 */
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ParseFixedError {
     kind: ParseErrorKind,
+    position: Option<usize>,
+    character: Option<char>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum ParseErrorKind {
+/**
+The kind of error in a [`ParseFixedError`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::I16F16, ParseErrorKind};
+let error = "12x".parse::<I16F16>().unwrap_err();
+assert_eq!(error.kind(), ParseErrorKind::InvalidDigit);
+let error = "99999".parse::<I16F16>().unwrap_err();
+assert_eq!(error.kind(), ParseErrorKind::Overflow);
+```
+
+[`ParseFixedError`]: struct.ParseFixedError.html
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "codec", derive(Encode, Decode))]
+pub enum ParseErrorKind {
+    /// A character which is not a valid digit, sign or point was found.
     InvalidDigit,
+    /// The string has no digits.
     NoDigits,
+    /// More than one point was found.
     TooManyPoints,
+    /// The number does not fit in the fixed-point type.
     Overflow,
 }
 
 impl From<ParseErrorKind> for ParseFixedError {
     #[inline]
     fn from(kind: ParseErrorKind) -> ParseFixedError {
-        ParseFixedError { kind }
+        ParseFixedError {
+            kind,
+            position: None,
+            character: None,
+        }
     }
 }
 
 impl ParseFixedError {
+    pub(crate) fn from_parts(
+        kind: ParseErrorKind,
+        position: Option<usize>,
+        character: Option<char>,
+    ) -> ParseFixedError {
+        ParseFixedError {
+            kind,
+            position,
+            character,
+        }
+    }
+
+    // The error is caused by the character starting at bytes[index].
+    fn at(kind: ParseErrorKind, bytes: &[u8], index: usize) -> ParseFixedError {
+        let end = bytes.len().min(index + 4);
+        let valid = match core::str::from_utf8(&bytes[index..end]) {
+            Ok(valid) => valid,
+            Err(e) => core::str::from_utf8(&bytes[index..index + e.valid_up_to()]).unwrap_or(""),
+        };
+        let character = valid.chars().next().unwrap_or(REPLACEMENT_CHARACTER);
+        ParseFixedError::from_parts(kind, Some(index), Some(character))
+    }
+
+    // The error was found in a slice starting at offset.
+    fn offset(self, offset: usize) -> ParseFixedError {
+        ParseFixedError {
+            position: self.position.map(|position| position + offset),
+            ..self
+        }
+    }
+
+    /// Returns the kind of error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, ParseErrorKind};
+    /// let error = "1.2.3".parse::<I16F16>().unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::TooManyPoints);
+    /// ```
+    #[inline]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte index in the string of the character that
+    /// caused the error, if the error was caused by a character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::types::I16F16;
+    /// let error = "1.2.3".parse::<I16F16>().unwrap_err();
+    /// assert_eq!(error.position(), Some(3));
+    /// let error = "99999".parse::<I16F16>().unwrap_err();
+    /// assert_eq!(error.position(), None);
+    /// ```
+    #[inline]
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Returns the character that caused the error, if the error was
+    /// caused by a character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::types::I16F16;
+    /// let error = "12€".parse::<I16F16>().unwrap_err();
+    /// assert_eq!(error.character(), Some('€'));
+    /// assert_eq!(error.position(), Some(2));
+    /// ```
+    #[inline]
+    pub fn character(&self) -> Option<char> {
+        self.character
+    }
+
     fn message(&self) -> &str {
         use self::ParseErrorKind::*;
         match self.kind {
@@ -559,7 +782,11 @@ impl ParseFixedError {
 
 impl Display for ParseFixedError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        Display::fmt(self.message(), f)
+        Display::fmt(self.message(), f)?;
+        if let Some(position) = self.position {
+            write!(f, " at byte {}", position)?;
+        }
+        Ok(())
     }
 }
 
@@ -570,8 +797,25 @@ impl Error for ParseFixedError {
     }
 }
 
-// also trims zeros at start of int and at end of frac
-fn parse_bounds(bytes: &[u8], radix: u32) -> Result<Parse<'_>, ParseFixedError> {
+// The syntax accepted in addition to the strict syntax of FromStr.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Syntax {
+    // underscores can appear anywhere after the sign
+    underscores: bool,
+    // the sign, if it was already parsed before a radix prefix
+    sign: Option<bool>,
+}
+
+impl Syntax {
+    const STRICT: Syntax = Syntax {
+        underscores: false,
+        sign: None,
+    };
+}
+
+// Also trims zeros at start of int and at end of frac. If underscores
+// are allowed, int and frac can contain them.
+fn parse_bounds(bytes: &[u8], radix: u32, syntax: Syntax) -> Result<Parse<'_>, ParseFixedError> {
     let mut sign: Option<bool> = None;
     let mut trimmed_int_start: Option<usize> = None;
     let mut point: Option<usize> = None;
@@ -580,34 +824,34 @@ fn parse_bounds(bytes: &[u8], radix: u32) -> Result<Parse<'_>, ParseFixedError>
 
     for (index, &byte) in bytes.iter().enumerate() {
         match (byte, radix) {
-            (b'+', _) => {
-                if sign.is_some() || point.is_some() || has_any_digit {
-                    return Err(ParseErrorKind::InvalidDigit.into());
+            (b'+', _) | (b'-', _) => {
+                // a sign can only be the first byte
+                if index > 0 || syntax.sign.is_some() {
+                    return Err(ParseFixedError::at(
+                        ParseErrorKind::InvalidDigit,
+                        bytes,
+                        index,
+                    ));
                 }
-                sign = Some(false);
-                continue;
-            }
-            (b'-', _) => {
-                if sign.is_some() || point.is_some() || has_any_digit {
-                    return Err(ParseErrorKind::InvalidDigit.into());
-                }
-                sign = Some(true);
+                sign = Some(byte == b'-');
                 continue;
             }
+            (b'_', _) if syntax.underscores => continue,
             (b'.', _) => {
                 if point.is_some() {
-                    return Err(ParseErrorKind::TooManyPoints.into());
+                    return Err(ParseFixedError::at(
+                        ParseErrorKind::TooManyPoints,
+                        bytes,
+                        index,
+                    ));
                 }
                 point = Some(index);
                 trimmed_frac_end = Some(index + 1);
                 continue;
             }
-            (b'0'..=b'1', 2)
-            | (b'0'..=b'7', 8)
-            | (b'0'..=b'9', 10)
-            | (b'0'..=b'9', 16)
-            | (b'a'..=b'f', 16)
-            | (b'A'..=b'F', 16) => {
+            (b'0'..=b'9', _) | (b'a'..=b'z', _) | (b'A'..=b'Z', _)
+                if u32::from(unchecked_digit(byte)) < radix =>
+            {
                 if trimmed_int_start.is_none() && point.is_none() && byte != b'0' {
                     trimmed_int_start = Some(index);
                 }
@@ -616,13 +860,19 @@ fn parse_bounds(bytes: &[u8], radix: u32) -> Result<Parse<'_>, ParseFixedError>
                 }
                 has_any_digit = true;
             }
-            _ => return Err(ParseErrorKind::InvalidDigit.into()),
+            _ => {
+                return Err(ParseFixedError::at(
+                    ParseErrorKind::InvalidDigit,
+                    bytes,
+                    index,
+                ))
+            }
         }
     }
     if !has_any_digit {
         return Err(ParseErrorKind::NoDigits.into());
     }
-    let neg = sign.unwrap_or(false);
+    let neg = syntax.sign.or(sign).unwrap_or(false);
     let int = match (trimmed_int_start, point) {
         (Some(start), Some(point)) => &bytes[start..point],
         (Some(start), None) => &bytes[start..],
@@ -636,16 +886,442 @@ fn parse_bounds(bytes: &[u8], radix: u32) -> Result<Parse<'_>, ParseFixedError>
 }
 
 fn frac_is_half(bytes: &[u8], radix: u32) -> bool {
-    // since zeros are trimmed, there must be exatly one byte
-    bytes.len() == 1 && bytes[0] - b'0' == (radix as u8) / 2
+    // since zeros are trimmed, there must be exatly one byte, and
+    // one half has no finite expansion in odd radices
+    radix & 1 == 0 && bytes.len() == 1 && u32::from(unchecked_digit(bytes[0])) == radix / 2
+}
+
+// Only the last EXP_INT_DIGITS integer digits can affect the wrapped
+// value, since radix^257 is a multiple of 2^256 for even radices; any
+// nonzero digit before them means the number overflows. After
+// FRAC256_MAX_DIGITS fractional digits, a nonzero tail can only affect
+// rounding, so it is replaced by a single sticky digit 1.
+//
+// The buffer holds the sign, a leading 0, the integer digits, the
+// point, the fractional digits and the sticky digit.
+const EXP_INT_DIGITS: usize = 257;
+const EXP_BUF_LEN: usize = 2 + EXP_INT_DIGITS + 1 + FRAC256_MAX_DIGITS + 1;
+
+// Returns the exponent in bytes[start..], saturated to a value far
+// beyond any shift that can make a difference.
+fn parse_exp(bytes: &[u8], start: usize, syntax: Syntax) -> Result<i64, ParseFixedError> {
+    const MAX_EXP: i64 = 1 << 48;
+    let (neg, digits_start) = match bytes.get(start) {
+        Some(&b'-') => (true, start + 1),
+        Some(&b'+') => (false, start + 1),
+        _ => (false, start),
+    };
+    let mut exp = 0i64;
+    let mut has_any_digit = false;
+    for (index, &byte) in bytes.iter().enumerate().skip(digits_start) {
+        match byte {
+            b'0'..=b'9' => {
+                exp = (exp * 10 + i64::from(byte - b'0')).min(MAX_EXP);
+                has_any_digit = true;
+            }
+            b'_' if syntax.underscores => {}
+            _ => {
+                return Err(ParseFixedError::at(
+                    ParseErrorKind::InvalidDigit,
+                    bytes,
+                    index,
+                ))
+            }
+        }
+    }
+    if !has_any_digit {
+        // the marker or sign is not followed by an exponent
+        return Err(ParseFixedError::at(
+            ParseErrorKind::InvalidDigit,
+            bytes,
+            bytes.len() - 1,
+        ));
+    }
+    Ok(if neg { -exp } else { exp })
+}
+
+// Parses a number with an optional exponent: e or E for decimal
+// numbers, and a binary exponent p or P for hexadecimal numbers. If
+// there is an exponent, or if there are underscores in an even radix,
+// the number is rewritten without them into a buffer, hexadecimal
+// digits are expanded to binary digits, and the rewritten number is
+// passed to parse. The returned flag is set if the rewritten number is
+// known to overflow.
+//
+// Numbers in odd radices cannot be rewritten as their digits cannot be
+// truncated, so their underscores are skipped by the digit conversion.
+fn parse_with_exp<T>(
+    bytes: &[u8],
+    radix: u32,
+    syntax: Syntax,
+    parse: impl FnOnce(Parse<'_>, u32) -> (T, bool),
+) -> Result<(T, bool), ParseFixedError> {
+    let is_marker = |&byte: &u8| match radix {
+        10 => byte == b'e' || byte == b'E',
+        16 => byte == b'p' || byte == b'P',
+        _ => false,
+    };
+    let marker = bytes.iter().position(is_marker);
+    let mantissa = &bytes[..marker.unwrap_or(bytes.len())];
+    let Parse { neg, int, frac } = parse_bounds(mantissa, radix, syntax)?;
+    let has_underscore = syntax.underscores && mantissa.contains(&b'_');
+    let exp = match marker {
+        Some(marker) => parse_exp(bytes, marker + 1, syntax)?,
+        None if has_underscore && radix & 1 == 0 => 0,
+        None => return Ok(parse(Parse { neg, int, frac }, radix)),
+    };
+
+    let is_digit = |byte: &&u8| **byte != b'_';
+    let digits_per_byte = if radix == 16 { 4 } else { 1 };
+    let int_len = int.iter().filter(is_digit).count() * digits_per_byte;
+    let len = (int_len + frac.iter().filter(is_digit).count() * digits_per_byte) as i64;
+    let mut digits = int.iter().chain(frac).filter(is_digit).flat_map(|&byte| {
+        (0..digits_per_byte).rev().map(move |shift| {
+            if radix == 16 {
+                b'0' + ((unchecked_hex_digit(byte) >> shift) & 1)
+            } else {
+                byte
+            }
+        })
+    });
+    // digit must be called with increasing indices
+    let mut next_index = 0;
+    let mut digit = |index: i64| -> u8 {
+        if index < 0 || index >= len {
+            return b'0';
+        }
+        let skip = (index - next_index) as usize;
+        next_index = index + 1;
+        digits.nth(skip).unwrap_or(b'0')
+    };
+
+    let point = int_len as i64 + exp;
+    let int_start = point - EXP_INT_DIGITS as i64;
+    let frac_end = point + FRAC256_MAX_DIGITS as i64;
+    let overflow = (0..int_start.min(len)).any(|i| digit(i) != b'0');
+    let mut buf = [0u8; EXP_BUF_LEN];
+    let mut buf_len = 0;
+    let mut push = |byte: u8| {
+        buf[buf_len] = byte;
+        buf_len += 1;
+    };
+    if neg {
+        push(b'-');
+    }
+    push(b'0');
+    for index in int_start.max(0)..point {
+        push(digit(index));
+    }
+    push(b'.');
+    for index in point..frac_end {
+        push(digit(index));
+    }
+    if (frac_end.max(0)..len).any(|i| digit(i) != b'0') {
+        push(b'1');
+    }
+    let parse_radix = if radix == 16 { 2 } else { radix };
+    let rewritten = parse_bounds(&buf[..buf_len], parse_radix, Syntax::STRICT)?;
+    let (val, rewritten_overflow) = parse(rewritten, parse_radix);
+    Ok((val, rewritten_overflow || overflow))
+}
+
+pub(crate) fn check_radix(radix: u32) {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix {} is not in the range 2 to 36 inclusive",
+        radix
+    );
+}
+
+/**
+Options for parsing fixed-point numbers with a syntax that is more
+lenient than the one accepted by [`FromStr`].
+
+The default options accept the same strings as [`FromStr`]: an
+optional sign `+` or `-`, digits with an optional point, and an
+optional exponent. Each option can then be enabled separately:
+
+  * [`radix`] sets the radix of the digits, which is 10 by default.
+  * [`prefix`] accepts a radix prefix `0b`, `0o` or `0x` after the
+    sign, which selects radix 2, 8 or 16 for that number. A prefix is
+    only recognized if its letter is not a digit in the radix
+    configured by [`radix`], so for example `0b1` in radix 16 is the
+    hexadecimal number B1.
+  * [`underscores`] ignores underscores after the sign and prefix, so
+    that digits can be grouped like in `1_000_000.000_001`.
+  * [`trim_whitespace`] ignores leading and trailing ASCII whitespace.
+
+The options are used by methods such as [`from_str_with`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::I16F16, ParseOptions};
+let options = ParseOptions::new()
+    .prefix(true)
+    .underscores(true)
+    .trim_whitespace(true);
+let check = I16F16::from_bits(0x1F_8000);
+assert_eq!(I16F16::from_str_with(" +0x1F.8 ", options), Ok(check));
+let check = I16F16::from_num(1_000.25);
+assert_eq!(I16F16::from_str_with("1_000.250_0", options), Ok(check));
+// the default options are as strict as FromStr
+assert!(I16F16::from_str_with("1_000", ParseOptions::new()).is_err());
+```
+
+[`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+[`from_str_with`]: struct.FixedI32.html#method.from_str_with
+[`prefix`]: #method.prefix
+[`radix`]: #method.radix
+[`trim_whitespace`]: #method.trim_whitespace
+[`underscores`]: #method.underscores
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    radix: u32,
+    prefix: bool,
+    underscores: bool,
+    trim_whitespace: bool,
+}
+
+impl Default for ParseOptions {
+    #[inline]
+    fn default() -> ParseOptions {
+        ParseOptions::new()
+    }
+}
+
+// A number split from its surrounding whitespace and radix prefix.
+struct Number<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    radix: u32,
+    syntax: Syntax,
+}
+
+impl ParseOptions {
+    /// Creates options that accept the same syntax as [`FromStr`],
+    /// that is decimal numbers without underscores, radix prefixes
+    /// or whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, ParseOptions};
+    /// let options = ParseOptions::new();
+    /// assert_eq!(I16F16::from_str_with("+1.5", options), "1.5".parse());
+    /// assert!(I16F16::from_str_with(" 1.5", options).is_err());
+    /// ```
+    ///
+    /// [`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+    #[inline]
+    pub const fn new() -> ParseOptions {
+        ParseOptions {
+            radix: 10,
+            prefix: false,
+            underscores: false,
+            trim_whitespace: false,
+        }
+    }
+
+    /// Sets the radix of the digits, which is 10 by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, ParseOptions};
+    /// let options = ParseOptions::new().radix(16);
+    /// assert_eq!(I16F16::from_str_with("1F.8", options), Ok(I16F16::from_num(31.5)));
+    /// ```
+    #[inline]
+    pub fn radix(self, radix: u32) -> ParseOptions {
+        check_radix(radix);
+        ParseOptions { radix, ..self }
+    }
+
+    /// Sets whether a radix prefix `0b`, `0o` or `0x` is accepted
+    /// after the sign, selecting radix 2, 8 or 16 for the number.
+    ///
+    /// Numbers without a prefix use the radix set by [`radix`]. A
+    /// prefix is only recognized if its letter is not a digit in that
+    /// radix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, ParseOptions};
+    /// let options = ParseOptions::new().prefix(true);
+    /// assert_eq!(I16F16::from_str_with("-0b1.1", options), Ok(I16F16::from_num(-1.5)));
+    /// assert_eq!(I16F16::from_str_with("0o17", options), Ok(I16F16::from_num(15)));
+    /// assert_eq!(I16F16::from_str_with("15", options), Ok(I16F16::from_num(15)));
+    /// // in radix 16, b is a digit, so 0b10 is not a prefixed number
+    /// let hex = options.radix(16);
+    /// assert_eq!(I16F16::from_str_with("0b10", hex), Ok(I16F16::from_num(0xB10)));
+    /// ```
+    ///
+    /// [`radix`]: #method.radix
+    #[inline]
+    pub fn prefix(self, prefix: bool) -> ParseOptions {
+        ParseOptions { prefix, ..self }
+    }
+
+    /// Sets whether underscores are ignored after the sign and radix
+    /// prefix, including in the exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I32F32, ParseOptions};
+    /// let options = ParseOptions::new().underscores(true);
+    /// let f = I32F32::from_str_with("1_000_000.000_001", options);
+    /// assert_eq!(f, "1000000.000001".parse());
+    /// ```
+    #[inline]
+    pub fn underscores(self, underscores: bool) -> ParseOptions {
+        ParseOptions {
+            underscores,
+            ..self
+        }
+    }
+
+    /// Sets whether leading and trailing ASCII whitespace is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, ParseOptions};
+    /// let options = ParseOptions::new().trim_whitespace(true);
+    /// assert_eq!(I16F16::from_str_with("\t1.5\n", options), Ok(I16F16::from_num(1.5)));
+    /// ```
+    #[inline]
+    pub fn trim_whitespace(self, trim_whitespace: bool) -> ParseOptions {
+        ParseOptions {
+            trim_whitespace,
+            ..self
+        }
+    }
+
+    // The strict syntax of FromStr in the given radix.
+    pub(crate) fn strict(radix: u32) -> ParseOptions {
+        ParseOptions {
+            radix,
+            ..ParseOptions::new()
+        }
+    }
+
+    fn split(self, bytes: &[u8]) -> Number<'_> {
+        let mut start = 0;
+        let mut end = bytes.len();
+        if self.trim_whitespace {
+            while start < end && bytes[start].is_ascii_whitespace() {
+                start += 1;
+            }
+            while end > start && bytes[end - 1].is_ascii_whitespace() {
+                end -= 1;
+            }
+        }
+        let mut number = Number {
+            bytes: &bytes[start..end],
+            offset: start,
+            radix: self.radix,
+            syntax: Syntax {
+                underscores: self.underscores,
+                sign: None,
+            },
+        };
+        if !self.prefix {
+            return number;
+        }
+        let (sign, sign_len) = match number.bytes.first() {
+            Some(&b'-') => (true, 1),
+            Some(&b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        if number.bytes.get(sign_len) != Some(&b'0') {
+            return number;
+        }
+        let (radix, letter) = match number.bytes.get(sign_len + 1) {
+            Some(&letter @ b'b') | Some(&letter @ b'B') => (2, letter),
+            Some(&letter @ b'o') | Some(&letter @ b'O') => (8, letter),
+            Some(&letter @ b'x') | Some(&letter @ b'X') => (16, letter),
+            _ => return number,
+        };
+        // the letter is not a prefix if it is a digit in self.radix
+        if u32::from(unchecked_digit(letter)) < self.radix {
+            return number;
+        }
+        let prefix_len = sign_len + 2;
+        number.bytes = &number.bytes[prefix_len..];
+        number.offset += prefix_len;
+        number.radix = radix;
+        number.syntax.sign = Some(sign);
+        number
+    }
+
+    // Whether the number is negative, assuming it is valid.
+    fn is_neg(self, bytes: &[u8]) -> bool {
+        let number = self.split(bytes);
+        number
+            .syntax
+            .sign
+            .unwrap_or_else(|| number.bytes.first() == Some(&b'-'))
+    }
+}
+
+fn parse_with_options<T>(
+    bytes: &[u8],
+    options: ParseOptions,
+    parse: impl FnOnce(Parse<'_>, u32) -> (T, bool),
+) -> Result<(T, bool), ParseFixedError> {
+    let Number {
+        bytes,
+        offset,
+        radix,
+        syntax,
+    } = options.split(bytes);
+    parse_with_exp(bytes, radix, syntax, parse).map_err(|err| err.offset(offset))
 }
 
 pub(crate) trait FromStrRadix: Sized {
-    type Err;
-    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err>;
-    fn saturating_from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err>;
-    fn wrapping_from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err>;
-    fn overflowing_from_str_radix(s: &str, radix: u32) -> Result<(Self, bool), Self::Err>;
+    fn overflowing_from_bytes(
+        bytes: &[u8],
+        options: ParseOptions,
+    ) -> Result<(Self, bool), ParseFixedError>;
+    fn saturating_from_bytes(bytes: &[u8], options: ParseOptions) -> Result<Self, ParseFixedError>;
+
+    #[inline]
+    fn from_bytes(bytes: &[u8], options: ParseOptions) -> Result<Self, ParseFixedError> {
+        match Self::overflowing_from_bytes(bytes, options) {
+            Ok((val, false)) => Ok(val),
+            Ok((_, true)) => Err(ParseErrorKind::Overflow.into()),
+            Err(e) => Err(e),
+        }
+    }
+    #[inline]
+    fn wrapping_from_bytes(bytes: &[u8], options: ParseOptions) -> Result<Self, ParseFixedError> {
+        Self::overflowing_from_bytes(bytes, options).map(|(val, _)| val)
+    }
+
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseFixedError> {
+        Self::from_bytes(s.as_bytes(), ParseOptions::strict(radix))
+    }
+    #[inline]
+    fn saturating_from_str_radix(s: &str, radix: u32) -> Result<Self, ParseFixedError> {
+        Self::saturating_from_bytes(s.as_bytes(), ParseOptions::strict(radix))
+    }
+    #[inline]
+    fn wrapping_from_str_radix(s: &str, radix: u32) -> Result<Self, ParseFixedError> {
+        Self::wrapping_from_bytes(s.as_bytes(), ParseOptions::strict(radix))
+    }
+    #[inline]
+    fn overflowing_from_str_radix(s: &str, radix: u32) -> Result<(Self, bool), ParseFixedError> {
+        Self::overflowing_from_bytes(s.as_bytes(), ParseOptions::strict(radix))
+    }
 }
 
 macro_rules! impl_from_str_traits {
@@ -654,28 +1330,35 @@ macro_rules! impl_from_str_traits {
             type Err = ParseFixedError;
             /// Parses a string slice to return a fixed-point number.
             ///
+            /// The digits can be followed by a decimal exponent
+            /// introduced by `e` or `E`, for example `1.5e-3`.
+            ///
             /// Rounding is to the nearest, with ties rounded to even.
             #[inline]
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                Self::from_str_radix(s, 10)
+                <Self as FromStrRadix>::from_str_radix(s, 10)
             }
         }
         impl<Frac: $LeEqU> FromStrRadix for $Fixed<Frac> {
-            type Err = ParseFixedError;
             #[inline]
-            fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err> {
-                match Self::overflowing_from_str_radix(s, radix) {
-                    Ok((val, false)) => Ok(val),
-                    Ok((_, true)) => Err(ParseErrorKind::Overflow.into()),
-                    Err(e) => Err(e),
-                }
+            fn overflowing_from_bytes(
+                bytes: &[u8],
+                options: ParseOptions,
+            ) -> Result<(Self, bool), ParseFixedError> {
+                parse_with_options(bytes, options, |parse, radix| {
+                    $from(parse, radix, Self::INT_NBITS, Self::FRAC_NBITS)
+                })
+                .map(|(bits, overflow)| (Self::from_bits(bits), overflow))
             }
             #[inline]
-            fn saturating_from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err> {
-                match Self::overflowing_from_str_radix(s, radix) {
+            fn saturating_from_bytes(
+                bytes: &[u8],
+                options: ParseOptions,
+            ) -> Result<Self, ParseFixedError> {
+                match <Self as FromStrRadix>::overflowing_from_bytes(bytes, options) {
                     Ok((val, false)) => Ok(val),
                     Ok((_, true)) => {
-                        if s.starts_with('-') {
+                        if options.is_neg(bytes) {
                             Ok(Self::min_value())
                         } else {
                             Ok(Self::max_value())
@@ -684,15 +1367,6 @@ macro_rules! impl_from_str_traits {
                     Err(e) => Err(e),
                 }
             }
-            #[inline]
-            fn wrapping_from_str_radix(s: &str, radix: u32) -> Result<Self, Self::Err> {
-                Self::overflowing_from_str_radix(s, radix).map(|(val, _)| val)
-            }
-            #[inline]
-            fn overflowing_from_str_radix(s: &str, radix: u32) -> Result<(Self, bool), Self::Err> {
-                $from(s.as_bytes(), radix, Self::INT_NBITS, Self::FRAC_NBITS)
-                    .map(|(bits, overflow)| (Self::from_bits(bits), overflow))
-            }
         }
     };
 }
@@ -710,41 +1384,41 @@ macro_rules! impl_from_str {
         impl_from_str_traits! { $FixedU($BitsU), $LeEqU; fn $from_u }
 
         fn $from_i(
-            bytes: &[u8],
+            parse: Parse<'_>,
             radix: u32,
             int_nbits: u32,
             frac_nbits: u32,
-        ) -> Result<($BitsI, bool), ParseFixedError> {
-            let (neg, abs, mut overflow) = $get_int_frac(bytes, radix, int_nbits, frac_nbits)?;
+        ) -> ($BitsI, bool) {
+            let (neg, abs, mut overflow) = $get_int_frac(parse, radix, int_nbits, frac_nbits);
             let max_abs = $BitsU::MSB - if !neg { 1 } else { 0 };
             if abs > max_abs {
                 overflow = true;
             }
             let abs = if neg { abs.wrapping_neg() } else { abs } as $BitsI;
-            Ok((abs, overflow))
+            (abs, overflow)
         }
 
         fn $from_u(
-            bytes: &[u8],
+            parse: Parse<'_>,
             radix: u32,
             int_nbits: u32,
             frac_nbits: u32,
-        ) -> Result<($BitsU, bool), ParseFixedError> {
-            let (neg, abs, mut overflow) = $get_int_frac(bytes, radix, int_nbits, frac_nbits)?;
+        ) -> ($BitsU, bool) {
+            let (neg, abs, mut overflow) = $get_int_frac(parse, radix, int_nbits, frac_nbits);
             if neg && abs > 0 {
                 overflow = true;
             }
             let abs = if neg { abs.wrapping_neg() } else { abs };
-            Ok((abs, overflow))
+            (abs, overflow)
         }
 
         fn $get_int_frac(
-            bytes: &[u8],
+            parse: Parse<'_>,
             radix: u32,
             int_nbits: u32,
             frac_nbits: u32,
-        ) -> Result<(bool, $BitsU, bool), ParseFixedError> {
-            let Parse { neg, int, frac } = parse_bounds(bytes, radix)?;
+        ) -> (bool, $BitsU, bool) {
+            let Parse { neg, int, frac } = parse;
             let (int_val, mut overflow) = $get_int(int, radix, int_nbits);
             let (frac_val, frac_overflow) = match $get_frac(frac, radix, frac_nbits) {
                 Some(val) => (val, false),
@@ -768,7 +1442,7 @@ macro_rules! impl_from_str {
                 }
                 val = new_val;
             }
-            Ok((neg, val, overflow))
+            (neg, val, overflow)
         }
 
         fn $get_int(int: &[u8], radix: u32, nbits: u32) -> ($BitsU, bool) {
@@ -785,7 +1459,8 @@ macro_rules! impl_from_str {
                 2 => bin_str_int_to_bin(int),
                 8 => oct_str_int_to_bin(int),
                 16 => hex_str_int_to_bin(int),
-                _ => dec_str_int_to_bin(int),
+                10 => dec_str_int_to_bin(int),
+                _ => radix_str_int_to_bin(int, radix),
             };
             let remove_bits = <$BitsU as IntHelper>::NBITS - nbits;
             if nbits == 0 {
@@ -812,7 +1487,9 @@ macro_rules! impl_from_str {
                 8 => oct_str_frac_to_bin(frac, nbits),
                 16 => hex_str_frac_to_bin(frac, nbits),
                 10 => dec_str_frac_to_bin(frac, nbits),
-                _ => unreachable!(),
+                _ => {
+                    radix_str_frac_to_bin(frac, radix, nbits).map(|val| val.to_words().1 as $BitsU)
+                }
             }
         }
     };
@@ -859,6 +1536,110 @@ impl_from_str! {
     fn get_frac128, (get_frac64, true);
 }
 
+impl_from_str_traits! { FixedI256(I256), LeEqU256; fn from_str_i256 }
+impl_from_str_traits! { FixedU256(U256), LeEqU256; fn from_str_u256 }
+
+fn from_str_i256(parse: Parse<'_>, radix: u32, int_nbits: u32, frac_nbits: u32) -> (I256, bool) {
+    let (neg, abs, overflow) = get_int_frac256(parse, radix, int_nbits, frac_nbits);
+    let (wrapped, new_overflow) = I256::overflowing_from_neg_abs(neg, abs);
+    (wrapped, overflow || new_overflow)
+}
+
+fn from_str_u256(parse: Parse<'_>, radix: u32, int_nbits: u32, frac_nbits: u32) -> (U256, bool) {
+    let (neg, abs, overflow) = get_int_frac256(parse, radix, int_nbits, frac_nbits);
+    let (wrapped, new_overflow) = U256::overflowing_from_neg_abs(neg, abs);
+    (wrapped, overflow || new_overflow)
+}
+
+// The 256-bit types have no double-width integer for dec_to_bin, so
+// the fractional digits are converted by repeated doubling in the
+// source radix instead: each doubling carries out one binary digit.
+//
+// Keeping FRAC256_MAX_DIGITS = 257 digits is enough for correct
+// rounding in even radices: every multiple of 2^-257 is a multiple of
+// radix^-257, so the truncated tail can only affect the sticky bit.
+// Odd radices do not have this property, so all their digits are
+// used by radix_str_frac_to_bin instead.
+const FRAC256_MAX_DIGITS: usize = 257;
+
+fn get_int_frac256(
+    parse: Parse<'_>,
+    radix: u32,
+    int_nbits: u32,
+    frac_nbits: u32,
+) -> (bool, U256, bool) {
+    let Parse { neg, int, frac } = parse;
+
+    let radix_u256 = U256::from(radix as u8);
+    let mut int_val = U256::ZERO;
+    let mut overflow = false;
+    for &byte in int {
+        if byte == b'_' {
+            continue;
+        }
+        let (mul, mul_overflow) = int_val.overflowing_mul(radix_u256);
+        let (add, add_overflow) = mul.overflowing_add(U256::from(unchecked_digit(byte)));
+        int_val = add;
+        overflow = overflow || mul_overflow || add_overflow;
+    }
+    let mut val = if int_nbits == 0 {
+        overflow = overflow || !int_val.is_zero();
+        U256::ZERO
+    } else {
+        if int_nbits < 256 && !(int_val >> int_nbits).is_zero() {
+            overflow = true;
+        }
+        if frac_nbits == 0 {
+            int_val
+        } else {
+            int_val << frac_nbits
+        }
+    };
+
+    if radix & 1 != 0 {
+        let (val, round_overflow) = match radix_str_frac_to_bin(frac, radix, frac_nbits) {
+            Some(frac_val) => (val | frac_val, false),
+            None if frac_nbits == 256 => (val, true),
+            None => val.overflowing_add(U256::ONE << frac_nbits),
+        };
+        return (neg, val, overflow || round_overflow);
+    }
+
+    let mut digits = [0u8; FRAC256_MAX_DIGITS];
+    let len = frac.len().min(FRAC256_MAX_DIGITS);
+    for (digit, &byte) in digits.iter_mut().zip(frac) {
+        *digit = unchecked_digit(byte);
+    }
+    let digits = &mut digits[..len];
+    let double = |digits: &mut [u8]| -> bool {
+        let mut carry = false;
+        for digit in digits.iter_mut().rev() {
+            let doubled = *digit * 2 + u8::from(carry);
+            carry = u32::from(doubled) >= radix;
+            *digit = if carry {
+                doubled - radix as u8
+            } else {
+                doubled
+            };
+        }
+        carry
+    };
+    let mut frac_val = U256::ZERO;
+    for _ in 0..frac_nbits {
+        frac_val = (frac_val << 1) | U256::from(double(digits));
+    }
+    let half = double(digits);
+    // since zeros are trimmed, any truncated tail is nonzero
+    let sticky = frac.len() > len || digits.iter().any(|&d| d != 0);
+    val = val | frac_val;
+    if half && (sticky || val.is_odd()) {
+        let (rounded, round_overflow) = val.overflowing_add(U256::ONE);
+        val = rounded;
+        overflow = overflow || round_overflow;
+    }
+    (neg, val, overflow)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -874,9 +1655,7 @@ mod tests {
 
     #[test]
     fn overflowing() {
-        let overflow = ParseFixedError {
-            kind: ParseErrorKind::Overflow,
-        };
+        let overflow = ParseFixedError::from(ParseErrorKind::Overflow);
         assert_eq!(
             U4F4::overflowing_from_str("15.5"),
             Ok((U4F4::from_bits(0xF8), false))
@@ -977,6 +1756,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn dec_digits_below_boundary() {
+        // Inputs with more digits than dec_to_bin handles at once but
+        // fewer than the rounding boundary has, such as 0.4277 which is
+        // just below 109.5/256 = 0.42773...
+        fn check(digits: usize, nbits: u32, step: usize) {
+            let pow10 = 10u128.pow(digits as u32);
+            let mut d = 0;
+            while d < pow10 {
+                let s = format!("0.{:01$}", d, digits);
+                let (q, r) = ((d << nbits) / pow10, (d << nbits) % pow10);
+                let up = r * 2 > pow10 || (r * 2 == pow10 && q % 2 == 1);
+                let exact = if up { q + 1 } else { q };
+                let parsed = match nbits {
+                    8 => U0F8::from_str(&s).map(|f| u128::from(f.to_bits())),
+                    16 => U0F16::from_str(&s).map(|f| u128::from(f.to_bits())),
+                    _ => U0F32::from_str(&s).map(|f| u128::from(f.to_bits())),
+                };
+                if exact >> nbits == 0 {
+                    assert_eq!(parsed, Ok(exact), "{}", s);
+                } else {
+                    assert!(parsed.is_err(), "{}", s);
+                }
+                d += step as u128;
+            }
+        }
+        assert_eq!(U8F8::from_str("0.4277"), Ok(U8F8::from_bits(109)));
+        assert_eq!(U8F8::from_str("0.9746"), Ok(U8F8::from_bits(249)));
+        check(4, 8, 1);
+        check(5, 8, 1);
+        check(7, 16, 97);
+        check(14, 32, 999_999_937);
+    }
+
     #[test]
     fn check_dec_16() {
         let two_pow = 16f64.exp2();
@@ -1088,34 +1901,47 @@ mod tests {
 
     #[test]
     fn check_parse_bounds() {
-        let Parse { neg, int, frac } = parse_bounds(b"-12.34", 10).unwrap();
+        let Parse { neg, int, frac } = parse_bounds(b"-12.34", 10, Syntax::STRICT).unwrap();
         assert_eq!((neg, int, frac), (true, &b"12"[..], &b"34"[..]));
-        let Parse { neg, int, frac } = parse_bounds(b"012.", 10).unwrap();
+        let Parse { neg, int, frac } = parse_bounds(b"012.", 10, Syntax::STRICT).unwrap();
         assert_eq!((neg, int, frac), (false, &b"12"[..], &b""[..]));
-        let Parse { neg, int, frac } = parse_bounds(b"+.340", 10).unwrap();
+        let Parse { neg, int, frac } = parse_bounds(b"+.340", 10, Syntax::STRICT).unwrap();
         assert_eq!((neg, int, frac), (false, &b""[..], &b"34"[..]));
-        let Parse { neg, int, frac } = parse_bounds(b"0", 10).unwrap();
+        let Parse { neg, int, frac } = parse_bounds(b"0", 10, Syntax::STRICT).unwrap();
         assert_eq!((neg, int, frac), (false, &b""[..], &b""[..]));
-        let Parse { neg, int, frac } = parse_bounds(b"-.C1A0", 16).unwrap();
+        let Parse { neg, int, frac } = parse_bounds(b"-.C1A0", 16, Syntax::STRICT).unwrap();
         assert_eq!((neg, int, frac), (true, &b""[..], &b"C1A"[..]));
 
-        let ParseFixedError { kind } = parse_bounds(b"0 ", 10).unwrap_err();
-        assert_eq!(kind, ParseErrorKind::InvalidDigit);
-        let ParseFixedError { kind } = parse_bounds(b"+-", 10).unwrap_err();
-        assert_eq!(kind, ParseErrorKind::InvalidDigit);
-        let ParseFixedError { kind } = parse_bounds(b"+.", 10).unwrap_err();
-        assert_eq!(kind, ParseErrorKind::NoDigits);
-        let ParseFixedError { kind } = parse_bounds(b".1.", 10).unwrap_err();
-        assert_eq!(kind, ParseErrorKind::TooManyPoints);
-        let ParseFixedError { kind } = parse_bounds(b"1+2", 10).unwrap_err();
-        assert_eq!(kind, ParseErrorKind::InvalidDigit);
-        let ParseFixedError { kind } = parse_bounds(b"1-2", 10).unwrap_err();
-        assert_eq!(kind, ParseErrorKind::InvalidDigit);
+        let check_err = |bytes: &[u8], kind, position, character| {
+            let err = parse_bounds(bytes, 10, Syntax::STRICT).unwrap_err();
+            assert_eq!(err.kind(), kind);
+            assert_eq!(err.position(), position);
+            assert_eq!(err.character(), character);
+        };
+        check_err(b"0 ", ParseErrorKind::InvalidDigit, Some(1), Some(' '));
+        check_err(b"+-", ParseErrorKind::InvalidDigit, Some(1), Some('-'));
+        check_err(b"+.", ParseErrorKind::NoDigits, None, None);
+        check_err(b".1.", ParseErrorKind::TooManyPoints, Some(2), Some('.'));
+        check_err(b"1+2", ParseErrorKind::InvalidDigit, Some(1), Some('+'));
+        check_err(b"1-2", ParseErrorKind::InvalidDigit, Some(1), Some('-'));
+        check_err(b"1A", ParseErrorKind::InvalidDigit, Some(1), Some('A'));
+        check_err(
+            "12\u{e9}".as_bytes(),
+            ParseErrorKind::InvalidDigit,
+            Some(2),
+            Some('\u{e9}'),
+        );
+        check_err(
+            b"1\xff",
+            ParseErrorKind::InvalidDigit,
+            Some(1),
+            Some(REPLACEMENT_CHARACTER),
+        );
     }
 
     fn assert_ok<F>(s: &str, radix: u32, bits: F::Bits, overflow: bool)
     where
-        F: Fixed + FromStrRadix<Err = ParseFixedError>,
+        F: Fixed,
         F::Bits: Eq + Debug,
     {
         match F::overflowing_from_str_radix(s, radix) {
@@ -1979,6 +2805,570 @@ mod tests {
         }
     }
 
+    fn radix_reference(int: u128, frac: &[u8], radix: u32, nbits: u32) -> u128 {
+        let (mut num, mut den) = (0u128, 1u128);
+        for &digit in frac {
+            num = num * u128::from(radix) + u128::from(digit);
+            den *= u128::from(radix);
+        }
+        let scaled = num << nbits;
+        let (mut q, rem) = (scaled / den, scaled % den);
+        if 2 * rem > den || (2 * rem == den && q % 2 != 0) {
+            q += 1;
+        }
+        (int << nbits) + q
+    }
+
+    fn radix_string(mut int: u128, frac: &[u8], radix: u32) -> String {
+        let to_char = |digit: u8| std::char::from_digit(u32::from(digit), radix).unwrap();
+        let mut int_digits = String::new();
+        loop {
+            int_digits.insert(0, to_char((int % u128::from(radix)) as u8));
+            int /= u128::from(radix);
+            if int == 0 {
+                break;
+            }
+        }
+        let frac_digits = frac.iter().map(|&d| to_char(d)).collect::<String>();
+        format!("{}.{}", int_digits, frac_digits.to_uppercase())
+    }
+
+    #[test]
+    fn check_radix() {
+        use crate::{int256::U256, FixedU256};
+        let mut seed = 0x1234_5678u32;
+        let mut next = |bound: u32| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 8) % bound
+        };
+        for radix in 2..=36 {
+            for _ in 0..50 {
+                let int = u128::from(next(100));
+                let len = 1 + next(5) as usize;
+                let mut frac = [0u8; 5];
+                for digit in frac.iter_mut().take(len) {
+                    *digit = next(radix) as u8;
+                }
+                let frac = &frac[..len];
+                let s = radix_string(int, frac, radix);
+
+                let bits = radix_reference(int, frac, radix, 8);
+                let parsed = FixedU16::<extra::U8>::from_str_radix(&s, radix).unwrap();
+                assert_eq!(u128::from(parsed.to_bits()), bits, "{} radix {}", s, radix);
+                let bits = radix_reference(int, frac, radix, 16);
+                let parsed = FixedU32::<extra::U16>::from_str_radix(&s, radix).unwrap();
+                assert_eq!(u128::from(parsed.to_bits()), bits, "{} radix {}", s, radix);
+                let bits = radix_reference(int, frac, radix, 32);
+                let parsed = FixedI64::<extra::U32>::from_str_radix(&s, radix).unwrap();
+                assert_eq!(parsed.to_bits() as u128, bits, "{} radix {}", s, radix);
+                let bits = radix_reference(int, frac, radix, 64);
+                let parsed = FixedU128::<extra::U64>::from_str_radix(&s, radix).unwrap();
+                assert_eq!(parsed.to_bits(), bits, "{} radix {}", s, radix);
+                let parsed = FixedU256::<extra::U64>::from_str_radix(&s, radix).unwrap();
+                assert_eq!(
+                    parsed.to_bits(),
+                    U256::from_words(0, bits),
+                    "{} radix {}",
+                    s,
+                    radix
+                );
+                let neg = FixedI128::<extra::U64>::from_str_radix(&format!("-{}", s), radix);
+                assert_eq!(
+                    neg.unwrap().to_bits(),
+                    -(bits as i128),
+                    "{} radix {}",
+                    s,
+                    radix
+                );
+            }
+        }
+
+        // ties are only possible in even radices
+        assert_eq!(U8F0::from_str_radix("1.3", 6), Ok(U8F0::from_num(2)));
+        assert_eq!(U8F0::from_str_radix("2.3", 6), Ok(U8F0::from_num(2)));
+        assert_eq!(U8F0::from_str_radix("2.3000001", 6), Ok(U8F0::from_num(3)));
+        assert_eq!(U8F0::from_str_radix("1.1111111", 3), Ok(U8F0::from_num(1)));
+        assert_eq!(U8F0::from_str_radix("1.1111112", 3), Ok(U8F0::from_num(2)));
+
+        // 0.222… in base 3 tends to 1
+        let almost_one = format!("0.{:2<200}", "");
+        assert_eq!(U8F8::from_str_radix(&almost_one, 3), Ok(U8F8::from_num(1)));
+        assert_eq!(
+            U0F8::overflowing_from_str_radix(&almost_one, 3),
+            Ok((U0F8::from_num(0), true))
+        );
+        assert_eq!(
+            FixedU256::<extra::U256>::overflowing_from_str_radix(&almost_one, 3),
+            Ok((FixedU256::from_bits(U256::ZERO), true))
+        );
+        assert_eq!(
+            FixedU256::<extra::U255>::from_str_radix(&almost_one, 3),
+            Ok(FixedU256::from_bits(U256::from_words(0, 1) << 255))
+        );
+        let third = FixedU256::<extra::U256>::from_str_radix("0.1", 3);
+        let third_bits = U256::from_words(!0 / 3, !0 / 3);
+        assert_eq!(third, Ok(FixedU256::from_bits(third_bits)));
+        // 0.111… in base 3 tends to one half from below
+        let almost_half = format!("0.{:1<200}", "");
+        let half = FixedU256::<extra::U256>::from_str_radix(&almost_half, 3);
+        assert_eq!(half, Ok(FixedU256::from_bits(U256::MSB)));
+        let half = U0F128::from_str_radix(&almost_half, 3);
+        assert_eq!(half, Ok(U0F128::from_bits(1 << 127)));
+
+        // digits must be below the radix, letters can have any case
+        assert_eq!(U8F8::from_str_radix("z.I", 36), Ok(U8F8::from_num(35.5)));
+        assert_eq!(U8F8::from_str_radix("Z.i", 36), Ok(U8F8::from_num(35.5)));
+        let err = U8F8::from_str_radix("1.5", 5).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidDigit);
+        assert_eq!((err.position(), err.character()), (Some(2), Some('5')));
+        // there are no exponents except in radix 10 and 16
+        assert_eq!(U16F16::from_str_radix("1e1", 15), Ok(U16F16::from_num(436)));
+        assert_eq!(
+            U16F16::from_str_radix("1p1", 26),
+            Ok(U16F16::from_num(1327))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "radix 37 is not in the range 2 to 36 inclusive")]
+    fn check_radix_out_of_range() {
+        let _ = U8F8::from_str_radix("1", 37);
+    }
+
+    #[test]
+    fn from_ascii() {
+        use crate::{types::extra::U100, FixedI256};
+
+        for &s in &["1.5", "-0.25", "+12e-1", "99999", "-", "1.2.3", "1x"] {
+            let b = s.as_bytes();
+            assert_eq!(I16F16::from_ascii(b), I16F16::from_str(s));
+            assert_eq!(I8F8::saturating_from_ascii(b), I8F8::saturating_from_str(s));
+            assert_eq!(U8F8::wrapping_from_ascii(b), U8F8::wrapping_from_str(s));
+            assert_eq!(
+                I8F8::overflowing_from_ascii(b),
+                I8F8::overflowing_from_str(s)
+            );
+            assert_eq!(
+                FixedI256::<U100>::overflowing_from_ascii(b),
+                FixedI256::<U100>::overflowing_from_str(s)
+            );
+            for &radix in &[3, 16, 36] {
+                assert_eq!(
+                    I16F16::from_ascii_radix(b, radix),
+                    I16F16::from_str_radix(s, radix)
+                );
+                assert_eq!(
+                    U8F8::saturating_from_ascii_radix(b, radix),
+                    U8F8::saturating_from_str_radix(s, radix)
+                );
+                assert_eq!(
+                    I8F8::wrapping_from_ascii_radix(b, radix),
+                    I8F8::wrapping_from_str_radix(s, radix)
+                );
+                assert_eq!(
+                    FixedI256::<U100>::overflowing_from_ascii_radix(b, radix),
+                    FixedI256::<U100>::overflowing_from_str_radix(s, radix)
+                );
+            }
+        }
+
+        // bytes that are not valid UTF-8 are reported as U+FFFD
+        let err = I16F16::from_ascii(b"12.\xc3").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidDigit);
+        assert_eq!(err.position(), Some(3));
+        assert_eq!(err.character(), Some(REPLACEMENT_CHARACTER));
+        let err = I16F16::from_ascii_radix(b"\x80", 16).unwrap_err();
+        assert_eq!(err.position(), Some(0));
+        assert_eq!(err.character(), Some(REPLACEMENT_CHARACTER));
+    }
+
+    #[test]
+    fn parse_options() {
+        use crate::{
+            types::extra::{U128, U200},
+            FixedI256, FixedU256,
+        };
+
+        let check_err = |s: &str, options, kind, position, character| {
+            let err = I16F16::from_str_with(s, options).unwrap_err();
+            assert_eq!(err.kind(), kind);
+            assert_eq!(err.position(), position);
+            assert_eq!(err.character(), character);
+        };
+
+        // the default options are as strict as FromStr
+        let strict = ParseOptions::default();
+        assert_eq!(strict, ParseOptions::new());
+        assert_eq!(
+            I16F16::from_str_with("+1.5", strict),
+            Ok(I16F16::from_num(1.5))
+        );
+        check_err(
+            "1_0",
+            strict,
+            ParseErrorKind::InvalidDigit,
+            Some(1),
+            Some('_'),
+        );
+        check_err(
+            " 1",
+            strict,
+            ParseErrorKind::InvalidDigit,
+            Some(0),
+            Some(' '),
+        );
+        check_err(
+            "0x1",
+            strict,
+            ParseErrorKind::InvalidDigit,
+            Some(1),
+            Some('x'),
+        );
+
+        let under = ParseOptions::new().underscores(true);
+        assert_eq!(
+            I32F32::from_str_with("1_000_000.000_001", under),
+            I32F32::from_str("1000000.000001")
+        );
+        assert_eq!(
+            I16F16::from_str_with("-_1__.__5_", under),
+            Ok(I16F16::from_num(-1.5))
+        );
+        assert_eq!(
+            I16F16::from_str_with("1_2.5e-_1", under),
+            Ok(I16F16::from_num(1.25))
+        );
+        assert_eq!(
+            I16F16::from_str_with("1_f.8", under.radix(16)),
+            Ok(I16F16::from_num(31.5))
+        );
+        check_err("_", under, ParseErrorKind::NoDigits, None, None);
+        check_err(
+            "_-1",
+            under,
+            ParseErrorKind::InvalidDigit,
+            Some(1),
+            Some('-'),
+        );
+        check_err(
+            "1e_",
+            under,
+            ParseErrorKind::InvalidDigit,
+            Some(2),
+            Some('_'),
+        );
+
+        let prefix = ParseOptions::new().prefix(true);
+        assert_eq!(
+            I16F16::from_str_with("0x1F.8", prefix),
+            Ok(I16F16::from_num(31.5))
+        );
+        assert_eq!(
+            I16F16::from_str_with("+0X1f.8", prefix),
+            Ok(I16F16::from_num(31.5))
+        );
+        assert_eq!(
+            I16F16::from_str_with("-0b1.1", prefix),
+            Ok(I16F16::from_num(-1.5))
+        );
+        assert_eq!(
+            I16F16::from_str_with("0o17", prefix),
+            Ok(I16F16::from_num(15))
+        );
+        assert_eq!(
+            I16F16::from_str_with("0x1p4", prefix),
+            Ok(I16F16::from_num(16))
+        );
+        assert_eq!(
+            I16F16::from_str_with("017", prefix),
+            Ok(I16F16::from_num(17))
+        );
+        assert_eq!(
+            I16F16::from_str_with("0.5", prefix),
+            Ok(I16F16::from_num(0.5))
+        );
+        assert_eq!(I16F16::from_str_with("-0", prefix), Ok(I16F16::from_num(0)));
+        check_err(
+            "0!",
+            prefix,
+            ParseErrorKind::InvalidDigit,
+            Some(1),
+            Some('!'),
+        );
+        let hex = prefix.radix(16);
+        assert_eq!(
+            I16F16::from_str_with("0b10", hex),
+            Ok(I16F16::from_num(0xB10))
+        );
+        assert_eq!(I16F16::from_str_with("0x10", hex), Ok(I16F16::from_num(16)));
+        assert_eq!(I16F16::from_str_with("10", hex), Ok(I16F16::from_num(16)));
+        let base36 = prefix.radix(36);
+        assert_eq!(
+            I16F16::from_str_with("0x", base36),
+            Ok(I16F16::from_num(33))
+        );
+        check_err("0x", prefix, ParseErrorKind::NoDigits, None, None);
+        check_err(
+            "0x-1",
+            prefix,
+            ParseErrorKind::InvalidDigit,
+            Some(2),
+            Some('-'),
+        );
+        check_err(
+            "-0x1G",
+            prefix,
+            ParseErrorKind::InvalidDigit,
+            Some(4),
+            Some('G'),
+        );
+        check_err(
+            "0b12",
+            prefix,
+            ParseErrorKind::InvalidDigit,
+            Some(3),
+            Some('2'),
+        );
+        check_err(
+            "0x_1",
+            prefix,
+            ParseErrorKind::InvalidDigit,
+            Some(2),
+            Some('_'),
+        );
+
+        let space = ParseOptions::new().trim_whitespace(true);
+        assert_eq!(
+            I16F16::from_str_with(" \t1.5\n ", space),
+            Ok(I16F16::from_num(1.5))
+        );
+        check_err(
+            "  1 5 ",
+            space,
+            ParseErrorKind::InvalidDigit,
+            Some(3),
+            Some(' '),
+        );
+        check_err(" \n", space, ParseErrorKind::NoDigits, None, None);
+
+        let all = prefix.underscores(true).trim_whitespace(true);
+        assert_eq!(
+            I16F16::from_str_with(" +0x1F.8 ", all),
+            Ok(I16F16::from_num(31.5))
+        );
+        assert_eq!(
+            I16F16::from_str_with(" 0x_1_F.8_ ", all),
+            Ok(I16F16::from_num(31.5))
+        );
+        check_err(
+            " -0x1_G ",
+            all,
+            ParseErrorKind::InvalidDigit,
+            Some(6),
+            Some('G'),
+        );
+
+        // saturation uses the sign before the prefix
+        assert_eq!(
+            I8F8::saturating_from_str_with(" -0x1000", all),
+            Ok(I8F8::min_value())
+        );
+        assert_eq!(
+            I8F8::saturating_from_str_with(" 0x1000", all),
+            Ok(I8F8::max_value())
+        );
+        assert_eq!(
+            U8F8::saturating_from_str_with("-0b1", all),
+            Ok(U8F8::min_value())
+        );
+        assert_eq!(
+            I8F8::overflowing_from_str_with("-0x1_00.8", all),
+            Ok((I8F8::from_bits(-0x80), true))
+        );
+        assert_eq!(
+            U8F8::wrapping_from_str_with("0x1_0F.8", all),
+            Ok(U8F8::from_bits(0x0F80))
+        );
+
+        // odd radices skip underscores during conversion
+        let base3 = under.radix(3);
+        for &s in &["1_0.1", "-2_2_1.0_1_2", "_0._1111_1111_1111_1111_1111"] {
+            let stripped = s.replace('_', "");
+            assert_eq!(
+                I16F16::overflowing_from_str_with(s, base3),
+                I16F16::overflowing_from_str_radix(&stripped, 3)
+            );
+            assert_eq!(
+                FixedI256::<U128>::overflowing_from_str_with(s, base3),
+                FixedI256::<U128>::overflowing_from_str_radix(&stripped, 3)
+            );
+        }
+
+        // long inputs in even radices are rewritten without underscores
+        let mut long_int = String::new();
+        let mut long_frac = String::new();
+        for i in 0..300 {
+            long_int.push(char::from(b'1' + (i % 9) as u8));
+            long_frac.push(char::from(b'9' - (i % 7) as u8));
+            if i % 3 == 2 {
+                long_int.push('_');
+                long_frac.push('_');
+            }
+        }
+        for &radix in &[2, 6, 10, 16] {
+            let options = under.radix(radix);
+            let digits = |s: &str| -> String {
+                s.chars()
+                    .map(|c| match c {
+                        '_' => c,
+                        _ => {
+                            core::char::from_digit(c.to_digit(10).unwrap() % radix, radix).unwrap()
+                        }
+                    })
+                    .collect()
+            };
+            for s in &[
+                digits(&long_int),
+                format!("0.{}", digits(&long_frac)),
+                format!("-{}.{}", digits(&long_int), digits(&long_frac)),
+            ] {
+                let stripped = s.replace('_', "");
+                assert_eq!(
+                    I32F32::overflowing_from_str_with(s, options),
+                    I32F32::overflowing_from_str_radix(&stripped, radix)
+                );
+                assert_eq!(
+                    FixedU256::<U200>::overflowing_from_str_with(s, options),
+                    FixedU256::<U200>::overflowing_from_str_radix(&stripped, radix)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn exponents() {
+        use crate::{
+            int256::U256,
+            types::extra::{U128, U200},
+            FixedI256, FixedU256,
+        };
+
+        let overflow = ParseFixedError::from(ParseErrorKind::Overflow);
+
+        for &(exp, plain) in &[
+            ("1.5e-3", "0.0015"),
+            ("2.5E-6", "0.0000025"),
+            ("-12.5e+2", "-1250"),
+            ("0.000125e7", "1250"),
+            ("125e-2", "1.25"),
+            ("0e99", "0"),
+            ("-1e-99999999999999999999", "0"),
+        ] {
+            assert_eq!(I32F32::from_str(exp), I32F32::from_str(plain), "{}", exp);
+            assert_eq!(I64F64::from_str(exp), I64F64::from_str(plain), "{}", exp);
+            assert_eq!(
+                FixedI256::<U128>::from_str(exp),
+                FixedI256::<U128>::from_str(plain),
+                "{}",
+                exp
+            );
+        }
+        assert_eq!(I32F32::from_str("1.5e-3"), Ok(I32F32::from_num(0.0015)));
+
+        // ties are still rounded to even, and a long tail is sticky
+        assert_ok::<U8F0>("25e-1", 10, 2, false);
+        assert_ok::<U8F0>("35e-1", 10, 4, false);
+        assert_ok::<U0F8>("1953125e-9", 10, 0, false);
+        assert_ok::<U0F8>("5859375e-9", 10, 2, false);
+        let sticky = format!("1953125{}1e-{}", "0".repeat(400), 410);
+        assert_ok::<U0F8>(&sticky, 10, 1, false);
+        let tiny = format!("0.{}1e300", "0".repeat(600));
+        assert_ok::<U0F8>(&tiny, 10, 0, false);
+
+        // wrapping keeps the low bits even for huge exponents
+        assert_ok::<U8F0>("1e3", 10, 232, true);
+        assert_ok::<U8F0>("1e1000", 10, 0, true);
+        assert_ok::<I64F64>("-1e99999", 10, 0, true);
+        let long_neg = format!("-{}.{}1e0", "1".repeat(257), "0".repeat(300));
+        assert_eq!(I64F64::from_str(&long_neg), Err(overflow));
+        assert!(I64F64::overflowing_from_str(&long_neg).unwrap().1);
+        assert_eq!(FixedI256::<U128>::from_str(&long_neg), Err(overflow));
+        assert!(
+            FixedI256::<U128>::overflowing_from_str(&long_neg)
+                .unwrap()
+                .1
+        );
+        assert_eq!(U8F8::from_str("1e3"), Err(overflow));
+        assert_eq!(U8F8::saturating_from_str("1e3"), Ok(U8F8::max_value()));
+        assert_eq!(I8F8::saturating_from_str("-1e3"), Ok(I8F8::min_value()));
+        assert_eq!(U8F8::wrapping_from_str("1e3"), Ok(U8F8::from_bits(0xe800)));
+        assert_eq!(
+            U8F8::overflowing_from_str("2.5e2"),
+            Ok((U8F8::from_num(250), false))
+        );
+
+        // binary exponents for hexadecimal
+        assert_ok::<U8F8>("1.8p4", 16, 24 << 8, false);
+        assert_ok::<U8F8>("1.Cp1", 16, 0x380, false);
+        assert_ok::<U8F8>("C.8P-3", 16, 0x190, false);
+        assert_ok::<U0F8>("1p-9", 16, 0, false);
+        assert_ok::<U0F8>("3p-9", 16, 2, false);
+        assert_ok::<I8F8>("-1p7", 16, -0x8000, false);
+        assert_ok::<I8F8>("1p7", 16, -0x8000, true);
+        assert_ok::<U64F64>("abcdefp-24", 16, 0xabcdef << 40, false);
+        assert_ok::<U8F8>("1p300", 16, 0, true);
+        assert_eq!(U8F8::from_str_hex("1.8p4"), U8F8::from_str_hex("18"));
+        assert_eq!(U8F8::saturating_from_str_hex("1p9"), Ok(U8F8::max_value()));
+        assert_eq!(
+            FixedU256::<U200>::from_str_hex("1p-200"),
+            Ok(FixedU256::<U200>::from_bits(U256::ONE))
+        );
+        // e is a hexadecimal digit, not an exponent
+        assert_eq!(U8F8::from_str_hex("1e"), Ok(U8F8::from_num(30)));
+        assert_eq!(U16F16::from_str_hex("1e1"), Ok(U16F16::from_num(481)));
+
+        let err = |res: Result<U8F8, ParseFixedError>| {
+            let err = res.unwrap_err();
+            (err.kind(), err.position(), err.character())
+        };
+        let invalid = ParseErrorKind::InvalidDigit;
+        assert_eq!(err(U8F8::from_str("1e")), (invalid, Some(1), Some('e')));
+        assert_eq!(err(U8F8::from_str("1e+")), (invalid, Some(2), Some('+')));
+        assert_eq!(err(U8F8::from_str("1e+-5")), (invalid, Some(3), Some('-')));
+        assert_eq!(err(U8F8::from_str("1e1.5")), (invalid, Some(3), Some('.')));
+        assert_eq!(err(U8F8::from_str("1e1e1")), (invalid, Some(3), Some('e')));
+        assert_eq!(err(U8F8::from_str("1.x5e1")), (invalid, Some(2), Some('x')));
+        assert_eq!(
+            err(U8F8::from_str("e5")),
+            (ParseErrorKind::NoDigits, None, None)
+        );
+        assert_eq!(err(U8F8::from_str("1p4")), (invalid, Some(1), Some('p')));
+        assert_eq!(err(U8F8::from_str_hex("1p")), (invalid, Some(1), Some('p')));
+        assert_eq!(
+            err(U8F8::from_str_binary("1e1")),
+            (invalid, Some(1), Some('e'))
+        );
+        assert_eq!(
+            err(U8F8::from_str_octal("1p1")),
+            (invalid, Some(1), Some('p'))
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        let err = U8F8::from_str("1x").unwrap_err();
+        assert_eq!(err.to_string(), "invalid digit found in string at byte 1");
+        let err = U8F8::from_str("1.2.3").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "more than one decimal point found in string at byte 3"
+        );
+        let err = U8F8::from_str("-").unwrap_err();
+        assert_eq!(err.to_string(), "string has no digits");
+        let err = U8F8::from_str("256").unwrap_err();
+        assert_eq!(err.to_string(), "overflow");
+    }
+
     fn similar<F: Fixed, G: ToFixed>(a: F, b: F, max_diff: G) -> bool {
         let abs_diff = if a > b { a - b } else { b - a };
         abs_diff <= max_diff.to_fixed::<F>()
@@ -15,6 +15,7 @@
 
 pub use crate::{float_helper::FloatHelper, int_helper::IntHelper};
 use crate::{
+    int256::U256,
     types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
     FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
     FixedU8,
@@ -39,9 +40,6 @@ pub struct ToFloatHelper {
     pub(crate) abs: u128,
 }
 
-pub struct FromFloatHelper {
-    pub(crate) kind: FloatKind,
-}
 pub enum FloatKind {
     NaN,
     Infinite { neg: bool },
@@ -50,9 +48,11 @@ pub enum FloatKind {
 
 pub trait Sealed: Copy {
     fn private_to_fixed_helper(self, dst_frac_nbits: u32, dst_int_nbits: u32) -> ToFixedHelper;
-    fn private_to_float_helper(self) -> ToFloatHelper;
-    fn private_saturating_from_float_helper(src: FromFloatHelper) -> Self;
-    fn private_overflowing_from_float_helper(src: FromFloatHelper) -> (Self, bool);
+    fn private_neg_abs(self) -> (bool, U256);
+    fn private_overflowing_from_neg_abs(neg: bool, abs: U256) -> (Self, bool);
+    fn private_to_float<Float: FloatHelper>(self) -> Float;
+    fn private_saturating_from_float<Float: FloatHelper>(src: Float) -> Self;
+    fn private_overflowing_from_float<Float: FloatHelper>(src: Float) -> (Self, bool);
 }
 macro_rules! impl_sealed {
     ($Fixed:ident($LeEqU:ident, $Signedness:tt)) => {
@@ -70,14 +70,35 @@ macro_rules! impl_sealed {
                 )
             }
             #[inline]
-            fn private_to_float_helper(self) -> ToFloatHelper {
+            fn private_neg_abs(self) -> (bool, U256) {
+                let (neg, abs) = self.to_bits().neg_abs();
+                (neg, U256::from(abs))
+            }
+            #[inline]
+            fn private_overflowing_from_neg_abs(neg: bool, abs: U256) -> (Self, bool) {
+                let (hi, lo) = abs.to_words();
+                let max_abs = if_signed_unsigned! {
+                    $Signedness,
+                    Self::max_value().to_bits() as u128 + if neg { 1 } else { 0 },
+                    if neg { 0 } else { Self::max_value().to_bits() as u128 },
+                };
+                let bits = if neg { lo.wrapping_neg() } else { lo };
+                (Self::from_bits(bits as _), hi != 0 || lo > max_abs)
+            }
+            #[inline]
+            fn private_to_float<Float: FloatHelper>(self) -> Float {
                 let (neg, abs) = self.to_bits().neg_abs();
                 let abs = abs.into();
-                ToFloatHelper { neg, abs }
+                Float::from_to_float_helper(
+                    ToFloatHelper { neg, abs },
+                    Self::FRAC_NBITS,
+                    Self::INT_NBITS,
+                )
             }
             #[inline]
-            fn private_saturating_from_float_helper(src: FromFloatHelper) -> Self {
-                let neg = match src.kind {
+            fn private_saturating_from_float<Float: FloatHelper>(src: Float) -> Self {
+                let kind = src.to_float_kind(Self::FRAC_NBITS, Self::INT_NBITS);
+                let neg = match kind {
                     FloatKind::NaN => panic!("NaN"),
                     FloatKind::Infinite { neg } => neg,
                     FloatKind::Finite { neg, .. } => neg,
@@ -87,7 +108,7 @@ macro_rules! impl_sealed {
                 } else {
                     Self::max_value()
                 };
-                let conv = match src.kind {
+                let conv = match kind {
                     FloatKind::Finite { conv, .. } => conv,
                     _ => return saturated,
                 };
@@ -114,8 +135,8 @@ macro_rules! impl_sealed {
                 Self::from_bits(bits)
             }
             #[inline]
-            fn private_overflowing_from_float_helper(src: FromFloatHelper) -> (Self, bool) {
-                let conv = match src.kind {
+            fn private_overflowing_from_float<Float: FloatHelper>(src: Float) -> (Self, bool) {
+                let conv = match src.to_float_kind(Self::FRAC_NBITS, Self::INT_NBITS) {
                     FloatKind::NaN => panic!("NaN"),
                     FloatKind::Infinite { .. } => panic!("infinite"),
                     FloatKind::Finite { conv, .. } => conv,
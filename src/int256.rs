@@ -0,0 +1,829 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+/*!
+256-bit integers used as the underlying representation of
+[`FixedI256`] and [`FixedU256`].
+
+The integers are stored as two 128-bit words. Only the operations
+needed by the fixed-point types are provided; arithmetic should be
+done on the fixed-point types themselves.
+
+# Examples
+
+```rust
+use substrate_fixed::int256::{I256, U256};
+let u = U256::from_words(1, 2);
+assert_eq!(u.to_words(), (1, 2));
+assert_eq!(u.to_string(), "340282366920938463463374607431768211458");
+let i = I256::from(-5i128);
+assert_eq!(i.to_words(), (-1, !4));
+assert_eq!(i.to_string(), "-5");
+```
+
+[`FixedI256`]: ../struct.FixedI256.html
+[`FixedU256`]: ../struct.FixedU256.html
+*/
+
+use crate::wide_div::WideDivRem;
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr},
+    str,
+};
+
+/// A 256-bit unsigned integer stored as two 128-bit words.
+///
+/// With the `codec` feature, the words are encoded least significant
+/// first, so that the [SCALE] encoding is the 32-byte little-endian
+/// representation. With the `serde` feature, the number is serialized
+/// as a tuple of the low word and the high word.
+///
+/// [SCALE]: https://docs.rs/parity-scale-codec
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct U256 {
+    lo: u128,
+    hi: u128,
+}
+
+/// A 256-bit signed integer stored as two 128-bit words in two’s
+/// complement.
+///
+/// With the `codec` feature, the words are encoded least significant
+/// first, so that the [SCALE] encoding is the 32-byte little-endian
+/// representation. With the `serde` feature, the number is serialized
+/// as a tuple of the low word and the high word.
+///
+/// [SCALE]: https://docs.rs/parity-scale-codec
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct I256 {
+    lo: u128,
+    hi: i128,
+}
+
+impl U256 {
+    pub(crate) const ZERO: U256 = U256 { lo: 0, hi: 0 };
+    pub(crate) const ONE: U256 = U256 { lo: 1, hi: 0 };
+    pub(crate) const MSB: U256 = U256 {
+        lo: 0,
+        hi: 1 << 127,
+    };
+
+    /// Creates an integer from its most significant and least
+    /// significant words.
+    #[inline]
+    pub const fn from_words(hi: u128, lo: u128) -> U256 {
+        U256 { lo, hi }
+    }
+
+    /// Returns the most significant and least significant words.
+    #[inline]
+    pub const fn to_words(self) -> (u128, u128) {
+        (self.hi, self.lo)
+    }
+
+    /// Returns the smallest value that can be represented, zero.
+    #[inline]
+    pub const fn min_value() -> U256 {
+        U256 { lo: 0, hi: 0 }
+    }
+
+    /// Returns the largest value that can be represented,
+    /// 2<sup>256</sup> − 1.
+    #[inline]
+    pub const fn max_value() -> U256 {
+        U256 { lo: !0, hi: !0 }
+    }
+
+    #[inline]
+    pub(crate) fn is_zero(self) -> bool {
+        self.lo == 0 && self.hi == 0
+    }
+
+    #[inline]
+    pub(crate) fn is_negative(self) -> bool {
+        false
+    }
+
+    #[inline]
+    pub(crate) fn is_odd(self) -> bool {
+        self.lo & 1 != 0
+    }
+
+    #[inline]
+    pub(crate) fn lower_byte(self) -> u8 {
+        self.lo as u8
+    }
+
+    #[inline]
+    pub(crate) fn leading_zeros(self) -> u32 {
+        if self.hi == 0 {
+            128 + self.lo.leading_zeros()
+        } else {
+            self.hi.leading_zeros()
+        }
+    }
+
+    #[inline]
+    pub(crate) fn trailing_zeros(self) -> u32 {
+        if self.lo == 0 {
+            128 + self.hi.trailing_zeros()
+        } else {
+            self.lo.trailing_zeros()
+        }
+    }
+
+    #[inline]
+    pub(crate) fn count_ones(self) -> u32 {
+        self.lo.count_ones() + self.hi.count_ones()
+    }
+
+    #[inline]
+    pub(crate) fn rotate_left(self, n: u32) -> U256 {
+        let n = n % 256;
+        if n == 0 {
+            self
+        } else {
+            (self << n) | (self >> (256 - n))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn rotate_right(self, n: u32) -> U256 {
+        self.rotate_left(256 - n % 256)
+    }
+
+    #[inline]
+    pub(crate) fn neg_abs(self) -> (bool, U256) {
+        (false, self)
+    }
+
+    // Returns the wrapped value and whether the value is out of range.
+    #[inline]
+    pub(crate) fn overflowing_from_neg_abs(neg: bool, abs: U256) -> (U256, bool) {
+        if neg {
+            (abs.wrapping_neg(), !abs.is_zero())
+        } else {
+            (abs, false)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn overflowing_neg(self) -> (U256, bool) {
+        (self.wrapping_neg(), !self.is_zero())
+    }
+
+    #[inline]
+    pub(crate) fn overflowing_add(self, rhs: U256) -> (U256, bool) {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+        let (hi, overflow1) = self.hi.overflowing_add(rhs.hi);
+        let (hi, overflow2) = hi.overflowing_add(u128::from(carry));
+        (U256 { lo, hi }, overflow1 || overflow2)
+    }
+
+    #[inline]
+    pub(crate) fn wrapping_add(self, rhs: U256) -> U256 {
+        self.overflowing_add(rhs).0
+    }
+
+    #[inline]
+    pub(crate) fn overflowing_sub(self, rhs: U256) -> (U256, bool) {
+        let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+        let (hi, overflow1) = self.hi.overflowing_sub(rhs.hi);
+        let (hi, overflow2) = hi.overflowing_sub(u128::from(borrow));
+        (U256 { lo, hi }, overflow1 || overflow2)
+    }
+
+    #[inline]
+    pub(crate) fn wrapping_sub(self, rhs: U256) -> U256 {
+        self.overflowing_sub(rhs).0
+    }
+
+    #[inline]
+    pub(crate) fn wrapping_neg(self) -> U256 {
+        U256::ZERO.wrapping_sub(self)
+    }
+
+    // Full 512-bit product as (hi, lo).
+    #[inline]
+    pub(crate) fn mul_wide(self, rhs: U256) -> (U256, U256) {
+        let (ll_hi, ll_lo) = mul_u128(self.lo, rhs.lo);
+        let (lh_hi, lh_lo) = mul_u128(self.lo, rhs.hi);
+        let (hl_hi, hl_lo) = mul_u128(self.hi, rhs.lo);
+        let (hh_hi, hh_lo) = mul_u128(self.hi, rhs.hi);
+        // column 1: ll_hi + lh_lo + hl_lo
+        let (col1, c1) = ll_hi.overflowing_add(lh_lo);
+        let (col1, c2) = col1.overflowing_add(hl_lo);
+        let carry1 = u128::from(c1) + u128::from(c2);
+        // column 2: lh_hi + hl_hi + hh_lo + carry1
+        let (col2, c1) = lh_hi.overflowing_add(hl_hi);
+        let (col2, c2) = col2.overflowing_add(hh_lo);
+        let (col2, c3) = col2.overflowing_add(carry1);
+        let carry2 = u128::from(c1) + u128::from(c2) + u128::from(c3);
+        // column 3 cannot overflow as the product fits in 512 bits
+        let col3 = hh_hi + carry2;
+        (
+            U256 { lo: col2, hi: col3 },
+            U256 {
+                lo: ll_lo,
+                hi: col1,
+            },
+        )
+    }
+
+    #[inline]
+    pub(crate) fn wrapping_mul(self, rhs: U256) -> U256 {
+        self.mul_wide(rhs).1
+    }
+
+    #[inline]
+    pub(crate) fn overflowing_mul(self, rhs: U256) -> (U256, bool) {
+        let (hi, lo) = self.mul_wide(rhs);
+        (lo, !hi.is_zero())
+    }
+
+    // Returns the quotient and remainder of division by a 128-bit divisor.
+    #[inline]
+    pub(crate) fn div_rem_u128(self, divisor: u128) -> (U256, u128) {
+        let ((hi, lo), rem) = divisor.div_rem_from((self.hi, self.lo));
+        (U256 { lo, hi }, rem)
+    }
+
+    // Returns the 512-bit (hi, lo) divided by divisor as a 512-bit
+    // (hi, lo) quotient and the remainder.
+    #[inline]
+    pub(crate) fn div_rem_wide(hi: U256, lo: U256, divisor: U256) -> ((U256, U256), U256) {
+        divisor.div_rem_from((hi, lo))
+    }
+
+    // Multiplies by 10 and returns the digit that overflowed.
+    #[inline]
+    pub(crate) fn mul10_assign(&mut self) -> u8 {
+        let (hi, lo) = self.mul_wide(U256::from(10u8));
+        *self = lo;
+        hi.lo as u8
+    }
+
+    fn fmt_dec(self, neg: bool, f: &mut Formatter) -> FmtResult {
+        // 10^38 < 2^128, and 2^256 < 10^78 needs at most three chunks
+        const CHUNK: u128 = 100_000_000_000_000_000_000_000_000_000_000_000_000;
+        let mut buf = [b'0'; 114];
+        let mut val = self;
+        let mut end = buf.len();
+        let mut begin = end - 1;
+        while !val.is_zero() {
+            let (q, mut chunk) = val.div_rem_u128(CHUNK);
+            val = q;
+            for b in buf[end - 38..end].iter_mut().rev() {
+                *b = b'0' + (chunk % 10) as u8;
+                chunk /= 10;
+                if chunk == 0 && val.is_zero() {
+                    break;
+                }
+            }
+            end -= 38;
+        }
+        for (i, b) in buf.iter().enumerate() {
+            if *b != b'0' {
+                begin = i;
+                break;
+            }
+        }
+        f.pad_integral(!neg, "", str::from_utf8(&buf[begin..]).unwrap())
+    }
+}
+
+impl I256 {
+    /// Creates an integer from its most significant and least
+    /// significant words.
+    #[inline]
+    pub const fn from_words(hi: i128, lo: u128) -> I256 {
+        I256 { lo, hi }
+    }
+
+    /// Returns the most significant and least significant words.
+    #[inline]
+    pub const fn to_words(self) -> (i128, u128) {
+        (self.hi, self.lo)
+    }
+
+    /// Returns the smallest value that can be represented,
+    /// −2<sup>255</sup>.
+    #[inline]
+    pub const fn min_value() -> I256 {
+        I256 {
+            lo: 0,
+            hi: -0x7fff_ffff_ffff_ffff_ffff_ffff_ffff_ffff - 1,
+        }
+    }
+
+    /// Returns the largest value that can be represented,
+    /// 2<sup>255</sup> − 1.
+    #[inline]
+    pub const fn max_value() -> I256 {
+        I256 {
+            lo: !0,
+            hi: 0x7fff_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_negative(self) -> bool {
+        self.hi < 0
+    }
+
+    #[inline]
+    pub(crate) fn to_unsigned(self) -> U256 {
+        U256 {
+            lo: self.lo,
+            hi: self.hi as u128,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn from_unsigned(val: U256) -> I256 {
+        I256 {
+            lo: val.lo,
+            hi: val.hi as i128,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_zero(self) -> bool {
+        self.lo == 0 && self.hi == 0
+    }
+
+    #[inline]
+    pub(crate) fn count_ones(self) -> u32 {
+        self.to_unsigned().count_ones()
+    }
+
+    #[inline]
+    pub(crate) fn leading_zeros(self) -> u32 {
+        self.to_unsigned().leading_zeros()
+    }
+
+    #[inline]
+    pub(crate) fn trailing_zeros(self) -> u32 {
+        self.to_unsigned().trailing_zeros()
+    }
+
+    #[inline]
+    pub(crate) fn rotate_left(self, n: u32) -> I256 {
+        I256::from_unsigned(self.to_unsigned().rotate_left(n))
+    }
+
+    #[inline]
+    pub(crate) fn rotate_right(self, n: u32) -> I256 {
+        I256::from_unsigned(self.to_unsigned().rotate_right(n))
+    }
+
+    #[inline]
+    pub(crate) fn neg_abs(self) -> (bool, U256) {
+        let val = self.to_unsigned();
+        if self.is_negative() {
+            (true, val.wrapping_neg())
+        } else {
+            (false, val)
+        }
+    }
+
+    // Returns the wrapped value and whether the value is out of range.
+    #[inline]
+    pub(crate) fn overflowing_from_neg_abs(neg: bool, abs: U256) -> (I256, bool) {
+        let max_abs = if neg {
+            U256::MSB
+        } else {
+            U256::MSB.wrapping_sub(U256::ONE)
+        };
+        let wrapped = if neg { abs.wrapping_neg() } else { abs };
+        (I256::from_unsigned(wrapped), abs > max_abs)
+    }
+
+    #[inline]
+    pub(crate) fn overflowing_neg(self) -> (I256, bool) {
+        let neg = I256::from_unsigned(self.to_unsigned().wrapping_neg());
+        (neg, self == I256::min_value())
+    }
+
+    #[inline]
+    pub(crate) fn overflowing_add(self, rhs: I256) -> (I256, bool) {
+        let sum = self.to_unsigned().wrapping_add(rhs.to_unsigned());
+        let sum = I256::from_unsigned(sum);
+        let overflow =
+            self.is_negative() == rhs.is_negative() && sum.is_negative() != self.is_negative();
+        (sum, overflow)
+    }
+
+    #[inline]
+    pub(crate) fn overflowing_sub(self, rhs: I256) -> (I256, bool) {
+        let diff = self.to_unsigned().wrapping_sub(rhs.to_unsigned());
+        let diff = I256::from_unsigned(diff);
+        let overflow =
+            self.is_negative() != rhs.is_negative() && diff.is_negative() != self.is_negative();
+        (diff, overflow)
+    }
+}
+
+#[inline]
+fn mul_u128(lhs: u128, rhs: u128) -> (u128, u128) {
+    crate::arith::mul_wide(lhs, rhs)
+}
+
+impl Ord for U256 {
+    #[inline]
+    fn cmp(&self, rhs: &U256) -> Ordering {
+        (self.hi, self.lo).cmp(&(rhs.hi, rhs.lo))
+    }
+}
+
+impl PartialOrd for U256 {
+    #[inline]
+    fn partial_cmp(&self, rhs: &U256) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for I256 {
+    #[inline]
+    fn cmp(&self, rhs: &I256) -> Ordering {
+        (self.hi, self.lo).cmp(&(rhs.hi, rhs.lo))
+    }
+}
+
+impl PartialOrd for I256 {
+    #[inline]
+    fn partial_cmp(&self, rhs: &I256) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+macro_rules! bytes {
+    ($Int:ident($Hi:ident)) => {
+        impl $Int {
+            /// Creates an integer from its representation as a byte
+            /// array in big endian.
+            #[inline]
+            pub fn from_be_bytes(bytes: [u8; 32]) -> $Int {
+                let mut hi = [0; 16];
+                let mut lo = [0; 16];
+                hi.copy_from_slice(&bytes[..16]);
+                lo.copy_from_slice(&bytes[16..]);
+                $Int {
+                    lo: u128::from_be_bytes(lo),
+                    hi: $Hi::from_be_bytes(hi),
+                }
+            }
+
+            /// Creates an integer from its representation as a byte
+            /// array in little endian.
+            #[inline]
+            pub fn from_le_bytes(bytes: [u8; 32]) -> $Int {
+                let mut lo = [0; 16];
+                let mut hi = [0; 16];
+                lo.copy_from_slice(&bytes[..16]);
+                hi.copy_from_slice(&bytes[16..]);
+                $Int {
+                    lo: u128::from_le_bytes(lo),
+                    hi: $Hi::from_le_bytes(hi),
+                }
+            }
+
+            /// Creates an integer from its representation as a byte
+            /// array in native endian.
+            #[inline]
+            pub fn from_ne_bytes(bytes: [u8; 32]) -> $Int {
+                if cfg!(target_endian = "big") {
+                    $Int::from_be_bytes(bytes)
+                } else {
+                    $Int::from_le_bytes(bytes)
+                }
+            }
+
+            /// Returns the memory representation of this integer as a
+            /// byte array in big-endian byte order.
+            #[inline]
+            pub fn to_be_bytes(self) -> [u8; 32] {
+                let mut bytes = [0; 32];
+                bytes[..16].copy_from_slice(&self.hi.to_be_bytes());
+                bytes[16..].copy_from_slice(&self.lo.to_be_bytes());
+                bytes
+            }
+
+            /// Returns the memory representation of this integer as a
+            /// byte array in little-endian byte order.
+            #[inline]
+            pub fn to_le_bytes(self) -> [u8; 32] {
+                let mut bytes = [0; 32];
+                bytes[..16].copy_from_slice(&self.lo.to_le_bytes());
+                bytes[16..].copy_from_slice(&self.hi.to_le_bytes());
+                bytes
+            }
+
+            /// Returns the memory representation of this integer as a
+            /// byte array in native byte order.
+            #[inline]
+            pub fn to_ne_bytes(self) -> [u8; 32] {
+                if cfg!(target_endian = "big") {
+                    self.to_be_bytes()
+                } else {
+                    self.to_le_bytes()
+                }
+            }
+        }
+    };
+}
+
+bytes! { U256(u128) }
+bytes! { I256(i128) }
+
+macro_rules! from_unsigned {
+    ($($Src:ty),*) => { $(
+        impl From<$Src> for U256 {
+            #[inline]
+            fn from(src: $Src) -> U256 {
+                U256 {
+                    lo: u128::from(src),
+                    hi: 0,
+                }
+            }
+        }
+
+        impl From<$Src> for I256 {
+            #[inline]
+            fn from(src: $Src) -> I256 {
+                I256 {
+                    lo: u128::from(src),
+                    hi: 0,
+                }
+            }
+        }
+    )* };
+}
+
+from_unsigned! { bool, u8, u16, u32, u64, u128 }
+
+macro_rules! from_signed {
+    ($($Src:ty),*) => { $(
+        impl From<$Src> for I256 {
+            #[inline]
+            fn from(src: $Src) -> I256 {
+                let src = i128::from(src);
+                I256 {
+                    lo: src as u128,
+                    hi: if src < 0 { -1 } else { 0 },
+                }
+            }
+        }
+    )* };
+}
+
+from_signed! { i8, i16, i32, i64, i128 }
+
+impl Not for U256 {
+    type Output = U256;
+    #[inline]
+    fn not(self) -> U256 {
+        U256 {
+            lo: !self.lo,
+            hi: !self.hi,
+        }
+    }
+}
+
+impl Not for I256 {
+    type Output = I256;
+    #[inline]
+    fn not(self) -> I256 {
+        I256 {
+            lo: !self.lo,
+            hi: !self.hi,
+        }
+    }
+}
+
+macro_rules! bitwise {
+    ($Int:ident; $($Imp:ident $method:ident $op:tt),*) => { $(
+        impl $Imp for $Int {
+            type Output = $Int;
+            #[inline]
+            fn $method(self, rhs: $Int) -> $Int {
+                $Int {
+                    lo: self.lo $op rhs.lo,
+                    hi: self.hi $op rhs.hi,
+                }
+            }
+        }
+    )* };
+}
+
+bitwise! { U256; BitAnd bitand &, BitOr bitor |, BitXor bitxor ^ }
+bitwise! { I256; BitAnd bitand &, BitOr bitor |, BitXor bitxor ^ }
+
+impl Shl<u32> for U256 {
+    type Output = U256;
+    #[inline]
+    fn shl(self, rhs: u32) -> U256 {
+        debug_assert!(rhs < 256, "attempt to shift left with overflow");
+        let rhs = rhs & 255;
+        if rhs == 0 {
+            self
+        } else if rhs < 128 {
+            U256 {
+                lo: self.lo << rhs,
+                hi: (self.hi << rhs) | (self.lo >> (128 - rhs)),
+            }
+        } else {
+            U256 {
+                lo: 0,
+                hi: self.lo << (rhs - 128),
+            }
+        }
+    }
+}
+
+impl Shr<u32> for U256 {
+    type Output = U256;
+    #[inline]
+    fn shr(self, rhs: u32) -> U256 {
+        debug_assert!(rhs < 256, "attempt to shift right with overflow");
+        let rhs = rhs & 255;
+        if rhs == 0 {
+            self
+        } else if rhs < 128 {
+            U256 {
+                lo: (self.lo >> rhs) | (self.hi << (128 - rhs)),
+                hi: self.hi >> rhs,
+            }
+        } else {
+            U256 {
+                lo: self.hi >> (rhs - 128),
+                hi: 0,
+            }
+        }
+    }
+}
+
+impl Shl<u32> for I256 {
+    type Output = I256;
+    #[inline]
+    fn shl(self, rhs: u32) -> I256 {
+        I256::from_unsigned(self.to_unsigned() << rhs)
+    }
+}
+
+impl Shr<u32> for I256 {
+    type Output = I256;
+    #[inline]
+    fn shr(self, rhs: u32) -> I256 {
+        debug_assert!(rhs < 256, "attempt to shift right with overflow");
+        let rhs = rhs & 255;
+        if rhs == 0 {
+            self
+        } else if rhs < 128 {
+            I256 {
+                lo: (self.lo >> rhs) | ((self.hi as u128) << (128 - rhs)),
+                hi: self.hi >> rhs,
+            }
+        } else {
+            I256 {
+                lo: (self.hi >> (rhs - 128)) as u128,
+                hi: self.hi >> 127,
+            }
+        }
+    }
+}
+
+impl Display for U256 {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        self.fmt_dec(false, f)
+    }
+}
+
+impl Debug for U256 {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        self.fmt_dec(false, f)
+    }
+}
+
+impl Display for I256 {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let (neg, abs) = self.neg_abs();
+        abs.fmt_dec(neg, f)
+    }
+}
+
+impl Debug for I256 {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let (neg, abs) = self.neg_abs();
+        abs.fmt_dec(neg, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn display() {
+        assert_eq!(U256::ZERO.to_string(), "0");
+        assert_eq!(U256::from(10u8).to_string(), "10");
+        assert_eq!(
+            U256::from_words(1, 0).to_string(),
+            "340282366920938463463374607431768211456"
+        );
+        assert_eq!(
+            U256::max_value().to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(
+            I256::min_value().to_string(),
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+        );
+        assert_eq!(I256::from(-1i8).to_string(), "-1");
+        assert_eq!(std::format!("{:>5}", U256::from(42u8)), "   42");
+    }
+
+    #[test]
+    fn arith() {
+        let max = U256::max_value();
+        assert_eq!(max.overflowing_add(U256::ONE), (U256::ZERO, true));
+        assert_eq!(U256::ZERO.overflowing_sub(U256::ONE), (max, true));
+        assert_eq!(max.mul_wide(max), (max.wrapping_sub(U256::ONE), U256::ONE));
+        let a = U256::from_words(3, 5);
+        assert_eq!(
+            a.mul_wide(U256::from(2u8)),
+            (U256::ZERO, U256::from_words(6, 10))
+        );
+        assert_eq!(a << 128, U256::from_words(5, 0));
+        assert_eq!(a >> 1, U256::from_words(1, (1 << 127) | 2));
+        assert_eq!(I256::from(-4i8) >> 1, I256::from(-2i8));
+        assert_eq!(I256::from(-4i8) >> 200, I256::from(-1i8));
+        assert_eq!(a.leading_zeros(), 126);
+        assert_eq!(U256::from_words(4, 0).trailing_zeros(), 130);
+        let ((q1, q0), r) = U256::div_rem_wide(U256::ONE, U256::ZERO, U256::from(3u8));
+        assert_eq!(q1, U256::ZERO);
+        assert_eq!(
+            q0,
+            U256::from_words(0x5555_5555_5555_5555_5555_5555_5555_5555, !0 / 3)
+        );
+        assert_eq!(r, U256::ONE);
+        let ((q1, q0), r) = U256::div_rem_wide(a, U256::from_words(7, 11), a);
+        assert_eq!(
+            (q1, q0, r),
+            (U256::ONE, U256::from_words(0, 2), U256::from_words(1, 1))
+        );
+    }
+
+    #[test]
+    fn div_rem_wide() {
+        let words = [
+            0,
+            1,
+            3,
+            0x8000_0000,
+            1 << 64,
+            !0 >> 1,
+            1 << 127,
+            !0 - 12,
+            !0,
+        ];
+        let mut vals = std::vec::Vec::new();
+        for &hi in &words {
+            for &lo in &words {
+                vals.push(U256::from_words(hi, lo));
+            }
+        }
+        for &d in &vals {
+            if d.is_zero() {
+                continue;
+            }
+            for &n1 in &vals {
+                for &n0 in &vals {
+                    let ((q1, q0), r) = U256::div_rem_wide(n1, n0, d);
+                    assert!(r < d);
+                    // q × d + r must give back n
+                    let (hi0, lo0) = q0.mul_wide(d);
+                    let (_, hi1) = q1.mul_wide(d);
+                    let (lo, carry) = lo0.overflowing_add(r);
+                    let hi = hi0.wrapping_add(hi1).wrapping_add(U256::from(carry));
+                    assert_eq!((hi, lo), (n1, n0));
+                }
+            }
+        }
+    }
+}
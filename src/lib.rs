@@ -23,6 +23,9 @@ The [*fixed* crate] provides fixed-point numbers.
   * [`FixedI32`] and [`FixedU32`] are 32-bit fixed-point numbers.
   * [`FixedI64`] and [`FixedU64`] are 64-bit fixed-point numbers.
   * [`FixedI128`] and [`FixedU128`] are 128-bit fixed-point numbers.
+  * [`FixedI256`] and [`FixedU256`] are 256-bit fixed-point numbers
+    backed by two 128-bit words; they convert to and from the
+    narrower types using the [`FromFixed`] and [`ToFixed`] traits.
 
 These types can have `Frac` fractional bits, where
 0 ≤ `Frac` ≤ <i>n</i> and <i>n</i> is the total number of bits. When
@@ -37,27 +40,23 @@ supported by the Rust compiler.
 
 The main features are
 
-  * Representation of fixed-point numbers up to 128 bits wide.
+  * Representation of fixed-point numbers up to 256 bits wide.
   * Conversions between fixed-point numbers and numeric primitives.
   * Comparisons between fixed-point numbers and numeric primitives.
   * Parsing from strings in decimal, binary, octal and hexadecimal.
   * Display as decimal, binary, octal and hexadecimal.
   * Arithmetic and logic operations.
 
-This crate does *not* provide general analytic functions.
+The crate also provides some analytic functions.
 
-  * No algebraic functions are provided, for example no `sqrt` or
-    `pow`.
-  * No trigonometric functions are provided, for example no `sin` or
-    `cos`.
-  * No other transcendental functions are provided, for example no
-    `log` or `exp`.
-
-These functions are not provided because different implementations can
-have different trade-offs, for example trading some correctness for
-speed. Implementations can be provided in other crates.
-
-  * The [*fixed-sqrt* crate] provides the square root operation.
+  * Algebraic functions: the [`sqrt`] method returns the square root
+    rounded down, and [`checked_powi`] and the related methods raise a
+    number to an integer power, rounding to the nearest.
+  * Trigonometric, hyperbolic, exponential and logarithmic functions
+    such as `sin`, `atan2`, `tanh`, `exp` and `ln` are in the
+    [`transcendental`] module. They are computed at the precision of
+    the operand type, and return a [`TranscendentalError`] when the
+    result is not defined or does not fit.
 
 The conversions supported cover the following cases.
 
@@ -75,11 +74,17 @@ The conversions supported cover the following cases.
   * Fixed-point numbers can be parsed from decimal strings using
     [`FromStr`], and from binary, octal and hexadecimal strings using
     the [`from_str_binary`], [`from_str_octal`] and [`from_str_hex`]
-    methods. The result is rounded to the nearest, with ties rounded
-    to even.
+    methods, or in any radix from 2 to 36 using [`from_str_radix`].
+    The result is rounded to the nearest, with ties rounded to even.
+    A more lenient syntax with underscores, radix prefixes and
+    surrounding whitespace can be enabled using [`ParseOptions`] and the
+    [`from_str_with`] methods. ASCII byte slices can be parsed
+    without UTF-8 validation using the [`from_ascii`] and
+    [`from_ascii_radix`] methods.
   * Fixed-point numbers can be converted to strings using [`Display`],
-    [`Binary`], [`Octal`], [`LowerHex`] and [`UpperHex`]. The output
-    is rounded to the nearest, with ties rounded to even.
+    [`Binary`], [`Octal`], [`LowerHex`] and [`UpperHex`], or in any
+    radix from 2 to 36 using [`display_radix`]. The output is rounded
+    to the nearest, with ties rounded to even.
 
 ## Quick examples
 
@@ -147,21 +152,33 @@ fixed = "0.5.4"
 ```
 
 The *fixed* crate requires rustc version 1.39.0 or later.
+The [`fixed!`] macro, which creates fixed-point constants from string
+literals at compile time, is only available with rustc version 1.57.0
+or later.
 
 ## Optional features
 
-The *fixed* crate has four optional feature:
+The *fixed* crate has six optional features:
 
  1. `az`, disabled by default. This implements the cast traits
     provided by the [*az* crate].
- 2. `f16`, disabled by default. This provides conversion to/from
+ 2. `codec`, disabled by default. This provides [SCALE] encoding
+    and decoding with the [*parity-scale-codec* crate], and type
+    information with the [*scale-info* crate]. Unsigned fixed-point
+    numbers of up to 128 bits can also be compact encoded.
+ 3. `f16`, disabled by default. This provides conversion to/from
     [`f16`] and [`bf16`]. This features requires the [*half* crate].
- 3. `serde`, disabled by default. This provides serialization support
+ 4. `serde`, disabled by default. This provides serialization support
     for the fixed-point types. This feature requires the
     [*serde* crate].
- 4. `std`, disabled by default. This is for features that are not
+ 5. `serde-str`, disabled by default. This serializes fixed-point
+    numbers as decimal strings in human-readable formats, and also
+    accepts strings and numbers when deserializing from them. This
+    feature enables the `serde` feature.
+ 6. `std`, disabled by default. This is for features that are not
     possible under `no_std`: currently the implementation of the
-    [`Error`] trait for [`ParseFixedError`].
+    [`Error`] trait for [`ParseFixedError`] and
+    [`TranscendentalError`], and the [`to_string_radix`] methods.
 
 To enable features, you can add the dependency like this to
 [*Cargo.toml*]:
@@ -192,22 +209,26 @@ additional terms or conditions.
 [*Cargo.toml*]: https://doc.rust-lang.org/cargo/guide/dependencies.html
 [*az* crate]: https://crates.io/crates/az
 [*fixed* crate]: https://crates.io/crates/fixed
-[*fixed-sqrt* crate]: https://crates.io/crates/fixed-sqrt
 [*half* crate]: https://crates.io/crates/half
+[*parity-scale-codec* crate]: https://crates.io/crates/parity-scale-codec
+[*scale-info* crate]: https://crates.io/crates/scale-info
 [*serde* crate]: https://crates.io/crates/serde
 [*typenum* crate]: https://crates.io/crates/typenum
 [LICENSE-APACHE]: https://www.apache.org/licenses/LICENSE-2.0
 [LICENSE-MIT]: https://opensource.org/licenses/MIT
+[SCALE]: https://docs.rs/parity-scale-codec
 [`Binary`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Binary.html
 [`Display`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Display.html
 [`Error`]: https://doc.rust-lang.org/nightly/std/error/trait.Error.html
 [`FixedI128`]: struct.FixedI128.html
 [`FixedI16`]: struct.FixedI16.html
+[`FixedI256`]: struct.FixedI256.html
 [`FixedI32`]: struct.FixedI32.html
 [`FixedI64`]: struct.FixedI64.html
 [`FixedI8`]: struct.FixedI8.html
 [`FixedU128`]: struct.FixedU128.html
 [`FixedU16`]: struct.FixedU16.html
+[`FixedU256`]: struct.FixedU256.html
 [`FixedU32`]: struct.FixedU32.html
 [`FixedU64`]: struct.FixedU64.html
 [`FixedU8`]: struct.FixedU8.html
@@ -223,18 +244,30 @@ additional terms or conditions.
 [`LowerHex`]: https://doc.rust-lang.org/nightly/core/fmt/trait.LowerHex.html
 [`Octal`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Octal.html
 [`ParseFixedError`]: struct.ParseFixedError.html
+[`ParseOptions`]: struct.ParseOptions.html
 [`ToFixed`]: traits/trait.ToFixed.html
+[`TranscendentalError`]: transcendental/enum.TranscendentalError.html
 [`U12`]: types/extra/type.U12.html
 [`U20F12`]: types/type.U20F12.html
 [`UpperHex`]: https://doc.rust-lang.org/nightly/core/fmt/trait.UpperHex.html
 [`bf16`]: https://docs.rs/half/^1/half/struct.bf16.html
 [`checked_from_num`]: struct.FixedI32.html#method.checked_from_num
+[`checked_powi`]: struct.FixedI32.html#method.checked_powi
+[`display_radix`]: struct.FixedI32.html#method.display_radix
 [`f16`]: https://docs.rs/half/^1/half/struct.f16.html
+[`fixed!`]: macro.fixed.html
+[`from_ascii`]: struct.FixedI32.html#method.from_ascii
+[`from_ascii_radix`]: struct.FixedI32.html#method.from_ascii_radix
 [`from_num`]: struct.FixedI32.html#method.from_num
 [`from_str_binary`]: struct.FixedI32.html#method.from_str_binary
 [`from_str_hex`]: struct.FixedI32.html#method.from_str_hex
 [`from_str_octal`]: struct.FixedI32.html#method.from_str_octal
+[`from_str_radix`]: struct.FixedI32.html#method.from_str_radix
+[`from_str_with`]: struct.FixedI32.html#method.from_str_with
+[`sqrt`]: struct.FixedI32.html#method.sqrt
 [`to_num`]: struct.FixedI32.html#method.to_num
+[`to_string_radix`]: struct.FixedI32.html#method.to_string_radix
+[`transcendental`]: transcendental/index.html
 [const generics]: https://github.com/rust-lang/rust/issues/44580
 */
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -243,6 +276,7 @@ additional terms or conditions.
 #![doc(test(attr(deny(warnings))))]
 #![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
 #![allow(clippy::type_repetition_in_bounds)]
+#![allow(clippy::manual_div_ceil)]
 
 #[cfg(all(not(feature = "std"), test))]
 extern crate std;
@@ -253,29 +287,48 @@ mod macros;
 mod arith;
 #[cfg(feature = "az")]
 mod cast;
+mod checked;
 mod cmp;
+#[cfg(feature = "codec")]
+mod codec;
 pub mod consts;
 mod convert;
 mod display;
+mod fixed256;
 mod float_helper;
 mod from_str;
 mod helpers;
+pub mod int256;
 mod int_helper;
+#[cfg(const_panic)]
+mod lit;
+mod saturating;
 #[cfg(feature = "serde")]
-mod serdeize;
+pub mod serdeize;
 pub mod traits;
 pub mod transcendental;
 pub mod types;
+mod unwrapped;
 mod wide_div;
 mod wrapping;
 
+pub use crate::{
+    arith::RoundingMode,
+    checked::Checked,
+    display::DisplayRadix,
+    fixed256::{FixedI256, FixedU256},
+    from_str::{ParseErrorKind, ParseFixedError, ParseOptions},
+    saturating::Saturating,
+    unwrapped::Unwrapped,
+    wrapping::Wrapping,
+};
 use crate::{
-    arith::MulDivOverflow,
-    from_str::FromStrRadix,
-    traits::{FromFixed, ToFixed},
+    arith::{MulDivOverflow, MulDivRound, PowiFrac, SqrtFrac},
+    from_str::{check_radix, FromStrRadix},
+    helpers::Sealed,
+    traits::{Fixed, FromFixed, ToFixed},
     types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
 };
-pub use crate::{from_str::ParseFixedError, wrapping::Wrapping};
 use core::{
     cmp::Ordering,
     hash::{Hash, Hasher},
@@ -311,15 +364,14 @@ mod macros_no_frac;
 #[macro_use]
 mod macros_frac;
 
-use codec::{Decode, Encode};
-macro_rules! fixed {
+macro_rules! fixed_type {
     (
         $description:expr,
         $Fixed:ident($Inner:ty, $LeEqU:tt, $s_nbits:expr, $s_nbits_m4:expr),
         $nbytes:expr, $bytes_val:expr, $be_bytes:expr, $le_bytes:expr,
         $UInner:ty, $Signedness:tt
     ) => {
-        fixed! {
+        fixed_type! {
             $description,
             $Fixed[stringify!($Fixed)]($Inner[stringify!($Inner)], $LeEqU, $s_nbits, $s_nbits_m4),
             $nbytes, $bytes_val, $be_bytes, $le_bytes,
@@ -361,7 +413,6 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
 [typenum crate]: https://crates.io/crates/typenum
 ";
             #[repr(transparent)]
-            #[derive(Encode, Decode)]
             pub struct $Fixed<Frac> {
                 bits: $Inner,
                 phantom: PhantomData<Frac>,
@@ -371,10 +422,7 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
         impl<Frac> Clone for $Fixed<Frac> {
             #[inline]
             fn clone(&self) -> $Fixed<Frac> {
-                $Fixed {
-                    bits: self.bits,
-                    phantom: PhantomData,
-                }
+                *self
             }
         }
 
@@ -413,25 +461,25 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
     };
 }
 
-fixed! {
+fixed_type! {
     "An eight-bit fixed-point unsigned",
     FixedU8(u8, LeEqU8, "8", "4"),
     1, "0x12", "[0x12]", "[0x12]",
     u8, Unsigned
 }
-fixed! {
+fixed_type! {
     "A 16-bit fixed-point unsigned",
     FixedU16(u16, LeEqU16, "16", "12"),
     2, "0x1234", "[0x12, 0x34]", "[0x34, 0x12]",
     u16, Unsigned
 }
-fixed! {
+fixed_type! {
     "A 32-bit fixed-point unsigned",
     FixedU32(u32, LeEqU32, "32", "28"),
     4, "0x1234_5678", "[0x12, 0x34, 0x56, 0x78]", "[0x78, 0x56, 0x34, 0x12]",
     u32, Unsigned
 }
-fixed! {
+fixed_type! {
     "A 64-bit fixed-point unsigned",
     FixedU64(u64, LeEqU64, "64", "60"),
     8, "0x1234_5678_9ABC_DEF0",
@@ -439,7 +487,7 @@ fixed! {
     "[0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
     u64, Unsigned
 }
-fixed! {
+fixed_type! {
     "A 128-bit fixed-point unsigned",
     FixedU128(u128, LeEqU128, "128", "124"),
     16, "0x1234_5678_9ABC_DEF0_1234_5678_9ABC_DEF0",
@@ -449,25 +497,25 @@ fixed! {
      0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
     u128, Unsigned
 }
-fixed! {
+fixed_type! {
     "An eight-bit fixed-point signed",
     FixedI8(i8, LeEqU8, "8", "4"),
     1, "0x12", "[0x12]", "[0x12]",
     u8, Signed
 }
-fixed! {
+fixed_type! {
     "A 16-bit fixed-point signed",
     FixedI16(i16, LeEqU16, "16", "12"),
     2, "0x1234", "[0x12, 0x34]", "[0x34, 0x12]",
     u16, Signed
 }
-fixed! {
+fixed_type! {
     "A 32-bit fixed-point signed",
     FixedI32(i32, LeEqU32, "32", "28"),
     4, "0x1234_5678", "[0x12, 0x34, 0x56, 0x78]", "[0x78, 0x56, 0x34, 0x12]",
     u32, Signed
 }
-fixed! {
+fixed_type! {
     "A 64-bit fixed-point signed",
     FixedI64(i64, LeEqU64, "64", "60"),
     8, "0x1234_5678_9ABC_DEF0",
@@ -475,7 +523,7 @@ fixed! {
     "[0xF0, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12]",
     u64, Signed
 }
-fixed! {
+fixed_type! {
     "A 128-bit fixed-point signed",
     FixedI128(i128, LeEqU128, "128", "124"),
     16, "0x1234_5678_9ABC_DEF0_1234_5678_9ABC_DEF0",
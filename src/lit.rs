@@ -0,0 +1,631 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Compile-time parsing for the fixed! macro.
+//
+// The literal syntax is the one accepted by ParseOptions with prefix
+// and underscores enabled: an optional sign, an optional radix prefix
+// 0b, 0o or 0x, digits with an optional point, and an optional
+// exponent, e or E for decimal and p or P for hexadecimal. Errors
+// panic, so that they become compile errors in constants.
+//
+// Everything has to be const, so the arithmetic is done on 320-bit
+// numbers stored as five little-endian u64 limbs. Hexadecimal digits
+// are expanded to binary digits so that the binary exponent only moves
+// the point, like in parse_with_exp. Fractional digits are converted
+// like in radix_str_frac_to_bin, so the result is rounded to the
+// nearest with ties rounded to even, exactly like FromStr.
+
+type Limbs = [u64; 5];
+
+const MAX_EXP: i64 = 1 << 48;
+
+const fn digit_value(byte: u8) -> u32 {
+    match byte {
+        b'0'..=b'9' => (byte - b'0') as u32,
+        b'a'..=b'z' => (byte - b'a') as u32 + 10,
+        b'A'..=b'Z' => (byte - b'A') as u32 + 10,
+        _ => 36,
+    }
+}
+
+// The mantissa is bytes[start..end], which contains digits,
+// underscores and at most one point. If expand is set, every digit is
+// a hexadecimal digit expanded to four binary digits.
+#[derive(Clone, Copy)]
+struct Mantissa<'a> {
+    bytes: &'a [u8],
+    start: usize,
+    end: usize,
+    expand: bool,
+}
+
+// Returns the digit with the given index, where digits outside the
+// mantissa are zero.
+const fn digit_at(m: Mantissa<'_>, index: i64) -> u64 {
+    if index < 0 {
+        return 0;
+    }
+    let (byte_index, shift) = if m.expand {
+        (index / 4, 3 - (index % 4) as u32)
+    } else {
+        (index, 0)
+    };
+    let mut count = 0;
+    let mut i = m.start;
+    while i < m.end {
+        let byte = m.bytes[i];
+        if byte != b'_' && byte != b'.' {
+            if count == byte_index {
+                let digit = digit_value(byte) as u64;
+                return if m.expand {
+                    (digit >> shift) & 1
+                } else {
+                    digit
+                };
+            }
+            count += 1;
+        }
+        i += 1;
+    }
+    0
+}
+
+// Returns the index of the first nonzero digit, if any.
+const fn first_nonzero(m: Mantissa<'_>) -> Option<i64> {
+    let mut count = 0;
+    let mut i = m.start;
+    while i < m.end {
+        let byte = m.bytes[i];
+        if byte != b'_' && byte != b'.' {
+            let digit = digit_value(byte);
+            if digit != 0 {
+                return Some(if m.expand {
+                    count * 4 + (digit.leading_zeros() - 28) as i64
+                } else {
+                    count
+                });
+            }
+            count += 1;
+        }
+        i += 1;
+    }
+    None
+}
+
+// Returns a × mul + add and the carry out of the top limb.
+const fn mul_add(a: Limbs, mul: u64, add: u64) -> (Limbs, u64) {
+    let mut ret = [0; 5];
+    let mut carry = add as u128;
+    let mut i = 0;
+    while i < 5 {
+        let cur = a[i] as u128 * mul as u128 + carry;
+        ret[i] = cur as u64;
+        carry = cur >> 64;
+        i += 1;
+    }
+    (ret, carry as u64)
+}
+
+// Returns a / div and whether the remainder is nonzero.
+const fn div_rem(a: Limbs, div: u64) -> (Limbs, bool) {
+    let mut ret = [0; 5];
+    let mut rem = 0u128;
+    let mut i = 5;
+    while i > 0 {
+        i -= 1;
+        let cur = (rem << 64) | a[i] as u128;
+        ret[i] = (cur / div as u128) as u64;
+        rem = cur % div as u128;
+    }
+    (ret, rem != 0)
+}
+
+const fn add(a: Limbs, b: Limbs) -> Limbs {
+    let mut ret = [0; 5];
+    let mut carry = 0u128;
+    let mut i = 0;
+    while i < 5 {
+        let cur = a[i] as u128 + b[i] as u128 + carry;
+        ret[i] = cur as u64;
+        carry = cur >> 64;
+        i += 1;
+    }
+    ret
+}
+
+// Shifts left by shift < 320, discarding the high bits.
+const fn shl(a: Limbs, shift: u32) -> Limbs {
+    let limbs = (shift / 64) as usize;
+    let bits = shift % 64;
+    let mut ret = [0; 5];
+    let mut i = limbs;
+    while i < 5 {
+        ret[i] = a[i - limbs] << bits;
+        if bits > 0 && i > limbs {
+            ret[i] |= a[i - limbs - 1] >> (64 - bits);
+        }
+        i += 1;
+    }
+    ret
+}
+
+// Shifts right by one.
+const fn shr1(a: Limbs) -> Limbs {
+    let mut ret = [0; 5];
+    let mut i = 0;
+    while i < 5 {
+        ret[i] = a[i] >> 1;
+        if i < 4 {
+            ret[i] |= a[i + 1] << 63;
+        }
+        i += 1;
+    }
+    ret
+}
+
+const fn bit_len(a: Limbs) -> u32 {
+    let mut i = 5;
+    while i > 0 {
+        i -= 1;
+        if a[i] != 0 {
+            return i as u32 * 64 + 64 - a[i].leading_zeros();
+        }
+    }
+    0
+}
+
+const fn count_ones(a: Limbs) -> u32 {
+    a[0].count_ones()
+        + a[1].count_ones()
+        + a[2].count_ones()
+        + a[3].count_ones()
+        + a[4].count_ones()
+}
+
+const fn neg(a: Limbs) -> Limbs {
+    let not = [!a[0], !a[1], !a[2], !a[3], !a[4]];
+    add(not, [1, 0, 0, 0, 0])
+}
+
+// Returns the fractional digits from the index point onwards as
+// nbits ≤ 256 fractional bits rounded down, the next bit, and whether
+// any of the remaining bits is nonzero.
+const fn frac_to_bin(
+    m: Mantissa<'_>,
+    radix: u64,
+    point: i64,
+    len: i64,
+    nbits: u32,
+) -> (Limbs, bool, bool) {
+    let mut acc = [0; 5];
+    let mut sticky = false;
+    let mut index = len;
+    while index > point {
+        index -= 1;
+        // acc < 2^(nbits + 1), so adding d × 2^(nbits + 1) cannot overflow
+        let digit = shl([digit_at(m, index), 0, 0, 0, 0], nbits + 1);
+        let (div, rem) = div_rem(add(acc, digit), radix);
+        acc = div;
+        sticky = sticky || rem;
+    }
+    (shr1(acc), acc[0] & 1 != 0, sticky)
+}
+
+// Parses a literal into an nbits-bit fixed-point number with
+// frac_nbits fractional bits, and returns the bits as two 128-bit
+// words, sign-extended to 256 bits.
+pub(crate) const fn parse(bytes: &[u8], nbits: u32, frac_nbits: u32, signed: bool) -> (u128, u128) {
+    let len = bytes.len();
+    let mut i = 0;
+    let mut is_neg = false;
+    if i < len && (bytes[i] == b'+' || bytes[i] == b'-') {
+        is_neg = bytes[i] == b'-';
+        i += 1;
+    }
+    let mut radix = 10;
+    if i + 1 < len && bytes[i] == b'0' {
+        radix = match bytes[i + 1] {
+            b'b' | b'B' => 2,
+            b'o' | b'O' => 8,
+            b'x' | b'X' => 16,
+            _ => 10,
+        };
+        if radix != 10 {
+            i += 2;
+        }
+    }
+
+    let start = i;
+    let mut ndigits = 0;
+    let mut int_ndigits = None;
+    while i < len {
+        let byte = bytes[i];
+        let is_marker = match radix {
+            10 => byte == b'e' || byte == b'E',
+            16 => byte == b'p' || byte == b'P',
+            _ => false,
+        };
+        if is_marker {
+            break;
+        }
+        if byte == b'.' {
+            if int_ndigits.is_some() {
+                panic!("more than one decimal point found in fixed-point literal");
+            }
+            int_ndigits = Some(ndigits);
+        } else if byte != b'_' {
+            if digit_value(byte) >= radix {
+                panic!("invalid digit found in fixed-point literal");
+            }
+            ndigits += 1;
+        }
+        i += 1;
+    }
+    if ndigits == 0 {
+        panic!("fixed-point literal has no digits");
+    }
+    let int_ndigits = match int_ndigits {
+        Some(int_ndigits) => int_ndigits,
+        None => ndigits,
+    };
+    let m = Mantissa {
+        bytes,
+        start,
+        end: i,
+        expand: radix == 16,
+    };
+
+    let mut exp = 0;
+    if i < len {
+        i += 1;
+        let mut exp_neg = false;
+        if i < len && (bytes[i] == b'+' || bytes[i] == b'-') {
+            exp_neg = bytes[i] == b'-';
+            i += 1;
+        }
+        let mut has_digit = false;
+        while i < len {
+            let byte = bytes[i];
+            if byte.is_ascii_digit() {
+                exp = exp * 10 + (byte - b'0') as i64;
+                if exp > MAX_EXP {
+                    exp = MAX_EXP;
+                }
+                has_digit = true;
+            } else if byte != b'_' {
+                panic!("invalid digit found in fixed-point literal");
+            }
+            i += 1;
+        }
+        if !has_digit {
+            panic!("invalid digit found in fixed-point literal");
+        }
+        if exp_neg {
+            exp = -exp;
+        }
+    }
+
+    let (digit_radix, digits_per_byte) = if m.expand { (2, 4) } else { (radix as u64, 1) };
+    let len = ndigits * digits_per_byte;
+    let point = int_ndigits * digits_per_byte + exp;
+    let first = match first_nonzero(m) {
+        Some(first) => first,
+        None => return (0, 0),
+    };
+    // more than 256 integer digits is at least 2^256
+    if point - first > 256 {
+        panic!("fixed-point literal overflows");
+    }
+    // less than radix^-257 ≤ 2^-257 always rounds to zero
+    if first - point >= 257 {
+        return (0, 0);
+    }
+
+    let mut int = [0; 5];
+    let mut index = first;
+    while index < point {
+        let (val, carry) = mul_add(int, digit_radix, digit_at(m, index));
+        if carry != 0 || val[4] != 0 {
+            panic!("fixed-point literal overflows");
+        }
+        int = val;
+        index += 1;
+    }
+    if bit_len(int) > nbits - frac_nbits {
+        panic!("fixed-point literal overflows");
+    }
+    let (frac, half, sticky) = frac_to_bin(m, digit_radix, point, len, frac_nbits);
+    let mut abs = add(shl(int, frac_nbits), frac);
+    // round to the nearest, with ties rounded to even
+    if half && (sticky || abs[0] & 1 != 0) {
+        abs = add(abs, [1, 0, 0, 0, 0]);
+    }
+
+    let abs_len = bit_len(abs);
+    let overflow = if signed {
+        abs_len > nbits || (abs_len == nbits && !(is_neg && count_ones(abs) == 1))
+    } else {
+        abs_len > nbits || (is_neg && abs_len > 0)
+    };
+    if overflow {
+        panic!("fixed-point literal overflows");
+    }
+    let bits = if is_neg { neg(abs) } else { abs };
+    let hi = bits[2] as u128 | (bits[3] as u128) << 64;
+    let lo = bits[0] as u128 | (bits[1] as u128) << 64;
+    (hi, lo)
+}
+
+/**
+Creates a fixed-point constant from a string literal at compile time.
+
+The literal is parsed like [`FromStr`], and can additionally contain
+underscores and a radix prefix `0b`, `0o` or `0x` after the sign, like
+with [`ParseOptions`] with [`prefix`] and [`underscores`] enabled.
+Rounding is to the nearest, with ties rounded to even.
+
+The value is computed in a constant, so an invalid literal or a
+literal that does not fit in the type is a compile error. The type has
+to be a concrete type, not a generic parameter.
+
+This macro is only available when compiling with rustc version 1.57.0
+or later, which can panic in constants.
+
+# Examples
+
+```rust
+use substrate_fixed::{fixed, types::{I16F16, U8F8}};
+const ONE_AND_A_QUARTER: I16F16 = fixed!("1.25": I16F16);
+assert_eq!(ONE_AND_A_QUARTER, I16F16::from_num(1.25));
+const MAX: I16F16 = fixed!("0x7FFF.FFFF": I16F16);
+assert_eq!(MAX, I16F16::max_value());
+const THIRD: U8F8 = fixed!("0.333_333": U8F8);
+assert_eq!(THIRD, U8F8::from_bits(85));
+assert_eq!(fixed!("-1.5e2": I16F16), -150);
+```
+
+A literal that overflows does not compile:
+
+```compile_fail
+use substrate_fixed::{fixed, types::I8F8};
+const TOO_LARGE: I8F8 = fixed!("128": I8F8);
+```
+
+[`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+[`ParseOptions`]: struct.ParseOptions.html
+[`prefix`]: struct.ParseOptions.html#method.prefix
+[`underscores`]: struct.ParseOptions.html#method.underscores
+*/
+#[macro_export]
+macro_rules! fixed {
+    ($src:literal: $Fixed:ty) => {{
+        const VAL: $Fixed = <$Fixed>::__from_lit($src, <$Fixed>::FRAC_NBITS);
+        VAL
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::{extra::*, *},
+        FixedI256, FixedU256, ParseOptions,
+    };
+    use core::str::FromStr;
+    use rand::{RngCore, SeedableRng};
+    use rand_xoshiro::Xoshiro256PlusPlus;
+    use std::{format, string::String, vec::Vec};
+
+    #[test]
+    fn constants() {
+        const A: I16F16 = fixed!("1.25": I16F16);
+        const B: I8F8 = fixed!("-128": I8F8);
+        const C: U8F8 = fixed!("255.998": U8F8);
+        const D: I32F32 = fixed!("0x_7fff_ffff.ffff_ffff": I32F32);
+        const E: U0F8 = fixed!("0b0.1111_1111": U0F8);
+        const F: I128F0 = fixed!("-0o17": I128F0);
+        const G: FixedU256<U255> = fixed!("1.5": FixedU256<U255>);
+        const H: FixedI256<U128> = fixed!("-0x1p-128": FixedI256<U128>);
+        assert_eq!(A, I16F16::from_num(1.25));
+        assert_eq!(B, I8F8::min_value());
+        assert_eq!(C, U8F8::from_bits(0xFFFF));
+        assert_eq!(D, I32F32::max_value());
+        assert_eq!(E, U0F8::max_value());
+        assert_eq!(F, -15);
+        assert_eq!(G, FixedU256::<U255>::from_str_binary("1.1").unwrap());
+        assert_eq!(H.to_bits().to_words(), (-1, !0));
+    }
+
+    // Random literals in every radix, with short fractions that are
+    // close to rounding boundaries of narrow types as well as long ones.
+    fn random_literal(rng: &mut Xoshiro256PlusPlus) -> String {
+        let mut pick = |n: u64| (rng.next_u64() % n) as usize;
+        let mut s = String::new();
+        match pick(4) {
+            0 => s.push('-'),
+            1 => s.push('+'),
+            _ => {}
+        }
+        let (radix, exp_marker) = match pick(8) {
+            0 => (16, Some('p')),
+            1 => (8, None),
+            2 => (2, None),
+            _ => (10, Some('e')),
+        };
+        match radix {
+            16 => s.push_str("0x"),
+            8 => s.push_str("0o"),
+            2 => s.push_str("0b"),
+            _ => {}
+        }
+        let int_len = if pick(4) == 0 { pick(45) } else { pick(4) };
+        let frac_len = if pick(4) == 0 { pick(70) } else { pick(7) };
+        let int_len = if int_len + frac_len == 0 { 1 } else { int_len };
+        let mut push_digits = |s: &mut String, len: usize| {
+            for i in 0..len {
+                if i > 0 && pick(10) == 0 {
+                    s.push('_');
+                }
+                s.push(char::from(b"0123456789abcdef"[pick(radix)]));
+            }
+        };
+        push_digits(&mut s, int_len);
+        if frac_len > 0 {
+            s.push('.');
+            push_digits(&mut s, frac_len);
+        }
+        if let Some(marker) = exp_marker {
+            if pick(4) == 0 {
+                s.push(marker);
+                s.push_str(&format!("{}", pick(41) as i32 - 20));
+            }
+        }
+        s
+    }
+
+    #[test]
+    fn same_as_from_str() {
+        let options = ParseOptions::new().prefix(true).underscores(true);
+        let mut strings = [
+            "0",
+            "-0",
+            "+1",
+            "0.5",
+            "1.5",
+            "2.5",
+            "-2.5",
+            "0.4277",
+            "0.9746",
+            "127.998",
+            "-128",
+            "-128.001",
+            "255.999",
+            "0.000_000_01",
+            "1_234.567_89e-2",
+            "3.14159265358979323846264338327950288419716939937510",
+            "0.49999999999999999999999999999999999999999999999999999",
+            "0.50000000000000000000000000000000000000000000000000001",
+            "1e-60",
+            "12e1",
+            "0x7f.ff8",
+            "0x7F.FF7",
+            "-0x80",
+            "0x1p-9",
+            "0x3p-10",
+            "0x1.8p3",
+            "0b1.1",
+            "0b0.000_000_011",
+            "0o7.7",
+            "-0o1.4",
+        ]
+        .iter()
+        .map(|&s| String::from(s))
+        .collect::<Vec<_>>();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(0x6c69_7465);
+        for _ in 0..5000 {
+            strings.push(random_literal(&mut rng));
+        }
+        macro_rules! check {
+            ($($Fixed:ty),*) => {
+                $(
+                    for s in &strings {
+                        let nbits = <$Fixed>::INT_NBITS + <$Fixed>::FRAC_NBITS;
+                        let signed = <$Fixed>::min_value() != 0;
+                        if let Ok(val) = <$Fixed>::from_str_with(s, options) {
+                            let (_, lo) = super::parse(s.as_bytes(), nbits, <$Fixed>::FRAC_NBITS, signed);
+                            assert_eq!(<$Fixed>::from_bits(lo as _), val, "{}", s);
+                        }
+                    }
+                )*
+            };
+        }
+        check!(I8F0, I4F4, I0F8, U8F0, U4F4, U0F8, I8F8, U8F8);
+        check!(I16F0, I8F8, I0F16, U16F0, U8F8, U0F16, I16F16, U16F16);
+        check!(I32F0, I16F16, I0F32, U32F0, U16F16, U0F32, I32F32, U32F32);
+        check!(I64F0, I32F32, I0F64, U64F0, U32F32, U0F64, I64F64, U64F64);
+        check!(I128F0, I64F64, I0F128, U128F0, U64F64, U0F128);
+        macro_rules! check256 {
+            ($($Frac:ty),*) => {
+                $(
+                    for s in &strings {
+                        let frac_nbits = <$Frac as Unsigned>::U32;
+                        if let Ok(val) = FixedI256::<$Frac>::from_str_with(s, options) {
+                            let (hi, lo) = super::parse(s.as_bytes(), 256, frac_nbits, true);
+                            let words = (hi as i128, lo);
+                            assert_eq!(val.to_bits().to_words(), words, "{}", s);
+                        }
+                        if let Ok(val) = FixedU256::<$Frac>::from_str_with(s, options) {
+                            let (hi, lo) = super::parse(s.as_bytes(), 256, frac_nbits, false);
+                            assert_eq!(val.to_bits().to_words(), (hi, lo), "{}", s);
+                        }
+                    }
+                )*
+            };
+        }
+        check256!(U0, U8, U128, U200, U255, U256);
+    }
+
+    #[test]
+    fn formatted_values() {
+        for bits in (i16::MIN..=i16::MAX).step_by(7) {
+            let f = I8F8::from_bits(bits);
+            for prec in 0..5 {
+                let s = format!("{:.*}", prec, f);
+                for &frac_nbits in &[0, 3, 8, 12] {
+                    let val = match frac_nbits {
+                        0 => I16F0::from_str(&s).map(|f| f.to_bits()),
+                        3 => I13F3::from_str(&s).map(|f| f.to_bits()),
+                        8 => I8F8::from_str(&s).map(|f| f.to_bits()),
+                        _ => I4F12::from_str(&s).map(|f| f.to_bits()),
+                    };
+                    if let Ok(val) = val {
+                        let (_, lo) = super::parse(s.as_bytes(), 16, frac_nbits, true);
+                        assert_eq!(lo as i16, val, "{} {}", s, frac_nbits);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point literal overflows")]
+    fn overflow() {
+        let _ = super::parse(b"128", 16, 8, true);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point literal overflows")]
+    fn overflow_rounding() {
+        let _ = super::parse(b"127.999", 16, 8, true);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point literal overflows")]
+    fn overflow_negative_unsigned() {
+        let _ = super::parse(b"-0.01", 16, 8, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid digit found in fixed-point literal")]
+    fn invalid_digit() {
+        let _ = super::parse(b"0x1g", 16, 8, true);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point literal has no digits")]
+    fn no_digits() {
+        let _ = super::parse(b"-.", 16, 8, true);
+    }
+}
@@ -265,6 +265,215 @@ assert_eq!(Fix::from_num(7.5).rem_euclid_int(2), Fix::from_num(1.5));
                 }
             }
 
+            comment! {
+                "Square root, rounded down.
+
+The result is bit-exact: it is computed with an integer square root
+on the widened bit representation.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "# Panics
+
+Panics if the number is negative, or if the root does not fit, which
+can only happen when there are no integer bits.
+
+",
+                },
+                "# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(6.25).sqrt(), Fix::from_num(2.5));
+// sqrt(2) = 1.0110101..., rounded down to four fractional bits
+assert_eq!(Fix::from_num(2).sqrt(), Fix::from_bits(0b1_0110));
+```
+";
+                #[inline]
+                pub fn sqrt(self) -> $Fixed<Frac> {
+                    if_signed! {
+                        $Signedness;
+                        assert!(self.to_bits() >= 0, "square root of negative number");
+                    }
+                    let root = self.to_bits().sqrt_frac(Frac::U32);
+                    Self::from_bits(root.expect("overflow"))
+                }
+            }
+            comment! {
+                "Multiplication with an explicit rounding mode.
+
+The exact product is rounded to the nearest representable value in
+the direction given by `mode`, whereas the `*` operator always rounds
+toward −∞.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the
+multiplication overflows. When debug assertions are not enabled, the
+wrapped value can be returned, but it is not considered a breaking
+change if in the future it panics; if wrapping is required use
+[`wrapping_mul_round`] instead.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, RoundingMode, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let a = Fix::from_bits(0b101);
+let half = Fix::from_num(0.5);
+// the exact product is 2.5 times the least significant bit
+assert_eq!(a.mul_round(half, RoundingMode::Floor), Fix::from_bits(2));
+assert_eq!(a.mul_round(half, RoundingMode::Ceil), Fix::from_bits(3));
+assert_eq!(a.mul_round(half, RoundingMode::HalfUp), Fix::from_bits(3));
+assert_eq!(a.mul_round(half, RoundingMode::HalfEven), Fix::from_bits(2));
+```
+
+[`wrapping_mul_round`]: #method.wrapping_mul_round
+";
+                #[inline]
+                pub fn mul_round(self, rhs: $Fixed<Frac>, mode: RoundingMode) -> $Fixed<Frac> {
+                    let (ans, overflow) = self.overflowing_mul_round(rhs, mode);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Division with an explicit rounding mode.
+
+The exact quotient is rounded to the nearest representable value in
+the direction given by `mode`, whereas the `/` operator always rounds
+toward zero.
+
+# Panics
+
+Panics if the divisor is zero.
+
+When debug assertions are enabled, this method also panics if the
+division overflows. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if
+in the future it panics; if wrapping is required use
+[`wrapping_div_round`] instead.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, RoundingMode, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (one, three) = (Fix::from_num(1), Fix::from_num(3));
+// 1/3 = 0.0101..., which lies between 5 and 6 times the least significant bit
+assert_eq!(one.div_round(three, RoundingMode::Floor), Fix::from_bits(5));
+assert_eq!(one.div_round(three, RoundingMode::Ceil), Fix::from_bits(6));
+assert_eq!(one.div_round(three, RoundingMode::HalfUp), Fix::from_bits(5));
+// the exact quotient is 2.5 times the least significant bit
+let a = Fix::from_bits(5);
+assert_eq!(a.div_round(Fix::from_num(2), RoundingMode::HalfUp), Fix::from_bits(3));
+assert_eq!(a.div_round(Fix::from_num(2), RoundingMode::HalfEven), Fix::from_bits(2));
+```
+
+[`wrapping_div_round`]: #method.wrapping_div_round
+";
+                #[inline]
+                pub fn div_round(self, rhs: $Fixed<Frac>, mode: RoundingMode) -> $Fixed<Frac> {
+                    let (ans, overflow) = self.overflowing_div_round(rhs, mode);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+            comment! {
+                "Multiplies by `mul` and divides by `div`, rounding the
+quotient using `mode`.
+
+The product is kept at double width, so the intermediate product
+cannot overflow; only the final quotient has to fit.
+
+# Panics
+
+Panics if `div` is zero.
+
+When debug assertions are enabled, this method also panics if the
+quotient overflows. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if
+in the future it panics.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, RoundingMode, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (one, three) = (Fix::from_num(1), Fix::from_num(3));
+assert_eq!(one.mul_div_round(one, three, RoundingMode::Floor), Fix::from_bits(5));
+assert_eq!(one.mul_div_round(one, three, RoundingMode::Ceil), Fix::from_bits(6));
+// max × 3 overflows, but max × 3 / 3 does not
+let max = Fix::max_value();
+assert_eq!(max.mul_div_round(three, three, RoundingMode::Floor), max);
+```
+";
+                #[inline]
+                pub fn mul_div_round(
+                    self,
+                    mul: $Fixed<Frac>,
+                    div: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> $Fixed<Frac> {
+                    let (ans, overflow) =
+                        MulDivRound::mul_div_round(self.to_bits(), mul.to_bits(), div.to_bits(), mode);
+                    debug_assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
+                }
+            }
+            comment! {
+                "Multiplies by a number with a possibly different
+number of fractional bits, returning the product as the destination
+type `Dst`.
+
+The exact product is rounded to the nearest representable value of
+`Dst`, with ties rounded to even.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the product
+does not fit in `Dst`. When debug assertions are not enabled, the
+wrapped value can be returned, but it is not considered a breaking
+change if in the future it panics; if checking is required use
+[`checked_mul_to`] instead.
+
+# Examples
+
+```rust
+use substrate_fixed::{
+    types::extra::{U2, U3, U4},
+    ", $s_fixed, ",
+};
+let a = ", $s_fixed, "::<U3>::from_num(1.5);
+let b = ", $s_fixed, "::<U2>::from_num(2.75);
+// 1.5 × 2.75 = 4.125 is exact with four fractional bits
+let prod: ", $s_fixed, "<U4> = a.mul_to(b);
+assert_eq!(prod, 4.125);
+// rounded to the nearest with two fractional bits, ties to even
+assert_eq!(a.mul_to::<", $s_fixed, "<U2>, _>(b), 4);
+```
+
+[`checked_mul_to`]: #method.checked_mul_to
+";
+                #[inline]
+                pub fn mul_to<Dst, RhsFrac>(self, rhs: $Fixed<RhsFrac>) -> Dst
+                where
+                    Dst: Fixed,
+                    RhsFrac: $LeEqU,
+                {
+                    let (ans, overflow) = arith::overflowing_mul_to(
+                        self.private_neg_abs(),
+                        rhs.private_neg_abs(),
+                        Frac::U32 + RhsFrac::U32,
+                    );
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
             comment! {
                 "Checked multiplication. Returns the product, or [`None`] on overflow.
 
@@ -501,6 +710,214 @@ assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(20), None);
                 }
             }
 
+            comment! {
+                "Checked square root. Returns the square root rounded down, or [`None`] ",
+                if_signed_unsigned! {
+                    $Signedness,
+                    "if the number is negative or if the root does not fit.",
+                    "only for consistency with the signed types, as the root always fits.",
+                },
+                "
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(6.25).checked_sqrt(), Some(Fix::from_num(2.5)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-1).checked_sqrt(), None);
+",
+                },
+                "```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn checked_sqrt(self) -> Option<$Fixed<Frac>> {
+                    self.to_bits().sqrt_frac(Frac::U32).map(Self::from_bits)
+                }
+            }
+
+            comment! {
+                "Checked power with an integer exponent. Returns the
+power rounded to the nearest, or [`None`] on overflow or if the number
+is zero and the exponent is negative.
+
+The power is computed by repeated squaring with 256-bit intermediate
+bounds, and negative exponents are handled by taking the reciprocal
+before the final rounding. The result is correctly rounded unless the
+exact power lies within a relative 2<sup>−222</sup> of a tie, in which
+case it can be one unit in the last place off.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.5).checked_powi(3), Some(Fix::from_num(3.375)));
+// 1/6 = 0.0010101..., rounded to the nearest with four fractional bits
+assert_eq!(Fix::from_num(6).checked_powi(-1), Some(Fix::from_bits(3)));
+assert_eq!(Fix::from_num(0).checked_powi(-1), None);
+assert_eq!(Fix::max_value().checked_powi(2), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn checked_powi(self, exp: i32) -> Option<$Fixed<Frac>> {
+                    match self.to_bits().powi_frac(exp, Frac::U32) {
+                        Some((ans, false)) => Some(Self::from_bits(ans)),
+                        _ => None,
+                    }
+                }
+            }
+            comment! {
+                "Checked multiplication with an explicit rounding mode.
+Returns the rounded product, or [`None`] on overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, RoundingMode, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let a = Fix::from_bits(0b101);
+let half = Fix::from_num(0.5);
+assert_eq!(a.checked_mul_round(half, RoundingMode::Ceil), Some(Fix::from_bits(3)));
+let two = Fix::from_num(2);
+assert_eq!(Fix::max_value().checked_mul_round(two, RoundingMode::Floor), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn checked_mul_round(
+                    self,
+                    rhs: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> Option<$Fixed<Frac>> {
+                    match self.overflowing_mul_round(rhs, mode) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked division with an explicit rounding mode.
+Returns the rounded quotient, or [`None`] if the divisor is zero or on
+overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, RoundingMode, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (one, three) = (Fix::from_num(1), Fix::from_num(3));
+assert_eq!(one.checked_div_round(three, RoundingMode::Ceil), Some(Fix::from_bits(6)));
+assert_eq!(one.checked_div_round(Fix::from_num(0), RoundingMode::Ceil), None);
+let quarter = Fix::from_num(0.25);
+assert_eq!(Fix::max_value().checked_div_round(quarter, RoundingMode::Floor), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn checked_div_round(
+                    self,
+                    rhs: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> Option<$Fixed<Frac>> {
+                    if rhs.to_bits() == 0 {
+                        return None;
+                    }
+                    match self.overflowing_div_round(rhs, mode) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+            comment! {
+                "Checked multiplication followed by division. Returns
+`self` × `mul` / `div` rounded toward zero, or [`None`] if `div` is
+zero or on overflow.
+
+The product is kept at double width, so only the final quotient has to
+fit. To use a different rounding mode, use [`mul_div_round`].
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (max, two, four) = (Fix::max_value(), Fix::from_num(2), Fix::from_num(4));
+// max × 2 overflows, but max × 2 / 4 does not
+assert_eq!(max.checked_mul(two), None);
+assert_eq!(max.checked_mul_div(two, four), Some(max / 2));
+assert_eq!(max.checked_mul_div(four, two), None);
+assert_eq!(max.checked_mul_div(two, Fix::from_num(0)), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+[`mul_div_round`]: #method.mul_div_round
+";
+                #[inline]
+                pub fn checked_mul_div(
+                    self,
+                    mul: $Fixed<Frac>,
+                    div: $Fixed<Frac>,
+                ) -> Option<$Fixed<Frac>> {
+                    if div.to_bits() == 0 {
+                        return None;
+                    }
+                    let mode = RoundingMode::TowardZero;
+                    match MulDivRound::mul_div_round(self.to_bits(), mul.to_bits(), div.to_bits(), mode) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+            comment! {
+                "Checked multiplication by a number with a possibly
+different number of fractional bits. Returns the product as the
+destination type `Dst` rounded to the nearest, with ties rounded to
+even, or [`None`] if it does not fit.
+
+# Examples
+
+```rust
+use substrate_fixed::{
+    types::extra::{U2, U3, U4},
+    ", $s_fixed, ",
+};
+let a = ", $s_fixed, "::<U3>::from_num(1.5);
+let b = ", $s_fixed, "::<U2>::from_num(2.75);
+assert_eq!(a.checked_mul_to::<", $s_fixed, "<U4>, _>(b), Some(", $s_fixed, "::from_num(4.125)));
+let max = ", $s_fixed, "::<U3>::max_value();
+assert_eq!(max.checked_mul_to::<", $s_fixed, "<U4>, _>(b), None);
+```
+
+[`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+";
+                #[inline]
+                pub fn checked_mul_to<Dst, RhsFrac>(self, rhs: $Fixed<RhsFrac>) -> Option<Dst>
+                where
+                    Dst: Fixed,
+                    RhsFrac: $LeEqU,
+                {
+                    match arith::overflowing_mul_to(
+                        self.private_neg_abs(),
+                        rhs.private_neg_abs(),
+                        Frac::U32 + RhsFrac::U32,
+                    ) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
             comment! {
                 "Saturating multiplication. Returns the product, saturating on overflow.
 
@@ -601,6 +1018,177 @@ assert_eq!(Fix::min_value().saturating_div_euclid(Fix::from_num(0.25)), Fix::min
                 }
             }
 
+            comment! {
+                "Saturating power with an integer exponent. Returns the
+power rounded to the nearest, saturating on overflow.
+
+# Panics
+
+Panics if the number is zero and the exponent is negative.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.5).saturating_powi(3), Fix::from_num(3.375));
+assert_eq!(Fix::max_value().saturating_powi(2), Fix::max_value());
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(Fix::min_value().saturating_powi(3), Fix::min_value());
+",
+                },
+                "```
+";
+                #[inline]
+                pub fn saturating_powi(self, exp: i32) -> $Fixed<Frac> {
+                    match self.overflowing_powi(exp) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if self < 0 && exp & 1 != 0 {
+                                Self::min_value()
+                            } else {
+                                Self::max_value()
+                            }
+                        }
+                    }
+                }
+            }
+            comment! {
+                "Saturating multiplication with an explicit rounding
+mode. Returns the rounded product, saturating on overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, RoundingMode, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let a = Fix::from_bits(0b101);
+let half = Fix::from_num(0.5);
+assert_eq!(a.saturating_mul_round(half, RoundingMode::Ceil), Fix::from_bits(3));
+let two = Fix::from_num(2);
+assert_eq!(Fix::max_value().saturating_mul_round(two, RoundingMode::Floor), Fix::max_value());
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "let neg_two = Fix::from_num(-2);
+assert_eq!(Fix::max_value().saturating_mul_round(neg_two, RoundingMode::Floor), Fix::min_value());
+",
+                },
+                "```
+";
+                #[inline]
+                pub fn saturating_mul_round(
+                    self,
+                    rhs: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> $Fixed<Frac> {
+                    match self.overflowing_mul_round(rhs, mode) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if (self < 0) != (rhs < 0) {
+                                Self::min_value()
+                            } else {
+                                Self::max_value()
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating division with an explicit rounding mode.
+Returns the rounded quotient, saturating on overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, RoundingMode, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (one, three) = (Fix::from_num(1), Fix::from_num(3));
+assert_eq!(one.saturating_div_round(three, RoundingMode::Ceil), Fix::from_bits(6));
+let quarter = Fix::from_num(0.25);
+assert_eq!(Fix::max_value().saturating_div_round(quarter, RoundingMode::Floor), Fix::max_value());
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "let neg_quarter = Fix::from_num(-0.25);
+assert_eq!(Fix::max_value().saturating_div_round(neg_quarter, RoundingMode::Floor), Fix::min_value());
+",
+                },
+                "```
+";
+                #[inline]
+                pub fn saturating_div_round(
+                    self,
+                    rhs: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> $Fixed<Frac> {
+                    match self.overflowing_div_round(rhs, mode) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if (self < 0) != (rhs < 0) {
+                                Self::min_value()
+                            } else {
+                                Self::max_value()
+                            }
+                        }
+                    }
+                }
+            }
+            comment! {
+                "Saturating multiplication followed by division.
+Returns `self` × `mul` / `div` rounded toward zero, saturating on
+overflow.
+
+The product is kept at double width, so only the final quotient has to
+fit.
+
+# Panics
+
+Panics if `div` is zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (max, two, four) = (Fix::max_value(), Fix::from_num(2), Fix::from_num(4));
+assert_eq!(max.saturating_mul_div(two, four), max / 2);
+assert_eq!(max.saturating_mul_div(four, two), max);
+",
+                if_signed_else_empty_str! {
+                    $Signedness,
+                    "assert_eq!(max.saturating_mul_div(four, -two), Fix::min_value());
+",
+                },
+                "```
+";
+                #[inline]
+                pub fn saturating_mul_div(
+                    self,
+                    mul: $Fixed<Frac>,
+                    div: $Fixed<Frac>,
+                ) -> $Fixed<Frac> {
+                    let mode = RoundingMode::TowardZero;
+                    match MulDivRound::mul_div_round(self.to_bits(), mul.to_bits(), div.to_bits(), mode) {
+                        (ans, false) => Self::from_bits(ans),
+                        (_, true) => {
+                            if ((self < 0) != (mul < 0)) != (div < 0) {
+                                Self::min_value()
+                            } else {
+                                Self::max_value()
+                            }
+                        }
+                    }
+                }
+            }
+
             comment! {
                 "Wrapping multiplication. Returns the product, wrapping on overflow.
 
@@ -749,6 +1337,90 @@ assert_eq!(Fix::from_num(-7.5).wrapping_rem_euclid_int(20), Fix::from_num(-3.5))
                 }
             }
 
+            comment! {
+                "Wrapping power with an integer exponent. Returns the
+power rounded to the nearest, wrapping on overflow.
+
+The exact power is wrapped when it has no fractional bits, or when it
+can be formed with 8192 bits down to the rounding bit; beyond that the
+wrapped value is unspecified.
+
+# Panics
+
+Panics if the number is zero and the exponent is negative.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.5).wrapping_powi(3), Fix::from_num(3.375));
+// 2^124 has all its significant bits above the most significant bit
+assert_eq!(Fix::from_num(2).wrapping_powi(124), Fix::from_num(0));
+```
+";
+                #[inline]
+                pub fn wrapping_powi(self, exp: i32) -> $Fixed<Frac> {
+                    self.overflowing_powi(exp).0
+                }
+            }
+            comment! {
+                "Wrapping multiplication with an explicit rounding
+mode. Returns the rounded product, wrapping on overflow.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, RoundingMode, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let a = Fix::from_bits(0b101);
+let half = Fix::from_num(0.5);
+assert_eq!(a.wrapping_mul_round(half, RoundingMode::Ceil), Fix::from_bits(3));
+let wrapped = Fix::from_bits(!0 << 2);
+let four = Fix::from_num(4);
+assert_eq!(Fix::max_value().wrapping_mul_round(four, RoundingMode::Floor), wrapped);
+```
+";
+                #[inline]
+                pub fn wrapping_mul_round(
+                    self,
+                    rhs: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> $Fixed<Frac> {
+                    self.overflowing_mul_round(rhs, mode).0
+                }
+            }
+
+            comment! {
+                "Wrapping division with an explicit rounding mode.
+Returns the rounded quotient, wrapping on overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, RoundingMode, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (one, three) = (Fix::from_num(1), Fix::from_num(3));
+assert_eq!(one.wrapping_div_round(three, RoundingMode::Ceil), Fix::from_bits(6));
+let quarter = Fix::from_num(0.25);
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(Fix::max_value().wrapping_div_round(quarter, RoundingMode::Floor), wrapped);
+```
+";
+                #[inline]
+                pub fn wrapping_div_round(
+                    self,
+                    rhs: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> $Fixed<Frac> {
+                    self.overflowing_div_round(rhs, mode).0
+                }
+            }
+
             comment! {
                 "Overflowing multiplication.
 
@@ -991,6 +1663,113 @@ assert_eq!(Fix::from_num(-7.5).overflowing_rem_euclid_int(20), (Fix::from_num(-3
                 }
             }
 
+            comment! {
+                "Overflowing power with an integer exponent.
+
+Returns a [tuple] of the power rounded to the nearest and a [`bool`]
+indicating whether an overflow has occurred. On overflow, the wrapped
+value is returned. The exact power is wrapped when it has no fractional
+bits, or when it can be formed with 8192 bits down to the rounding bit;
+beyond that the wrapped value is unspecified.
+
+# Panics
+
+Panics if the number is zero and the exponent is negative.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.5).overflowing_powi(3), (Fix::from_num(3.375), false));
+assert_eq!(Fix::from_num(2).overflowing_powi(124), (Fix::from_num(0), true));
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+                #[inline]
+                pub fn overflowing_powi(self, exp: i32) -> ($Fixed<Frac>, bool) {
+                    let (ans, overflow) = self
+                        .to_bits()
+                        .powi_frac(exp, Frac::U32)
+                        .expect("division by zero");
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+            comment! {
+                "Overflowing multiplication with an explicit rounding mode.
+
+Returns a [tuple] of the rounded product and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, RoundingMode, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let a = Fix::from_bits(0b101);
+let half = Fix::from_num(0.5);
+assert_eq!(a.overflowing_mul_round(half, RoundingMode::Ceil), (Fix::from_bits(3), false));
+let wrapped = Fix::from_bits(!0 << 2);
+let four = Fix::from_num(4);
+assert_eq!(Fix::max_value().overflowing_mul_round(four, RoundingMode::Floor), (wrapped, true));
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+                #[inline]
+                pub fn overflowing_mul_round(
+                    self,
+                    rhs: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> ($Fixed<Frac>, bool) {
+                    let (ans, overflow) =
+                        MulDivRound::mul_round(self.to_bits(), rhs.to_bits(), Frac::U32, mode);
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Overflowing division with an explicit rounding mode.
+
+Returns a [tuple] of the rounded quotient and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, RoundingMode, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let (one, three) = (Fix::from_num(1), Fix::from_num(3));
+assert_eq!(one.overflowing_div_round(three, RoundingMode::Ceil), (Fix::from_bits(6), false));
+let quarter = Fix::from_num(0.25);
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(Fix::max_value().overflowing_div_round(quarter, RoundingMode::Floor), (wrapped, true));
+```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+                #[inline]
+                pub fn overflowing_div_round(
+                    self,
+                    rhs: $Fixed<Frac>,
+                    mode: RoundingMode,
+                ) -> ($Fixed<Frac>, bool) {
+                    let (ans, overflow) =
+                        MulDivRound::div_round(self.to_bits(), rhs.to_bits(), Frac::U32, mode);
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
             /// Remainder for division by an integer.
             ///
             /// # Panics
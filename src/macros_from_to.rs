@@ -924,6 +924,9 @@ assert_eq!(neg, Ok(-check));
         comment! {
             "Parses a string slice containing hexadecimal digits to return a fixed-point number.
 
+The digits can be followed by a binary exponent introduced by `p` or
+`P`, for example `1.8p4` is 24.
+
 Rounding is to the nearest, with ties rounded to even.
 
 # Examples
@@ -935,6 +938,8 @@ type Fix = ", $s_fixed, "<U4>;
 let f = Fix::from_str_hex(\"1.C\");
 let check = Fix::from_bits(0b111 << (4 - 2));
 assert_eq!(f, Ok(check));
+// 1.75 is also 7 × 2^−2
+assert_eq!(Fix::from_str_hex(\"7p-2\"), Ok(check));
 ",
             if_signed_else_empty_str! {
                 $Signedness,
@@ -950,6 +955,51 @@ assert_eq!(neg, Ok(-check));
             }
         }
 
+        comment! {
+            "Parses a string slice containing digits in the given radix to return a
+fixed-point number.
+
+The radix must be in the range 2 to 36 inclusive. Digits above 9 are
+the letters `a` to `z`, which can be uppercase or lowercase. Exponents
+are supported only for radix 10 and radix 16, as in
+[`from_str`][`FromStr`] and [`from_str_hex`][`from_str_hex`].
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the radix is not in the range 2 to 36 inclusive.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.1 in base 3 is 4/3, which rounds to 21/16
+let f = Fix::from_str_radix(\"1.1\", 3);
+assert_eq!(f, Ok(Fix::from_bits(21)));
+// 1.I in base 36 is 1.5
+let check = Fix::from_bits(0b11 << (4 - 1));
+assert_eq!(Fix::from_str_radix(\"1.I\", 36), Ok(check));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "let neg = Fix::from_str_radix(\"-1.i\", 36);
+assert_eq!(neg, Ok(-check));
+",
+            },
+            "```
+
+[`FromStr`]: https://doc.rust-lang.org/nightly/std/str/trait.FromStr.html
+[`from_str_hex`]: #method.from_str_hex
+";
+            #[inline]
+            pub fn from_str_radix(src: &str, radix: u32) -> Result<$Fixed<Frac>, ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::from_str_radix(src, radix)
+            }
+        }
+
         comment! {
             "Parses a string slice containing decimal digits to return a fixed-point number,
 saturating on overflow.
@@ -1066,6 +1116,51 @@ assert_eq!(U8F8::saturating_from_str_hex(\"-1\"), Ok(U8F8::from_num(0)));
             }
         }
 
+        comment! {
+            "Parses a string slice containing digits in the given radix to return a
+fixed-point number, saturating on overflow.
+
+The radix must be in the range 2 to 36 inclusive. Digits above 9 are
+the letters `a` to `z`, which can be uppercase or lowercase. Exponents
+are supported only for radix 10 and radix 16, as in
+[`from_str`][`FromStr`] and [`from_str_hex`][`from_str_hex`].
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the radix is not in the range 2 to 36 inclusive.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "use substrate_fixed::types::I8F8;
+assert_eq!(I8F8::saturating_from_str_radix(\"ZZZ\", 36), Ok(I8F8::max_value()));
+assert_eq!(I8F8::saturating_from_str_radix(\"-ZZZ\", 36), Ok(I8F8::min_value()));
+",
+                "use substrate_fixed::types::U8F8;
+assert_eq!(U8F8::saturating_from_str_radix(\"ZZZ\", 36), Ok(U8F8::max_value()));
+assert_eq!(U8F8::saturating_from_str_radix(\"-1\", 36), Ok(U8F8::from_num(0)));
+",
+            },
+            "```
+
+[`FromStr`]: https://doc.rust-lang.org/nightly/std/str/trait.FromStr.html
+[`from_str_hex`]: #method.from_str_hex
+";
+            #[inline]
+            pub fn saturating_from_str_radix(
+                src: &str,
+                radix: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::saturating_from_str_radix(src, radix)
+            }
+        }
+
         comment! {
             "Parses a string slice containing decimal digits to return a fixed-point number,
 wrapping on overflow.
@@ -1190,6 +1285,55 @@ assert_eq!(U8F8::wrapping_from_str_hex(\"-C0F.FE\"), Ok(check.wrapping_neg()));
             }
         }
 
+        comment! {
+            "Parses a string slice containing digits in the given radix to return a
+fixed-point number, wrapping on overflow.
+
+The radix must be in the range 2 to 36 inclusive. Digits above 9 are
+the letters `a` to `z`, which can be uppercase or lowercase. Exponents
+are supported only for radix 10 and radix 16, as in
+[`from_str`][`FromStr`] and [`from_str_hex`][`from_str_hex`].
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the radix is not in the range 2 to 36 inclusive.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "use substrate_fixed::types::I8F8;
+// 12.1 in base 3 is 16/3, and 100200.1 in base 3 is 16/3 + 256
+let check = I8F8::from_bits(1365);
+assert_eq!(I8F8::wrapping_from_str_radix(\"12.1\", 3), Ok(check));
+assert_eq!(I8F8::wrapping_from_str_radix(\"-100200.1\", 3), Ok(-check));
+",
+                "use substrate_fixed::types::U8F8;
+// 12.1 in base 3 is 16/3, and 100200.1 in base 3 is 16/3 + 256
+let check = U8F8::from_bits(1365);
+assert_eq!(U8F8::wrapping_from_str_radix(\"12.1\", 3), Ok(check));
+assert_eq!(U8F8::wrapping_from_str_radix(\"100200.1\", 3), Ok(check));
+",
+            },
+            "```
+
+[`FromStr`]: https://doc.rust-lang.org/nightly/std/str/trait.FromStr.html
+[`from_str_hex`]: #method.from_str_hex
+";
+            #[inline]
+            pub fn wrapping_from_str_radix(
+                src: &str,
+                radix: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::wrapping_from_str_radix(src, radix)
+            }
+        }
+
         comment! {
             "Parses a string slice containing decimal digits to return a fixed-point number.
 
@@ -1345,5 +1489,578 @@ assert_eq!(U8F8::overflowing_from_str_hex(\"C0F.FE\"), Ok((check, true)));
                 FromStrRadix::overflowing_from_str_radix(src, 16)
             }
         }
+
+        comment! {
+            "Parses a string slice containing digits in the given radix to return a
+fixed-point number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+The radix must be in the range 2 to 36 inclusive. Digits above 9 are
+the letters `a` to `z`, which can be uppercase or lowercase. Exponents
+are supported only for radix 10 and radix 16, as in
+[`from_str`][`FromStr`] and [`from_str_hex`][`from_str_hex`].
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the radix is not in the range 2 to 36 inclusive.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "use substrate_fixed::types::I8F8;
+// 12.1 in base 3 is 16/3, and 100200.1 in base 3 is 16/3 + 256
+let check = I8F8::from_bits(1365);
+assert_eq!(I8F8::overflowing_from_str_radix(\"12.1\", 3), Ok((check, false)));
+assert_eq!(I8F8::overflowing_from_str_radix(\"-100200.1\", 3), Ok((-check, true)));
+",
+                "use substrate_fixed::types::U8F8;
+// 12.1 in base 3 is 16/3, and 100200.1 in base 3 is 16/3 + 256
+let check = U8F8::from_bits(1365);
+assert_eq!(U8F8::overflowing_from_str_radix(\"12.1\", 3), Ok((check, false)));
+assert_eq!(U8F8::overflowing_from_str_radix(\"100200.1\", 3), Ok((check, true)));
+",
+            },
+            "```
+
+[`FromStr`]: https://doc.rust-lang.org/nightly/std/str/trait.FromStr.html
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[`from_str_hex`]: #method.from_str_hex
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+            #[inline]
+            pub fn overflowing_from_str_radix(
+                src: &str,
+                radix: u32,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::overflowing_from_str_radix(src, radix)
+            }
+        }
+
+        comment! {
+            "Parses a string slice to return a fixed-point number, using the
+given [`ParseOptions`] to accept underscores, radix prefixes or
+surrounding whitespace.
+
+With the default options, this is equivalent to
+[`from_str`][`FromStr`].
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, ", ParseOptions};
+type Fix = ", $s_fixed, "<U4>;
+let options = ParseOptions::new().prefix(true).underscores(true);
+let check = Fix::from_bits(0b11 << (4 - 1));
+assert_eq!(Fix::from_str_with(\"+0b_1.1\", options), Ok(check));
+assert_eq!(Fix::from_str_with(\"0x1.8\", options), Ok(check));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "assert_eq!(Fix::from_str_with(\"-1.5\", options), Ok(-check));
+",
+            },
+            "```
+
+[`FromStr`]: https://doc.rust-lang.org/nightly/std/str/trait.FromStr.html
+[`ParseOptions`]: struct.ParseOptions.html
+";
+            #[inline]
+            pub fn from_str_with(
+                src: &str,
+                options: ParseOptions,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_bytes(src.as_bytes(), options)
+            }
+        }
+
+        comment! {
+            "Parses a string slice to return a fixed-point number using the
+given [`ParseOptions`], saturating on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{",
+            if_signed_unsigned! {
+                $Signedness,
+                "types::I8F8, ParseOptions};
+let options = ParseOptions::new().underscores(true);
+assert_eq!(I8F8::saturating_from_str_with(\"9_999\", options), Ok(I8F8::max_value()));
+assert_eq!(I8F8::saturating_from_str_with(\"-9_999\", options), Ok(I8F8::min_value()));
+",
+                "types::U8F8, ParseOptions};
+let options = ParseOptions::new().underscores(true);
+assert_eq!(U8F8::saturating_from_str_with(\"9_999\", options), Ok(U8F8::max_value()));
+assert_eq!(U8F8::saturating_from_str_with(\"-1\", options), Ok(U8F8::min_value()));
+",
+            },
+            "```
+
+[`ParseOptions`]: struct.ParseOptions.html
+";
+            #[inline]
+            pub fn saturating_from_str_with(
+                src: &str,
+                options: ParseOptions,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_bytes(src.as_bytes(), options)
+            }
+        }
+
+        comment! {
+            "Parses a string slice to return a fixed-point number using the
+given [`ParseOptions`], wrapping on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{",
+            if_signed_unsigned! {
+                $Signedness,
+                "types::I8F8, ParseOptions};
+let options = ParseOptions::new().prefix(true);
+// 9999.5 = 0x270F.8
+let check = I8F8::from_bits(0x0F80);
+assert_eq!(I8F8::wrapping_from_str_with(\"0x270F.8\", options), Ok(check));
+assert_eq!(I8F8::wrapping_from_str_with(\"-0x270F.8\", options), Ok(-check));
+",
+                "types::U8F8, ParseOptions};
+let options = ParseOptions::new().prefix(true);
+// 9999.5 = 0x270F.8
+let check = U8F8::from_bits(0x0F80);
+assert_eq!(U8F8::wrapping_from_str_with(\"0x270F.8\", options), Ok(check));
+assert_eq!(U8F8::wrapping_from_str_with(\"-0x270F.8\", options), Ok(check.wrapping_neg()));
+",
+            },
+            "```
+
+[`ParseOptions`]: struct.ParseOptions.html
+";
+            #[inline]
+            pub fn wrapping_from_str_with(
+                src: &str,
+                options: ParseOptions,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_bytes(src.as_bytes(), options)
+            }
+        }
+
+        comment! {
+            "Parses a string slice to return a fixed-point number using the
+given [`ParseOptions`].
+
+Returns a [tuple] of the fixed-point number and a [`bool`]
+indicating whether an overflow has occurred. On overflow, the wrapped
+value is returned.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{",
+            if_signed_unsigned! {
+                $Signedness,
+                "types::I8F8, ParseOptions};
+let options = ParseOptions::new().trim_whitespace(true);
+let check = I8F8::from_bits(0x0F80);
+assert_eq!(I8F8::overflowing_from_str_with(\" 15.5 \", options), Ok((check, false)));
+assert_eq!(I8F8::overflowing_from_str_with(\" 9999.5 \", options), Ok((check, true)));
+",
+                "types::U8F8, ParseOptions};
+let options = ParseOptions::new().trim_whitespace(true);
+let check = U8F8::from_bits(0x0F80);
+assert_eq!(U8F8::overflowing_from_str_with(\" 15.5 \", options), Ok((check, false)));
+assert_eq!(U8F8::overflowing_from_str_with(\" 9999.5 \", options), Ok((check, true)));
+",
+            },
+            "```
+
+[`ParseOptions`]: struct.ParseOptions.html
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+            #[inline]
+            pub fn overflowing_from_str_with(
+                src: &str,
+                options: ParseOptions,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                FromStrRadix::overflowing_from_bytes(src.as_bytes(), options)
+            }
+        }
+
+        comment! {
+            "Parses a byte slice containing ASCII decimal digits to return a
+fixed-point number.
+
+This accepts the same syntax as [`from_str`][`FromStr`], but it does
+not require the bytes to be valid UTF-8, which is useful when parsing
+fields from raw buffers. Errors report the byte index of the offending
+byte; a byte that is not valid UTF-8 is reported as the replacement
+character U+FFFD.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.75 is 1.11 in binary
+let f = Fix::from_ascii(b\"1.75\");
+let check = Fix::from_bits(0b111 << (4 - 2));
+assert_eq!(f, Ok(check));
+",
+            if_signed_else_empty_str! {
+                $Signedness,
+                "let neg = Fix::from_ascii(b\"-1.75\");
+assert_eq!(neg, Ok(-check));
+",
+            },
+            "let err = Fix::from_ascii(b\"1\\xff\").unwrap_err();
+assert_eq!(err.position(), Some(1));
+```
+
+[`FromStr`]: https://doc.rust-lang.org/nightly/std/str/trait.FromStr.html
+";
+            #[inline]
+            pub fn from_ascii(src: &[u8]) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::from_bytes(src, ParseOptions::new())
+            }
+        }
+
+        comment! {
+            "Parses a byte slice containing ASCII decimal digits to return a
+fixed-point number, saturating on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "use substrate_fixed::types::I8F8;
+assert_eq!(I8F8::saturating_from_ascii(b\"9999\"), Ok(I8F8::max_value()));
+assert_eq!(I8F8::saturating_from_ascii(b\"-9999\"), Ok(I8F8::min_value()));
+",
+                "use substrate_fixed::types::U8F8;
+assert_eq!(U8F8::saturating_from_ascii(b\"9999\"), Ok(U8F8::max_value()));
+assert_eq!(U8F8::saturating_from_ascii(b\"-1\"), Ok(U8F8::min_value()));
+",
+            },
+            "```
+";
+            #[inline]
+            pub fn saturating_from_ascii(src: &[u8]) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::saturating_from_bytes(src, ParseOptions::new())
+            }
+        }
+
+        comment! {
+            "Parses a byte slice containing ASCII decimal digits to return a
+fixed-point number, wrapping on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "use substrate_fixed::types::I8F8;
+// 9999.5 = 15.5 + 256 × n
+assert_eq!(I8F8::wrapping_from_ascii(b\"9999.5\"), Ok(I8F8::from_num(15.5)));
+assert_eq!(I8F8::wrapping_from_ascii(b\"-9999.5\"), Ok(I8F8::from_num(-15.5)));
+",
+                "use substrate_fixed::types::U8F8;
+// 9999.5 = 15.5 + 256 × n
+assert_eq!(U8F8::wrapping_from_ascii(b\"9999.5\"), Ok(U8F8::from_num(15.5)));
+assert_eq!(U8F8::wrapping_from_ascii(b\"-9999.5\"), Ok(U8F8::from_num(240.5)));
+",
+            },
+            "```
+";
+            #[inline]
+            pub fn wrapping_from_ascii(src: &[u8]) -> Result<$Fixed<Frac>, ParseFixedError> {
+                FromStrRadix::wrapping_from_bytes(src, ParseOptions::new())
+            }
+        }
+
+        comment! {
+            "Parses a byte slice containing ASCII decimal digits to return a
+fixed-point number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`]
+indicating whether an overflow has occurred. On overflow, the wrapped
+value is returned.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use substrate_fixed::types::",
+            if_signed_unsigned! {
+                $Signedness,
+                "I8F8;
+assert_eq!(I8F8::overflowing_from_ascii(b\"99.5\"), Ok((I8F8::from_num(99.5), false)));
+assert_eq!(I8F8::overflowing_from_ascii(b\"9999.5\"), Ok((I8F8::from_num(15.5), true)));
+",
+                "U8F8;
+assert_eq!(U8F8::overflowing_from_ascii(b\"99.5\"), Ok((U8F8::from_num(99.5), false)));
+assert_eq!(U8F8::overflowing_from_ascii(b\"9999.5\"), Ok((U8F8::from_num(15.5), true)));
+",
+            },
+            "```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+            #[inline]
+            pub fn overflowing_from_ascii(
+                src: &[u8],
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                FromStrRadix::overflowing_from_bytes(src, ParseOptions::new())
+            }
+        }
+
+        comment! {
+            "Parses a byte slice containing ASCII digits in the given radix to
+return a fixed-point number.
+
+This accepts the same syntax as [`from_str_radix`], but it does not
+require the bytes to be valid UTF-8.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the radix is not in the range 2 to 36 inclusive.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+let check = Fix::from_bits(0b11 << (4 - 1));
+assert_eq!(Fix::from_ascii_radix(b\"1.8\", 16), Ok(check));
+assert_eq!(Fix::from_ascii_radix(b\"1.I\", 36), Ok(check));
+```
+
+[`from_str_radix`]: #method.from_str_radix
+";
+            #[inline]
+            pub fn from_ascii_radix(
+                src: &[u8],
+                radix: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::from_bytes(src, ParseOptions::strict(radix))
+            }
+        }
+
+        comment! {
+            "Parses a byte slice containing ASCII digits in the given radix to
+return a fixed-point number, saturating on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the radix is not in the range 2 to 36 inclusive.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "use substrate_fixed::types::I8F8;
+assert_eq!(I8F8::saturating_from_ascii_radix(b\"zz\", 36), Ok(I8F8::max_value()));
+assert_eq!(I8F8::saturating_from_ascii_radix(b\"-zz\", 36), Ok(I8F8::min_value()));
+",
+                "use substrate_fixed::types::U8F8;
+assert_eq!(U8F8::saturating_from_ascii_radix(b\"zz\", 36), Ok(U8F8::max_value()));
+assert_eq!(U8F8::saturating_from_ascii_radix(b\"-1\", 36), Ok(U8F8::min_value()));
+",
+            },
+            "```
+";
+            #[inline]
+            pub fn saturating_from_ascii_radix(
+                src: &[u8],
+                radix: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::saturating_from_bytes(src, ParseOptions::strict(radix))
+            }
+        }
+
+        comment! {
+            "Parses a byte slice containing ASCII digits in the given radix to
+return a fixed-point number, wrapping on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the radix is not in the range 2 to 36 inclusive.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned! {
+                $Signedness,
+                "use substrate_fixed::types::I8F8;
+// 11F.8 in hexadecimal is 31.5 + 256
+let check = I8F8::from_num(31.5);
+assert_eq!(I8F8::wrapping_from_ascii_radix(b\"11F.8\", 16), Ok(check));
+assert_eq!(I8F8::wrapping_from_ascii_radix(b\"-11F.8\", 16), Ok(-check));
+",
+                "use substrate_fixed::types::U8F8;
+// 11F.8 in hexadecimal is 31.5 + 256
+let check = U8F8::from_num(31.5);
+assert_eq!(U8F8::wrapping_from_ascii_radix(b\"11F.8\", 16), Ok(check));
+assert_eq!(U8F8::wrapping_from_ascii_radix(b\"-11F.8\", 16), Ok(check.wrapping_neg()));
+",
+            },
+            "```
+";
+            #[inline]
+            pub fn wrapping_from_ascii_radix(
+                src: &[u8],
+                radix: u32,
+            ) -> Result<$Fixed<Frac>, ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::wrapping_from_bytes(src, ParseOptions::strict(radix))
+            }
+        }
+
+        comment! {
+            "Parses a byte slice containing ASCII digits in the given radix to
+return a fixed-point number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`]
+indicating whether an overflow has occurred. On overflow, the wrapped
+value is returned.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the radix is not in the range 2 to 36 inclusive.
+
+# Examples
+
+```rust
+use substrate_fixed::types::",
+            if_signed_unsigned! {
+                $Signedness,
+                "I8F8;
+// 12.1 in base 3 is 16/3, and 100200.1 in base 3 is 16/3 + 256
+let check = I8F8::from_bits(1365);
+assert_eq!(I8F8::overflowing_from_ascii_radix(b\"12.1\", 3), Ok((check, false)));
+assert_eq!(I8F8::overflowing_from_ascii_radix(b\"100200.1\", 3), Ok((check, true)));
+",
+                "U8F8;
+// 12.1 in base 3 is 16/3, and 100200.1 in base 3 is 16/3 + 256
+let check = U8F8::from_bits(1365);
+assert_eq!(U8F8::overflowing_from_ascii_radix(b\"12.1\", 3), Ok((check, false)));
+assert_eq!(U8F8::overflowing_from_ascii_radix(b\"100200.1\", 3), Ok((check, true)));
+",
+            },
+            "```
+
+[`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+[tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+";
+            #[inline]
+            pub fn overflowing_from_ascii_radix(
+                src: &[u8],
+                radix: u32,
+            ) -> Result<($Fixed<Frac>, bool), ParseFixedError> {
+                check_radix(radix);
+                FromStrRadix::overflowing_from_bytes(src, ParseOptions::strict(radix))
+            }
+        }
+
+        comment! {
+            "Returns a wrapper that formats the fixed-point number in the given radix.
+
+Digits above 9 are written as lowercase letters. Without a precision,
+the fractional part is written using the fewest digits that identify
+the number exactly; otherwise it is rounded to the nearest, with ties
+rounded to an even last digit. See [`DisplayRadix`] for details.
+
+# Panics
+
+Panics if the radix is not in the range 2 to 36 inclusive.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+// 1.5 is 1.I in base 36
+let f = Fix::from_num(1.5);
+assert_eq!(format!(\"{}\", f.display_radix(36)), \"1.i\");
+// 1.25 is 1.111… in base 5, and 1.3125 is 1.1240… in base 5
+assert_eq!(format!(\"{}\", Fix::from_num(1.25).display_radix(5)), \"1.11\");
+assert_eq!(format!(\"{:.2}\", Fix::from_num(1.3125).display_radix(5)), \"1.13\");
+```
+
+[`DisplayRadix`]: struct.DisplayRadix.html
+";
+            #[inline]
+            pub fn display_radix(self, radix: u32) -> DisplayRadix<$Fixed<Frac>> {
+                DisplayRadix::new(self, radix)
+            }
+        }
+
+        comment! {
+            "Formats the fixed-point number as a string in the given radix.
+
+This is equivalent to `self.display_radix(radix).to_string()`, and is
+available only if the [`std` feature] is enabled.
+
+# Panics
+
+Panics if the radix is not in the range 2 to 36 inclusive.
+
+# Examples
+
+```rust
+use substrate_fixed::{types::extra::U4, ", $s_fixed, "};
+type Fix = ", $s_fixed, "<U4>;
+assert_eq!(Fix::from_num(1.5).to_string_radix(36), \"1.i\");
+```
+
+[`std` feature]: index.html#optional-features
+";
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn to_string_radix(self, radix: u32) -> String {
+                self.display_radix(radix).to_string()
+            }
+        }
     };
 }
@@ -96,6 +96,18 @@ assert_eq!(Fix::from_num(2).to_bits(), 0b10_0000);
                 }
             }
 
+            // Used by the fixed! macro, which passes FRAC_NBITS as it
+            // cannot be used in a const fn here without Frac bounds.
+            #[cfg(const_panic)]
+            #[doc(hidden)]
+            #[inline]
+            pub const fn __from_lit(src: &str, frac_nbits: u32) -> $Fixed<Frac> {
+                let nbits = $nbytes * 8;
+                let signed = if_signed_unsigned!($Signedness, true, false);
+                let (_, lo) = crate::lit::parse(src.as_bytes(), nbits, frac_nbits, signed);
+                Self::from_bits(lo as $Inner)
+            }
+
             comment! {
                 "Creates a fixed-point number from its representation
 as a byte array in big endian.
@@ -13,21 +13,184 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
+/*!
+Alternative [serde] representations for fixed-point numbers.
+
+By default, a fixed-point number is serialized as a struct with its
+underlying bits in a field named `bits`. If the `serde-str` feature is
+enabled, human-readable formats such as JSON get the decimal string
+instead, while compact formats keep the bits. With the `serde-str`
+feature, deserializing from a human-readable format accepts a struct, a
+decimal string or a number; otherwise only the struct is accepted.
+
+The modules [`decimal`] and [`float`] can be used with
+`#[serde(with = "substrate_fixed::serdeize::decimal")]` and
+`#[serde(with = "substrate_fixed::serdeize::float")]` to select a
+representation for a single field, independently of the format and of
+the `serde-str` feature.
+
+# Examples
+
+```rust
+use substrate_fixed::{serdeize::decimal, types::I16F16};
+let val = I16F16::from_num(-3.5);
+let mut json = Vec::new();
+decimal::serialize(&val, &mut serde_json::Serializer::new(&mut json)).unwrap();
+assert_eq!(json, b"\"-3.5\"");
+let mut de = serde_json::Deserializer::from_slice(&json);
+assert_eq!(decimal::deserialize::<I16F16, _>(&mut de).unwrap(), val);
+```
+
+[`decimal`]: decimal/index.html
+[`float`]: float/index.html
+[serde]: https://crates.io/crates/serde
+*/
+
 use crate::{
-    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8, Wrapping,
+    from_str::{ParseErrorKind, ParseFixedError},
+    int256::{I256, U256},
+    traits::Fixed,
+    types::extra::{LeEqU128, LeEqU16, LeEqU256, LeEqU32, LeEqU64, LeEqU8},
+    Checked, FixedI128, FixedI16, FixedI256, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16,
+    FixedU256, FixedU32, FixedU64, FixedU8, Saturating, Unwrapped, Wrapping,
+};
+use core::{
+    fmt::{Formatter, Result as FmtResult},
+    marker::PhantomData,
 };
-use core::fmt::{Formatter, Result as FmtResult};
 use serde::{
-    de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor},
+    de::{
+        self, Deserialize, Deserializer, EnumAccess, Expected, MapAccess, SeqAccess, Unexpected,
+        VariantAccess, Visitor,
+    },
     ser::{Serialize, SerializeStruct, Serializer},
 };
 
+fn from_str_value<F: Fixed, E: de::Error>(value: &str) -> Result<F, E> {
+    value.parse().map_err(E::custom)
+}
+
+fn from_f64_value<F: Fixed, E: de::Error>(value: f64, exp: &dyn Expected) -> Result<F, E> {
+    F::checked_from_num(value).ok_or_else(|| E::invalid_value(Unexpected::Float(value), exp))
+}
+
+fn from_i64_value<F: Fixed, E: de::Error>(value: i64, exp: &dyn Expected) -> Result<F, E> {
+    F::checked_from_num(value).ok_or_else(|| E::invalid_value(Unexpected::Signed(value), exp))
+}
+
+fn from_u64_value<F: Fixed, E: de::Error>(value: u64, exp: &dyn Expected) -> Result<F, E> {
+    F::checked_from_num(value).ok_or_else(|| E::invalid_value(Unexpected::Unsigned(value), exp))
+}
+
+/// Serializes fixed-point numbers as decimal strings.
+///
+/// Serialization uses [`Display`], so the string has enough digits
+/// for the value to be parsed back exactly. Deserialization uses
+/// [`FromStr`], so the string can also have an exponent.
+///
+/// [`Display`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Display.html
+/// [`FromStr`]: https://doc.rust-lang.org/nightly/core/str/trait.FromStr.html
+pub mod decimal {
+    use crate::traits::Fixed;
+    use core::{
+        fmt::{Formatter, Result as FmtResult},
+        marker::PhantomData,
+    };
+    use serde::{
+        de::{self, Deserializer, Visitor},
+        ser::Serializer,
+    };
+
+    /// Serializes a fixed-point number as a decimal string.
+    pub fn serialize<F: Fixed, S: Serializer>(num: &F, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(num)
+    }
+
+    /// Deserializes a fixed-point number from a decimal string.
+    pub fn deserialize<'de, F: Fixed, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<F, D::Error> {
+        deserializer.deserialize_str(DecimalVisitor(PhantomData))
+    }
+
+    struct DecimalVisitor<F>(PhantomData<F>);
+
+    impl<'de, F: Fixed> Visitor<'de> for DecimalVisitor<F> {
+        type Value = F;
+
+        fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+            formatter.write_str("a decimal string")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<F, E> {
+            super::from_str_value(value)
+        }
+    }
+}
+
+/// Serializes fixed-point numbers as floating-point numbers.
+///
+/// Serialization converts the number to [`f64`], rounding to the
+/// nearest if the number has more significant bits than [`f64`].
+/// Deserialization accepts floating-point and integer numbers, and
+/// fails if the number does not fit.
+///
+/// [`f64`]: https://doc.rust-lang.org/nightly/std/primitive.f64.html
+pub mod float {
+    use crate::traits::Fixed;
+    use core::{
+        fmt::{Formatter, Result as FmtResult},
+        marker::PhantomData,
+    };
+    use serde::{
+        de::{self, Deserializer, Visitor},
+        ser::Serializer,
+    };
+
+    /// Serializes a fixed-point number as an [`f64`].
+    ///
+    /// [`f64`]: https://doc.rust-lang.org/nightly/std/primitive.f64.html
+    pub fn serialize<F: Fixed, S: Serializer>(num: &F, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(num.to_num())
+    }
+
+    /// Deserializes a fixed-point number from a number.
+    pub fn deserialize<'de, F: Fixed, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<F, D::Error> {
+        deserializer.deserialize_f64(FloatVisitor(PhantomData))
+    }
+
+    struct FloatVisitor<F>(PhantomData<F>);
+
+    impl<'de, F: Fixed> Visitor<'de> for FloatVisitor<F> {
+        type Value = F;
+
+        fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+            formatter.write_str("a number")
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<F, E> {
+            super::from_f64_value(value, &self)
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<F, E> {
+            super::from_i64_value(value, &self)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<F, E> {
+            super::from_u64_value(value, &self)
+        }
+    }
+}
+
 macro_rules! serde_fixed {
     ($Fixed:ident($LeEqU:ident) is $TBits:ident name $Name:expr) => {
         impl<Frac: $LeEqU> Serialize for $Fixed<Frac> {
             fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if cfg!(feature = "serde-str") && serializer.is_human_readable() {
+                    return decimal::serialize(self, serializer);
+                }
                 let bits = self.to_bits();
                 let mut state = serializer.serialize_struct($Name, 1)?;
                 state.serialize_field("bits", &bits)?;
@@ -39,28 +202,50 @@ macro_rules! serde_fixed {
                 self.0.serialize(serializer)
             }
         }
+        impl<Frac: $LeEqU> Serialize for Saturating<$Fixed<Frac>> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+        impl<Frac: $LeEqU> Serialize for Unwrapped<$Fixed<Frac>> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+        impl<Frac: $LeEqU> Serialize for Checked<$Fixed<Frac>> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
 
         impl<'de, Frac: $LeEqU> Deserialize<'de> for $Fixed<Frac> {
             fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-                struct FixedVisitor;
+                struct FixedVisitor<Frac>(PhantomData<Frac>);
 
-                impl<'de> Visitor<'de> for FixedVisitor {
-                    type Value = $TBits;
+                impl<'de, Frac: $LeEqU> Visitor<'de> for FixedVisitor<Frac> {
+                    type Value = $Fixed<Frac>;
 
                     fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
                         formatter.write_str("struct ")?;
-                        formatter.write_str($Name)
+                        formatter.write_str($Name)?;
+                        formatter.write_str(", a decimal string or a number")
                     }
 
-                    fn visit_seq<V: SeqAccess<'de>>(self, mut seq: V) -> Result<$TBits, V::Error> {
-                        let bits = seq
+                    fn visit_seq<V: SeqAccess<'de>>(
+                        self,
+                        mut seq: V,
+                    ) -> Result<Self::Value, V::Error> {
+                        let bits: $TBits = seq
                             .next_element()?
                             .ok_or_else(|| de::Error::invalid_length(0, &self))?;
-                        Ok(bits)
+                        Ok($Fixed::from_bits(bits))
                     }
 
-                    fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<$TBits, V::Error> {
-                        let mut bits = None;
+                    fn visit_map<V: MapAccess<'de>>(
+                        self,
+                        mut map: V,
+                    ) -> Result<Self::Value, V::Error> {
+                        let mut bits: Option<$TBits> = None;
                         while let Some(key) = map.next_key()? {
                             match key {
                                 Field::Bits => {
@@ -72,12 +257,35 @@ macro_rules! serde_fixed {
                             }
                         }
                         let bits = bits.ok_or_else(|| de::Error::missing_field("bits"))?;
-                        Ok(bits)
+                        Ok($Fixed::from_bits(bits))
+                    }
+
+                    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                        from_str_value(value)
+                    }
+
+                    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+                        from_f64_value(value, &self)
+                    }
+
+                    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                        from_i64_value(value, &self)
+                    }
+
+                    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                        from_u64_value(value, &self)
                     }
                 }
 
-                let bits = deserializer.deserialize_struct($Name, FIELDS, FixedVisitor)?;
-                Ok($Fixed::from_bits(bits))
+                // Only self-describing human-readable formats can hold
+                // either a struct, a string or a number, and without
+                // serde-str we keep requesting a struct as before.
+                let visitor = FixedVisitor(PhantomData);
+                if cfg!(feature = "serde-str") && deserializer.is_human_readable() {
+                    deserializer.deserialize_any(visitor)
+                } else {
+                    deserializer.deserialize_struct($Name, FIELDS, visitor)
+                }
             }
         }
 
@@ -86,6 +294,24 @@ macro_rules! serde_fixed {
                 $Fixed::deserialize(deserializer).map(Wrapping)
             }
         }
+
+        impl<'de, Frac: $LeEqU> Deserialize<'de> for Saturating<$Fixed<Frac>> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $Fixed::deserialize(deserializer).map(Saturating)
+            }
+        }
+
+        impl<'de, Frac: $LeEqU> Deserialize<'de> for Unwrapped<$Fixed<Frac>> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $Fixed::deserialize(deserializer).map(Unwrapped)
+            }
+        }
+
+        impl<'de, Frac: $LeEqU> Deserialize<'de> for Checked<$Fixed<Frac>> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Option::<$Fixed<Frac>>::deserialize(deserializer).map(Checked)
+            }
+        }
     };
 }
 
@@ -99,6 +325,31 @@ serde_fixed! { FixedU16(LeEqU16) is u16 name "FixedU16" }
 serde_fixed! { FixedU32(LeEqU32) is u32 name "FixedU32" }
 serde_fixed! { FixedU64(LeEqU64) is u64 name "FixedU64" }
 serde_fixed! { FixedU128(LeEqU128) is u128 name "FixedU128" }
+serde_fixed! { FixedI256(LeEqU256) is I256 name "FixedI256" }
+serde_fixed! { FixedU256(LeEqU256) is U256 name "FixedU256" }
+
+// The 256-bit integers are serialized as a tuple of the low word and
+// the high word, in the same order as the SCALE encoding.
+macro_rules! serde_int256 {
+    ($Int:ident($Hi:ident)) => {
+        impl Serialize for $Int {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let (hi, lo) = self.to_words();
+                (lo, hi).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $Int {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let (lo, hi) = <(u128, $Hi)>::deserialize(deserializer)?;
+                Ok($Int::from_words(hi, lo))
+            }
+        }
+    };
+}
+
+serde_int256! { U256(u128) }
+serde_int256! { I256(i128) }
 
 const FIELDS: &[&str] = &["bits"];
 
@@ -128,3 +379,343 @@ impl<'de> Deserialize<'de> for Field {
         deserializer.deserialize_identifier(FieldVisitor)
     }
 }
+
+const KINDS: [ParseErrorKind; 4] = [
+    ParseErrorKind::InvalidDigit,
+    ParseErrorKind::NoDigits,
+    ParseErrorKind::TooManyPoints,
+    ParseErrorKind::Overflow,
+];
+const KIND_VARIANTS: &[&str] = &["InvalidDigit", "NoDigits", "TooManyPoints", "Overflow"];
+
+impl Serialize for ParseErrorKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let index = KINDS.iter().position(|kind| kind == self).unwrap();
+        serializer.serialize_unit_variant("ParseErrorKind", index as u32, KIND_VARIANTS[index])
+    }
+}
+
+impl<'de> Deserialize<'de> for ParseErrorKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KindVisitor;
+
+        impl<'de> Visitor<'de> for KindVisitor {
+            type Value = ParseErrorKind;
+
+            fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                formatter.write_str("enum ParseErrorKind")
+            }
+
+            fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<ParseErrorKind, A::Error> {
+                let (KindVariant(kind), variant) = data.variant()?;
+                variant.unit_variant()?;
+                Ok(kind)
+            }
+        }
+
+        deserializer.deserialize_enum("ParseErrorKind", KIND_VARIANTS, KindVisitor)
+    }
+}
+
+struct KindVariant(ParseErrorKind);
+
+impl<'de> Deserialize<'de> for KindVariant {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<KindVariant, D::Error> {
+        struct KindVariantVisitor;
+
+        impl<'de> Visitor<'de> for KindVariantVisitor {
+            type Value = KindVariant;
+
+            fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                formatter.write_str("variant identifier")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<KindVariant, E> {
+                KINDS
+                    .get(value as usize)
+                    .map(|&kind| KindVariant(kind))
+                    .ok_or_else(|| de::Error::invalid_value(Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<KindVariant, E> {
+                KIND_VARIANTS
+                    .iter()
+                    .position(|&name| name == value)
+                    .map(|index| KindVariant(KINDS[index]))
+                    .ok_or_else(|| de::Error::unknown_variant(value, KIND_VARIANTS))
+            }
+        }
+
+        deserializer.deserialize_identifier(KindVariantVisitor)
+    }
+}
+
+const ERROR_FIELDS: &[&str] = &["kind", "position", "character"];
+
+impl Serialize for ParseFixedError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ParseFixedError", 3)?;
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("position", &self.position())?;
+        state.serialize_field("character", &self.character())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ParseFixedError {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ErrorVisitor;
+
+        impl<'de> Visitor<'de> for ErrorVisitor {
+            type Value = ParseFixedError;
+
+            fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                formatter.write_str("struct ParseFixedError")
+            }
+
+            fn visit_seq<V: SeqAccess<'de>>(self, mut seq: V) -> Result<ParseFixedError, V::Error> {
+                let kind = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let position = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let character = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                Ok(ParseFixedError::from_parts(kind, position, character))
+            }
+
+            fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<ParseFixedError, V::Error> {
+                let mut kind = None;
+                let mut position = None;
+                let mut character = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        ErrorField::Kind => {
+                            if kind.is_some() {
+                                return Err(de::Error::duplicate_field("kind"));
+                            }
+                            kind = Some(map.next_value()?);
+                        }
+                        ErrorField::Position => {
+                            if position.is_some() {
+                                return Err(de::Error::duplicate_field("position"));
+                            }
+                            position = Some(map.next_value()?);
+                        }
+                        ErrorField::Character => {
+                            if character.is_some() {
+                                return Err(de::Error::duplicate_field("character"));
+                            }
+                            character = Some(map.next_value()?);
+                        }
+                    }
+                }
+                let kind = kind.ok_or_else(|| de::Error::missing_field("kind"))?;
+                Ok(ParseFixedError::from_parts(
+                    kind,
+                    position.unwrap_or(None),
+                    character.unwrap_or(None),
+                ))
+            }
+        }
+
+        deserializer.deserialize_struct("ParseFixedError", ERROR_FIELDS, ErrorVisitor)
+    }
+}
+
+enum ErrorField {
+    Kind,
+    Position,
+    Character,
+}
+
+impl<'de> Deserialize<'de> for ErrorField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ErrorField, D::Error> {
+        struct ErrorFieldVisitor;
+
+        impl<'de> Visitor<'de> for ErrorFieldVisitor {
+            type Value = ErrorField;
+
+            fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                formatter.write_str("`kind`, `position` or `character`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<ErrorField, E> {
+                match value {
+                    "kind" => Ok(ErrorField::Kind),
+                    "position" => Ok(ErrorField::Position),
+                    "character" => Ok(ErrorField::Character),
+                    _ => Err(de::Error::unknown_field(value, ERROR_FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(ErrorFieldVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        from_str::{ParseErrorKind, ParseFixedError},
+        serdeize::{decimal, float},
+        types::{extra::U3, I16F16, I8F8, U64F64, U8F8},
+        Checked, FixedI256, FixedU256, Wrapping,
+    };
+    use serde_json::{Deserializer as JsonDeserializer, Serializer as JsonSerializer};
+    use std::{format, string::String, vec::Vec};
+
+    #[test]
+    fn default_repr() {
+        let val = I16F16::from_num(-3.5);
+        let json = serde_json::to_string(&val).unwrap();
+        if cfg!(feature = "serde-str") {
+            assert_eq!(json, "\"-3.5\"");
+        } else {
+            assert_eq!(json, "{\"bits\":-229376}");
+        }
+        assert_eq!(serde_json::from_str::<I16F16>(&json).unwrap(), val);
+        assert_eq!(
+            serde_json::to_string(&Wrapping(val)).unwrap(),
+            serde_json::to_string(&val).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_string(&Checked::<I16F16>(None)).unwrap(),
+            "null"
+        );
+
+        // compact formats always keep the bits
+        let cbor = serde_cbor::to_vec(&val).unwrap();
+        assert_eq!(&cbor[..6], b"\xa1dbits");
+        assert_eq!(serde_cbor::from_slice::<I16F16>(&cbor).unwrap(), val);
+    }
+
+    #[test]
+    fn repr_256() {
+        let val = FixedI256::<U3>::from_num(-3.5);
+        let json = serde_json::to_string(&val).unwrap();
+        if cfg!(feature = "serde-str") {
+            assert_eq!(json, "\"-3.5\"");
+        } else {
+            assert_eq!(
+                json,
+                "{\"bits\":[340282366920938463463374607431768211428,-1]}"
+            );
+        }
+        assert_eq!(serde_json::from_str::<FixedI256<U3>>(&json).unwrap(), val);
+
+        let small = FixedU256::<U3>::from_num(3.5);
+        let cbor = serde_cbor::to_vec(&small).unwrap();
+        assert_eq!(
+            serde_cbor::from_slice::<FixedU256<U3>>(&cbor).unwrap(),
+            small
+        );
+        let max = FixedU256::<U3>::max_value();
+        let json = serde_json::to_string(&max).unwrap();
+        assert_eq!(serde_json::from_str::<FixedU256<U3>>(&json).unwrap(), max);
+    }
+
+    #[test]
+    fn human_readable_input() {
+        let val = I16F16::from_num(-3.5);
+        for json in &["{\"bits\":-229376}", "[-229376]"] {
+            assert_eq!(
+                serde_json::from_str::<I16F16>(json).unwrap(),
+                val,
+                "{}",
+                json
+            );
+        }
+        for json in &["\"-3.5\"", "\"-0.35e1\"", "-3.5", "-35e-1"] {
+            if cfg!(feature = "serde-str") {
+                assert_eq!(
+                    serde_json::from_str::<I16F16>(json).unwrap(),
+                    val,
+                    "{}",
+                    json
+                );
+            } else {
+                assert!(serde_json::from_str::<I16F16>(json).is_err(), "{}", json);
+            }
+        }
+        if !cfg!(feature = "serde-str") {
+            return;
+        }
+        assert_eq!(serde_json::from_str::<U8F8>("7").unwrap(), 7);
+        assert_eq!(serde_json::from_str::<I8F8>("-7").unwrap(), -7);
+        assert_eq!(
+            serde_json::from_str::<Checked<I8F8>>("\"1.5\"").unwrap(),
+            Checked(Some(I8F8::from_num(1.5)))
+        );
+        assert!(serde_json::from_str::<U8F8>("256").is_err());
+        assert!(serde_json::from_str::<U8F8>("-1").is_err());
+        assert!(serde_json::from_str::<U8F8>("1e10").is_err());
+        assert!(serde_json::from_str::<U8F8>("\"1.5.\"").is_err());
+        assert!(serde_json::from_str::<U8F8>("true").is_err());
+    }
+
+    #[test]
+    fn decimal_helper() {
+        let val = U64F64::from_num(2.5e-6);
+        let mut json = Vec::new();
+        decimal::serialize(&val, &mut JsonSerializer::new(&mut json)).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert_eq!(json, format!("\"{}\"", val));
+        let mut de = JsonDeserializer::from_str(&json);
+        assert_eq!(decimal::deserialize::<U64F64, _>(&mut de).unwrap(), val);
+        let mut de = JsonDeserializer::from_str("\"2.5e-6\"");
+        assert_eq!(decimal::deserialize::<U64F64, _>(&mut de).unwrap(), val);
+        let mut de = JsonDeserializer::from_str("2.5");
+        assert!(decimal::deserialize::<U64F64, _>(&mut de).is_err());
+    }
+
+    #[test]
+    fn float_helper() {
+        let val = I16F16::from_num(-3.5);
+        let mut json = Vec::new();
+        float::serialize(&val, &mut JsonSerializer::new(&mut json)).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert_eq!(json, "-3.5");
+        let mut de = JsonDeserializer::from_str(&json);
+        assert_eq!(float::deserialize::<I16F16, _>(&mut de).unwrap(), val);
+        let mut de = JsonDeserializer::from_str("12");
+        assert_eq!(float::deserialize::<I16F16, _>(&mut de).unwrap(), 12);
+        let mut de = JsonDeserializer::from_str("1e10");
+        assert!(float::deserialize::<I16F16, _>(&mut de).is_err());
+        let mut de = JsonDeserializer::from_str("\"1\"");
+        assert!(float::deserialize::<I16F16, _>(&mut de).is_err());
+    }
+
+    #[test]
+    fn parse_fixed_error() {
+        let error = "12\u{e9}".parse::<I16F16>().unwrap_err();
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(
+            json,
+            "{\"kind\":\"InvalidDigit\",\"position\":2,\"character\":\"\u{e9}\"}"
+        );
+        assert_eq!(
+            serde_json::from_str::<ParseFixedError>(&json).unwrap(),
+            error
+        );
+        let overflow = serde_json::from_str::<ParseFixedError>("{\"kind\":\"Overflow\"}").unwrap();
+        assert_eq!(overflow.kind(), ParseErrorKind::Overflow);
+        assert_eq!(overflow.position(), None);
+        assert!(serde_json::from_str::<ParseFixedError>("{\"kind\":\"Bad\"}").is_err());
+
+        for error in &[
+            error,
+            "1.2.3".parse::<I16F16>().unwrap_err(),
+            "-".parse::<I16F16>().unwrap_err(),
+            "99999".parse::<I16F16>().unwrap_err(),
+        ] {
+            let cbor = serde_cbor::to_vec(error).unwrap();
+            assert_eq!(
+                serde_cbor::from_slice::<ParseFixedError>(&cbor).unwrap(),
+                *error
+            );
+        }
+    }
+}
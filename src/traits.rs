@@ -18,10 +18,11 @@ This module contains traits.
 */
 
 use crate::{
-    helpers::{FloatHelper, FloatKind, FromFloatHelper, IntHelper, Sealed, Widest},
-    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8, Unsigned},
-    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
-    FixedU8, ParseFixedError,
+    helpers::{IntHelper, Sealed, Widest},
+    int256::{I256, U256},
+    types::extra::{LeEqU128, LeEqU16, LeEqU256, LeEqU32, LeEqU64, LeEqU8, Unsigned},
+    FixedI128, FixedI16, FixedI256, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU256,
+    FixedU32, FixedU64, FixedU8, ParseFixedError, ParseOptions, RoundingMode,
 };
 use core::{
     fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
@@ -100,8 +101,9 @@ depending on the crate’s [optional features].
 ///
 /// This trait is sealed and cannot be implemented for more types; it
 /// is implemented for [`FixedI8`], [`FixedI16`], [`FixedI32`],
-/// [`FixedI64`], [`FixedI128`], [`FixedU8`], [`FixedU16`],
-/// [`FixedU32`], [`FixedU64`], and [`FixedU128`].
+/// [`FixedI64`], [`FixedI128`], [`FixedI256`], [`FixedU8`],
+/// [`FixedU16`], [`FixedU32`], [`FixedU64`], [`FixedU128`], and
+/// [`FixedU256`].
 ///
 /// # Examples
 ///
@@ -191,12 +193,14 @@ depending on the crate’s [optional features].
 ///
 /// [`FixedI128`]: ../struct.FixedI128.html
 /// [`FixedI16`]: ../struct.FixedI16.html
+/// [`FixedI256`]: ../struct.FixedI256.html
 /// [`FixedI32`]: ../struct.FixedI32.html
 /// [`FixedI64`]: ../struct.FixedI64.html
 /// [`FixedI8`]: ../struct.FixedI8.html
 /// [`FixedSigned`]: trait.FixedSigned.html
 /// [`FixedU128`]: ../struct.FixedU128.html
 /// [`FixedU16`]: ../struct.FixedU16.html
+/// [`FixedU256`]: ../struct.FixedU256.html
 /// [`FixedU32`]: ../struct.FixedU32.html
 /// [`FixedU64`]: ../struct.FixedU64.html
 /// [`FixedU8`]: ../struct.FixedU8.html
@@ -231,7 +235,7 @@ where
     Self: Sealed,
 {
     /// The primitive integer underlying type.
-    type Bits;
+    type Bits: Copy + Display;
 
     /// A byte array with the same size as the type.
     type Bytes;
@@ -383,6 +387,16 @@ where
     /// Rounding is to the nearest, with ties rounded to even.
     fn from_str_hex(src: &str) -> Result<Self, ParseFixedError>;
 
+    /// Parses a string slice containing digits in the given radix to
+    /// return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range 2 to 36 inclusive.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseFixedError>;
+
     /// Parses a string slice containing decimal digits to return a
     /// fixed-point number, saturating on overflow.
     ///
@@ -407,6 +421,16 @@ where
     /// Rounding is to the nearest, with ties rounded to even.
     fn saturating_from_str_hex(src: &str) -> Result<Self, ParseFixedError>;
 
+    /// Parses a string slice containing digits in the given radix to
+    /// return a fixed-point number, saturating on overflow.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range 2 to 36 inclusive.
+    fn saturating_from_str_radix(src: &str, radix: u32) -> Result<Self, ParseFixedError>;
+
     /// Parses a string slice containing decimal digits to return a
     /// fixed-point number, wrapping on overflow.
     ///
@@ -431,6 +455,16 @@ where
     /// Rounding is to the nearest, with ties rounded to even.
     fn wrapping_from_str_hex(src: &str) -> Result<Self, ParseFixedError>;
 
+    /// Parses a string slice containing digits in the given radix to
+    /// return a fixed-point number, wrapping on overflow.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range 2 to 36 inclusive.
+    fn wrapping_from_str_radix(src: &str, radix: u32) -> Result<Self, ParseFixedError>;
+
     /// Parses a string slice containing decimal digits to return a
     /// fixed-point number.
     ///
@@ -483,6 +517,145 @@ where
     /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
     fn overflowing_from_str_hex(src: &str) -> Result<(Self, bool), ParseFixedError>;
 
+    /// Parses a string slice containing digits in the given radix to
+    /// return a fixed-point number.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range 2 to 36 inclusive.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+    fn overflowing_from_str_radix(src: &str, radix: u32) -> Result<(Self, bool), ParseFixedError>;
+
+    /// Parses a string slice to return a fixed-point number, using the
+    /// given [`ParseOptions`].
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// [`ParseOptions`]: ../struct.ParseOptions.html
+    fn from_str_with(src: &str, options: ParseOptions) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice to return a fixed-point number, using the
+    /// given [`ParseOptions`] and saturating on overflow.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// [`ParseOptions`]: ../struct.ParseOptions.html
+    fn saturating_from_str_with(src: &str, options: ParseOptions) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice to return a fixed-point number, using the
+    /// given [`ParseOptions`] and wrapping on overflow.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// [`ParseOptions`]: ../struct.ParseOptions.html
+    fn wrapping_from_str_with(src: &str, options: ParseOptions) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice to return a fixed-point number, using the
+    /// given [`ParseOptions`].
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// [`ParseOptions`]: ../struct.ParseOptions.html
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+    fn overflowing_from_str_with(
+        src: &str,
+        options: ParseOptions,
+    ) -> Result<(Self, bool), ParseFixedError>;
+
+    /// Parses a byte slice containing ASCII decimal digits to return a
+    /// fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    fn from_ascii(src: &[u8]) -> Result<Self, ParseFixedError>;
+
+    /// Parses a byte slice containing ASCII decimal digits to return a
+    /// fixed-point number, saturating on overflow.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    fn saturating_from_ascii(src: &[u8]) -> Result<Self, ParseFixedError>;
+
+    /// Parses a byte slice containing ASCII decimal digits to return a
+    /// fixed-point number, wrapping on overflow.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    fn wrapping_from_ascii(src: &[u8]) -> Result<Self, ParseFixedError>;
+
+    /// Parses a byte slice containing ASCII decimal digits to return a
+    /// fixed-point number.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+    fn overflowing_from_ascii(src: &[u8]) -> Result<(Self, bool), ParseFixedError>;
+
+    /// Parses a byte slice containing ASCII digits in the given radix to return a
+    /// fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range 2 to 36 inclusive.
+    fn from_ascii_radix(src: &[u8], radix: u32) -> Result<Self, ParseFixedError>;
+
+    /// Parses a byte slice containing ASCII digits in the given radix to return a
+    /// fixed-point number, saturating on overflow.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range 2 to 36 inclusive.
+    fn saturating_from_ascii_radix(src: &[u8], radix: u32) -> Result<Self, ParseFixedError>;
+
+    /// Parses a byte slice containing ASCII digits in the given radix to return a
+    /// fixed-point number, wrapping on overflow.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range 2 to 36 inclusive.
+    fn wrapping_from_ascii_radix(src: &[u8], radix: u32) -> Result<Self, ParseFixedError>;
+
+    /// Parses a byte slice containing ASCII digits in the given radix to return a
+    /// fixed-point number.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range 2 to 36 inclusive.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+    fn overflowing_from_ascii_radix(
+        src: &[u8],
+        radix: u32,
+    ) -> Result<(Self, bool), ParseFixedError>;
+
     /// Returns the integer part.
     fn int(self) -> Self;
 
@@ -648,6 +821,38 @@ where
     /// Panics if the divisor is zero or if the division results in overflow.
     fn rem_euclid_int(self, rhs: Self::Bits) -> Self;
 
+    /// Square root, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative, or if the root does not fit.
+    fn sqrt(self) -> Self;
+
+    /// Multiplication with an explicit rounding mode.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the
+    /// multiplication overflows.
+    fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Division with an explicit rounding mode.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero. When debug assertions are
+    /// enabled, this method also panics if the division overflows.
+    fn div_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Multiplies by `mul` and divides by `div`, keeping the product
+    /// at double width and rounding the quotient using `mode`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `div` is zero. When debug assertions are enabled,
+    /// this method also panics if the quotient overflows.
+    fn mul_div_round(self, mul: Self, div: Self, mode: RoundingMode) -> Self;
+
     /// Checked negation. Returns the negated value, or [`None`] on overflow.
     ///
     /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
@@ -727,6 +932,39 @@ where
     /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
     fn checked_rem_euclid_int(self, rhs: Self::Bits) -> Option<Self>;
 
+    /// Checked square root. Returns the square root rounded down, or
+    /// [`None`] if the number is negative or if the root does not fit.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    fn checked_sqrt(self) -> Option<Self>;
+
+    /// Checked power with an integer exponent. Returns the power
+    /// rounded to the nearest, or [`None`] on overflow or if the
+    /// number is zero and the exponent is negative.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    fn checked_powi(self, exp: i32) -> Option<Self>;
+
+    /// Checked multiplication with an explicit rounding mode. Returns
+    /// the rounded product, or [`None`] on overflow.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    fn checked_mul_round(self, rhs: Self, mode: RoundingMode) -> Option<Self>;
+
+    /// Checked division with an explicit rounding mode. Returns the
+    /// rounded quotient, or [`None`] if the divisor is zero or on
+    /// overflow.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    fn checked_div_round(self, rhs: Self, mode: RoundingMode) -> Option<Self>;
+
+    /// Checked multiplication followed by division. Returns `self` ×
+    /// `mul` / `div` rounded toward zero, keeping the product at
+    /// double width, or [`None`] if `div` is zero or on overflow.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+    fn checked_mul_div(self, mul: Self, div: Self) -> Option<Self>;
+
     /// Checked shift left. Returns the shifted number, or [`None`] if
     /// `rhs` ≥ the number of bits.
     ///
@@ -765,6 +1003,35 @@ where
     /// Panics if the divisor is zero.
     fn saturating_div_euclid(self, rhs: Self) -> Self;
 
+    /// Saturating power with an integer exponent. Returns the power
+    /// rounded to the nearest, saturating on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero and the exponent is negative.
+    fn saturating_powi(self, exp: i32) -> Self;
+
+    /// Saturating multiplication with an explicit rounding mode.
+    /// Returns the rounded product, saturating on overflow.
+    fn saturating_mul_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Saturating division with an explicit rounding mode. Returns
+    /// the rounded quotient, saturating on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    fn saturating_div_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Saturating multiplication followed by division. Returns `self`
+    /// × `mul` / `div` rounded toward zero, keeping the product at
+    /// double width and saturating on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `div` is zero.
+    fn saturating_mul_div(self, mul: Self, div: Self) -> Self;
+
     /// Saturating multiplication by an integer. Returns the product, saturating on overflow.
     fn saturating_mul_int(self, rhs: Self::Bits) -> Self;
 
@@ -824,6 +1091,26 @@ where
     /// Panics if the divisor is zero.
     fn wrapping_rem_euclid_int(self, rhs: Self::Bits) -> Self;
 
+    /// Wrapping power with an integer exponent. Returns the power
+    /// rounded to the nearest, wrapping on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero and the exponent is negative.
+    fn wrapping_powi(self, exp: i32) -> Self;
+
+    /// Wrapping multiplication with an explicit rounding mode.
+    /// Returns the rounded product, wrapping on overflow.
+    fn wrapping_mul_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Wrapping division with an explicit rounding mode. Returns the
+    /// rounded quotient, wrapping on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    fn wrapping_div_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
     /// Wrapping shift left. Wraps `rhs` if `rhs` ≥ the number of
     /// bits, then shifts and returns the number.
     fn wrapping_shl(self, rhs: u32) -> Self;
@@ -952,6 +1239,44 @@ where
     /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
     fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool);
 
+    /// Overflowing power with an integer exponent.
+    ///
+    /// Returns a [tuple] of the power rounded to the nearest and a
+    /// [`bool`], indicating whether an overflow has occurred. On
+    /// overflow, the wrapped value is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero and the exponent is negative.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+    fn overflowing_powi(self, exp: i32) -> (Self, bool);
+
+    /// Overflowing multiplication with an explicit rounding mode.
+    ///
+    /// Returns a [tuple] of the rounded product and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+    fn overflowing_mul_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool);
+
+    /// Overflowing division with an explicit rounding mode.
+    ///
+    /// Returns a [tuple] of the rounded quotient and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
+    fn overflowing_div_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool);
+
     /// Overflowing shift left.
     ///
     /// Returns a [tuple] of the shifted value and a [`bool`],
@@ -1006,14 +1331,23 @@ where
 ///
 /// This trait is sealed and cannot be implemented for more types; it
 /// is implemented for [`FixedI8`], [`FixedI16`], [`FixedI32`],
-/// [`FixedI64`], and [`FixedI128`].
+/// [`FixedI64`], [`FixedI128`], and [`FixedI256`].
+///
+/// The functions in the [`transcendental`] module accept any type
+/// implementing this trait, but compute with at most 118 fractional
+/// bits. For types with more fractional bits, such as most
+/// [`FixedI256`] types, their results are only correct to about 35
+/// decimal digits. Square roots and integer powers use the full
+/// precision of every width.
 ///
 /// [`FixedI128`]: ../struct.FixedI128.html
 /// [`FixedI16`]: ../struct.FixedI16.html
+/// [`FixedI256`]: ../struct.FixedI256.html
 /// [`FixedI32`]: ../struct.FixedI32.html
 /// [`FixedI64`]: ../struct.FixedI64.html
 /// [`FixedI8`]: ../struct.FixedI8.html
 /// [`Fixed`]: trait.Fixed.html
+/// [`transcendental`]: ../transcendental/index.html
 pub trait FixedSigned: Fixed + Neg<Output = Self> {
     /// Returns [`true`][`bool`] if the number is > 0.
     ///
@@ -1080,10 +1414,11 @@ pub trait FixedSigned: Fixed + Neg<Output = Self> {
 ///
 /// This trait is sealed and cannot be implemented for more types; it
 /// is implemented for [`FixedU8`], [`FixedU16`], [`FixedU32`],
-/// [`FixedU64`], and [`FixedU128`].
+/// [`FixedU64`], [`FixedU128`], and [`FixedU256`].
 ///
 /// [`FixedU128`]: ../struct.FixedU128.html
 /// [`FixedU16`]: ../struct.FixedU16.html
+/// [`FixedU256`]: ../struct.FixedU256.html
 /// [`FixedU32`]: ../struct.FixedU32.html
 /// [`FixedU64`]: ../struct.FixedU64.html
 /// [`FixedU8`]: ../struct.FixedU8.html
@@ -1528,8 +1863,7 @@ macro_rules! impl_float {
             /// [`wrapping_from_fixed`]: #method.wrapping_from_fixed
             #[inline]
             fn from_fixed<F: Fixed>(src: F) -> Self {
-                let helper = src.private_to_float_helper();
-                FloatHelper::from_to_float_helper(helper, F::frac_nbits(), F::int_nbits())
+                src.private_to_float()
             }
 
             /// Converts a fixed-point number to a floating-point
@@ -1611,16 +1945,12 @@ macro_rules! impl_float {
             /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
             #[inline]
             fn checked_to_fixed<F: Fixed>(self) -> Option<F> {
-                let kind = self.to_float_kind(F::frac_nbits(), F::int_nbits());
-                match kind {
-                    FloatKind::Finite { .. } => {
-                        let helper = FromFloatHelper { kind };
-                        match F::private_overflowing_from_float_helper(helper) {
-                            (_, true) => None,
-                            (wrapped, false) => Some(wrapped),
-                        }
-                    }
-                    _ => None,
+                if !self.is_finite() {
+                    return None;
+                }
+                match F::private_overflowing_from_float(self) {
+                    (_, true) => None,
+                    (wrapped, false) => Some(wrapped),
                 }
             }
 
@@ -1636,9 +1966,7 @@ macro_rules! impl_float {
             /// [NaN]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_nan
             #[inline]
             fn saturating_to_fixed<F: Fixed>(self) -> F {
-                let kind = self.to_float_kind(F::frac_nbits(), F::int_nbits());
-                let helper = FromFloatHelper { kind };
-                F::private_saturating_from_float_helper(helper)
+                F::private_saturating_from_float(self)
             }
 
             /// Converts a floating-point number to a fixed-point
@@ -1674,9 +2002,7 @@ macro_rules! impl_float {
             /// [tuple]: https://doc.rust-lang.org/nightly/std/primitive.tuple.html
             #[inline]
             fn overflowing_to_fixed<F: Fixed>(self) -> (F, bool) {
-                let kind = self.to_float_kind(F::frac_nbits(), F::int_nbits());
-                let helper = FromFloatHelper { kind };
-                F::private_overflowing_from_float_helper(helper)
+                F::private_overflowing_from_float(self)
             }
         }
     };
@@ -1718,177 +2044,31 @@ macro_rules! trait_delegate {
 
 macro_rules! impl_fixed {
     ($Fixed:ident, $LeEqU:ident, $Bits:ident, $Signedness:tt) => {
-        impl<Frac: $LeEqU> FixedOptionalFeatures for $Fixed<Frac> {}
+        impl_fixed! { @fixed $Fixed, $LeEqU, $Bits, $Signedness }
 
-        impl<Frac: $LeEqU> Fixed for $Fixed<Frac> {
-            type Bits = $Bits;
-            type Bytes = [u8; mem::size_of::<$Bits>()];
-            type Frac = Frac;
-            trait_delegate! { fn min_value() -> Self }
-            trait_delegate! { fn max_value() -> Self }
-            trait_delegate! { fn int_nbits() -> u32 }
-            trait_delegate! { fn frac_nbits() -> u32 }
-            trait_delegate! { fn from_bits(bits: Self::Bits) -> Self }
-            trait_delegate! { fn to_bits(self) -> Self::Bits }
-            trait_delegate! { fn from_be_bytes(bits: Self::Bytes) -> Self }
-            trait_delegate! { fn from_le_bytes(bits: Self::Bytes) -> Self }
-            trait_delegate! { fn from_ne_bytes(bits: Self::Bytes) -> Self }
-            trait_delegate! { fn to_be_bytes(self) -> Self::Bytes }
-            trait_delegate! { fn to_le_bytes(self) -> Self::Bytes }
-            trait_delegate! { fn to_ne_bytes(self) -> Self::Bytes }
-            trait_delegate! { fn from_num<Src: ToFixed>(src: Src) -> Self }
-            trait_delegate! { fn to_num<Dst: FromFixed>(self) -> Dst }
-            trait_delegate! { fn checked_from_num<Src: ToFixed>(val: Src) -> Option<Self> }
-            trait_delegate! { fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst> }
-            trait_delegate! { fn saturating_from_num<Src: ToFixed>(val: Src) -> Self }
-            trait_delegate! { fn saturating_to_num<Dst: FromFixed>(self) -> Dst }
-            trait_delegate! { fn wrapping_from_num<Src: ToFixed>(val: Src) -> Self }
-            trait_delegate! { fn wrapping_to_num<Dst: FromFixed>(self) -> Dst }
-            trait_delegate! { fn overflowing_from_num<Src: ToFixed>(val: Src) -> (Self, bool) }
-            trait_delegate! { fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool) }
-            trait_delegate! { fn from_str_binary(src: &str) -> Result<Self, ParseFixedError> }
-            trait_delegate! { fn from_str_octal(src: &str) -> Result<Self, ParseFixedError> }
-            trait_delegate! { fn from_str_hex(src: &str) -> Result<Self, ParseFixedError> }
-            trait_delegate! {
-                fn saturating_from_str(src: &str) -> Result<Self, ParseFixedError>
+        impl<Frac: $LeEqU> FromFixed for $Fixed<Frac> {
+            /// Converts a fixed-point number.
+            ///
+            /// Any extra fractional bits are truncated.
+            #[inline]
+            fn from_fixed<F: Fixed>(src: F) -> Self {
+                let (wrapped, overflow) = FromFixed::overflowing_from_fixed(src);
+                debug_assert!(!overflow, "{} overflows", src);
+                let _ = overflow;
+                wrapped
             }
-            trait_delegate! {
-                fn saturating_from_str_binary(src: &str) -> Result<Self, ParseFixedError>
-            }
-            trait_delegate! {
-                fn saturating_from_str_octal(src: &str) -> Result<Self, ParseFixedError>
-            }
-            trait_delegate! {
-                fn saturating_from_str_hex(src: &str) -> Result<Self, ParseFixedError>
-            }
-            trait_delegate! {
-                fn wrapping_from_str(src: &str) -> Result<Self, ParseFixedError>
-            }
-            trait_delegate! {
-                fn wrapping_from_str_binary(src: &str) -> Result<Self, ParseFixedError>
-            }
-            trait_delegate! {
-                fn wrapping_from_str_octal(src: &str) -> Result<Self, ParseFixedError>
-            }
-            trait_delegate! {
-                fn wrapping_from_str_hex(src: &str) -> Result<Self, ParseFixedError>
-            }
-            trait_delegate! {
-                fn overflowing_from_str(src: &str) -> Result<(Self, bool), ParseFixedError>
-            }
-            trait_delegate! {
-                fn overflowing_from_str_binary(src: &str) -> Result<(Self, bool), ParseFixedError>
-            }
-            trait_delegate! {
-                fn overflowing_from_str_octal(src: &str) -> Result<(Self, bool), ParseFixedError>
-            }
-            trait_delegate! {
-                fn overflowing_from_str_hex(src: &str) -> Result<(Self, bool), ParseFixedError>
-            }
-            trait_delegate! { fn int(self) -> Self }
-            trait_delegate! { fn frac(self) -> Self }
-            trait_delegate! { fn ceil(self) -> Self }
-            trait_delegate! { fn floor(self) -> Self }
-            trait_delegate! { fn round_to_zero(self) -> Self }
-            trait_delegate! { fn round(self) -> Self }
-            trait_delegate! { fn round_ties_to_even(self) -> Self }
-            trait_delegate! { fn checked_ceil(self) -> Option<Self> }
-            trait_delegate! { fn checked_floor(self) -> Option<Self> }
-            trait_delegate! { fn checked_round(self) -> Option<Self> }
-            trait_delegate! { fn checked_round_ties_to_even(self) -> Option<Self> }
-            trait_delegate! { fn saturating_ceil(self) -> Self }
-            trait_delegate! { fn saturating_floor(self) -> Self }
-            trait_delegate! { fn saturating_round(self) -> Self }
-            trait_delegate! { fn saturating_round_ties_to_even(self) -> Self }
-            trait_delegate! { fn wrapping_ceil(self) -> Self }
-            trait_delegate! { fn wrapping_floor(self) -> Self }
-            trait_delegate! { fn wrapping_round(self) -> Self }
-            trait_delegate! { fn wrapping_round_ties_to_even(self) -> Self }
-            trait_delegate! { fn overflowing_ceil(self) -> (Self, bool) }
-            trait_delegate! { fn overflowing_floor(self) -> (Self, bool) }
-            trait_delegate! { fn overflowing_round(self) -> (Self, bool) }
-            trait_delegate! { fn overflowing_round_ties_to_even(self) -> (Self, bool) }
-            trait_delegate! { fn count_ones(self) -> u32 }
-            trait_delegate! { fn count_zeros(self) -> u32 }
-            trait_delegate! { fn leading_zeros(self) -> u32 }
-            trait_delegate! { fn trailing_zeros(self) -> u32 }
-            trait_delegate! { fn rotate_left(self, n: u32) -> Self }
-            trait_delegate! { fn rotate_right(self, n: u32) -> Self }
-            trait_delegate! { fn div_euclid(self, rhs: Self) -> Self }
-            trait_delegate! { fn rem_euclid(self, rhs: Self) -> Self }
-            trait_delegate! { fn div_euclid_int(self, rhs: Self::Bits) -> Self }
-            trait_delegate! { fn rem_euclid_int(self, rhs: Self::Bits) -> Self }
-            trait_delegate! { fn checked_neg(self) -> Option<Self> }
-            trait_delegate! { fn checked_add(self, rhs: Self) -> Option<Self> }
-            trait_delegate! { fn checked_sub(self, rhs: Self) -> Option<Self> }
-            trait_delegate! { fn checked_mul(self, rhs: Self) -> Option<Self> }
-            trait_delegate! { fn checked_div(self, rhs: Self) -> Option<Self> }
-            trait_delegate! { fn checked_rem(self, rhs: Self) -> Option<Self> }
-            trait_delegate! { fn checked_div_euclid(self, rhs: Self) -> Option<Self> }
-            trait_delegate! { fn checked_rem_euclid(self, rhs: Self) -> Option<Self> }
-            trait_delegate! { fn checked_mul_int(self, rhs: Self::Bits) -> Option<Self> }
-            trait_delegate! { fn checked_div_int(self, rhs: Self::Bits) -> Option<Self> }
-            trait_delegate! { fn checked_rem_int(self, rhs: Self::Bits) -> Option<Self> }
-            trait_delegate! { fn checked_div_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
-            trait_delegate! { fn checked_rem_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
-            trait_delegate! { fn checked_shl(self, rhs: u32) -> Option<Self> }
-            trait_delegate! { fn checked_shr(self, rhs: u32) -> Option<Self> }
-            trait_delegate! { fn saturating_neg(self) -> Self }
-            trait_delegate! { fn saturating_add(self, rhs: Self) -> Self }
-            trait_delegate! { fn saturating_sub(self, rhs: Self) -> Self }
-            trait_delegate! { fn saturating_mul(self, rhs: Self) -> Self }
-            trait_delegate! { fn saturating_div(self, rhs: Self) -> Self }
-            trait_delegate! { fn saturating_div_euclid(self, rhs: Self) -> Self }
-            trait_delegate! { fn saturating_mul_int(self, rhs: Self::Bits) -> Self }
-            trait_delegate! { fn wrapping_neg(self) -> Self }
-            trait_delegate! { fn wrapping_add(self, rhs: Self) -> Self }
-            trait_delegate! { fn wrapping_sub(self, rhs: Self) -> Self }
-            trait_delegate! { fn wrapping_mul(self, rhs: Self) -> Self }
-            trait_delegate! { fn wrapping_div(self, rhs: Self) -> Self }
-            trait_delegate! { fn wrapping_div_euclid(self, rhs: Self) -> Self }
-            trait_delegate! { fn wrapping_mul_int(self, rhs: Self::Bits) -> Self }
-            trait_delegate! { fn wrapping_div_int(self, rhs: Self::Bits) -> Self }
-            trait_delegate! { fn wrapping_div_euclid_int(self, rhs: Self::Bits) -> Self }
-            trait_delegate! { fn wrapping_rem_euclid_int(self, rhs: Self::Bits) -> Self }
-            trait_delegate! { fn wrapping_shl(self, rhs: u32) -> Self }
-            trait_delegate! { fn wrapping_shr(self, rhs: u32) -> Self }
-            trait_delegate! { fn overflowing_neg(self) -> (Self, bool) }
-            trait_delegate! { fn overflowing_add(self, rhs: Self) -> (Self, bool) }
-            trait_delegate! { fn overflowing_sub(self, rhs: Self) -> (Self, bool) }
-            trait_delegate! { fn overflowing_mul(self, rhs: Self) -> (Self, bool) }
-            trait_delegate! { fn overflowing_div(self, rhs: Self) -> (Self, bool) }
-            trait_delegate! { fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool) }
-            trait_delegate! { fn overflowing_mul_int(self, rhs: Self::Bits) -> (Self, bool) }
-            trait_delegate! { fn overflowing_div_int(self, rhs: Self::Bits) -> (Self, bool) }
-            trait_delegate! { fn overflowing_div_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
-            trait_delegate! { fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
-            trait_delegate! { fn overflowing_shl(self, rhs: u32) -> (Self, bool) }
-            trait_delegate! { fn overflowing_shr(self, rhs: u32) -> (Self, bool) }
-        }
-
-        impl<Frac: $LeEqU> FromFixed for $Fixed<Frac> {
-            /// Converts a fixed-point number.
-            ///
-            /// Any extra fractional bits are truncated.
-            #[inline]
-            fn from_fixed<F: Fixed>(src: F) -> Self {
-                let (wrapped, overflow) = FromFixed::overflowing_from_fixed(src);
-                debug_assert!(!overflow, "{} overflows", src);
-                let _ = overflow;
-                wrapped
-            }
-
-            /// Converts a fixed-point number if it fits, otherwise returns [`None`].
-            ///
-            /// Any extra fractional bits are truncated.
-            ///
-            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
-            #[inline]
-            fn checked_from_fixed<F: Fixed>(src: F) -> Option<Self> {
-                match FromFixed::overflowing_from_fixed(src) {
-                    (_, true) => None,
-                    (wrapped, false) => Some(wrapped),
-                }
+
+            /// Converts a fixed-point number if it fits, otherwise returns [`None`].
+            ///
+            /// Any extra fractional bits are truncated.
+            ///
+            /// [`None`]: https://doc.rust-lang.org/nightly/core/option/enum.Option.html#variant.None
+            #[inline]
+            fn checked_from_fixed<F: Fixed>(src: F) -> Option<Self> {
+                match FromFixed::overflowing_from_fixed(src) {
+                    (_, true) => None,
+                    (wrapped, false) => Some(wrapped),
+                }
             }
 
             /// Converts a fixed-point number, saturating if it does not fit.
@@ -2021,6 +2201,225 @@ macro_rules! impl_fixed {
                 FromFixed::overflowing_from_fixed(self)
             }
         }
+    };
+    (@fixed $Fixed:ident, $LeEqU:ident, $Bits:ident, $Signedness:tt) => {
+        impl<Frac: $LeEqU> FixedOptionalFeatures for $Fixed<Frac> {}
+
+        impl<Frac: $LeEqU> Fixed for $Fixed<Frac> {
+            type Bits = $Bits;
+            type Bytes = [u8; mem::size_of::<$Bits>()];
+            type Frac = Frac;
+            trait_delegate! { fn min_value() -> Self }
+            trait_delegate! { fn max_value() -> Self }
+            trait_delegate! { fn int_nbits() -> u32 }
+            trait_delegate! { fn frac_nbits() -> u32 }
+            trait_delegate! { fn from_bits(bits: Self::Bits) -> Self }
+            trait_delegate! { fn to_bits(self) -> Self::Bits }
+            trait_delegate! { fn from_be_bytes(bits: Self::Bytes) -> Self }
+            trait_delegate! { fn from_le_bytes(bits: Self::Bytes) -> Self }
+            trait_delegate! { fn from_ne_bytes(bits: Self::Bytes) -> Self }
+            trait_delegate! { fn to_be_bytes(self) -> Self::Bytes }
+            trait_delegate! { fn to_le_bytes(self) -> Self::Bytes }
+            trait_delegate! { fn to_ne_bytes(self) -> Self::Bytes }
+            trait_delegate! { fn from_num<Src: ToFixed>(src: Src) -> Self }
+            trait_delegate! { fn to_num<Dst: FromFixed>(self) -> Dst }
+            trait_delegate! { fn checked_from_num<Src: ToFixed>(val: Src) -> Option<Self> }
+            trait_delegate! { fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst> }
+            trait_delegate! { fn saturating_from_num<Src: ToFixed>(val: Src) -> Self }
+            trait_delegate! { fn saturating_to_num<Dst: FromFixed>(self) -> Dst }
+            trait_delegate! { fn wrapping_from_num<Src: ToFixed>(val: Src) -> Self }
+            trait_delegate! { fn wrapping_to_num<Dst: FromFixed>(self) -> Dst }
+            trait_delegate! { fn overflowing_from_num<Src: ToFixed>(val: Src) -> (Self, bool) }
+            trait_delegate! { fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool) }
+            trait_delegate! { fn from_str_binary(src: &str) -> Result<Self, ParseFixedError> }
+            trait_delegate! { fn from_str_octal(src: &str) -> Result<Self, ParseFixedError> }
+            trait_delegate! { fn from_str_hex(src: &str) -> Result<Self, ParseFixedError> }
+            trait_delegate! {
+                fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn saturating_from_str(src: &str) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn saturating_from_str_binary(src: &str) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn saturating_from_str_octal(src: &str) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn saturating_from_str_hex(src: &str) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn saturating_from_str_radix(src: &str, radix: u32) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_str(src: &str) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_str_binary(src: &str) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_str_octal(src: &str) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_str_hex(src: &str) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_str_radix(src: &str, radix: u32) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_str(src: &str) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_str_binary(src: &str) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_str_octal(src: &str) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_str_hex(src: &str) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_str_radix(src: &str, radix: u32) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! {
+                fn from_str_with(src: &str, options: ParseOptions) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn saturating_from_str_with(src: &str, options: ParseOptions) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_str_with(src: &str, options: ParseOptions) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_str_with(
+                    src: &str,
+                    options: ParseOptions
+                ) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! {
+                fn from_ascii(src: &[u8]) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn saturating_from_ascii(src: &[u8]) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_ascii(src: &[u8]) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_ascii(src: &[u8]) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! {
+                fn from_ascii_radix(src: &[u8], radix: u32) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn saturating_from_ascii_radix(src: &[u8], radix: u32) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_ascii_radix(src: &[u8], radix: u32) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_ascii_radix(src: &[u8], radix: u32) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! { fn int(self) -> Self }
+            trait_delegate! { fn frac(self) -> Self }
+            trait_delegate! { fn ceil(self) -> Self }
+            trait_delegate! { fn floor(self) -> Self }
+            trait_delegate! { fn round_to_zero(self) -> Self }
+            trait_delegate! { fn round(self) -> Self }
+            trait_delegate! { fn round_ties_to_even(self) -> Self }
+            trait_delegate! { fn checked_ceil(self) -> Option<Self> }
+            trait_delegate! { fn checked_floor(self) -> Option<Self> }
+            trait_delegate! { fn checked_round(self) -> Option<Self> }
+            trait_delegate! { fn checked_round_ties_to_even(self) -> Option<Self> }
+            trait_delegate! { fn saturating_ceil(self) -> Self }
+            trait_delegate! { fn saturating_floor(self) -> Self }
+            trait_delegate! { fn saturating_round(self) -> Self }
+            trait_delegate! { fn saturating_round_ties_to_even(self) -> Self }
+            trait_delegate! { fn wrapping_ceil(self) -> Self }
+            trait_delegate! { fn wrapping_floor(self) -> Self }
+            trait_delegate! { fn wrapping_round(self) -> Self }
+            trait_delegate! { fn wrapping_round_ties_to_even(self) -> Self }
+            trait_delegate! { fn overflowing_ceil(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_floor(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_round(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_round_ties_to_even(self) -> (Self, bool) }
+            trait_delegate! { fn count_ones(self) -> u32 }
+            trait_delegate! { fn count_zeros(self) -> u32 }
+            trait_delegate! { fn leading_zeros(self) -> u32 }
+            trait_delegate! { fn trailing_zeros(self) -> u32 }
+            trait_delegate! { fn rotate_left(self, n: u32) -> Self }
+            trait_delegate! { fn rotate_right(self, n: u32) -> Self }
+            trait_delegate! { fn div_euclid(self, rhs: Self) -> Self }
+            trait_delegate! { fn rem_euclid(self, rhs: Self) -> Self }
+            trait_delegate! { fn div_euclid_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn rem_euclid_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn sqrt(self) -> Self }
+            trait_delegate! { fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn div_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn mul_div_round(self, mul: Self, div: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn checked_neg(self) -> Option<Self> }
+            trait_delegate! { fn checked_add(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_sub(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_mul(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_div(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_rem(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_div_euclid(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_rem_euclid(self, rhs: Self) -> Option<Self> }
+            trait_delegate! { fn checked_mul_int(self, rhs: Self::Bits) -> Option<Self> }
+            trait_delegate! { fn checked_div_int(self, rhs: Self::Bits) -> Option<Self> }
+            trait_delegate! { fn checked_rem_int(self, rhs: Self::Bits) -> Option<Self> }
+            trait_delegate! { fn checked_div_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
+            trait_delegate! { fn checked_rem_euclid_int(self, rhs: Self::Bits) -> Option<Self> }
+            trait_delegate! { fn checked_sqrt(self) -> Option<Self> }
+            trait_delegate! { fn checked_powi(self, exp: i32) -> Option<Self> }
+            trait_delegate! { fn checked_mul_round(self, rhs: Self, mode: RoundingMode) -> Option<Self> }
+            trait_delegate! { fn checked_div_round(self, rhs: Self, mode: RoundingMode) -> Option<Self> }
+            trait_delegate! { fn checked_mul_div(self, mul: Self, div: Self) -> Option<Self> }
+            trait_delegate! { fn checked_shl(self, rhs: u32) -> Option<Self> }
+            trait_delegate! { fn checked_shr(self, rhs: u32) -> Option<Self> }
+            trait_delegate! { fn saturating_neg(self) -> Self }
+            trait_delegate! { fn saturating_add(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_sub(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_mul(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_div(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_div_euclid(self, rhs: Self) -> Self }
+            trait_delegate! { fn saturating_powi(self, exp: i32) -> Self }
+            trait_delegate! { fn saturating_mul_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn saturating_div_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn saturating_mul_div(self, mul: Self, div: Self) -> Self }
+            trait_delegate! { fn saturating_mul_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn wrapping_neg(self) -> Self }
+            trait_delegate! { fn wrapping_add(self, rhs: Self) -> Self }
+            trait_delegate! { fn wrapping_sub(self, rhs: Self) -> Self }
+            trait_delegate! { fn wrapping_mul(self, rhs: Self) -> Self }
+            trait_delegate! { fn wrapping_div(self, rhs: Self) -> Self }
+            trait_delegate! { fn wrapping_div_euclid(self, rhs: Self) -> Self }
+            trait_delegate! { fn wrapping_mul_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn wrapping_div_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn wrapping_div_euclid_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn wrapping_rem_euclid_int(self, rhs: Self::Bits) -> Self }
+            trait_delegate! { fn wrapping_powi(self, exp: i32) -> Self }
+            trait_delegate! { fn wrapping_mul_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn wrapping_div_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn wrapping_shl(self, rhs: u32) -> Self }
+            trait_delegate! { fn wrapping_shr(self, rhs: u32) -> Self }
+            trait_delegate! { fn overflowing_neg(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_add(self, rhs: Self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_sub(self, rhs: Self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_mul(self, rhs: Self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_div(self, rhs: Self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_mul_int(self, rhs: Self::Bits) -> (Self, bool) }
+            trait_delegate! { fn overflowing_div_int(self, rhs: Self::Bits) -> (Self, bool) }
+            trait_delegate! { fn overflowing_div_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
+            trait_delegate! { fn overflowing_rem_euclid_int(self, rhs: Self::Bits) -> (Self, bool) }
+            trait_delegate! { fn overflowing_powi(self, exp: i32) -> (Self, bool) }
+            trait_delegate! { fn overflowing_mul_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool) }
+            trait_delegate! { fn overflowing_div_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool) }
+            trait_delegate! { fn overflowing_shl(self, rhs: u32) -> (Self, bool) }
+            trait_delegate! { fn overflowing_shr(self, rhs: u32) -> (Self, bool) }
+        }
 
         if_signed! {
             $Signedness;
@@ -2057,3 +2456,5 @@ impl_fixed! { FixedU16, LeEqU16, u16, Unsigned }
 impl_fixed! { FixedU32, LeEqU32, u32, Unsigned }
 impl_fixed! { FixedU64, LeEqU64, u64, Unsigned }
 impl_fixed! { FixedU128, LeEqU128, u128, Unsigned }
+impl_fixed! { @fixed FixedI256, LeEqU256, I256, Signed }
+impl_fixed! { @fixed FixedU256, LeEqU256, U256, Unsigned }
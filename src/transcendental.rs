@@ -14,13 +14,72 @@
 
 /*!
 This module contains transcendental functions.
+
+Apart from [`sqrt`], the functions compute internally with 118
+fractional bits, rounding the constants they need from the [`consts`]
+module to that precision. Results are therefore correct to about 35
+decimal digits; types with more fractional bits, such as most
+[`FixedI256`] types, get no further precision. The constants defined
+here are [`I9F23`] convenience values.
+
+[`FixedI256`]: ../struct.FixedI256.html
+[`I9F23`]: ../types/type.I9F23.html
+[`consts`]: ../consts/index.html
 */
+use crate::arith::{mantissa_normalize, mantissa_to_int};
 use crate::consts;
-use crate::traits::{Fixed, FixedSigned, LossyFrom, ToFixed};
-use crate::types::{I9F23, I9F55, U0F128};
-use core::ops::{AddAssign, BitOrAssign, ShlAssign};
+use crate::int256::U256;
+use crate::traits::{Fixed, FixedSigned, ToFixed};
+use crate::types::extra::U118;
+use crate::types::{I9F23, U0F128, U1F127};
+use crate::FixedI128;
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{AddAssign, BitOrAssign, ShlAssign},
+};
+#[cfg(feature = "codec")]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// An error which can be returned by the transcendental functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "codec", derive(Encode, Decode))]
+pub enum TranscendentalError {
+    /// The operand is outside the domain of the function.
+    Domain,
+    /// The result is too large to be represented.
+    Overflow,
+    /// The result is too small to be distinguished from zero.
+    Underflow,
+    /// The function has a pole at the operand.
+    DivisionByZero,
+}
+
+impl TranscendentalError {
+    fn message(&self) -> &str {
+        use self::TranscendentalError::*;
+        match *self {
+            Domain => "argument outside the domain of the function",
+            Overflow => "overflow",
+            Underflow => "underflow",
+            DivisionByZero => "division by zero",
+        }
+    }
+}
 
-type ConstType = I9F23;
+impl Display for TranscendentalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self.message(), f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TranscendentalError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
 
 /// zero
 pub const ZERO: I9F23 = I9F23::from_bits(0i32 << 23);
@@ -44,56 +103,131 @@ pub const LOG2_E: I9F23 = I9F23::from_bits((consts::LOG2_E.to_bits() >> 104) as
 pub const E: I9F23 = I9F23::from_bits((consts::E.to_bits() >> 103) as i32);
 
 // generate with
-// ```matlab
-// for i = [0:63]
-//   disp(["0x", dec2hex(round(atan(2^(-i)) * 2^128),32)])
-// end
+// ```python
+// from mpmath import mp, mpf, atan, nint
+// mp.prec = 400
+// for i in range(64):
+//     print("0x%032X" % int(nint(atan(mpf(2) ** -i) * 2 ** 128)))
 // ```
 /// arctan(2^-i) lookup table for cordic
 const ARCTAN_ANGLES: [U0F128; 64] = [
-    U0F128::from_bits(0xC90FDAA22168C0000000000000000000),
-    U0F128::from_bits(0x76B19C1586ED3C000000000000000000),
-    U0F128::from_bits(0x3EB6EBF25901BA000000000000000000),
-    U0F128::from_bits(0x1FD5BA9AAC2F6E000000000000000000),
-    U0F128::from_bits(0x0FFAADDB967EF5000000000000000000),
-    U0F128::from_bits(0x07FF556EEA5D89400000000000000000),
-    U0F128::from_bits(0x03FFEAAB776E53600000000000000000),
-    U0F128::from_bits(0x01FFFD555BBBA9700000000000000000),
-    U0F128::from_bits(0x00FFFFAAAADDDDB80000000000000000),
-    U0F128::from_bits(0x007FFFF55556EEF00000000000000000),
-    U0F128::from_bits(0x003FFFFEAAAAB7780000000000000000),
-    U0F128::from_bits(0x001FFFFFD55555BC0000000000000000),
-    U0F128::from_bits(0x000FFFFFFAAAAAAE0000000000000000),
-    U0F128::from_bits(0x0007FFFFFF5555558000000000000000),
-    U0F128::from_bits(0x0003FFFFFFEAAAAAA000000000000000),
-    U0F128::from_bits(0x0001FFFFFFFD55555000000000000000),
-    U0F128::from_bits(0x0000FFFFFFFFAAAAA800000000000000),
-    U0F128::from_bits(0x00007FFFFFFFF5555400000000000000),
-    U0F128::from_bits(0x00003FFFFFFFFEAAAA00000000000000),
-    U0F128::from_bits(0x00001FFFFFFFFFD55500000000000000),
-    U0F128::from_bits(0x00000FFFFFFFFFFAAA80000000000000),
-    U0F128::from_bits(0x000007FFFFFFFFFF5540000000000000),
-    U0F128::from_bits(0x000003FFFFFFFFFFEAA0000000000000),
-    U0F128::from_bits(0x000001FFFFFFFFFFFD50000000000000),
-    U0F128::from_bits(0x000000FFFFFFFFFFFFA8000000000000),
-    U0F128::from_bits(0x0000007FFFFFFFFFFFF4000000000000),
-    U0F128::from_bits(0x0000003FFFFFFFFFFFFE000000000000),
-    U0F128::from_bits(0x00000020000000000000000000000000),
-    U0F128::from_bits(0x00000010000000000000000000000000),
-    U0F128::from_bits(0x00000008000000000000000000000000),
-    U0F128::from_bits(0x00000004000000000000000000000000),
-    U0F128::from_bits(0x00000002000000000000000000000000),
-    U0F128::from_bits(0x00000001000000000000000000000000),
-    U0F128::from_bits(0x00000000800000000000000000000000),
-    U0F128::from_bits(0x00000000400000000000000000000000),
-    U0F128::from_bits(0x00000000200000000000000000000000),
-    U0F128::from_bits(0x00000000100000000000000000000000),
-    U0F128::from_bits(0x00000000080000000000000000000000),
-    U0F128::from_bits(0x00000000040000000000000000000000),
-    U0F128::from_bits(0x00000000020000000000000000000000),
-    U0F128::from_bits(0x00000000010000000000000000000000),
-    U0F128::from_bits(0x00000000008000000000000000000000),
-    U0F128::from_bits(0x00000000004000000000000000000000),
+    U0F128::from_bits(0xC90FDAA22168C234C4C6628B80DC1CD1),
+    U0F128::from_bits(0x76B19C1586ED3DA2B7F222F65E1D4682),
+    U0F128::from_bits(0x3EB6EBF25901BAC55B71E7BD7DE885F9),
+    U0F128::from_bits(0x1FD5BA9AAC2F6DC65912F313E7D111DF),
+    U0F128::from_bits(0x0FFAADDB967EF4E36CB2792DC0E2E0D5),
+    U0F128::from_bits(0x07FF556EEA5D892A13BCEBBB6ED46311),
+    U0F128::from_bits(0x03FFEAAB776E5356EF9E31590057DD81),
+    U0F128::from_bits(0x01FFFD555BBBA972D00C46A3F77CC15F),
+    U0F128::from_bits(0x00FFFFAAAADDDDB94BB12AFB6B6D4F7E),
+    U0F128::from_bits(0x007FFFF55556EEEEA5CA6ADEAB02251D),
+    U0F128::from_bits(0x003FFFFEAAAAB77776E52E5A019FBCEA),
+    U0F128::from_bits(0x001FFFFFD55555BBBBBA97297625624B),
+    U0F128::from_bits(0x000FFFFFFAAAAAADDDDDDB94B94D5BD6),
+    U0F128::from_bits(0x0007FFFFFF5555556EEEEEEA5CA5CB40),
+    U0F128::from_bits(0x0003FFFFFFEAAAAAAB7777776E52E52F),
+    U0F128::from_bits(0x0001FFFFFFFD5555555BBBBBBBA97297),
+    U0F128::from_bits(0x0000FFFFFFFFAAAAAAAADDDDDDDDB94C),
+    U0F128::from_bits(0x00007FFFFFFFF555555556EEEEEEEEA6),
+    U0F128::from_bits(0x00003FFFFFFFFEAAAAAAAAB777777777),
+    U0F128::from_bits(0x00001FFFFFFFFFD555555555BBBBBBBC),
+    U0F128::from_bits(0x00000FFFFFFFFFFAAAAAAAAAADDDDDDE),
+    U0F128::from_bits(0x000007FFFFFFFFFF55555555556EEEEF),
+    U0F128::from_bits(0x000003FFFFFFFFFFEAAAAAAAAAAB7777),
+    U0F128::from_bits(0x000001FFFFFFFFFFFD55555555555BBC),
+    U0F128::from_bits(0x000000FFFFFFFFFFFFAAAAAAAAAAAADE),
+    U0F128::from_bits(0x0000007FFFFFFFFFFFF5555555555557),
+    U0F128::from_bits(0x0000003FFFFFFFFFFFFEAAAAAAAAAAAB),
+    U0F128::from_bits(0x0000001FFFFFFFFFFFFFD55555555555),
+    U0F128::from_bits(0x0000000FFFFFFFFFFFFFFAAAAAAAAAAB),
+    U0F128::from_bits(0x00000007FFFFFFFFFFFFFF5555555555),
+    U0F128::from_bits(0x00000003FFFFFFFFFFFFFFEAAAAAAAAB),
+    U0F128::from_bits(0x00000001FFFFFFFFFFFFFFFD55555555),
+    U0F128::from_bits(0x00000000FFFFFFFFFFFFFFFFAAAAAAAB),
+    U0F128::from_bits(0x000000007FFFFFFFFFFFFFFFF5555555),
+    U0F128::from_bits(0x000000003FFFFFFFFFFFFFFFFEAAAAAB),
+    U0F128::from_bits(0x000000001FFFFFFFFFFFFFFFFFD55555),
+    U0F128::from_bits(0x000000000FFFFFFFFFFFFFFFFFFAAAAB),
+    U0F128::from_bits(0x0000000007FFFFFFFFFFFFFFFFFF5555),
+    U0F128::from_bits(0x0000000003FFFFFFFFFFFFFFFFFFEAAB),
+    U0F128::from_bits(0x0000000001FFFFFFFFFFFFFFFFFFFD55),
+    U0F128::from_bits(0x0000000000FFFFFFFFFFFFFFFFFFFFAB),
+    U0F128::from_bits(0x00000000007FFFFFFFFFFFFFFFFFFFF5),
+    U0F128::from_bits(0x00000000003FFFFFFFFFFFFFFFFFFFFF),
+    U0F128::from_bits(0x00000000002000000000000000000000),
+    U0F128::from_bits(0x00000000001000000000000000000000),
+    U0F128::from_bits(0x00000000000800000000000000000000),
+    U0F128::from_bits(0x00000000000400000000000000000000),
+    U0F128::from_bits(0x00000000000200000000000000000000),
+    U0F128::from_bits(0x00000000000100000000000000000000),
+    U0F128::from_bits(0x00000000000080000000000000000000),
+    U0F128::from_bits(0x00000000000040000000000000000000),
+    U0F128::from_bits(0x00000000000020000000000000000000),
+    U0F128::from_bits(0x00000000000010000000000000000000),
+    U0F128::from_bits(0x00000000000008000000000000000000),
+    U0F128::from_bits(0x00000000000004000000000000000000),
+    U0F128::from_bits(0x00000000000002000000000000000000),
+    U0F128::from_bits(0x00000000000001000000000000000000),
+    U0F128::from_bits(0x00000000000000800000000000000000),
+    U0F128::from_bits(0x00000000000000400000000000000000),
+    U0F128::from_bits(0x00000000000000200000000000000000),
+    U0F128::from_bits(0x00000000000000100000000000000000),
+    U0F128::from_bits(0x00000000000000080000000000000000),
+    U0F128::from_bits(0x00000000000000040000000000000000),
+    U0F128::from_bits(0x00000000000000020000000000000000),
+];
+
+// generate with
+// ```python
+// from mpmath import mp, mpf, atanh, nint
+// mp.prec = 400
+// for i in range(1, 65):
+//     print("0x%032X" % int(nint(atanh(mpf(2) ** -i) * 2 ** 128)))
+// ```
+/// arctanh(2^-i) lookup table for hyperbolic cordic, starting at i = 1
+const ARCTANH_ANGLES: [U0F128; 64] = [
+    U0F128::from_bits(0x8C9F53D5681854BB520CC6AA829DBE5B),
+    U0F128::from_bits(0x4162BBEA0451469C9DAF0BE0810EDA9F),
+    U0F128::from_bits(0x202B12393D5DEED328CF41ED722D8C93),
+    U0F128::from_bits(0x1005588AD375ACDCB1312A563C685256),
+    U0F128::from_bits(0x0800AAC448D77125A4EE9FEE2DB37750),
+    U0F128::from_bits(0x04001556222B47263834E958AB3B4CA3),
+    U0F128::from_bits(0x020002AAB111235A6E87A29F88BB425E),
+    U0F128::from_bits(0x01000055558888AD1AEE1EF934040797),
+    U0F128::from_bits(0x0080000AAAAC44448D68E4C64F4D8118),
+    U0F128::from_bits(0x004000015555622222B46B4DD0DD6AE8),
+    U0F128::from_bits(0x002000002AAAAB11111235A35DC3DC49),
+    U0F128::from_bits(0x001000000555555888888AD1AD1C98CA),
+    U0F128::from_bits(0x0008000000AAAAAAC4444448D68D69BA),
+    U0F128::from_bits(0x0004000000155555562222222B46B46C),
+    U0F128::from_bits(0x000200000002AAAAAAB1111111235A36),
+    U0F128::from_bits(0x0001000000005555555588888888AD1B),
+    U0F128::from_bits(0x0000800000000AAAAAAAAC444444448D),
+    U0F128::from_bits(0x00004000000001555555556222222223),
+    U0F128::from_bits(0x000020000000002AAAAAAAAB11111111),
+    U0F128::from_bits(0x00001000000000055555555558888889),
+    U0F128::from_bits(0x0000080000000000AAAAAAAAAAC44444),
+    U0F128::from_bits(0x00000400000000001555555555562222),
+    U0F128::from_bits(0x000002000000000002AAAAAAAAAAB111),
+    U0F128::from_bits(0x00000100000000000055555555555589),
+    U0F128::from_bits(0x0000008000000000000AAAAAAAAAAAAC),
+    U0F128::from_bits(0x00000040000000000001555555555555),
+    U0F128::from_bits(0x000000200000000000002AAAAAAAAAAB),
+    U0F128::from_bits(0x00000010000000000000055555555555),
+    U0F128::from_bits(0x0000000800000000000000AAAAAAAAAB),
+    U0F128::from_bits(0x00000004000000000000001555555555),
+    U0F128::from_bits(0x000000020000000000000002AAAAAAAB),
+    U0F128::from_bits(0x00000001000000000000000055555555),
+    U0F128::from_bits(0x0000000080000000000000000AAAAAAB),
+    U0F128::from_bits(0x00000000400000000000000001555555),
+    U0F128::from_bits(0x000000002000000000000000002AAAAB),
+    U0F128::from_bits(0x00000000100000000000000000055555),
+    U0F128::from_bits(0x0000000008000000000000000000AAAB),
+    U0F128::from_bits(0x00000000040000000000000000001555),
+    U0F128::from_bits(0x000000000200000000000000000002AB),
+    U0F128::from_bits(0x00000000010000000000000000000055),
+    U0F128::from_bits(0x0000000000800000000000000000000B),
+    U0F128::from_bits(0x00000000004000000000000000000001),
     U0F128::from_bits(0x00000000002000000000000000000000),
     U0F128::from_bits(0x00000000001000000000000000000000),
     U0F128::from_bits(0x00000000000800000000000000000000),
@@ -115,8 +249,169 @@ const ARCTAN_ANGLES: [U0F128; 64] = [
     U0F128::from_bits(0x00000000000000080000000000000000),
     U0F128::from_bits(0x00000000000000040000000000000000),
     U0F128::from_bits(0x00000000000000020000000000000000),
+    U0F128::from_bits(0x00000000000000010000000000000000),
 ];
 
+/// 1/K for circular cordic, where K = prod(sqrt(1 + 2^-2i)) ~ 1.647
+///
+/// After n iterations the gain differs from K by about 2^-2n, which is
+/// well below the precision of a type with n fractional bits.
+const CIRCULAR_GAIN_INV: U0F128 = U0F128::from_bits(0x9B74EDA8435E5A67F5F9092BD7FD40EA);
+
+/// 1/K for hyperbolic cordic, where K = prod(sqrt(1 - 2^-2i)) ~ 0.828,
+/// including the repeated iterations 4, 13, 40, 121
+const HYPERBOLIC_GAIN_INV: U1F127 = U1F127::from_bits(0x9A8F439007761194B252764753E1EF99);
+
+/// Converts a constant from `consts` to `T`, rounding to the nearest.
+///
+/// This keeps the full precision of `T` whatever its width, instead of
+/// going through a fixed intermediate type. Returns `None` if the
+/// constant does not fit in `T`.
+fn constant<T, C>(value: C) -> Option<T>
+where
+    T: Fixed,
+    C: Fixed<Bits = u128>,
+{
+    // round in 256 bits, where adding one half cannot overflow
+    let bits = U256::from(value.to_bits());
+    let shift = C::frac_nbits() as i32 - T::frac_nbits() as i32;
+    let (abs, overflow) = if shift > 0 {
+        let half = U256::ONE << (shift - 1) as u32;
+        (bits.wrapping_add(half) >> shift as u32, false)
+    } else {
+        let shift = (-shift) as u32;
+        (bits << shift, bits.leading_zeros() < shift)
+    };
+    from_neg_abs(false, abs, overflow)
+}
+
+/// Converts a sign and the magnitude of the bits of `T` to `T`, or
+/// returns `None` if it does not fit.
+fn from_neg_abs<T>(neg: bool, abs: U256, overflow: bool) -> Option<T>
+where
+    T: Fixed,
+{
+    match T::private_overflowing_from_neg_abs(neg, abs) {
+        (ans, false) if !overflow => Some(ans),
+        _ => None,
+    }
+}
+
+/// Working type for the functions computed at extended precision, with
+/// enough integer bits for the base 2 logarithm of any operand.
+type Wide = FixedI128<U118>;
+
+/// Converts `value * 2^shift` to `T`, rounding to the nearest, or
+/// returns `None` if it does not fit.
+fn from_wide<T>(value: Wide, shift: i32) -> Option<T>
+where
+    T: Fixed,
+{
+    let bits = value.to_bits();
+    if bits == 0 {
+        return Some(T::from_num(0));
+    }
+    let mantissa = mantissa_normalize(bits.wrapping_abs() as u128);
+    let shift =
+        mantissa.1 + i64::from(shift) - i64::from(Wide::frac_nbits()) + i64::from(T::frac_nbits());
+    let (abs, overflow) = if shift > 0 {
+        // the mantissa has 128 significant bits
+        if shift > 128 {
+            (U256::ZERO, true)
+        } else {
+            (U256::from(mantissa.0) << shift as u32, false)
+        }
+    } else {
+        let (abs, overflow) = mantissa_to_int(mantissa, shift);
+        (U256::from(abs), overflow)
+    };
+    from_neg_abs(bits < 0, abs, overflow)
+}
+
+/// Number of cordic iterations for a result with the fractional bits of
+/// `T`, with a few guard bits, and at most the precision of `Wide`.
+fn cordic_nbits<T>() -> u32
+where
+    T: Fixed,
+{
+    (T::frac_nbits() + 4).min(Wide::frac_nbits())
+}
+
+/// Divides `num` by `den` into `T`, rounding to the nearest, or returns
+/// `None` if the quotient does not fit or `den` is zero.
+fn div_wide<T>(num: Wide, den: Wide) -> Option<T>
+where
+    T: Fixed,
+{
+    if den == 0 {
+        return None;
+    }
+    let neg = num.is_negative() != den.is_negative();
+    let num = U256::from(num.to_bits().wrapping_abs() as u128);
+    let den = U256::from(den.to_bits().wrapping_abs() as u128);
+    // num * 2^frac_nbits as a 512-bit number
+    let (hi, lo) = match T::frac_nbits() {
+        0 => (U256::ZERO, num),
+        256 => (num, U256::ZERO),
+        frac_nbits => (num >> (256 - frac_nbits), num << frac_nbits),
+    };
+    let ((q_hi, q_lo), rem) = U256::div_rem_wide(hi, lo, den);
+    // rem < den, so 2 * rem >= den exactly when rem >= den - rem
+    let round = if rem >= den.wrapping_sub(rem) {
+        U256::ONE
+    } else {
+        U256::ZERO
+    };
+    let (abs, carry) = q_lo.overflowing_add(round);
+    from_neg_abs(neg, abs, !q_hi.is_zero() || carry)
+}
+
+/// Returns the result of a function without an error return.
+///
+/// # Panics
+///
+/// Panics if the result does not fit in `T`.
+fn expect_fit<T>(result: Option<T>) -> T {
+    match result {
+        Some(result) => result,
+        None => panic!("overflow"),
+    }
+}
+
+/// Splits `abs * 2^-frac_nbits`, which must not be zero, into m * 2^e
+/// with m in [1, 2), and returns e and m.
+fn split_wide(abs: U256, frac_nbits: i32) -> (i32, Wide) {
+    let zeros = abs.leading_zeros();
+    let (top, _) = (abs << zeros).to_words();
+    let mantissa = Wide::from_bits((top >> 9) as i128);
+    (255 - zeros as i32 - frac_nbits, mantissa)
+}
+
+/// base 2 logarithm of `abs * 2^-frac_nbits`, which must not be zero
+fn log2_wide(abs: U256, frac_nbits: i32) -> Wide {
+    // log2(m * 2^e) = e + log2(m) with m in [1, 2)
+    let (exponent, mantissa) = split_wide(abs, frac_nbits);
+    Wide::from_num(exponent) + log2_inner::<Wide, Wide>(mantissa)
+}
+
+/// 2^exponent, negated if `neg` is set
+fn exp2_wide<T>(exponent: Wide, neg: bool) -> Result<T, TranscendentalError>
+where
+    T: Fixed,
+{
+    // 2^(k + r) with r in [0, 1)
+    let k = (exponent.to_bits() >> Wide::frac_nbits()) as i32;
+    let ln_2: Wide = constant(consts::LN_2).ok_or(TranscendentalError::Overflow)?;
+    let (k_r, cosh, sinh) =
+        exp_parts(exponent.frac() * ln_2).ok_or(TranscendentalError::Overflow)?;
+    let mantissa = if neg { -(cosh + sinh) } else { cosh + sinh };
+    match from_wide::<T>(mantissa, k + k_r) {
+        Some(result) if result == T::from_num(0) => Err(TranscendentalError::Underflow),
+        Some(result) => Ok(result),
+        None => Err(TranscendentalError::Overflow),
+    }
+}
+
 /// right-shift with rounding
 fn rs<T>(operand: T) -> T
 where
@@ -128,68 +423,49 @@ where
     //T::from_bits((x >> 1) + (x & 1))
 }
 
-/// square root
-pub fn sqrt<S, D>(operand: S) -> Result<D, &'static str>
+/// square root, rounded down
+///
+/// Returns [`Domain`] for negative operands.
+///
+/// [`Domain`]: enum.TranscendentalError.html#variant.Domain
+pub fn sqrt<S, D>(operand: S) -> Result<D, TranscendentalError>
 where
-    S: Fixed + PartialOrd<ConstType>,
-    D: Fixed + PartialOrd<ConstType> + From<S>,
+    S: Fixed,
+    D: Fixed + From<S>,
 {
-    let mut invert = false;
-    if operand < ZERO {
-        return Err("Can't calculate sqrt from negative numbers.");
+    if operand < S::from_num(0) {
+        return Err(TranscendentalError::Domain);
     };
-
-    let mut operand = D::from(operand);
-    if operand == ZERO || operand == ONE {
-        return Ok(operand);
-    };
-    if operand < ONE {
-        invert = true;
-        operand = if let Some(r) = D::from_num(1).checked_div(operand) {
-            r
-        } else {
-            return Err("Overflow inverting operand.");
-        };
-    }
-    // Newton iterations
-    let mut l = (operand / D::from_num(2)) + D::from_num(1);
-    for _i in 0..D::frac_nbits() {
-        l = (l + operand / l) / D::from_num(2);
-    }
-    if invert {
-        l = if let Some(r) = D::from_num(1).checked_div(l) {
-            r
-        } else {
-            return Err("Overflow un-inverting operand.");
-        };
-    }
-    Ok(l)
+    D::from(operand)
+        .checked_sqrt()
+        .ok_or(TranscendentalError::Overflow)
 }
 
 /// base 2 logarithm assuming self >=1
 fn log2_inner<S, D>(operand: S) -> D
 where
-    S: FixedSigned + PartialOrd<ConstType>,
+    S: FixedSigned,
     D: FixedSigned,
     D::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
 {
+    let two = S::from_num(2);
     let mut x = operand;
     let mut result = D::from_num(0).to_bits();
     let lsb = (D::from_num(1) >> D::frac_nbits()).to_bits();
 
-    while x >= TWO {
+    while x >= two {
         result += lsb;
         x = rs(x);
     }
 
-    if x == ONE {
+    if x == S::from_num(1) {
         return D::from_num(result);
     };
 
     for _i in (0..D::frac_nbits()).rev() {
         x *= x;
         result <<= lsb;
-        if x >= TWO {
+        if x >= two {
             result |= lsb;
             x = rs(x);
         }
@@ -197,258 +473,994 @@ where
     D::from_bits(result)
 }
 
-/// base 2 logarithm
-pub fn log2<S, D>(operand: S) -> Result<D, ()>
+/// base 2 logarithm of a positive operand at extended precision
+fn log2_positive<S>(operand: S) -> Result<Wide, TranscendentalError>
 where
-    S: FixedSigned + PartialOrd<ConstType>,
-    D: FixedSigned + PartialOrd<ConstType> + From<S>,
-    D::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+    S: Fixed,
 {
-    if operand <= S::from_num(0) {
-        return Err(());
-    };
+    let (neg, abs) = operand.private_neg_abs();
+    if abs.is_zero() {
+        return Err(TranscendentalError::DivisionByZero);
+    }
+    if neg {
+        return Err(TranscendentalError::Domain);
+    }
+    Ok(log2_wide(abs, S::frac_nbits() as i32))
+}
 
-    let operand = D::from(operand);
-    if operand < D::from_num(1) {
-        let inverse = D::from_num(1).checked_div(operand).unwrap();
-        return Ok(-log2_inner::<D, D>(inverse));
-    };
-    return Ok(log2_inner::<D, D>(operand));
+/// base 2 logarithm
+///
+/// The logarithm is computed at extended precision and rounded to the
+/// nearest, so neither the operand nor the result needs 1 or 2 to be
+/// representable.
+///
+/// Returns [`DivisionByZero`] for zero, [`Domain`] for negative
+/// operands, and [`Overflow`] if the result does not fit in `D`.
+///
+/// [`Domain`]: enum.TranscendentalError.html#variant.Domain
+/// [`DivisionByZero`]: enum.TranscendentalError.html#variant.DivisionByZero
+/// [`Overflow`]: enum.TranscendentalError.html#variant.Overflow
+pub fn log2<S, D>(operand: S) -> Result<D, TranscendentalError>
+where
+    S: FixedSigned,
+    D: FixedSigned + From<S>,
+{
+    from_wide(log2_positive(operand)?, 0).ok_or(TranscendentalError::Overflow)
 }
 
 /// natural logarithm
-pub fn ln<S, D>(operand: S) -> Result<D, ()>
+pub fn ln<S, D>(operand: S) -> Result<D, TranscendentalError>
 where
-    S: FixedSigned + PartialOrd<ConstType>,
-    D: FixedSigned + PartialOrd<ConstType> + From<S> + From<ConstType>,
-    D::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+    S: FixedSigned,
+    D: FixedSigned + From<S>,
+{
+    let ln_2: Wide = constant(consts::LN_2).ok_or(TranscendentalError::Overflow)?;
+    from_wide(log2_positive(operand)? * ln_2, 0).ok_or(TranscendentalError::Overflow)
+}
+
+/// base 10 logarithm
+pub fn log10<S, D>(operand: S) -> Result<D, TranscendentalError>
+where
+    S: FixedSigned,
+    D: FixedSigned + From<S>,
 {
-    Ok(log2::<S, D>(operand)? / D::from(LOG2_E))
+    let log10_2: Wide = constant(consts::LOG10_2).ok_or(TranscendentalError::Overflow)?;
+    from_wide(log2_positive(operand)? * log10_2, 0).ok_or(TranscendentalError::Overflow)
+}
+
+/// logarithm to the base `base`
+///
+/// Returns [`Domain`] if the operand or the base is not positive, and
+/// [`DivisionByZero`] for a base of 1.
+///
+/// [`Domain`]: enum.TranscendentalError.html#variant.Domain
+/// [`DivisionByZero`]: enum.TranscendentalError.html#variant.DivisionByZero
+pub fn log_b<S, D>(operand: S, base: S) -> Result<D, TranscendentalError>
+where
+    S: FixedSigned,
+    D: FixedSigned + From<S>,
+{
+    if base <= S::from_num(0) {
+        return Err(TranscendentalError::Domain);
+    }
+    let log_base = log2_positive(base)?;
+    if log_base == 0 {
+        return Err(TranscendentalError::DivisionByZero);
+    }
+    div_wide(log2_positive(operand)?, log_base).ok_or(TranscendentalError::Overflow)
 }
 
 /// exponential function e^(operand)
-pub fn exp<S, D>(mut operand: S) -> Result<D, ()>
+///
+/// The operand is reduced to x = k * ln(2) + r with r in [0, ln(2)),
+/// so that e^x = 2^k * e^r where 2^k is a shift. The result is rounded
+/// to the nearest.
+///
+/// Returns [`Overflow`] if the result is too large for `D`, and
+/// [`Underflow`] if it is too small to be distinguished from zero.
+///
+/// [`Overflow`]: enum.TranscendentalError.html#variant.Overflow
+/// [`Underflow`]: enum.TranscendentalError.html#variant.Underflow
+pub fn exp<S, D>(operand: S) -> Result<D, TranscendentalError>
 where
-    S: FixedSigned + PartialOrd<ConstType>,
-    D: FixedSigned + PartialOrd<ConstType> + From<S> + From<ConstType>,
+    S: FixedSigned,
+    D: FixedSigned + From<S>,
 {
-    if operand == ZERO {
-        return Ok(D::from_num(1));
-    };
-    if operand == ONE {
-        return Ok(D::from(E));
-    };
-    let neg = operand < ZERO;
-    if neg {
-        operand = -operand;
+    let overflow = if operand.is_negative() {
+        TranscendentalError::Underflow
+    } else {
+        TranscendentalError::Overflow
     };
+    let log2_e: Wide = constant(consts::LOG2_E).ok_or(TranscendentalError::Overflow)?;
+    let exponent = Wide::checked_from_num(operand)
+        .and_then(|x| x.checked_mul(log2_e))
+        .ok_or(overflow)?;
+    exp2_wide(exponent, false)
+}
 
-    let operand = D::from(operand);
-    let mut result = operand + D::from_num(1);
-    let mut term = operand;
-
-    for i in 2..D::frac_nbits() {
-        term = if let Some(r) = term.checked_mul(operand) {
-            r
-        } else {
-            return Err(());
-        };
-        //let bits = if let Some(r) = D::from_num(i)
-        //    { r } else { return Err(()) };
-        term = if let Some(r) = term.checked_div(D::from_num(i)) {
-            r
-        } else {
-            return Err(());
-        };
+/// checked exponential function e^(operand)
+///
+/// Returns `None` if the result is too large for `D`. Results too small
+/// to be distinguished from zero are returned as zero.
+pub fn checked_exp<S, D>(operand: S) -> Option<D>
+where
+    S: FixedSigned,
+    D: FixedSigned + From<S>,
+{
+    match exp(operand) {
+        Ok(result) => Some(result),
+        Err(TranscendentalError::Underflow) => Some(D::from_num(0)),
+        Err(_) => None,
+    }
+}
 
-        result = if let Some(r) = result.checked_add(term) {
-            r
-        } else {
-            return Err(());
-        };
-        //if term < 500 && (i > 15 || term < $ty(20i32).unwrap()) {
-        //    break;
-        //};
+/// saturating exponential function e^(operand)
+///
+/// Returns the maximum value of `D` if the result is too large, and
+/// zero if it is too small to be distinguished from zero.
+pub fn saturating_exp<S, D>(operand: S) -> D
+where
+    S: FixedSigned,
+    D: FixedSigned + From<S>,
+{
+    match exp(operand) {
+        Ok(result) => result,
+        Err(TranscendentalError::Underflow) => D::from_num(0),
+        Err(_) => D::max_value(),
     }
-    if neg {
-        result = if let Some(r) = D::from_num(1).checked_div(result) {
-            r
-        } else {
-            return Err(());
-        };
+}
+
+/// base 2 exponential function 2^(operand)
+///
+/// The result is rounded to the nearest. Returns [`Overflow`] if it is
+/// too large for `D`, and [`Underflow`] if it rounds to zero.
+///
+/// [`Overflow`]: enum.TranscendentalError.html#variant.Overflow
+/// [`Underflow`]: enum.TranscendentalError.html#variant.Underflow
+pub fn exp2<S, D>(operand: S) -> Result<D, TranscendentalError>
+where
+    S: Fixed,
+    D: Fixed + From<S>,
+{
+    match Wide::checked_from_num(operand) {
+        Some(exponent) => exp2_wide(exponent, false),
+        None if operand < S::from_num(0) => Err(TranscendentalError::Underflow),
+        None => Err(TranscendentalError::Overflow),
     }
-    Ok(result)
 }
 
-/// power
-pub fn pow<S, D>(operand: S, exponent: S) -> Result<D, ()>
+/// base 10 exponential function 10^(operand)
+///
+/// The result is rounded to the nearest. Returns [`Overflow`] if it is
+/// too large for `D`, and [`Underflow`] if it rounds to zero.
+///
+/// [`Overflow`]: enum.TranscendentalError.html#variant.Overflow
+/// [`Underflow`]: enum.TranscendentalError.html#variant.Underflow
+pub fn exp10<S, D>(operand: S) -> Result<D, TranscendentalError>
 where
-    S: FixedSigned + PartialOrd<ConstType>,
-    D: FixedSigned + PartialOrd<ConstType> + From<S> + From<ConstType>,
-    D::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+    S: Fixed,
+    D: Fixed + From<S>,
 {
-    // TODO: dynamic typing depending on input
-    //type I = FixedI128<U64>; // internal
-    if operand == S::from_num(0) {
-        return Ok(D::from_num(0));
+    let overflow = if operand < S::from_num(0) {
+        TranscendentalError::Underflow
+    } else {
+        TranscendentalError::Overflow
     };
-    if exponent == S::from_num(0) {
-        return Ok(D::from_num(1));
+    let log2_10: Wide = constant(consts::LOG2_10).ok_or(TranscendentalError::Overflow)?;
+    let exponent = Wide::checked_from_num(operand)
+        .and_then(|x| x.checked_mul(log2_10))
+        .ok_or(overflow)?;
+    exp2_wide(exponent, false)
+}
+
+/// e^(operand) - 1
+///
+/// For small operands the subtraction is done at extended precision,
+/// so that the result is rounded to the nearest without cancellation.
+pub fn expm1<S, D>(operand: S) -> Result<D, TranscendentalError>
+where
+    S: FixedSigned,
+    D: FixedSigned + From<S>,
+{
+    let one = Wide::from_num(1);
+    let x = match Wide::checked_from_num(operand) {
+        Some(x) => x,
+        // e^x is negligible
+        None if operand.is_negative() => {
+            return from_wide(-one, 0).ok_or(TranscendentalError::Overflow)
+        }
+        None => return Err(TranscendentalError::Overflow),
     };
-    if exponent == S::from_num(1) {
-        return Ok(D::from(operand));
+    if x >= Wide::from_num(5) {
+        // the result is large enough for the subtraction to be exact
+        return exp::<S, D>(operand)?
+            .checked_sub(D::from_num(1))
+            .ok_or(TranscendentalError::Overflow);
+    }
+    // below -80, e^x is smaller than the precision of Wide
+    let value = if x < Wide::from_num(-80) {
+        -one
+    } else {
+        let (k, cosh, sinh) = exp_parts(x).ok_or(TranscendentalError::Overflow)?;
+        let power = cosh + sinh;
+        let power = if k >= 0 {
+            power << k as u32
+        } else {
+            power >> (-k) as u32
+        };
+        power - one
     };
+    from_wide(value, 0).ok_or(TranscendentalError::Overflow)
+}
 
-    let r = if let Some(r) = ln::<S, D>(operand)?.checked_mul(exponent.into()) {
-        r
+/// ln(1 + operand)
+///
+/// The sum is formed exactly and the logarithm is computed at extended
+/// precision, so that small operands keep their precision.
+///
+/// Returns [`DivisionByZero`] for an operand of -1, and [`Domain`] for
+/// operands less than -1.
+///
+/// [`Domain`]: enum.TranscendentalError.html#variant.Domain
+/// [`DivisionByZero`]: enum.TranscendentalError.html#variant.DivisionByZero
+pub fn ln_1p<S, D>(operand: S) -> Result<D, TranscendentalError>
+where
+    S: FixedSigned,
+    D: FixedSigned + From<S>,
+{
+    let frac_nbits = S::frac_nbits();
+    let (neg, abs) = operand.private_neg_abs();
+    // 2^256 wraps to zero, and the difference below is still exact
+    let one = if frac_nbits == 256 {
+        U256::ZERO
     } else {
-        return Err(());
+        U256::ONE << frac_nbits
     };
-    let result: D = if let Ok(r) = exp(r) {
-        r
+    // 1 + operand as sum * 2^-frac_nbits, with a carry into bit 256
+    let (sum, carry) = if neg {
+        if frac_nbits < 256 && abs >= one {
+            return if abs == one {
+                Err(TranscendentalError::DivisionByZero)
+            } else {
+                Err(TranscendentalError::Domain)
+            };
+        }
+        (one.wrapping_sub(abs), false)
+    } else if frac_nbits == 256 {
+        (abs, true)
     } else {
-        return Err(());
+        abs.overflowing_add(one)
     };
-    let (result, oflw) = result.overflowing_to_num::<D>();
-    if oflw {
-        return Err(());
+    let log = if carry {
+        log2_wide((sum >> 1) | U256::MSB, frac_nbits as i32 - 1)
+    } else {
+        log2_wide(sum, frac_nbits as i32)
     };
-    Ok(result)
+    let ln_2: Wide = constant(consts::LN_2).ok_or(TranscendentalError::Overflow)?;
+    from_wide(log * ln_2, 0).ok_or(TranscendentalError::Overflow)
 }
 
-/// power with integer exponend
-pub fn powi<S,D>(operand: S, exponent: i32) -> Result<D, ()>
+/// power
+///
+/// Whole exponents that fit in an [`i32`] take the exact path of
+/// [`powi`], so negative operands are supported for them. Otherwise the
+/// power is computed as 2^(log2(operand) * exponent), with the product
+/// kept at extended precision.
+///
+/// Returns [`DivisionByZero`] for a zero operand with a negative
+/// exponent, and [`Domain`] for a negative operand with a fractional
+/// exponent.
+///
+/// [`i32`]: https://doc.rust-lang.org/nightly/std/primitive.i32.html
+/// [`powi`]: fn.powi.html
+/// [`Domain`]: enum.TranscendentalError.html#variant.Domain
+/// [`DivisionByZero`]: enum.TranscendentalError.html#variant.DivisionByZero
+pub fn pow<S, D>(operand: S, exponent: S) -> Result<D, TranscendentalError>
 where
-    S: Fixed + PartialOrd<ConstType>,
-    D: Fixed + PartialOrd<ConstType> + From<S> + From<ConstType>,
-    D::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+    S: Fixed,
+    D: Fixed + From<S>,
 {
-    if operand == S::from_num(0) {
-        return Ok(D::from_num(0));
+    let frac_nbits = S::frac_nbits();
+    let (neg, abs) = operand.private_neg_abs();
+    let (exp_neg, exp_abs) = exponent.private_neg_abs();
+    let (exp_int, exp_frac) = match frac_nbits {
+        0 => (exp_abs, U256::ZERO),
+        256 => (U256::ZERO, exp_abs),
+        _ => (exp_abs >> frac_nbits, exp_abs << (256 - frac_nbits)),
     };
-    if exponent == 0 {
-        return Ok(D::from_num(1));
-    };
-    if exponent == 1 {
-        return Ok(D::from(operand));
-    };
-    let operand = D::from(operand);
-    let mut r = operand;
-
-    for _i in 1..exponent.abs() {
-        r = if let Some(r) = r.checked_mul(operand) {
-            r
+    if exp_frac.is_zero() {
+        let i32_limit = U256::from(1u32 << 31);
+        let exp_int_lo = exp_int.to_words().1 as u32;
+        if exp_neg && exp_int <= i32_limit {
+            return powi(operand, exp_int_lo.wrapping_neg() as i32);
+        }
+        if !exp_neg && exp_int < i32_limit {
+            return powi(operand, exp_int_lo as i32);
+        }
+    } else if neg {
+        return Err(TranscendentalError::Domain);
+    }
+    if abs.is_zero() {
+        return if exp_neg {
+            Err(TranscendentalError::DivisionByZero)
         } else {
-            return Err(());
+            Ok(D::from_num(0))
         };
     }
-    if exponent < 0 {
-        r = if let Some(r) = D::from_num(1).checked_div(r) {
-            r
+    // only whole exponents are left for negative operands
+    let neg = neg && exp_int.is_odd();
+
+    let log = log2_wide(abs, frac_nbits as i32);
+
+    // the full product has 118 + frac_nbits fractional bits
+    let log_neg = log.is_negative();
+    let log_abs = U256::from(log.to_bits().wrapping_abs() as u128);
+    let (hi, lo) = log_abs.mul_wide(exp_abs);
+    let (hi, lo) = match frac_nbits {
+        0 => (hi, lo),
+        256 => (U256::ZERO, hi),
+        _ => (
+            hi >> frac_nbits,
+            (lo >> frac_nbits) | (hi << (256 - frac_nbits)),
+        ),
+    };
+    let wide_max = U256::from(Wide::max_value().to_bits() as u128);
+    if !hi.is_zero() || lo > wide_max {
+        // |log2(result)| > 512 is beyond the range of any type
+        return if log_neg != exp_neg {
+            Err(TranscendentalError::Underflow)
         } else {
-            return Err(());
+            Err(TranscendentalError::Overflow)
         };
     }
-    Ok(r)
+    let lo = lo.to_words().1 as i128;
+    let product = if log_neg != exp_neg {
+        -Wide::from_bits(lo)
+    } else {
+        Wide::from_bits(lo)
+    };
+    exp2_wide(product, neg)
 }
 
-/// CORDIC in rotation mode.
-fn cordic_rotation<T>(mut x: T, mut y: T, mut z: T) -> (T, T)
+/// power with integer exponend, computed by repeated squaring
+///
+/// Returns [`DivisionByZero`] for a zero operand with a negative
+/// exponent.
+///
+/// [`DivisionByZero`]: enum.TranscendentalError.html#variant.DivisionByZero
+pub fn powi<S, D>(operand: S, exponent: i32) -> Result<D, TranscendentalError>
 where
-    T: FixedSigned + PartialOrd<ConstType> + LossyFrom<U0F128>,
+    S: Fixed,
+    D: Fixed + From<S>,
 {
-    for (angle, i) in ARCTAN_ANGLES.iter().cloned().zip(0..) {
-        let angle = T::lossy_from(angle);
-        //if z == ZERO {
-        //    break;
-        //};
-        if i >= 24 {
-            break;
-        }
-        let prev_x = x;
-        if z < ZERO {
-            x += y >> i;
-            y -= prev_x >> i;
+    if operand == S::from_num(0) && exponent < 0 {
+        return Err(TranscendentalError::DivisionByZero);
+    };
+    match D::from(operand).checked_powi(exponent) {
+        Some(r) if exponent < 0 && r == D::from_num(0) => Err(TranscendentalError::Underflow),
+        Some(r) => Ok(r),
+        None => Err(TranscendentalError::Overflow),
+    }
+}
+
+/// CORDIC coordinate system
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Coordinates {
+    /// rotations on the unit circle, using arctan(2^-i) angles
+    Circular,
+    /// rotations on the unit hyperbola, using arctanh(2^-i) angles
+    Hyperbolic,
+}
+
+/// CORDIC operating mode
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    /// rotate `(x, y)` by the angle `z`, driving `z` to zero
+    Rotation,
+    /// rotate `(x, y)` onto the x axis, accumulating the angle in `z`
+    Vectoring,
+}
+
+/// angle of the i-th CORDIC micro-rotation
+fn cordic_angle(coordinates: Coordinates, i: u32) -> U0F128 {
+    let table = match coordinates {
+        Coordinates::Circular => ARCTAN_ANGLES.get(i as usize),
+        Coordinates::Hyperbolic => ARCTANH_ANGLES.get(i as usize - 1),
+    };
+    match table {
+        Some(angle) => *angle,
+        // beyond the table, arctan(2^-i) and arctanh(2^-i) equal 2^-i
+        // to within 2^-3i, which is below the precision of U0F128
+        None if i < 128 => U0F128::from_bits(1 << (128 - i)),
+        None => U0F128::from_bits(0),
+    }
+}
+
+/// CORDIC engine.
+///
+/// Performs one micro-rotation per bit of the `nbits` fractional bits
+/// wanted, which must not exceed those of `T`. Hyperbolic mode
+/// starts at i = 1 and repeats iterations 4, 13, 40, 121 to converge.
+///
+/// The returned `(x, y)` are scaled by the gain of the chosen coordinate
+/// system, see `CIRCULAR_GAIN_INV` and `HYPERBOLIC_GAIN_INV`. Returns
+/// `None` if an angle of the table does not fit in `T`.
+fn cordic<T>(
+    coordinates: Coordinates,
+    mode: Mode,
+    mut x: T,
+    mut y: T,
+    mut z: T,
+    nbits: u32,
+) -> Option<(T, T, T)>
+where
+    T: FixedSigned,
+{
+    let zero = T::from_num(0);
+    let (mut i, mut repeat) = match coordinates {
+        Coordinates::Circular => (0, None),
+        Coordinates::Hyperbolic => (1, Some(4)),
+    };
+    while i <= nbits {
+        let angle: T = constant(cordic_angle(coordinates, i))?;
+        let positive = match mode {
+            Mode::Rotation => z >= zero,
+            Mode::Vectoring => y < zero,
+        };
+        let (x_step, y_step) = match coordinates {
+            Coordinates::Circular => (-(y >> i), x >> i),
+            Coordinates::Hyperbolic => (y >> i, x >> i),
+        };
+        if positive {
+            x += x_step;
+            y += y_step;
+            z -= angle;
+        } else {
+            x -= x_step;
+            y -= y_step;
             z += angle;
+        }
+        if repeat == Some(i) {
+            repeat = Some(3 * i + 1);
         } else {
-            x -= y >> i;
-            y += prev_x >> i;
-            z -= angle;
+            i += 1;
         }
     }
-    (x, y)
+    Some((x, y, z))
 }
 
-/// sine function in radians
-pub fn sin<T>(mut angle: T) -> T
+/// reduces an angle to [-pi, pi]
+fn reduce_angle<T>(mut angle: T) -> T
 where
-    T: FixedSigned
-        + PartialOrd<ConstType>
-        + LossyFrom<ConstType>
-        + LossyFrom<I9F23>
-        + LossyFrom<U0F128>,
+    T: FixedSigned,
 {
-    //wraparound
-    while angle > PI {
-        angle -= T::lossy_from(TWO_PI);
+    // if pi does not fit in T, neither does an angle outside [-pi, pi]
+    let pi: T = match constant(consts::PI) {
+        Some(pi) => pi,
+        None => return angle,
+    };
+    if let Some(tau) = constant::<T, _>(consts::TAU) {
+        angle %= tau;
     }
-    while angle < -PI {
-        angle += T::lossy_from(TWO_PI);
+    // subtract 2pi in two steps as it may not fit in T
+    if angle > pi {
+        angle = angle - pi - pi;
+    } else if angle < -pi {
+        angle = angle + pi + pi;
     }
+    angle
+}
+
+/// sine and cosine in radians, computed with a single cordic rotation
+/// at extended precision for `nbits` fractional bits
+fn sin_cos<T>(angle: T, nbits: u32) -> Option<(Wide, Wide)>
+where
+    T: FixedSigned,
+{
+    let angle = match Wide::checked_from_num(angle) {
+        Some(angle) => angle,
+        // the angle has at least ten integer bits, so 2pi fits in T
+        None => Wide::checked_from_num(angle % constant::<T, _>(consts::TAU)?)?,
+    };
+    let mut angle = reduce_angle(angle);
     //mirror
-    if angle > FRAC_PI_2 {
-        angle = T::lossy_from(FRAC_PI_2) - (angle - T::lossy_from(FRAC_PI_2));
+    let mut negate_cos = false;
+    let frac_pi_2: Wide = constant(consts::FRAC_PI_2)?;
+    if angle > frac_pi_2 {
+        angle = frac_pi_2 - (angle - frac_pi_2);
+        negate_cos = true;
+    } else if angle < -frac_pi_2 {
+        angle = -frac_pi_2 - (angle + frac_pi_2);
+        negate_cos = true;
     }
-    if angle < -FRAC_PI_2 {
-        angle = -T::lossy_from(FRAC_PI_2) - (angle + T::lossy_from(FRAC_PI_2));
+
+    // start at 1/K so that the cordic gain brings the vector to unit length
+    let x = constant(CIRCULAR_GAIN_INV)?;
+    let (cos, sin, _z) = cordic(
+        Coordinates::Circular,
+        Mode::Rotation,
+        x,
+        Wide::from_num(0),
+        angle,
+        nbits,
+    )?;
+    if negate_cos {
+        Some((sin, -cos))
+    } else {
+        Some((sin, cos))
     }
+}
 
-    //FIXME: find correction factor for constant iterations
-    // now this is optimized for I32F32 type
-    // x0= 1/K with K ~ 1.647 for infinite iterations
-    // dec2hex(round(1 / 1.6467602578923106 * 2^128),32)
-    let x = T::lossy_from(U0F128::from_bits(0x9B74EDA8A01E20000000000000000000));
-    //let x = T::from_num(1);
-    let (_x, y) = cordic_rotation(x, T::from_num(0), angle);
-    y
+/// sine function in radians
+pub fn sin<T>(angle: T) -> T
+where
+    T: FixedSigned,
+{
+    expect_fit(sin_cos(angle, cordic_nbits::<T>()).and_then(|(sin, _)| from_wide(sin, 0)))
 }
 
 /// cosine function in radians
+///
+/// # Panics
+///
+/// Panics if the result does not fit, which happens near zero for types
+/// with less than two integer bits.
 pub fn cos<T>(angle: T) -> T
 where
-    T: FixedSigned
-        + PartialOrd<ConstType>
-        + LossyFrom<ConstType>
-        + LossyFrom<I9F55>
-        + LossyFrom<U0F128>,
+    T: FixedSigned,
 {
-    sin(angle + T::lossy_from(FRAC_PI_2))
+    expect_fit(sin_cos(angle, cordic_nbits::<T>()).and_then(|(_, cos)| from_wide(cos, 0)))
 }
 
 /// tangent function in radians
-pub fn tan<T>(mut angle: T) -> T
+///
+/// The sine and cosine are computed at the full extended precision, so
+/// that the quotient stays precise close to the poles.
+///
+/// # Panics
+///
+/// Panics if the result does not fit.
+pub fn tan<T>(angle: T) -> T
+where
+    T: FixedSigned,
+{
+    expect_fit(sin_cos(angle, Wide::frac_nbits()).and_then(|(sin, cos)| div_wide(sin, cos)))
+}
+
+/// Converts the magnitude `abs * 2^-frac_nbits` to `Wide`, truncating
+/// the fractional bits beyond those of `Wide`, or returns `None` if it
+/// does not fit.
+fn wide_from_abs(abs: U256, frac_nbits: u32) -> Option<Wide> {
+    let wide_frac_nbits = Wide::frac_nbits();
+    let bits = if frac_nbits >= wide_frac_nbits {
+        abs >> (frac_nbits - wide_frac_nbits)
+    } else {
+        let shift = wide_frac_nbits - frac_nbits;
+        if abs.leading_zeros() < shift {
+            return None;
+        }
+        abs << shift
+    };
+    match bits.to_words() {
+        (0, lo) if lo <= i128::MAX as u128 => Some(Wide::from_bits(lo as i128)),
+        _ => None,
+    }
+}
+
+/// first-quadrant arctangent of `y / x` for magnitudes at the same
+/// scale, which must not both be zero
+fn atan_abs(y: U256, x: U256, nbits: u32) -> Option<Wide> {
+    // only the ratio matters, so scale the larger magnitude into
+    // [0.25, 0.5) to keep all the precision while leaving room for the
+    // cordic gain of up to 1.65 * sqrt(2)
+    let zeros = y.max(x).leading_zeros();
+    let scale = |abs: U256| Wide::from_bits(((abs << zeros).to_words().0 >> 11) as i128);
+    let (_x, _y, angle) = cordic(
+        Coordinates::Circular,
+        Mode::Vectoring,
+        scale(x),
+        scale(y),
+        Wide::from_num(0),
+        nbits,
+    )?;
+    Some(angle)
+}
+
+/// four-quadrant arctangent of `y / x` in radians
+///
+/// Returns [`Domain`] if both `y` and `x` are zero, and [`Overflow`] if
+/// the result does not fit in `T`.
+///
+/// [`Domain`]: enum.TranscendentalError.html#variant.Domain
+/// [`Overflow`]: enum.TranscendentalError.html#variant.Overflow
+pub fn atan2<T>(y: T, x: T) -> Result<T, TranscendentalError>
 where
-    T: FixedSigned
-        + PartialOrd<ConstType>
-        + LossyFrom<ConstType>
-        + LossyFrom<I9F55>
-        + LossyFrom<U0F128>,
+    T: FixedSigned,
 {
-    angle *= T::from_num(2);
-    sin(angle) / (T::from_num(1) + cos(angle))
+    let (y_neg, y_abs) = y.private_neg_abs();
+    let (x_neg, x_abs) = x.private_neg_abs();
+    if y_abs.is_zero() && x_abs.is_zero() {
+        return Err(TranscendentalError::Domain);
+    }
+    // work in the first quadrant and mirror the result afterwards
+    let mut angle =
+        atan_abs(y_abs, x_abs, cordic_nbits::<T>()).ok_or(TranscendentalError::Overflow)?;
+    if x_neg {
+        let pi: Wide = constant(consts::PI).ok_or(TranscendentalError::Overflow)?;
+        angle = pi - angle;
+    }
+    if y_neg {
+        angle = -angle;
+    }
+    from_wide(angle, 0).ok_or(TranscendentalError::Overflow)
+}
+
+/// arctangent function in radians
+///
+/// This does not need 1 to be representable in `T`, and the result
+/// always fits.
+pub fn atan<T>(operand: T) -> T
+where
+    T: FixedSigned,
+{
+    let (neg, abs) = operand.private_neg_abs();
+    // 1 at the scale of the operand, halving both if it needs 257 bits
+    let (abs, one) = match T::frac_nbits() {
+        256 => (abs >> 1, U256::MSB),
+        frac_nbits => (abs, U256::ONE << frac_nbits),
+    };
+    let angle = atan_abs(abs, one, cordic_nbits::<T>());
+    expect_fit(angle.and_then(|angle| from_wide(if neg { -angle } else { angle }, 0)))
+}
+
+/// |x| and sqrt(1 - x^2) for x in [-1, 1], as magnitudes at the scale
+/// of `Wide`
+///
+/// As 1 + |x| is at most 2, the product (1 - |x|)(1 + |x|) is formed in
+/// `Wide` without overflow whatever the integer bits of `T`.
+fn asin_parts<T>(operand: T) -> Result<(U256, U256), TranscendentalError>
+where
+    T: FixedSigned,
+{
+    let frac_nbits = T::frac_nbits();
+    let (_, abs) = operand.private_neg_abs();
+    // with 256 fractional bits, |x| is at most one half
+    if frac_nbits < 256 && abs > U256::ONE << frac_nbits {
+        return Err(TranscendentalError::Domain);
+    }
+    let abs = wide_from_abs(abs, frac_nbits).ok_or(TranscendentalError::Overflow)?;
+    let one = Wide::from_num(1);
+    let root = ((one - abs) * (one + abs))
+        .checked_sqrt()
+        .ok_or(TranscendentalError::Overflow)?;
+    Ok((
+        U256::from(abs.to_bits() as u128),
+        U256::from(root.to_bits() as u128),
+    ))
 }
 
 /// arcsine function in radians
-//FIXME: only valid for very small angles
-pub fn asin<T>(angle: T) -> T {
-    angle
+///
+/// Returns [`Domain`] if the operand is outside [-1, 1], and
+/// [`Overflow`] if the result does not fit in `T`.
+///
+/// [`Domain`]: enum.TranscendentalError.html#variant.Domain
+/// [`Overflow`]: enum.TranscendentalError.html#variant.Overflow
+pub fn asin<T>(operand: T) -> Result<T, TranscendentalError>
+where
+    T: FixedSigned,
+{
+    let (abs, root) = asin_parts(operand)?;
+    let angle = atan_abs(abs, root, cordic_nbits::<T>()).ok_or(TranscendentalError::Overflow)?;
+    let angle = if operand.is_negative() { -angle } else { angle };
+    from_wide(angle, 0).ok_or(TranscendentalError::Overflow)
+}
+
+/// arccosine function in radians
+///
+/// Returns [`Domain`] if the operand is outside [-1, 1], and
+/// [`Overflow`] if the result does not fit in `T`.
+///
+/// [`Domain`]: enum.TranscendentalError.html#variant.Domain
+/// [`Overflow`]: enum.TranscendentalError.html#variant.Overflow
+pub fn acos<T>(operand: T) -> Result<T, TranscendentalError>
+where
+    T: FixedSigned,
+{
+    let (abs, root) = asin_parts(operand)?;
+    let mut angle =
+        atan_abs(root, abs, cordic_nbits::<T>()).ok_or(TranscendentalError::Overflow)?;
+    if operand.is_negative() {
+        let pi: Wide = constant(consts::PI).ok_or(TranscendentalError::Overflow)?;
+        angle = pi - angle;
+    }
+    from_wide(angle, 0).ok_or(TranscendentalError::Overflow)
+}
+
+/// `value * 2^-shift` for a non-negative shift of any size
+fn shr_wide(value: Wide, shift: i32) -> Wide {
+    if shift >= 128 {
+        Wide::from_num(0)
+    } else {
+        value >> shift as u32
+    }
+}
+
+/// Splits e^operand into 2^k * e^r with |r| <= ln(2)/2.
+///
+/// Returns k, cosh(r) and sinh(r), or `None` if |k| would exceed 512,
+/// in which case e^operand either overflows or rounds to zero in every
+/// type.
+fn exp_parts(operand: Wide) -> Option<(i32, Wide, Wide)> {
+    let ln_2: Wide = constant(consts::LN_2)?;
+    let half_ln_2: Wide = ln_2 >> 1;
+    let limit = 512;
+    let mut k = 0;
+    let mut r = operand;
+    while r > half_ln_2 {
+        if k == limit {
+            return None;
+        }
+        r -= ln_2;
+        k += 1;
+    }
+    while r < -half_ln_2 {
+        if k == -limit {
+            return None;
+        }
+        r += ln_2;
+        k -= 1;
+    }
+    let x = constant(HYPERBOLIC_GAIN_INV)?;
+    let (cosh, sinh, _z) = cordic(
+        Coordinates::Hyperbolic,
+        Mode::Rotation,
+        x,
+        Wide::from_num(0),
+        r,
+        Wide::frac_nbits(),
+    )?;
+    Some((k, cosh, sinh))
+}
+
+/// (2^k e^r ± 2^-k e^-r) / 2 for the parts returned by `exp_parts`
+///
+/// The larger power is factored out and applied by `from_wide`, so the
+/// result keeps the full precision of `Wide` whatever its magnitude.
+fn exp_sum<T>(k: i32, cosh_r: Wide, sinh_r: Wide, sub: bool) -> Option<T>
+where
+    T: Fixed,
+{
+    let (pos, neg) = (cosh_r + sinh_r, cosh_r - sinh_r);
+    let (pos, neg) = if k >= 0 {
+        (pos, shr_wide(neg, 2 * k))
+    } else {
+        (shr_wide(pos, -2 * k), neg)
+    };
+    let value = if sub { pos - neg } else { pos + neg };
+    from_wide(value, k.abs() - 1)
+}
+
+/// hyperbolic sine function
+///
+/// Returns [`Overflow`] if the result does not fit in `T`.
+///
+/// [`Overflow`]: enum.TranscendentalError.html#variant.Overflow
+pub fn sinh<T>(operand: T) -> Result<T, TranscendentalError>
+where
+    T: FixedSigned,
+{
+    // beyond the range of Wide, the result overflows every type
+    let x = Wide::checked_from_num(operand).ok_or(TranscendentalError::Overflow)?;
+    let (k, cosh_r, sinh_r) = exp_parts(x).ok_or(TranscendentalError::Overflow)?;
+    let result = if k == 0 {
+        from_wide(sinh_r, 0)
+    } else {
+        exp_sum(k, cosh_r, sinh_r, true)
+    };
+    result.ok_or(TranscendentalError::Overflow)
+}
+
+/// hyperbolic cosine function
+///
+/// Returns [`Overflow`] if the result does not fit in `T`.
+///
+/// [`Overflow`]: enum.TranscendentalError.html#variant.Overflow
+pub fn cosh<T>(operand: T) -> Result<T, TranscendentalError>
+where
+    T: FixedSigned,
+{
+    let x = Wide::checked_from_num(operand).ok_or(TranscendentalError::Overflow)?;
+    let (k, cosh_r, sinh_r) = exp_parts(x).ok_or(TranscendentalError::Overflow)?;
+    let result = if k == 0 {
+        from_wide(cosh_r, 0)
+    } else {
+        exp_sum(k, cosh_r, sinh_r, false)
+    };
+    result.ok_or(TranscendentalError::Overflow)
+}
+
+/// hyperbolic tangent function
+///
+/// Large operands saturate to ±1. The quotient is computed at extended
+/// precision, so types where 1 does not fit are supported too.
+pub fn tanh<T>(operand: T) -> Result<T, TranscendentalError>
+where
+    T: FixedSigned,
+{
+    let one = Wide::from_num(1);
+    let saturated = if operand.is_negative() { -one } else { one };
+    let parts = Wide::checked_from_num(operand).and_then(exp_parts);
+    let value = match parts {
+        None => saturated,
+        Some((0, cosh_r, sinh_r)) => sinh_r / cosh_r,
+        Some((k, cosh_r, sinh_r)) => {
+            // divide numerator and denominator by the larger of 2^k and 2^-k
+            let (pos, neg) = (cosh_r + sinh_r, cosh_r - sinh_r);
+            let (pos, neg) = if k > 0 {
+                (pos, shr_wide(neg, 2 * k))
+            } else {
+                (shr_wide(pos, -2 * k), neg)
+            };
+            (pos - neg) / (pos + neg)
+        }
+    };
+    from_wide(value, 0).ok_or(TranscendentalError::Overflow)
+}
+
+/// base 2 logarithm of a positive `Wide` value
+fn log2_of_wide(value: Wide) -> Wide {
+    log2_wide(
+        U256::from(value.to_bits() as u128),
+        Wide::frac_nbits() as i32,
+    )
+}
+
+/// ln(a + sqrt(a^2 ± 1)) for a = `abs * 2^-frac_nbits` greater than 1,
+/// computed as ln(a) + ln(2) + ln((1 + sqrt(1 ± 1/a^2)) / 2)
+fn ln_sum_sqrt(abs: U256, frac_nbits: u32, sub: bool) -> Option<Wide> {
+    let one = Wide::from_num(1);
+    // 1/a^2 = 2^-2e / m^2 for a = m * 2^e with m in [1, 2)
+    let (exponent, mantissa) = split_wide(abs, frac_nbits as i32);
+    let inv = one / mantissa;
+    let inv_sq = shr_wide(inv * inv, 2 * exponent);
+    let root = if sub { one - inv_sq } else { one + inv_sq }.checked_sqrt()?;
+    let log2 = log2_wide(abs, frac_nbits as i32) + one + log2_of_wide((one + root) >> 1);
+    let ln_2: Wide = constant(consts::LN_2)?;
+    Some(log2 * ln_2)
+}
+
+/// inverse hyperbolic sine function
+///
+/// Returns [`Overflow`] if the result does not fit in `T`.
+///
+/// [`Overflow`]: enum.TranscendentalError.html#variant.Overflow
+pub fn asinh<T>(operand: T) -> Result<T, TranscendentalError>
+where
+    T: FixedSigned,
+{
+    let frac_nbits = T::frac_nbits();
+    let (neg, abs) = operand.private_neg_abs();
+    let one = Wide::from_num(1);
+    let result = match wide_from_abs(abs, frac_nbits) {
+        Some(a) if a <= one => {
+            // asinh(a) = atanh(a / sqrt(1 + a^2)), with a ratio of at most 0.71
+            let hyp = (one + a * a)
+                .checked_sqrt()
+                .ok_or(TranscendentalError::Overflow)?;
+            let (_x, _y, z) = cordic(
+                Coordinates::Hyperbolic,
+                Mode::Vectoring,
+                hyp,
+                a,
+                Wide::from_num(0),
+                cordic_nbits::<T>(),
+            )
+            .ok_or(TranscendentalError::Overflow)?;
+            z
+        }
+        _ => ln_sum_sqrt(abs, frac_nbits, false).ok_or(TranscendentalError::Overflow)?,
+    };
+    from_wide(if neg { -result } else { result }, 0).ok_or(TranscendentalError::Overflow)
+}
+
+/// inverse hyperbolic cosine function
+///
+/// Returns [`Domain`] if the operand is less than 1.
+///
+/// [`Domain`]: enum.TranscendentalError.html#variant.Domain
+pub fn acosh<T>(operand: T) -> Result<T, TranscendentalError>
+where
+    T: FixedSigned,
+{
+    if operand < 1 {
+        return Err(TranscendentalError::Domain);
+    }
+    let frac_nbits = T::frac_nbits();
+    let (_, abs) = operand.private_neg_abs();
+    let one = Wide::from_num(1);
+    let result = match wide_from_abs(abs, frac_nbits) {
+        Some(x) if x < one + (one >> 1) => {
+            // acosh(x) = atanh(sqrt(x^2 - 1) / x), with a ratio below 0.75;
+            // x^2 - 1 = 2u(1 + u/2) with u = x - 1 keeps its precision
+            let u = x - one;
+            let root = ((u << 1u32) * (one + (u >> 1u32)))
+                .checked_sqrt()
+                .ok_or(TranscendentalError::Overflow)?;
+            let (_x, _y, z) = cordic(
+                Coordinates::Hyperbolic,
+                Mode::Vectoring,
+                x,
+                root,
+                Wide::from_num(0),
+                cordic_nbits::<T>(),
+            )
+            .ok_or(TranscendentalError::Overflow)?;
+            z
+        }
+        _ => ln_sum_sqrt(abs, frac_nbits, true).ok_or(TranscendentalError::Overflow)?,
+    };
+    from_wide(result, 0).ok_or(TranscendentalError::Overflow)
+}
+
+/// inverse hyperbolic tangent function
+///
+/// Returns [`DivisionByZero`] for an operand of ±1, where the function
+/// has a pole, [`Domain`] for other operands outside (-1, 1), and
+/// [`Overflow`] if the result does not fit in `T`.
+///
+/// [`Domain`]: enum.TranscendentalError.html#variant.Domain
+/// [`DivisionByZero`]: enum.TranscendentalError.html#variant.DivisionByZero
+/// [`Overflow`]: enum.TranscendentalError.html#variant.Overflow
+pub fn atanh<T>(operand: T) -> Result<T, TranscendentalError>
+where
+    T: FixedSigned,
+{
+    let frac_nbits = T::frac_nbits();
+    let (neg, abs) = operand.private_neg_abs();
+    // with 256 fractional bits, |x| is at most one half
+    if frac_nbits < 256 {
+        let one = U256::ONE << frac_nbits;
+        if abs == one {
+            return Err(TranscendentalError::DivisionByZero);
+        }
+        if abs > one {
+            return Err(TranscendentalError::Domain);
+        }
+    }
+    // truncated, so that a stays below 1
+    let a = wide_from_abs(abs, frac_nbits).ok_or(TranscendentalError::Overflow)?;
+    let one = Wide::from_num(1);
+    let result = if a <= one - (one >> 2) {
+        // within the convergence range of hyperbolic cordic
+        let (_x, _y, z) = cordic(
+            Coordinates::Hyperbolic,
+            Mode::Vectoring,
+            one,
+            a,
+            Wide::from_num(0),
+            cordic_nbits::<T>(),
+        )
+        .ok_or(TranscendentalError::Overflow)?;
+        z
+    } else {
+        // (ln(1 + a) - ln(1 - a)) / 2
+        let ln_2: Wide = constant(consts::LN_2).ok_or(TranscendentalError::Overflow)?;
+        (log2_of_wide(one + a) - log2_of_wide(one - a)) * (ln_2 >> 1)
+    };
+    from_wide(if neg { -result } else { result }, 0).ok_or(TranscendentalError::Overflow)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::traits::LossyInto;
-    use crate::types::{I32F32, I64F64, U64F64};
+    use crate::traits::{LossyFrom, LossyInto};
+    use crate::types::extra::U10;
+    use crate::types::{
+        I0F32, I128F0, I16F16, I1F127, I1F31, I2F126, I2F30, I32F32, I48F16, I4F28, I64F64, I8F8,
+        U16F16, U32F32, U64F64,
+    };
+    use crate::FixedI256;
 
     #[test]
     fn sqrt_works() {
@@ -476,18 +1488,22 @@ mod tests {
     }
 
     #[test]
-    fn sqrt_check_lower_bound_of_working_values() {
-        // Todo: This could be done for other types too.
+    fn sqrt_works_for_tiny_values() {
         type S = I32F32;
         type D = I32F32;
 
-        // this works
-        let result: f64 = sqrt::<S, D>(S::from_num(5.8208e-10)).unwrap().lossy_into();
-        assert_relative_eq!(result, 0.0000261, epsilon = 1.0e-6);
+        let operand = S::from_num(5.8208e-10);
+        let result: f64 = sqrt::<S, D>(operand).unwrap().lossy_into();
+        let expected = LossyInto::<f64>::lossy_into(operand).sqrt();
+        assert_relative_eq!(result, expected, max_relative = 1.0e-4);
 
-        // slightly below lower bound that produces an overflow
-        let res = sqrt::<S, D>(S::from_num(5.8205e-10));
-        assert_eq!(res.unwrap_err(), "Overflow inverting operand.")
+        // operands below one are no longer inverted, so they cannot overflow
+        let result = sqrt::<S, D>(S::from_bits(1)).unwrap();
+        assert_eq!(result, S::from_bits(1 << 16));
+        assert_eq!(
+            sqrt::<S, D>(S::from_bits(3)).unwrap(),
+            S::from_bits(0x1_bb67)
+        );
     }
 
     #[test]
@@ -517,7 +1533,14 @@ mod tests {
     fn log2_works() {
         type S = I9F23;
         type D = I32F32;
-        assert!(log2::<S, D>(S::from_num(0)).is_err());
+        assert_eq!(
+            log2::<S, D>(S::from_num(0)),
+            Err(TranscendentalError::DivisionByZero)
+        );
+        assert_eq!(
+            log2::<S, D>(S::from_num(-1)),
+            Err(TranscendentalError::Domain)
+        );
 
         assert_eq!(log2::<S, D>(S::from_num(1)).unwrap(), ZERO);
 
@@ -533,22 +1556,134 @@ mod tests {
         let result: D = log2::<S, D>(S::from_num(0.11111_f64)).unwrap();
         let result: f64 = result.lossy_into();
         assert_relative_eq!(result, -3.16994, epsilon = 1.0e-2);
+
+        // the reciprocal of the smallest value does not fit in the type
+        let result: f64 = log2::<I32F32, I32F32>(I32F32::from_bits(1))
+            .unwrap()
+            .lossy_into();
+        assert_eq!(result, -32.0);
+        let result: f64 = log2::<I32F32, I32F32>(I32F32::from_bits(3))
+            .unwrap()
+            .lossy_into();
+        assert_relative_eq!(result, 3f64.log2() - 32.0, epsilon = 1.0e-8);
     }
 
     #[test]
     fn ln_works() {
         type S = I9F23;
         type D = I32F32;
-        assert!(ln::<S, D>(S::from_num(0)).is_err());
+        assert_eq!(
+            ln::<S, D>(S::from_num(0)),
+            Err(TranscendentalError::DivisionByZero)
+        );
         assert_eq!(ln::<S, D>(S::from_num(1)).unwrap(), ZERO);
         let result: f64 = ln::<S, D>(E).unwrap().lossy_into();
         assert_relative_eq!(result, 1.0, epsilon = 1.0e-4);
         let result: f64 = ln::<S, D>(S::from_num(10)).unwrap().lossy_into();
-        assert_relative_eq!(result, 2.30259, epsilon = 1.0e-4);
+        assert_relative_eq!(result, core::f64::consts::LN_10, epsilon = 1.0e-4);
         let result: f64 = ln::<S, D>(S::from_num(0.00001)).unwrap().lossy_into();
         assert_relative_eq!(result, -11.5129, epsilon = 1.0e-1);
     }
 
+    #[test]
+    fn log10_and_log_b_work() {
+        type F = I32F32;
+        let result: f64 = log10::<F, F>(F::from_num(1000)).unwrap().lossy_into();
+        assert_relative_eq!(result, 3.0, epsilon = 1.0e-8);
+        let result: f64 = log10::<F, F>(F::from_num(0.01)).unwrap().lossy_into();
+        assert_relative_eq!(result, -2.0, epsilon = 1.0e-8);
+        assert_eq!(
+            log10::<F, F>(F::from_num(0)),
+            Err(TranscendentalError::DivisionByZero)
+        );
+
+        let result: f64 = log_b::<F, F>(F::from_num(81), F::from_num(3))
+            .unwrap()
+            .lossy_into();
+        assert_relative_eq!(result, 4.0, epsilon = 1.0e-8);
+        let result: f64 = log_b::<F, F>(F::from_num(8), F::from_num(0.5))
+            .unwrap()
+            .lossy_into();
+        assert_relative_eq!(result, -3.0, epsilon = 1.0e-8);
+        assert_eq!(
+            log_b::<F, F>(F::from_num(8), F::from_num(1)),
+            Err(TranscendentalError::DivisionByZero)
+        );
+        assert_eq!(
+            log_b::<F, F>(F::from_num(8), F::from_num(-2)),
+            Err(TranscendentalError::Domain)
+        );
+    }
+
+    #[test]
+    fn exp2_and_exp10_work() {
+        type F = I32F32;
+        assert_eq!(exp2::<F, F>(F::from_num(10)), Ok(F::from_num(1024)));
+        assert_eq!(exp2::<F, F>(F::from_num(-1)), Ok(F::from_num(0.5)));
+        let result = exp2::<F, F>(F::from_num(0.5)).unwrap();
+        assert_eq!(Some(result), constant(consts::SQRT_2));
+        assert_eq!(
+            exp2::<F, F>(F::from_num(31)),
+            Err(TranscendentalError::Overflow)
+        );
+        assert_eq!(
+            exp2::<F, F>(F::from_num(-34)),
+            Err(TranscendentalError::Underflow)
+        );
+        let result: U32F32 = exp2(U16F16::from_num(3.5)).unwrap();
+        let result: f64 = result.lossy_into();
+        assert_relative_eq!(result, 3.5f64.exp2(), epsilon = 1.0e-8);
+
+        let result: f64 = exp10::<F, F>(F::from_num(2)).unwrap().lossy_into();
+        assert_relative_eq!(result, 100.0, epsilon = 1.0e-8);
+        let result: f64 = exp10::<F, F>(F::from_num(-2)).unwrap().lossy_into();
+        assert_relative_eq!(result, 0.01, epsilon = 1.0e-9);
+        assert_eq!(
+            exp10::<F, F>(F::from_num(10)),
+            Err(TranscendentalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn expm1_and_ln_1p_work() {
+        type F = I64F64;
+        let small = 1.0e-10_f64;
+        let result: f64 = expm1::<F, F>(F::from_num(small)).unwrap().lossy_into();
+        assert_relative_eq!(result, small.exp_m1(), max_relative = 1.0e-15);
+        let result: f64 = ln_1p::<F, F>(F::from_num(small)).unwrap().lossy_into();
+        assert_relative_eq!(result, small.ln_1p(), max_relative = 1.0e-15);
+
+        // the results round to the least significant bit
+        let lsb = I32F32::from_bits(1);
+        assert_eq!(expm1::<_, I32F32>(lsb), Ok(lsb));
+        assert_eq!(ln_1p::<_, I32F32>(lsb), Ok(lsb));
+        assert_eq!(expm1::<_, I32F32>(-lsb), Ok(-lsb));
+        assert_eq!(ln_1p::<_, I32F32>(-lsb), Ok(-lsb));
+
+        for &x in &[-1.0, -0.5, 0.25, 1.0, 4.0, 10.0] {
+            let result: f64 = expm1::<F, F>(F::from_num(x)).unwrap().lossy_into();
+            assert_relative_eq!(result, f64::exp_m1(x), max_relative = 1.0e-15);
+        }
+        for &x in &[-0.75, -0.5, 0.25, 1.0, 1000.0] {
+            let result: f64 = ln_1p::<F, F>(F::from_num(x)).unwrap().lossy_into();
+            assert_relative_eq!(result, f64::ln_1p(x), max_relative = 1.0e-15);
+        }
+        assert_eq!(expm1::<F, F>(F::from_num(-100)), Ok(F::from_num(-1)));
+        assert_eq!(
+            ln_1p::<F, F>(F::from_num(-1)),
+            Err(TranscendentalError::DivisionByZero)
+        );
+        assert_eq!(
+            ln_1p::<F, F>(F::from_num(-2)),
+            Err(TranscendentalError::Domain)
+        );
+        // the sum does not fit in the operand type
+        let result: f64 = ln_1p::<_, I64F64>(I1F31::from_num(0.5))
+            .unwrap()
+            .lossy_into();
+        assert_relative_eq!(result, 1.5f64.ln(), max_relative = 1.0e-9);
+    }
+
     #[test]
     fn exp_works() {
         type S = I9F23;
@@ -558,17 +1693,56 @@ mod tests {
         assert_eq!(result, 1.0);
 
         let result: f64 = exp::<S, D>(ONE).unwrap().lossy_into();
-        assert_relative_eq!(result, 2.718281828459045235_f64, epsilon = 1.0e-4);
+        assert_relative_eq!(result, core::f64::consts::E, epsilon = 1.0e-4);
 
         let result: f64 = exp::<S, D>(S::from_num(5.0)).unwrap().lossy_into();
         assert_relative_eq!(result, 148.413159, epsilon = 1.0e-1);
-        // overflow if type too small
-        assert!(exp::<S, D>(S::from_num(-23)).is_err());
+        // underflow if type too small
+        assert_eq!(
+            exp::<S, D>(S::from_num(-23)),
+            Err(TranscendentalError::Underflow)
+        );
+        assert_eq!(
+            exp::<S, D>(S::from_num(23)),
+            Err(TranscendentalError::Overflow)
+        );
         // same is fine with larger destination type
         let result: f64 = exp::<S, I64F64>(S::from_num(-23)).unwrap().lossy_into();
         assert_relative_eq!(result, 102.619e-12, epsilon = 1.0e-12);
     }
 
+    #[test]
+    fn exp_range_reduction() {
+        type F = I64F64;
+        // the terms of a series on the raw operand would overflow here
+        for &x in &[20.0_f64, 43.5, 0.125, -1.0] {
+            let result: f64 = exp::<F, F>(F::from_num(x)).unwrap().lossy_into();
+            assert_relative_eq!(result, x.exp(), max_relative = 1.0e-15);
+        }
+        for &x in &[-20.0_f64, -43.5] {
+            let result: f64 = exp::<F, F>(F::from_num(x)).unwrap().lossy_into();
+            assert_relative_eq!(result, x.exp(), epsilon = 1.0e-19);
+        }
+        // ln(2^63) = 43.668...
+        assert!(exp::<F, F>(F::from_num(43.668)).is_ok());
+        assert_eq!(
+            exp::<F, F>(F::from_num(43.669)),
+            Err(TranscendentalError::Overflow)
+        );
+        assert_eq!(exp::<F, F>(F::from_num(0)), Ok(F::from_num(1)));
+        assert_eq!(
+            Some(exp::<F, F>(F::from_num(1)).unwrap()),
+            constant(consts::E)
+        );
+
+        assert_eq!(checked_exp::<F, F>(F::from_num(50)), None);
+        assert_eq!(checked_exp::<F, F>(F::from_num(-50)), Some(F::from_num(0)));
+        assert_eq!(saturating_exp::<F, F>(F::from_num(50)), F::max_value());
+        assert_eq!(saturating_exp::<F, F>(F::from_num(-50)), F::from_num(0));
+        assert_eq!(saturating_exp::<F, F>(F::max_value()), F::max_value());
+        assert_eq!(saturating_exp::<F, F>(F::min_value()), F::from_num(0));
+    }
+
     #[test]
     fn pow_works() {
         type S = I9F23;
@@ -595,8 +1769,77 @@ mod tests {
         let result: f64 = result.lossy_into();
         assert_relative_eq!(result, 0.00000001, epsilon = 1.0e-9);
 
-        // this would lead a complex result due to computation method
-        assert!(pow::<S, D>(S::from_num(-0.0001), S::from_num(2)).is_err());
+        // whole exponents take the integer path, so negative operands work
+        let result: D = pow(S::from_num(-0.0001), S::from_num(2)).unwrap();
+        let result: f64 = result.lossy_into();
+        assert_relative_eq!(result, 0.00000001, epsilon = 1.0e-9);
+        assert_eq!(pow::<S, D>(-TWO, THREE), Ok(D::from_num(-8)));
+        assert_eq!(pow::<S, D>(-TWO, -TWO), Ok(D::from_num(0.25)));
+        assert_eq!(
+            pow::<S, D>(-TWO, S::from_num(0.5)),
+            Err(TranscendentalError::Domain)
+        );
+
+        assert_eq!(pow::<S, D>(ZERO, ZERO), Ok(D::from_num(1)));
+        assert_eq!(pow::<S, D>(ZERO, S::from_num(0.5)), Ok(D::from_num(0)));
+        assert_eq!(
+            pow::<S, D>(ZERO, -TWO),
+            Err(TranscendentalError::DivisionByZero)
+        );
+        assert_eq!(
+            pow::<S, D>(ZERO, S::from_num(-0.5)),
+            Err(TranscendentalError::DivisionByZero)
+        );
+
+        assert_eq!(
+            pow::<I32F32, I32F32>(I32F32::from_num(2), I32F32::from_num(40.5)),
+            Err(TranscendentalError::Overflow)
+        );
+        assert_eq!(
+            pow::<I32F32, I32F32>(I32F32::from_num(2), I32F32::from_num(-40.5)),
+            Err(TranscendentalError::Underflow)
+        );
+    }
+
+    #[test]
+    fn pow_unsigned() {
+        let result: U32F32 = pow(U16F16::from_num(2.5), U16F16::from_num(1.5)).unwrap();
+        let result: f64 = result.lossy_into();
+        assert_relative_eq!(result, 2.5f64.powf(1.5), epsilon = 1.0e-8);
+        let result: U32F32 = pow(U16F16::from_num(0.25), U16F16::from_num(0.5)).unwrap();
+        assert_eq!(result, U32F32::from_num(0.5));
+        let result: U32F32 = pow(U16F16::from_num(3), U16F16::from_num(4)).unwrap();
+        assert_eq!(result, U32F32::from_num(81));
+    }
+
+    #[test]
+    fn pow_extended_precision() {
+        type F = I64F64;
+        assert_eq!(
+            pow::<F, F>(F::from_num(4), F::from_num(0.5)),
+            Ok(F::from_num(2))
+        );
+
+        // sqrt(2) * 2^62 uses all the integer bits
+        let result: F = pow(F::from_num(2), F::from_num(62.5)).unwrap();
+        let expected = F::from_bits((consts::SQRT_2.to_bits() >> 1) as i128);
+        assert!((result - expected).abs() < F::from_num(1) >> 40);
+
+        let result: F = pow(F::from_num(2.5), F::from_num(-3.7)).unwrap();
+        let result: f64 = result.lossy_into();
+        assert_relative_eq!(result, 2.5f64.powf(-3.7), max_relative = 1.0e-15);
+
+        // a whole exponent that does not fit in i32
+        let big = F::from_num(1u64 << 40);
+        assert_eq!(
+            pow::<F, F>(-F::from_num(1), big + F::from_num(1)),
+            Ok(-F::from_num(1))
+        );
+        assert_eq!(pow::<F, F>(-F::from_num(1), big), Ok(F::from_num(1)));
+        assert_eq!(
+            pow::<F, F>(F::from_num(1.5), big),
+            Err(TranscendentalError::Overflow)
+        );
     }
 
     #[test]
@@ -622,6 +1865,18 @@ mod tests {
         let result: D = powi(TWO, 3).unwrap();
         let result: f64 = result.lossy_into();
         assert_relative_eq!(result, 8.0, epsilon = 1.0e-3);
+
+        assert_eq!(
+            powi::<_, D>(ZERO, -1),
+            Err(TranscendentalError::DivisionByZero)
+        );
+        assert_eq!(powi::<_, D>(TWO, 40), Err(TranscendentalError::Overflow));
+        // the exponent no longer bounds the running time
+        assert_eq!(powi::<_, D>(ONE, 0x7fff_ffff), Ok(D::from_num(1)));
+        assert_eq!(
+            powi::<_, D>(TWO, -0x7fff_ffff - 1),
+            Err(TranscendentalError::Underflow)
+        );
     }
 
     #[test]
@@ -644,19 +1899,189 @@ mod tests {
         let result: f64 = sin(TWO_PI).lossy_into();
         assert_relative_eq!(result, 0.0, epsilon = 1.0e-5);
         let result: f64 = sin(FRAC_PI_4).lossy_into();
-        assert_relative_eq!(result, 0.707107, epsilon = 1.0e-1);
+        assert_relative_eq!(result, core::f64::consts::FRAC_1_SQRT_2, epsilon = 1.0e-1);
         let result: f64 = sin(-FRAC_PI_2).lossy_into();
         assert_relative_eq!(result, -1.0, epsilon = 1.0e-1);
         let result: f64 = sin(-FRAC_PI_4).lossy_into();
-        assert_relative_eq!(result, -0.707107, epsilon = 1.0e-1);
+        assert_relative_eq!(result, -core::f64::consts::FRAC_1_SQRT_2, epsilon = 1.0e-1);
         let result: f64 = sin(PI + FRAC_PI_4).lossy_into();
-        assert_relative_eq!(result, -0.707107, epsilon = 1.0e-1);
+        assert_relative_eq!(result, -core::f64::consts::FRAC_1_SQRT_2, epsilon = 1.0e-1);
         let result: f64 = sin(TWO).lossy_into();
         assert_relative_eq!(result, 0.909297, epsilon = 1.0e-5);
         let result: f64 = sin(-TWO).lossy_into();
         assert_relative_eq!(result, -0.909297, epsilon = 1.0e-5);
     }
 
+    #[test]
+    fn cordic_precision_scales_with_width() {
+        let args: [f64; 8] = [-1.5, -1.0, -0.3, 0.0, 0.001, 0.5, 1.2, 1.5];
+        for &arg in &args {
+            let result: f64 = sin(I16F16::from_num(arg)).lossy_into();
+            assert_abs_diff_eq!(result, arg.sin(), epsilon = 1.0e-4);
+            let result: f64 = sin(I32F32::from_num(arg)).lossy_into();
+            assert_abs_diff_eq!(result, arg.sin(), epsilon = 1.0e-8);
+            let result: f64 = sin(I64F64::from_num(arg)).lossy_into();
+            assert_abs_diff_eq!(result, arg.sin(), epsilon = 1.0e-15);
+            let result: f64 = atan(I64F64::from_num(arg)).lossy_into();
+            assert_abs_diff_eq!(result, arg.atan(), epsilon = 1.0e-15);
+        }
+    }
+
+    #[test]
+    fn constants_use_destination_precision() {
+        let pi: I64F64 = constant(consts::PI).unwrap();
+        assert_eq!(pi, I64F64::from_num(consts::PI));
+        // rounded to nearest rather than down
+        let sqrt_2: I16F16 = constant(consts::SQRT_2).unwrap();
+        assert_eq!(sqrt_2.to_bits(), 0x0001_6A09 + 1);
+        assert!(constant::<I2F30, _>(consts::PI).is_none());
+
+        let result: f64 = ln::<I64F64, I64F64>(I64F64::from_num(10))
+            .unwrap()
+            .lossy_into();
+        assert_abs_diff_eq!(result, core::f64::consts::LN_10, epsilon = 1.0e-15);
+        let result: f64 = sin(I64F64::from_num(3)).lossy_into();
+        assert_abs_diff_eq!(result, 3f64.sin(), epsilon = 1.0e-15);
+        let result: f64 = cos(I64F64::from_num(-2.5)).lossy_into();
+        assert_abs_diff_eq!(result, (-2.5f64).cos(), epsilon = 1.0e-15);
+        let result: f64 = atan2(I64F64::from_num(0.5), I64F64::from_num(-3))
+            .unwrap()
+            .lossy_into();
+        assert_abs_diff_eq!(result, 0.5f64.atan2(-3.0), epsilon = 1.0e-15);
+    }
+
+    #[test]
+    fn constants_round_without_overflow() {
+        // rounding 0.75 to an integer must not overflow the 128-bit bits
+        assert_eq!(
+            constant::<I128F0, _>(U0F128::from_num(0.75)),
+            Some(I128F0::from_num(1))
+        );
+        assert_eq!(
+            constant::<I128F0, _>(U0F128::max_value()),
+            Some(I128F0::from_num(1))
+        );
+        // rounds up to 1, which does not fit in I1F127
+        assert!(constant::<I1F127, _>(U0F128::max_value()).is_none());
+        assert_eq!(
+            constant::<I2F126, _>(U0F128::max_value()),
+            Some(I2F126::from_num(1))
+        );
+    }
+
+    #[test]
+    fn extreme_widths_work() {
+        let max = I128F0::max_value();
+        let result: f64 = sin(max).lossy_into();
+        assert!(result.abs() <= 1.0);
+        let result: f64 = ln::<I128F0, I128F0>(max).unwrap().lossy_into();
+        assert_eq!(result, 88.0);
+        assert_eq!(log2::<I128F0, I128F0>(max), Ok(I128F0::from_num(127)));
+
+        // 2 is not representable in these types
+        let result: f64 = ln::<I2F30, I2F30>(I2F30::from_num(1.5))
+            .unwrap()
+            .lossy_into();
+        assert_abs_diff_eq!(result, 1.5f64.ln(), epsilon = 1.0e-9);
+        let result: f64 = log2::<I1F31, I1F31>(I1F31::from_num(0.75))
+            .unwrap()
+            .lossy_into();
+        assert_abs_diff_eq!(result, 0.75f64.log2(), epsilon = 1.0e-9);
+        assert_eq!(
+            log2::<I1F31, I1F31>(I1F31::from_num(0.25)),
+            Err(TranscendentalError::Overflow)
+        );
+        let result: f64 = log10::<I2F30, I2F30>(I2F30::from_num(1.9))
+            .unwrap()
+            .lossy_into();
+        assert_abs_diff_eq!(result, 1.9f64.log10(), epsilon = 1.0e-9);
+
+        // neither pi/4 nor the cordic gain fits in I0F32
+        for &arg in &[-0.5, -0.3, 0.0, 0.1, 0.49] {
+            let result: f64 = sin(I0F32::from_num(arg)).lossy_into();
+            assert_abs_diff_eq!(result, f64::sin(arg), epsilon = 1.0e-9);
+            let result: f64 = sin(I1F31::from_num(arg * 2.0)).lossy_into();
+            assert_abs_diff_eq!(result, f64::sin(arg * 2.0), epsilon = 1.0e-9);
+            let result: f64 = cos(I2F30::from_num(arg * 4.0)).lossy_into();
+            assert_abs_diff_eq!(result, f64::cos(arg * 4.0), epsilon = 1.0e-9);
+            let result: f64 = tan(I1F31::from_num(arg)).lossy_into();
+            assert_abs_diff_eq!(result, f64::tan(arg), epsilon = 1.0e-9);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn cos_overflow_panics() {
+        let _ = cos(I1F31::from_num(0));
+    }
+
+    #[test]
+    fn no_integer_bit_limit() {
+        // values beyond the range of I9F23
+        let result: f64 = ln::<I48F16, I48F16>(I48F16::from_num(1_000_000))
+            .unwrap()
+            .lossy_into();
+        assert_abs_diff_eq!(result, 1_000_000f64.ln(), epsilon = 1.0e-3);
+        let result: f64 = exp::<I48F16, I48F16>(I48F16::from_num(4))
+            .unwrap()
+            .lossy_into();
+        assert_relative_eq!(result, 4f64.exp(), max_relative = 1.0e-3);
+        let result: f64 = sin(I48F16::from_num(1000)).lossy_into();
+        assert_abs_diff_eq!(result, 1000f64.sin(), epsilon = 1.0e-3);
+        let result: f64 = atan(I48F16::from_num(-100_000)).lossy_into();
+        assert_abs_diff_eq!(result, (-100_000f64).atan(), epsilon = 1.0e-4);
+
+        // narrow types where pi does not fit
+        for &arg in &[-1.9, -1.0, 0.0, 0.5, 1.9] {
+            let result: f64 = sin(I2F30::from_num(arg)).lossy_into();
+            assert_abs_diff_eq!(result, f64::sin(arg), epsilon = 1.0e-8);
+            let result: f64 = atan(I2F30::from_num(arg)).lossy_into();
+            assert_abs_diff_eq!(result, f64::atan(arg), epsilon = 1.0e-8);
+            let result: f64 = sin(I4F28::from_num(arg * 4.0)).lossy_into();
+            assert_abs_diff_eq!(result, f64::sin(arg * 4.0), epsilon = 1.0e-7);
+        }
+        assert!(atan2(I2F30::from_num(1), I2F30::from_num(-1)).is_err());
+        let result: f64 = atan2(I4F28::from_num(1), I4F28::from_num(-1))
+            .unwrap()
+            .lossy_into();
+        assert_abs_diff_eq!(result, 1f64.atan2(-1.0), epsilon = 1.0e-7);
+    }
+
+    #[test]
+    fn cordic_hyperbolic_works() {
+        for &arg in &[-1.1, -0.5, 0.0, 0.25, 1.1] {
+            let x = I32F32::from_num(HYPERBOLIC_GAIN_INV);
+            let z = I32F32::from_num(arg);
+            let (cosh, sinh, _z) = cordic(
+                Coordinates::Hyperbolic,
+                Mode::Rotation,
+                x,
+                I32F32::from_num(0),
+                z,
+                32,
+            )
+            .unwrap();
+            let cosh: f64 = cosh.lossy_into();
+            let sinh: f64 = sinh.lossy_into();
+            assert_abs_diff_eq!(cosh, f64::cosh(arg), epsilon = 1.0e-8);
+            assert_abs_diff_eq!(sinh, f64::sinh(arg), epsilon = 1.0e-8);
+        }
+        for &arg in &[-0.8, -0.5, 0.0, 0.25, 0.8] {
+            let y = I64F64::from_num(arg);
+            let (_x, _y, z) = cordic(
+                Coordinates::Hyperbolic,
+                Mode::Vectoring,
+                I64F64::from_num(1),
+                y,
+                I64F64::from_num(0),
+                64,
+            )
+            .unwrap();
+            let z: f64 = z.lossy_into();
+            assert_abs_diff_eq!(z, f64::atanh(arg), epsilon = 1.0e-15);
+        }
+    }
+
     #[test]
     fn cos_works() {
         let result: f64 = cos(I9F23::from_num(0)).lossy_into();
@@ -672,11 +2097,320 @@ mod tests {
         assert_relative_eq!(result, 1.55741, epsilon = 1.0e-5);
     }
 
+    fn assert_max_error<T>(
+        f: impl Fn(T) -> T,
+        reference: impl Fn(f64) -> f64,
+        args: &[f64],
+        epsilon: f64,
+    ) where
+        T: FixedSigned + LossyInto<f64>,
+    {
+        for &arg in args {
+            let arg = T::from_num(arg);
+            let result: f64 = f(arg).lossy_into();
+            assert_abs_diff_eq!(result, reference(arg.lossy_into()), epsilon = epsilon);
+        }
+    }
+
+    const UNIT_ARGS: [f64; 11] = [
+        -1.0, -0.99, -0.75, -0.5, -0.1, 0.0, 0.01, 0.3, 0.5, 0.9, 1.0,
+    ];
+
     #[test]
     fn asin_works() {
-        let result: f64 = asin(I9F23::from_num(0)).lossy_into();
-        assert_relative_eq!(result, 0.0, epsilon = 1.0e-5);
-        let result: f64 = asin(I9F23::from_num(0.01)).lossy_into();
-        assert_relative_eq!(result, 0.01, epsilon = 1.0e-5);
+        assert_eq!(
+            asin(I9F23::from_num(1.01)),
+            Err(TranscendentalError::Domain)
+        );
+        assert!(asin(I9F23::from_num(-1.01)).is_err());
+        assert_max_error(|x: I9F23| asin(x).unwrap(), f64::asin, &UNIT_ARGS, 3.0e-7);
+        assert_max_error(|x: I32F32| asin(x).unwrap(), f64::asin, &UNIT_ARGS, 1.0e-9);
+        assert_max_error(|x: I64F64| asin(x).unwrap(), f64::asin, &UNIT_ARGS, 1.0e-15);
+        // 1 + x does not fit in I2F30 for x close to 1
+        assert_max_error(|x: I2F30| asin(x).unwrap(), f64::asin, &UNIT_ARGS, 1.0e-9);
+        // 1 does not fit in I1F31, nor does asin(x) for |x| > sin(1)
+        let args = [-0.84, -0.5, 0.0, 0.3, 0.6];
+        assert_max_error(|x: I1F31| asin(x).unwrap(), f64::asin, &args, 1.0e-9);
+        assert_eq!(
+            asin(I1F31::from_num(-1)),
+            Err(TranscendentalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn acos_works() {
+        assert!(acos(I32F32::from_num(2)).is_err());
+        assert!(acos(I32F32::from_num(-2)).is_err());
+        assert_max_error(|x: I9F23| acos(x).unwrap(), f64::acos, &UNIT_ARGS, 3.0e-7);
+        assert_max_error(|x: I32F32| acos(x).unwrap(), f64::acos, &UNIT_ARGS, 1.0e-9);
+        assert_max_error(|x: I64F64| acos(x).unwrap(), f64::acos, &UNIT_ARGS, 1.0e-15);
+        // pi does not fit in I2F30, but acos(x) for x >= -0.4 does
+        let args = [-0.4, 0.0, 0.5, 0.99, 1.0];
+        assert_max_error(|x: I2F30| acos(x).unwrap(), f64::acos, &args, 1.0e-9);
+        assert_eq!(
+            acos(I2F30::from_num(-1)),
+            Err(TranscendentalError::Overflow)
+        );
+        assert_max_error(|x: I1F31| acos(x).unwrap(), f64::acos, &[0.6, 0.9], 1.0e-9);
+    }
+
+    #[test]
+    fn atan_works() {
+        let args = [
+            -200.0, -3.5, -1.0, -0.4, 0.0, 0.001, 0.5, 1.0, 2.0, 17.25, 250.0,
+        ];
+        assert_max_error(atan::<I9F23>, f64::atan, &args, 3.0e-7);
+        assert_max_error(atan::<I32F32>, f64::atan, &args, 1.0e-9);
+        assert_max_error(atan::<I64F64>, f64::atan, &args, 1.0e-15);
+        // large arguments approach pi/2
+        let result: f64 = atan(I64F64::from_num(1u64 << 50)).lossy_into();
+        assert_abs_diff_eq!(result, 2f64.powi(50).atan(), epsilon = 1.0e-15);
+        // 1 does not fit in these types
+        let args = [-1.0, -0.75, -0.1, 0.0, 0.2, 0.99];
+        assert_max_error(atan::<I1F31>, f64::atan, &args, 1.0e-9);
+        let args = [-0.5, -0.3, 0.0, 0.1, 0.49];
+        assert_max_error(atan::<I0F32>, f64::atan, &args, 1.0e-9);
+    }
+
+    #[test]
+    fn atan2_works() {
+        assert_eq!(
+            atan2(I32F32::from_num(0), I32F32::from_num(0)),
+            Err(TranscendentalError::Domain)
+        );
+        let points = [
+            (0.0, 1.0),
+            (1.0, 0.0),
+            (0.0, -1.0),
+            (-1.0, 0.0),
+            (3.0, 4.0),
+            (-3.0, 4.0),
+            (3.0, -4.0),
+            (-3.0, -4.0),
+            (0.001, -250.0),
+            (-0.001, -250.0),
+            (1000.0, 0.5),
+        ];
+        for &(y, x) in &points {
+            let result: f64 = atan2(I32F32::from_num(y), I32F32::from_num(x))
+                .unwrap()
+                .lossy_into();
+            assert_abs_diff_eq!(result, f64::atan2(y, x), epsilon = 1.0e-9);
+            let result: f64 = atan2(I64F64::from_num(y), I64F64::from_num(x))
+                .unwrap()
+                .lossy_into();
+            assert_abs_diff_eq!(result, f64::atan2(y, x), epsilon = 1.0e-15);
+        }
+        // tiny operands keep their precision
+        let y = I32F32::from_bits(3);
+        let x = I32F32::from_bits(4);
+        let result: f64 = atan2(y, x).unwrap().lossy_into();
+        assert_abs_diff_eq!(result, f64::atan2(3.0, 4.0), epsilon = 1.0e-9);
+        // the angle is accumulated beyond the range of I1F31
+        let result: f64 = atan2(I1F31::from_num(0.5), I1F31::from_num(0.75))
+            .unwrap()
+            .lossy_into();
+        assert_abs_diff_eq!(result, f64::atan2(0.5, 0.75), epsilon = 1.0e-9);
+        assert_eq!(
+            atan2(I1F31::from_num(0.5), I1F31::from_num(-0.75)),
+            Err(TranscendentalError::Overflow)
+        );
+    }
+
+    fn assert_hyperbolic<T>(
+        f: impl Fn(T) -> Result<T, TranscendentalError>,
+        reference: impl Fn(f64) -> f64,
+        args: &[f64],
+        epsilon: f64,
+    ) where
+        T: FixedSigned + LossyInto<f64>,
+    {
+        for &arg in args {
+            let arg = T::from_num(arg);
+            let result: f64 = f(arg).unwrap().lossy_into();
+            let expected = reference(arg.lossy_into());
+            assert_abs_diff_eq!(
+                result,
+                expected,
+                epsilon = epsilon * expected.abs().max(1.0)
+            );
+        }
+    }
+
+    const HYPERBOLIC_ARGS: [f64; 12] = [
+        -20.0, -3.7, -1.0, -0.4, -0.001, 0.0, 0.001, 0.3466, 0.75, 2.0, 9.5, 20.0,
+    ];
+
+    #[test]
+    fn sinh_cosh_work() {
+        assert_hyperbolic(sinh::<I32F32>, f64::sinh, &HYPERBOLIC_ARGS, 1.0e-7);
+        assert_hyperbolic(cosh::<I32F32>, f64::cosh, &HYPERBOLIC_ARGS, 1.0e-7);
+        assert_hyperbolic(sinh::<I64F64>, f64::sinh, &HYPERBOLIC_ARGS, 1.0e-14);
+        assert_hyperbolic(cosh::<I64F64>, f64::cosh, &HYPERBOLIC_ARGS, 1.0e-14);
+        // e^23 / 2 overflows I32F32 but not I64F64
+        assert_eq!(
+            sinh(I32F32::from_num(23)),
+            Err(TranscendentalError::Overflow)
+        );
+        assert!(sinh(I32F32::from_num(-23)).is_err());
+        assert!(cosh(I32F32::from_num(-23)).is_err());
+        assert!(cosh(I64F64::from_num(23)).is_ok());
+        assert!(cosh(I64F64::max_value()).is_err());
+    }
+
+    #[test]
+    fn tanh_works() {
+        assert_hyperbolic(tanh::<I32F32>, f64::tanh, &HYPERBOLIC_ARGS, 1.0e-8);
+        assert_hyperbolic(tanh::<I64F64>, f64::tanh, &HYPERBOLIC_ARGS, 1.0e-15);
+        // no overflow for large operands
+        assert_eq!(tanh(I32F32::max_value()).unwrap(), 1);
+        assert_eq!(tanh(I32F32::min_value()).unwrap(), -1);
+        assert_eq!(tanh(I64F64::from_num(1000)).unwrap(), 1);
+    }
+
+    #[test]
+    fn asinh_works() {
+        let args = [-1.0e6, -30.0, -1.0, -0.5, 0.0, 0.001, 0.9, 1.1, 4.0, 1.0e6];
+        assert_hyperbolic(asinh::<I32F32>, f64::asinh, &args, 1.0e-8);
+        assert_hyperbolic(asinh::<I64F64>, f64::asinh, &args, 1.0e-15);
+    }
+
+    #[test]
+    fn acosh_works() {
+        assert_eq!(
+            acosh(I32F32::from_num(0.999)),
+            Err(TranscendentalError::Domain)
+        );
+        assert!(acosh(I32F32::from_num(-2)).is_err());
+        let args = [1.0, 1.0001, 1.3, 1.5, 2.0, 17.5, 1.0e6];
+        assert_hyperbolic(acosh::<I32F32>, f64::acosh, &args, 1.0e-8);
+        assert_hyperbolic(acosh::<I64F64>, f64::acosh, &args, 1.0e-15);
+    }
+
+    #[test]
+    fn atanh_works() {
+        assert_eq!(
+            atanh(I32F32::from_num(1)),
+            Err(TranscendentalError::DivisionByZero)
+        );
+        assert_eq!(
+            atanh(I32F32::from_num(-1)),
+            Err(TranscendentalError::DivisionByZero)
+        );
+        assert_eq!(
+            atanh(I32F32::from_num(1.5)),
+            Err(TranscendentalError::Domain)
+        );
+        assert_eq!(atanh(I32F32::min_value()), Err(TranscendentalError::Domain));
+        let args = [-0.999_999, -0.9, -0.75, -0.2, 0.0, 0.001, 0.5, 0.8, 0.99];
+        // f64::atanh loses precision close to ±1
+        let reference = |x: f64| ((1.0 + x).ln() - (1.0 - x).ln()) / 2.0;
+        assert_hyperbolic(atanh::<I32F32>, reference, &args, 1.0e-8);
+        assert_hyperbolic(atanh::<I64F64>, reference, &args, 1.0e-14);
+    }
+
+    #[test]
+    fn hyperbolic_narrow_types_work() {
+        // 1 does not fit in I1F31 and I0F32, but every tanh value does
+        let args = [-1.0, -0.5, 0.0, 0.3, 0.99];
+        assert_hyperbolic(tanh::<I1F31>, f64::tanh, &args, 1.0e-9);
+        assert_hyperbolic(tanh::<I0F32>, f64::tanh, &[-0.5, 0.1, 0.49], 1.0e-9);
+        assert_hyperbolic(sinh::<I1F31>, f64::sinh, &[-0.8, 0.0, 0.5], 1.0e-9);
+        assert_eq!(cosh(I1F31::from_num(0)), Err(TranscendentalError::Overflow));
+
+        // atanh(x) fits in I1F31 for |x| < tanh(1)
+        let reference = |x: f64| ((1.0 + x).ln() - (1.0 - x).ln()) / 2.0;
+        let args_atanh = [-0.76, -0.5, 0.0, 0.2, 0.5, 0.76];
+        assert_hyperbolic(atanh::<I1F31>, reference, &args_atanh, 1.0e-9);
+        assert_eq!(
+            atanh(I1F31::from_num(0.9)),
+            Err(TranscendentalError::Overflow)
+        );
+        assert_eq!(
+            atanh(I1F31::from_num(-1)),
+            Err(TranscendentalError::DivisionByZero)
+        );
+        assert_hyperbolic(asinh::<I1F31>, f64::asinh, &args, 1.0e-9);
+
+        let args = [-2.0, -1.5, 0.0, 0.5, 1.5, 1.99];
+        assert_hyperbolic(asinh::<I2F30>, f64::asinh, &args, 1.0e-9);
+        assert_hyperbolic(tanh::<I2F30>, f64::tanh, &args, 1.0e-9);
+        assert!(acosh(I1F31::max_value()).is_err());
+
+        // the magnitude of the minimum does not fit, but the result does
+        let result: f64 = asinh(I8F8::min_value()).unwrap().lossy_into();
+        assert_abs_diff_eq!(result, (-128f64).asinh(), epsilon = 2.0e-3);
+    }
+
+    #[test]
+    fn hyperbolic_large_results_work() {
+        type F = FixedI256<U10>;
+        // e^100 / 2 needs more than 128 integer bits
+        let result: f64 = sinh(F::from_num(100)).unwrap().to_num();
+        assert_relative_eq!(result, 100f64.sinh(), max_relative = 1.0e-14);
+        let result: f64 = cosh(F::from_num(-100)).unwrap().to_num();
+        assert_relative_eq!(result, 100f64.cosh(), max_relative = 1.0e-14);
+        assert_eq!(sinh(F::from_num(200)), Err(TranscendentalError::Overflow));
+        assert_eq!(tanh(F::from_num(-200)).unwrap(), -1);
+
+        let result: f64 = sinh(I128F0::from_num(80)).unwrap().lossy_into();
+        assert_relative_eq!(result, 80f64.sinh(), max_relative = 1.0e-14);
+        assert_eq!(
+            sinh(I128F0::max_value()),
+            Err(TranscendentalError::Overflow)
+        );
+        assert_eq!(tanh(I128F0::max_value()).unwrap(), 1);
+        let result: f64 = asinh(I128F0::max_value()).unwrap().lossy_into();
+        assert_eq!(result, 89.0);
+    }
+
+    #[test]
+    fn wide_fractions_work() {
+        use crate::types::extra::U200;
+        use core::str::FromStr;
+
+        type F = FixedI256<U200>;
+        // results are only correct to about 118 fractional bits
+        let check = |result: F, expected: &str| {
+            let expected = F::from_str(expected).unwrap();
+            assert!(
+                (result - expected).abs() < F::from_num(1) >> 110,
+                "{} != {}",
+                result,
+                expected
+            );
+        };
+        let x = F::from_num(1) / F::from_num(3);
+        check(
+            sin(x),
+            "0.327194696796152244173344085267620606064301406893759791590056",
+        );
+        check(
+            atan(x),
+            "0.321750554396642193401404614358661319020755295557656191432803",
+        );
+        check(
+            exp(x).unwrap(),
+            "1.395612425086089528628125319602586837597906515199406982617517",
+        );
+        check(
+            ln(x).unwrap(),
+            "-1.098612288668109691395245236922525704647490557822749451734694",
+        );
+        check(
+            log2(x).unwrap(),
+            "-1.584962500721156181453738943947816508759814407692481060455753",
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        use std::string::ToString;
+
+        assert_eq!(TranscendentalError::Overflow.to_string(), "overflow");
+        assert_eq!(
+            TranscendentalError::DivisionByZero.to_string(),
+            "division by zero"
+        );
     }
 }
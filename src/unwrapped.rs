@@ -0,0 +1,1460 @@
+// Copyright © 2018–2019 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+#![allow(clippy::suspicious_op_assign_impl)]
+
+use crate::{
+    from_str::ParseFixedError,
+    traits::{Fixed, FixedSigned, FixedUnsigned, FromFixed, ToFixed},
+    types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8},
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::{Product, Sum},
+    mem,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
+        SubAssign,
+    },
+    str::FromStr,
+};
+
+/// Provides arithmetic on fixed-point numbers that panics on overflow.
+///
+/// The underlying value can be retrieved through the `.0` index.
+///
+/// Unlike the operators of the fixed-point numbers themselves, which
+/// only panic on overflow when debug assertions are enabled, the
+/// operators of `Unwrapped` always panic on overflow, and the panic
+/// message names the operation and its operands. When supported by
+/// the compiler, the panic location is that of the caller.
+///
+/// # Examples
+///
+/// ```rust
+/// use substrate_fixed::{types::I16F16, Unwrapped};
+/// let max = Unwrapped(I16F16::max_value());
+/// let delta = Unwrapped(I16F16::from_bits(1));
+/// assert_eq!(I16F16::max_value() - delta.0, (max - delta).0);
+/// ```
+///
+/// The following panics even when debug assertions are not enabled.
+///
+/// ```rust,should_panic
+/// use substrate_fixed::{types::I16F16, Unwrapped};
+/// let max = Unwrapped(I16F16::max_value());
+/// let delta = Unwrapped(I16F16::from_bits(1));
+/// let _overflow = max + delta;
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, Hash, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Unwrapped<F>(pub F);
+
+impl<F: Fixed> Unwrapped<F> {
+    /// Returns the smallest value that can be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped::<I16F16>::min_value(), Unwrapped(I16F16::min_value()));
+    /// ```
+    #[inline]
+    pub fn min_value() -> Unwrapped<F> {
+        Unwrapped(F::min_value())
+    }
+
+    /// Returns the largest value that can be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped::<I16F16>::max_value(), Unwrapped(I16F16::max_value()));
+    /// ```
+    #[inline]
+    pub fn max_value() -> Unwrapped<F> {
+        Unwrapped(F::max_value())
+    }
+
+    /// Returns the number of integer bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped::<I16F16>::int_nbits(), I16F16::int_nbits());
+    /// ```
+    #[inline]
+    pub fn int_nbits() -> u32 {
+        F::int_nbits()
+    }
+
+    /// Returns the number of fractional bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped::<I16F16>::frac_nbits(), I16F16::frac_nbits());
+    /// ```
+    #[inline]
+    pub fn frac_nbits() -> u32 {
+        F::frac_nbits()
+    }
+
+    /// Creates a fixed-point number that has a bitwise representation
+    /// identical to the given integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped::<I16F16>::from_bits(0x1C), Unwrapped(I16F16::from_bits(0x1C)));
+    /// ```
+    #[inline]
+    pub fn from_bits(bits: F::Bits) -> Unwrapped<F> {
+        Unwrapped(F::from_bits(bits))
+    }
+
+    /// Creates an integer that has a bitwise representation identical
+    /// to the given fixed-point number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let w = Unwrapped(I16F16::from_bits(0x1C));
+    /// assert_eq!(w.to_bits(), 0x1C);
+    /// ```
+    #[inline]
+    pub fn to_bits(self) -> F::Bits {
+        self.0.to_bits()
+    }
+
+    /// Creates a fixed-point number from another number, panicking on
+    /// overflow.
+    ///
+    /// The other number can be:
+    ///
+    ///   * A fixed-point number. Any extra fractional bits are truncated.
+    ///   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    ///     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    ///     [`usize`].
+    ///   * A floating-point number of type [`f32`] or [`f64`]. If the
+    ///     [`f16` feature] is enabled, it can also be of type [`f16`]
+    ///     or [`bf16`]. For this conversion, the method rounds to the
+    ///     nearest, with ties rounding to even.
+    ///   * Any other number `src` for which [`ToFixed`] and
+    ///     [`Display`] are implemented.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit, or if a floating-point
+    /// value is not [finite]. The panic message includes the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{
+    ///     types::{I4F4, I16F16},
+    ///     Unwrapped,
+    /// };
+    /// let src = I16F16::from_num(1.75);
+    /// let dst = Unwrapped::<I4F4>::from_num(src);
+    /// assert_eq!(dst, Unwrapped(I4F4::from_bits(0b11100)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use substrate_fixed::{types::I4F4, Unwrapped};
+    /// let _overflow = Unwrapped::<I4F4>::from_num(0x1234_i32);
+    /// ```
+    ///
+    /// [`Display`]: https://doc.rust-lang.org/nightly/core/fmt/trait.Display.html
+    /// [`ToFixed`]: traits/trait.ToFixed.html
+    /// [`bf16`]: https://docs.rs/half/^1.2/half/struct.bf16.html
+    /// [`f16` feature]: index.html#optional-features
+    /// [`f16`]: https://docs.rs/half/^1.2/half/struct.f16.html
+    /// [`f32`]: https://doc.rust-lang.org/nightly/std/primitive.f32.html
+    /// [`f64`]: https://doc.rust-lang.org/nightly/std/primitive.f64.html
+    /// [`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+    /// [`i16`]: https://doc.rust-lang.org/nightly/std/primitive.i16.html
+    /// [`i32`]: https://doc.rust-lang.org/nightly/std/primitive.i32.html
+    /// [`i64`]: https://doc.rust-lang.org/nightly/std/primitive.i64.html
+    /// [`i8`]: https://doc.rust-lang.org/nightly/std/primitive.i8.html
+    /// [`isize`]: https://doc.rust-lang.org/nightly/std/primitive.isize.html
+    /// [`u128`]: https://doc.rust-lang.org/nightly/std/primitive.u128.html
+    /// [`u16`]: https://doc.rust-lang.org/nightly/std/primitive.u16.html
+    /// [`u32`]: https://doc.rust-lang.org/nightly/std/primitive.u32.html
+    /// [`u64`]: https://doc.rust-lang.org/nightly/std/primitive.u64.html
+    /// [`u8`]: https://doc.rust-lang.org/nightly/std/primitive.u8.html
+    /// [`usize`]: https://doc.rust-lang.org/nightly/std/primitive.usize.html
+    /// [finite]: https://doc.rust-lang.org/nightly/std/primitive.f64.html#method.is_finite
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn from_num<Src: ToFixed + Copy + Display>(src: Src) -> Unwrapped<F> {
+        match src.checked_to_fixed() {
+            Some(val) => Unwrapped(val),
+            None => panic!("overflow in conversion to fixed-point number: {}", src),
+        }
+    }
+
+    /// Converts a fixed-point number to another number, panicking on
+    /// overflow.
+    ///
+    /// The other number can be:
+    ///
+    ///   * Another fixed-point number. Any extra fractional bits are truncated.
+    ///   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    ///     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    ///     [`usize`]. Any fractional bits are truncated.
+    ///   * A floating-point number of type [`f32`] or [`f64`]. If the
+    ///     [`f16` feature] is enabled, it can also be of type [`f16`]
+    ///     or [`bf16`]. For this conversion, the method rounds to the
+    ///     nearest, with ties rounding to even.
+    ///   * Any other type `Dst` for which [`FromFixed`] is implemented.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{
+    ///     types::{I16F16, I4F4},
+    ///     Unwrapped,
+    /// };
+    /// let src = Unwrapped(I4F4::from_num(1.75));
+    /// assert_eq!(src.to_num::<I16F16>(), I16F16::from_num(1.75));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use substrate_fixed::{
+    ///     types::{I2F6, I4F4},
+    ///     Unwrapped,
+    /// };
+    /// let src = Unwrapped(I4F4::max_value());
+    /// let _overflow = src.to_num::<I2F6>();
+    /// ```
+    ///
+    /// [`FromFixed`]: traits/trait.FromFixed.html
+    /// [`bf16`]: https://docs.rs/half/^1.2/half/struct.bf16.html
+    /// [`f16` feature]: index.html#optional-features
+    /// [`f16`]: https://docs.rs/half/^1.2/half/struct.f16.html
+    /// [`f32`]: https://doc.rust-lang.org/nightly/std/primitive.f32.html
+    /// [`f64`]: https://doc.rust-lang.org/nightly/std/primitive.f64.html
+    /// [`i128`]: https://doc.rust-lang.org/nightly/std/primitive.i128.html
+    /// [`i16`]: https://doc.rust-lang.org/nightly/std/primitive.i16.html
+    /// [`i32`]: https://doc.rust-lang.org/nightly/std/primitive.i32.html
+    /// [`i64`]: https://doc.rust-lang.org/nightly/std/primitive.i64.html
+    /// [`i8`]: https://doc.rust-lang.org/nightly/std/primitive.i8.html
+    /// [`isize`]: https://doc.rust-lang.org/nightly/std/primitive.isize.html
+    /// [`u128`]: https://doc.rust-lang.org/nightly/std/primitive.u128.html
+    /// [`u16`]: https://doc.rust-lang.org/nightly/std/primitive.u16.html
+    /// [`u32`]: https://doc.rust-lang.org/nightly/std/primitive.u32.html
+    /// [`u64`]: https://doc.rust-lang.org/nightly/std/primitive.u64.html
+    /// [`u8`]: https://doc.rust-lang.org/nightly/std/primitive.u8.html
+    /// [`usize`]: https://doc.rust-lang.org/nightly/std/primitive.usize.html
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn to_num<Dst: FromFixed>(self) -> Dst {
+        match Dst::checked_from_fixed(self.0) {
+            Some(val) => val,
+            None => panic!("overflow in conversion from fixed-point number {}", self.0),
+        }
+    }
+
+    /// Parses a string slice containing binary digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I8F8, Unwrapped};
+    /// let check = Unwrapped(I8F8::from_bits(0b11100011 << (8 - 1)));
+    /// assert_eq!(Unwrapped::<I8F8>::from_str_binary("1110001.1"), Ok(check));
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn from_str_binary(src: &str) -> Result<Unwrapped<F>, ParseFixedError> {
+        F::overflowing_from_str_binary(src).map(|o| unwrap_parsed(o, src))
+    }
+
+    /// Parses a string slice containing octal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I8F8, Unwrapped};
+    /// let check = Unwrapped(I8F8::from_bits(0o1654 << (8 - 3)));
+    /// assert_eq!(Unwrapped::<I8F8>::from_str_octal("165.4"), Ok(check));
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn from_str_octal(src: &str) -> Result<Unwrapped<F>, ParseFixedError> {
+        F::overflowing_from_str_octal(src).map(|o| unwrap_parsed(o, src))
+    }
+
+    /// Parses a string slice containing hexadecimal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I8F8, Unwrapped};
+    /// let check = Unwrapped(I8F8::from_bits(0xFFE));
+    /// assert_eq!(Unwrapped::<I8F8>::from_str_hex("F.FE"), Ok(check));
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn from_str_hex(src: &str) -> Result<Unwrapped<F>, ParseFixedError> {
+        F::overflowing_from_str_hex(src).map(|o| unwrap_parsed(o, src))
+    }
+
+    /// Parses a string slice containing digits in the given radix to
+    /// return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit or if the radix is not in the
+    /// range 2 to 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I8F8, Unwrapped};
+    /// // 12.1 in base 3 is 16/3
+    /// let check = Unwrapped(I8F8::from_bits(1365));
+    /// assert_eq!(Unwrapped::<I8F8>::from_str_radix("12.1", 3), Ok(check));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use substrate_fixed::{types::I8F8, Unwrapped};
+    /// let _overflow = Unwrapped::<I8F8>::from_str_radix("100200.1", 3);
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Unwrapped<F>, ParseFixedError> {
+        F::overflowing_from_str_radix(src, radix).map(|o| unwrap_parsed(o, src))
+    }
+
+    /// Returns the integer part.
+    ///
+    /// Note that since the numbers are stored in two’s complement,
+    /// negative numbers with non-zero fractional parts will be
+    /// rounded towards −∞, except in the case where there are no
+    /// integer bits, for example for the type
+    /// <code>[Unwrapped][`Unwrapped`]&lt;[I0F16][`I0F16`]&gt;</code>,
+    /// where the return value is always zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::from_num(12.25)).int(), Unwrapped(I16F16::from_num(12)));
+    /// assert_eq!(Unwrapped(I16F16::from_num(-12.25)).int(), Unwrapped(I16F16::from_num(-13)));
+    /// ```
+    ///
+    /// [`I0F16`]: types/type.I0F16.html
+    /// [`Unwrapped`]: struct.Unwrapped.html
+    #[inline]
+    pub fn int(self) -> Unwrapped<F> {
+        Unwrapped(self.0.int())
+    }
+
+    /// Returns the fractional part.
+    ///
+    /// Note that since the numbers are stored in two’s complement,
+    /// the returned fraction will be non-negative for negative
+    /// numbers, except in the case where there are no integer bits,
+    /// for example for the type
+    /// <code>[Unwrapped][`Unwrapped`]&lt;[I0F16][`I0F16`]&gt;</code>,
+    /// where the return value is always equal to `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::from_num(12.25)).frac(), Unwrapped(I16F16::from_num(0.25)));
+    /// assert_eq!(Unwrapped(I16F16::from_num(-12.25)).frac(), Unwrapped(I16F16::from_num(0.75)));
+    /// ```
+    ///
+    /// [`I0F16`]: types/type.I0F16.html
+    /// [`Unwrapped`]: struct.Unwrapped.html
+    #[inline]
+    pub fn frac(self) -> Unwrapped<F> {
+        Unwrapped(self.0.frac())
+    }
+
+    /// Rounds to the next integer towards 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let three = Unwrapped(I16F16::from_num(3));
+    /// assert_eq!(Unwrapped(I16F16::from_num(3.9)).round_to_zero(), three);
+    /// assert_eq!(Unwrapped(I16F16::from_num(-3.9)).round_to_zero(), -three);
+    /// ```
+    #[inline]
+    pub fn round_to_zero(self) -> Unwrapped<F> {
+        Unwrapped(self.0.round_to_zero())
+    }
+
+    /// Rounds to the next integer towards +∞, panicking on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let two_half = Unwrapped(I16F16::from_num(5) / 2);
+    /// assert_eq!(two_half.ceil(), Unwrapped(I16F16::from_num(3)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let _overflow = Unwrapped(I16F16::max_value()).ceil();
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn ceil(self) -> Unwrapped<F> {
+        Unwrapped(unwrap_unary(self.0.checked_ceil(), "ceil", self.0))
+    }
+
+    /// Rounds to the next integer towards −∞, panicking on overflow.
+    ///
+    /// Overflow can only occur for signed numbers with zero integer
+    /// bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let two_half = Unwrapped(I16F16::from_num(5) / 2);
+    /// assert_eq!(two_half.floor(), Unwrapped(I16F16::from_num(2)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use substrate_fixed::{types::I0F32, Unwrapped};
+    /// let _overflow = Unwrapped(I0F32::min_value()).floor();
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn floor(self) -> Unwrapped<F> {
+        Unwrapped(unwrap_unary(self.0.checked_floor(), "floor", self.0))
+    }
+
+    /// Rounds to the next integer to the nearest, with ties rounded
+    /// away from zero, and panicking on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let two_half = Unwrapped(I16F16::from_num(5) / 2);
+    /// assert_eq!(two_half.round(), Unwrapped(I16F16::from_num(3)));
+    /// assert_eq!((-two_half).round(), Unwrapped(I16F16::from_num(-3)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let _overflow = Unwrapped(I16F16::max_value()).round();
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn round(self) -> Unwrapped<F> {
+        Unwrapped(unwrap_unary(self.0.checked_round(), "round", self.0))
+    }
+
+    /// Rounds to the next integer to the nearest, with ties rounded
+    /// to even, and panicking on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let two_half = Unwrapped(I16F16::from_num(2.5));
+    /// assert_eq!(two_half.round_ties_to_even(), Unwrapped(I16F16::from_num(2)));
+    /// let three_half = Unwrapped(I16F16::from_num(3.5));
+    /// assert_eq!(three_half.round_ties_to_even(), Unwrapped(I16F16::from_num(4)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let _overflow = Unwrapped(I16F16::max_value()).round_ties_to_even();
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn round_ties_to_even(self) -> Unwrapped<F> {
+        let res = self.0.checked_round_ties_to_even();
+        Unwrapped(unwrap_unary(res, "round_ties_to_even", self.0))
+    }
+
+    /// Returns the number of ones in the binary representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let w = Unwrapped(I16F16::from_bits(0x00FF_FF00));
+    /// assert_eq!(w.count_ones(), w.0.count_ones());
+    /// ```
+    #[inline]
+    pub fn count_ones(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Returns the number of zeros in the binary representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let w = Unwrapped(I16F16::from_bits(0x00FF_FF00));
+    /// assert_eq!(w.count_zeros(), w.0.count_zeros());
+    /// ```
+    #[inline]
+    pub fn count_zeros(self) -> u32 {
+        self.0.count_zeros()
+    }
+
+    /// Returns the number of leading zeros in the binary representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let w = Unwrapped(I16F16::from_bits(0x00FF_FF00));
+    /// assert_eq!(w.leading_zeros(), w.0.leading_zeros());
+    /// ```
+    #[inline]
+    pub fn leading_zeros(self) -> u32 {
+        self.0.leading_zeros()
+    }
+
+    /// Returns the number of trailing zeros in the binary representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let w = Unwrapped(I16F16::from_bits(0x00FF_FF00));
+    /// assert_eq!(w.trailing_zeros(), w.0.trailing_zeros());
+    /// ```
+    #[inline]
+    pub fn trailing_zeros(self) -> u32 {
+        self.0.trailing_zeros()
+    }
+
+    /// Shifts to the left by `n` bits, wrapping the truncated bits to the right end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let i = I16F16::from_bits(0x00FF_FF00);
+    /// assert_eq!(Unwrapped(i).rotate_left(12), Unwrapped(i.rotate_left(12)));
+    /// ```
+    #[inline]
+    pub fn rotate_left(self, n: u32) -> Unwrapped<F> {
+        Unwrapped(self.0.rotate_left(n))
+    }
+
+    /// Shifts to the right by `n` bits, wrapping the truncated bits to the left end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let i = I16F16::from_bits(0x00FF_FF00);
+    /// assert_eq!(Unwrapped(i).rotate_right(12), Unwrapped(i.rotate_right(12)));
+    /// ```
+    #[inline]
+    pub fn rotate_right(self, n: u32) -> Unwrapped<F> {
+        Unwrapped(self.0.rotate_right(n))
+    }
+
+    /// Euclidean division.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the division results in overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let num = Unwrapped(I16F16::from_num(7.5));
+    /// let den = Unwrapped(I16F16::from_num(2));
+    /// assert_eq!(num.div_euclid(den), Unwrapped(I16F16::from_num(3)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let quarter = Unwrapped(I16F16::from_num(0.25));
+    /// let _overflow = Unwrapped::<I16F16>::max_value().div_euclid(quarter);
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn div_euclid(self, divisor: Unwrapped<F>) -> Unwrapped<F> {
+        let res = self.0.checked_div_euclid(divisor.0);
+        let zero = divisor.0.count_ones() == 0;
+        Unwrapped(unwrap_binary(res, "div_euclid", self.0, divisor.0, zero))
+    }
+
+    /// Remainder for Euclidean division.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let num = Unwrapped(I16F16::from_num(7.5));
+    /// let den = Unwrapped(I16F16::from_num(2));
+    /// assert_eq!(num.rem_euclid(den), Unwrapped(I16F16::from_num(1.5)));
+    /// assert_eq!((-num).rem_euclid(den), Unwrapped(I16F16::from_num(0.5)));
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn rem_euclid(self, divisor: Unwrapped<F>) -> Unwrapped<F> {
+        let res = self.0.checked_rem_euclid(divisor.0);
+        let zero = divisor.0.count_ones() == 0;
+        Unwrapped(unwrap_binary(res, "rem_euclid", self.0, divisor.0, zero))
+    }
+
+    /// Euclidean division by an integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the division results in overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let num = Unwrapped(I16F16::from_num(7.5));
+    /// assert_eq!(num.div_euclid_int(2), Unwrapped(I16F16::from_num(3)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let min = Unwrapped(I16F16::min_value());
+    /// let _overflow = min.div_euclid_int(-1);
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn div_euclid_int(self, divisor: F::Bits) -> Unwrapped<F> {
+        let res = self.0.checked_div_euclid_int(divisor);
+        let zero = F::from_bits(divisor).count_ones() == 0;
+        Unwrapped(unwrap_binary(res, "div_euclid_int", self.0, divisor, zero))
+    }
+
+    /// Remainder for Euclidean division by an integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the remainder results in overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let num = Unwrapped(I16F16::from_num(7.5));
+    /// assert_eq!(num.rem_euclid_int(2), Unwrapped(I16F16::from_num(1.5)));
+    /// assert_eq!((-num).rem_euclid_int(2), Unwrapped(I16F16::from_num(0.5)));
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn rem_euclid_int(self, divisor: F::Bits) -> Unwrapped<F> {
+        let res = self.0.checked_rem_euclid_int(divisor);
+        let zero = F::from_bits(divisor).count_ones() == 0;
+        Unwrapped(unwrap_binary(res, "rem_euclid_int", self.0, divisor, zero))
+    }
+}
+
+impl<F: FixedSigned> Unwrapped<F> {
+    /// Returns [`true`][`bool`] if the number is > 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// assert!(Unwrapped(I16F16::from_num(4.3)).is_positive());
+    /// assert!(!Unwrapped(I16F16::from_num(0)).is_positive());
+    /// assert!(!Unwrapped(I16F16::from_num(-4.3)).is_positive());
+    /// ```
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    #[inline]
+    pub fn is_positive(self) -> bool {
+        self.0.is_positive()
+    }
+
+    /// Returns [`true`][`bool`] if the number is < 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// assert!(!Unwrapped(I16F16::from_num(4.3)).is_negative());
+    /// assert!(!Unwrapped(I16F16::from_num(0)).is_negative());
+    /// assert!(Unwrapped(I16F16::from_num(-4.3)).is_negative());
+    /// ```
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    #[inline]
+    pub fn is_negative(self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// Returns the absolute value, panicking on overflow.
+    ///
+    /// Overflow can only occur when trying to find the absolute value
+    /// of the minimum value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(I16F16::from_num(-5)).abs(), Unwrapped(I16F16::from_num(5)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// let _overflow = Unwrapped(I16F16::min_value()).abs();
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn abs(self) -> Unwrapped<F> {
+        Unwrapped(unwrap_unary(self.0.checked_abs(), "abs", self.0))
+    }
+
+    /// Returns a number representing the sign of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics
+    ///   * if the value is positive and the fixed-point number has
+    ///     zero or one integer bits such that it cannot hold the
+    ///     value 1.
+    ///   * if the value is negative and the fixed-point number has
+    ///     zero integer bits, such that it cannot hold the value −1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I16F16, Unwrapped};
+    /// assert_eq!(Unwrapped(<I16F16>::from_num(-3.9)).signum(), Unwrapped(I16F16::from_num(-1)));
+    /// assert_eq!(Unwrapped(<I16F16>::from_num(0)).signum(), Unwrapped(I16F16::from_num(0)));
+    /// assert_eq!(Unwrapped(<I16F16>::from_num(3.9)).signum(), Unwrapped(I16F16::from_num(1)));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use substrate_fixed::{types::I1F15, Unwrapped};
+    /// let _overflow = Unwrapped(<I1F15>::from_num(0.5)).signum();
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn signum(self) -> Unwrapped<F> {
+        let res = if self.0.is_positive() {
+            1.checked_to_fixed()
+        } else if self.0.is_negative() {
+            (-1).checked_to_fixed()
+        } else {
+            Some(self.0)
+        };
+        Unwrapped(unwrap_unary(res, "signum", self.0))
+    }
+}
+
+impl<F: FixedUnsigned> Unwrapped<F> {
+    /// Returns [`true`][`bool`] if the fixed-point number is
+    /// 2<sup><i>k</i></sup> for some integer <i>k</i>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::U16F16, Unwrapped};
+    /// assert!(Unwrapped(U16F16::from_num(0.5)).is_power_of_two());
+    /// assert!(Unwrapped(U16F16::from_num(4)).is_power_of_two());
+    /// assert!(!Unwrapped(U16F16::from_num(5)).is_power_of_two());
+    /// ```
+    ///
+    /// [`bool`]: https://doc.rust-lang.org/nightly/std/primitive.bool.html
+    #[inline]
+    pub fn is_power_of_two(self) -> bool {
+        self.0.is_power_of_two()
+    }
+
+    /// Returns the smallest power of two that is ≥ `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the next power of two is too large to fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::U16F16, Unwrapped};
+    /// let half = Unwrapped(U16F16::from_num(0.5));
+    /// assert_eq!(Unwrapped(U16F16::from_num(0.3)).next_power_of_two(), half);
+    /// let four = Unwrapped(U16F16::from_num(4));
+    /// assert_eq!(Unwrapped(U16F16::from_num(4)).next_power_of_two(), four);
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use substrate_fixed::{types::U16F16, Unwrapped};
+    /// let _overflow = Unwrapped(U16F16::max_value()).next_power_of_two();
+    /// ```
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    pub fn next_power_of_two(self) -> Unwrapped<F> {
+        let res = self.0.checked_next_power_of_two();
+        Unwrapped(unwrap_unary(res, "next_power_of_two", self.0))
+    }
+}
+
+// The panic messages name the operation and the operands, for example
+// "overflow in addition: 32767.99998 + 0.00002".
+
+#[cfg_attr(track_caller, track_caller)]
+#[inline]
+fn unwrap_unary<F: Fixed>(res: Option<F>, op: &str, val: F) -> F {
+    match res {
+        Some(res) => res,
+        None => panic!("overflow in {}: {}", op, val),
+    }
+}
+
+#[cfg_attr(track_caller, track_caller)]
+#[inline]
+fn unwrap_binary<F: Fixed, Rhs: Display>(
+    res: Option<F>,
+    op: &str,
+    lhs: F,
+    rhs: Rhs,
+    rhs_is_zero: bool,
+) -> F {
+    match res {
+        Some(res) => res,
+        None if rhs_is_zero => panic!("division by zero in {}: {}, {}", op, lhs, rhs),
+        None => panic!("overflow in {}: {}, {}", op, lhs, rhs),
+    }
+}
+
+#[cfg_attr(track_caller, track_caller)]
+#[inline]
+fn unwrap_op<F: Fixed, Rhs: Display>(
+    res: Option<F>,
+    op: &str,
+    lhs: F,
+    sym: &str,
+    rhs: Rhs,
+    rhs_is_zero: bool,
+) -> F {
+    match res {
+        Some(res) => res,
+        None if rhs_is_zero => panic!("division by zero in {}: {} {} {}", op, lhs, sym, rhs),
+        None => panic!("overflow in {}: {} {} {}", op, lhs, sym, rhs),
+    }
+}
+
+#[cfg_attr(track_caller, track_caller)]
+#[inline]
+fn unwrap_parsed<F: Fixed>((value, overflow): (F, bool), src: &str) -> Unwrapped<F> {
+    if overflow {
+        panic!("overflow in parsing: {:?}", src);
+    }
+    Unwrapped(value)
+}
+
+impl<F: Fixed> Display for Unwrapped<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<F: Fixed> From<F> for Unwrapped<F> {
+    /// Wraps a fixed-point number for arithmetic that panics on overflow.
+    #[inline]
+    fn from(src: F) -> Unwrapped<F> {
+        Unwrapped(src)
+    }
+}
+
+impl<F: Fixed> FromStr for Unwrapped<F> {
+    type Err = ParseFixedError;
+    /// Parses a string slice containing decimal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit.
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        F::overflowing_from_str(s).map(|o| unwrap_parsed(o, s))
+    }
+}
+
+macro_rules! op {
+    (
+        $checked:ident($name:expr, $sym:expr, $div:expr),
+        $Op:ident $op:ident,
+        $OpAssign:ident $op_assign:ident
+    ) => {
+        impl<F: Fixed> $Op<Unwrapped<F>> for Unwrapped<F> {
+            type Output = Unwrapped<F>;
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op(self, other: Unwrapped<F>) -> Unwrapped<F> {
+                let zero = $div && other.0.count_ones() == 0;
+                let res = (self.0).$checked(other.0);
+                Unwrapped(unwrap_op(res, $name, self.0, $sym, other.0, zero))
+            }
+        }
+        impl<'a, F: Fixed> $Op<Unwrapped<F>> for &'a Unwrapped<F> {
+            type Output = Unwrapped<F>;
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op(self, other: Unwrapped<F>) -> Unwrapped<F> {
+                (*self).$op(other)
+            }
+        }
+        impl<'a, F: Fixed> $Op<&'a Unwrapped<F>> for Unwrapped<F> {
+            type Output = Unwrapped<F>;
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op(self, other: &Unwrapped<F>) -> Unwrapped<F> {
+                self.$op(*other)
+            }
+        }
+        impl<'a, 'b, F: Fixed> $Op<&'a Unwrapped<F>> for &'b Unwrapped<F> {
+            type Output = Unwrapped<F>;
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op(self, other: &Unwrapped<F>) -> Unwrapped<F> {
+                (*self).$op(*other)
+            }
+        }
+        impl<F: Fixed> $OpAssign<Unwrapped<F>> for Unwrapped<F> {
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op_assign(&mut self, other: Unwrapped<F>) {
+                *self = (*self).$op(other);
+            }
+        }
+        impl<'a, F: Fixed> $OpAssign<&'a Unwrapped<F>> for Unwrapped<F> {
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op_assign(&mut self, other: &Unwrapped<F>) {
+                *self = (*self).$op(*other);
+            }
+        }
+    };
+}
+
+macro_rules! op_bitwise {
+    ($Op:ident $op:ident, $OpAssign:ident $op_assign:ident) => {
+        impl<F> $Op<Unwrapped<F>> for Unwrapped<F>
+        where
+            F: $Op<F, Output = F>,
+        {
+            type Output = Unwrapped<F>;
+            #[inline]
+            fn $op(self, other: Unwrapped<F>) -> Unwrapped<F> {
+                Unwrapped((self.0).$op(other.0))
+            }
+        }
+        impl<'a, F> $Op<Unwrapped<F>> for &'a Unwrapped<F>
+        where
+            &'a F: $Op<F, Output = F>,
+        {
+            type Output = Unwrapped<F>;
+            #[inline]
+            fn $op(self, other: Unwrapped<F>) -> Unwrapped<F> {
+                Unwrapped((self.0).$op(other.0))
+            }
+        }
+        impl<'a, F> $Op<&'a Unwrapped<F>> for Unwrapped<F>
+        where
+            F: $Op<&'a F, Output = F>,
+        {
+            type Output = Unwrapped<F>;
+            #[inline]
+            fn $op(self, other: &'a Unwrapped<F>) -> Unwrapped<F> {
+                Unwrapped((self.0).$op(&other.0))
+            }
+        }
+        impl<'a, 'b, F> $Op<&'a Unwrapped<F>> for &'b Unwrapped<F>
+        where
+            &'b F: $Op<&'a F, Output = F>,
+        {
+            type Output = Unwrapped<F>;
+            #[inline]
+            fn $op(self, other: &'a Unwrapped<F>) -> Unwrapped<F> {
+                Unwrapped((self.0).$op(&other.0))
+            }
+        }
+        impl<F> $OpAssign<Unwrapped<F>> for Unwrapped<F>
+        where
+            F: $OpAssign<F>,
+        {
+            #[inline]
+            fn $op_assign(&mut self, other: Unwrapped<F>) {
+                (self.0).$op_assign(other.0);
+            }
+        }
+        impl<'a, F> $OpAssign<&'a Unwrapped<F>> for Unwrapped<F>
+        where
+            F: $OpAssign<&'a F>,
+        {
+            #[inline]
+            fn $op_assign(&mut self, other: &'a Unwrapped<F>) {
+                (self.0).$op_assign(&other.0);
+            }
+        }
+    };
+}
+
+// Shifting by a negative amount or by at least the number of bits panics.
+macro_rules! op_shift {
+    (
+        $Op:ident $op:ident, $OpAssign:ident $op_assign:ident, $name:expr, $sym:expr;
+        $($Rhs:ident),*
+    ) => { $(
+        impl<F: Fixed> $Op<$Rhs> for Unwrapped<F> {
+            type Output = Unwrapped<F>;
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op(self, other: $Rhs) -> Unwrapped<F> {
+                let nbits = mem::size_of::<F>() as i128 * 8;
+                let amount = other as i128;
+                let res = if 0 <= amount && amount < nbits {
+                    Some((self.0).$op(amount as u32))
+                } else {
+                    None
+                };
+                Unwrapped(unwrap_op(res, $name, self.0, $sym, other, false))
+            }
+        }
+        impl<'a, F: Fixed> $Op<$Rhs> for &'a Unwrapped<F> {
+            type Output = Unwrapped<F>;
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op(self, other: $Rhs) -> Unwrapped<F> {
+                (*self).$op(other)
+            }
+        }
+        impl<'a, F: Fixed> $Op<&'a $Rhs> for Unwrapped<F> {
+            type Output = Unwrapped<F>;
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op(self, other: &$Rhs) -> Unwrapped<F> {
+                self.$op(*other)
+            }
+        }
+        impl<'a, 'b, F: Fixed> $Op<&'a $Rhs> for &'b Unwrapped<F> {
+            type Output = Unwrapped<F>;
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op(self, other: &$Rhs) -> Unwrapped<F> {
+                (*self).$op(*other)
+            }
+        }
+        impl<F: Fixed> $OpAssign<$Rhs> for Unwrapped<F> {
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op_assign(&mut self, other: $Rhs) {
+                *self = (*self).$op(other);
+            }
+        }
+        impl<'a, F: Fixed> $OpAssign<&'a $Rhs> for Unwrapped<F> {
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op_assign(&mut self, other: &$Rhs) {
+                *self = (*self).$op(*other);
+            }
+        }
+    )* };
+}
+
+impl<F: Fixed> Neg for Unwrapped<F> {
+    type Output = Unwrapped<F>;
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    fn neg(self) -> Unwrapped<F> {
+        Unwrapped(unwrap_unary(self.0.checked_neg(), "negation", self.0))
+    }
+}
+
+impl<F: Fixed> Neg for &Unwrapped<F> {
+    type Output = Unwrapped<F>;
+    #[cfg_attr(track_caller, track_caller)]
+    #[inline]
+    fn neg(self) -> Unwrapped<F> {
+        -*self
+    }
+}
+op! { checked_add("addition", "+", false), Add add, AddAssign add_assign }
+op! { checked_sub("subtraction", "-", false), Sub sub, SubAssign sub_assign }
+op! { checked_mul("multiplication", "*", false), Mul mul, MulAssign mul_assign }
+op! { checked_div("division", "/", true), Div div, DivAssign div_assign }
+op! { checked_rem("remainder", "%", true), Rem rem, RemAssign rem_assign }
+
+impl<F> Not for Unwrapped<F>
+where
+    F: Not<Output = F>,
+{
+    type Output = Unwrapped<F>;
+    #[inline]
+    fn not(self) -> Unwrapped<F> {
+        Unwrapped((self.0).not())
+    }
+}
+impl<'a, F> Not for &'a Unwrapped<F>
+where
+    &'a F: Not<Output = F>,
+{
+    type Output = Unwrapped<F>;
+    #[inline]
+    fn not(self) -> Unwrapped<F> {
+        Unwrapped((self.0).not())
+    }
+}
+op_bitwise! { BitAnd bitand, BitAndAssign bitand_assign }
+op_bitwise! { BitOr bitor, BitOrAssign bitor_assign }
+op_bitwise! { BitXor bitxor, BitXorAssign bitxor_assign }
+
+op_shift! {
+    Shl shl, ShlAssign shl_assign, "left shift", "<<";
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+}
+op_shift! {
+    Shr shr, ShrAssign shr_assign, "right shift", ">>";
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+}
+
+impl<F: Fixed> Sum<Unwrapped<F>> for Unwrapped<F> {
+    #[cfg_attr(track_caller, track_caller)]
+    fn sum<I>(iter: I) -> Unwrapped<F>
+    where
+        I: Iterator<Item = Unwrapped<F>>,
+    {
+        let mut sum = Unwrapped(F::from_num(0));
+        for item in iter {
+            sum += item;
+        }
+        sum
+    }
+}
+
+impl<'a, F: 'a + Fixed> Sum<&'a Unwrapped<F>> for Unwrapped<F> {
+    #[cfg_attr(track_caller, track_caller)]
+    fn sum<I>(iter: I) -> Unwrapped<F>
+    where
+        I: Iterator<Item = &'a Unwrapped<F>>,
+    {
+        let mut sum = Unwrapped(F::from_num(0));
+        for item in iter {
+            sum += item;
+        }
+        sum
+    }
+}
+
+impl<F: Fixed> Product<Unwrapped<F>> for Unwrapped<F> {
+    #[cfg_attr(track_caller, track_caller)]
+    fn product<I>(mut iter: I) -> Unwrapped<F>
+    where
+        I: Iterator<Item = Unwrapped<F>>,
+    {
+        let mut product = match iter.next() {
+            None => return Unwrapped::from_num(1),
+            Some(first) => first,
+        };
+        for item in iter {
+            product *= item;
+        }
+        product
+    }
+}
+
+impl<'a, F: 'a + Fixed> Product<&'a Unwrapped<F>> for Unwrapped<F> {
+    #[cfg_attr(track_caller, track_caller)]
+    fn product<I>(mut iter: I) -> Unwrapped<F>
+    where
+        I: Iterator<Item = &'a Unwrapped<F>>,
+    {
+        let mut product = match iter.next() {
+            None => return Unwrapped::from_num(1),
+            Some(first) => *first,
+        };
+        for item in iter {
+            product *= item;
+        }
+        product
+    }
+}
+
+// The following cannot be implemented for Unwrapped<F> where F: Fixed,
+// otherwise there will be a conflicting implementation error, so
+// implementations are provided for each concrete type, as is done
+// for Wrapping<F>.
+
+macro_rules! op_bits {
+    (
+        $Fixed:ident($Bits:ident, $LeEqU:ident)::$checked:ident($name:expr, $sym:expr, $div:expr),
+        $Op:ident $op:ident,
+        $OpAssign:ident $op_assign:ident
+    ) => {
+        impl<Frac: $LeEqU> $Op<$Bits> for Unwrapped<$Fixed<Frac>> {
+            type Output = Unwrapped<$Fixed<Frac>>;
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op(self, other: $Bits) -> Unwrapped<$Fixed<Frac>> {
+                let zero = $div && other == 0;
+                let res = (self.0).$checked(other);
+                Unwrapped(unwrap_op(res, $name, self.0, $sym, other, zero))
+            }
+        }
+        impl<'a, Frac: $LeEqU> $Op<$Bits> for &'a Unwrapped<$Fixed<Frac>> {
+            type Output = Unwrapped<$Fixed<Frac>>;
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op(self, other: $Bits) -> Unwrapped<$Fixed<Frac>> {
+                (*self).$op(other)
+            }
+        }
+        impl<'a, Frac: $LeEqU> $Op<&'a $Bits> for Unwrapped<$Fixed<Frac>> {
+            type Output = Unwrapped<$Fixed<Frac>>;
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op(self, other: &$Bits) -> Unwrapped<$Fixed<Frac>> {
+                self.$op(*other)
+            }
+        }
+        impl<'a, 'b, Frac: $LeEqU> $Op<&'a $Bits> for &'b Unwrapped<$Fixed<Frac>> {
+            type Output = Unwrapped<$Fixed<Frac>>;
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op(self, other: &$Bits) -> Unwrapped<$Fixed<Frac>> {
+                (*self).$op(*other)
+            }
+        }
+        impl<Frac: $LeEqU> $OpAssign<$Bits> for Unwrapped<$Fixed<Frac>> {
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op_assign(&mut self, other: $Bits) {
+                *self = (*self).$op(other);
+            }
+        }
+        impl<'a, Frac: $LeEqU> $OpAssign<&'a $Bits> for Unwrapped<$Fixed<Frac>> {
+            #[cfg_attr(track_caller, track_caller)]
+            #[inline]
+            fn $op_assign(&mut self, other: &$Bits) {
+                *self = (*self).$op(*other);
+            }
+        }
+    };
+}
+
+macro_rules! ops {
+    ($Fixed:ident($Bits:ident, $LeEqU:ident)) => {
+        op_bits! {
+            $Fixed($Bits, $LeEqU)::checked_mul_int("multiplication", "*", false),
+            Mul mul,
+            MulAssign mul_assign
+        }
+        op_bits! {
+            $Fixed($Bits, $LeEqU)::checked_div_int("division", "/", true),
+            Div div,
+            DivAssign div_assign
+        }
+        op_bits! {
+            $Fixed($Bits, $LeEqU)::checked_rem_int("remainder", "%", true),
+            Rem rem,
+            RemAssign rem_assign
+        }
+    };
+}
+ops! { FixedI8(i8, LeEqU8) }
+ops! { FixedI16(i16, LeEqU16) }
+ops! { FixedI32(i32, LeEqU32) }
+ops! { FixedI64(i64, LeEqU64) }
+ops! { FixedI128(i128, LeEqU128) }
+ops! { FixedU8(u8, LeEqU8) }
+ops! { FixedU16(u16, LeEqU16) }
+ops! { FixedU32(u32, LeEqU32) }
+ops! { FixedU64(u64, LeEqU64) }
+ops! { FixedU128(u128, LeEqU128) }
+
+#[cfg(test)]
+mod tests {
+    use crate::{types::*, Unwrapped};
+
+    #[test]
+    fn no_overflow() {
+        let a = Unwrapped(I16F16::from_num(3.5));
+        let b = Unwrapped(I16F16::from_num(-1.25));
+        assert_eq!((a + b).0, I16F16::from_num(2.25));
+        assert_eq!((a - b).0, I16F16::from_num(4.75));
+        assert_eq!((a * b).0, I16F16::from_num(-4.375));
+        assert_eq!(
+            (a / Unwrapped(I16F16::from_num(-0.25))).0,
+            I16F16::from_num(-14)
+        );
+        assert_eq!((a % b).0, I16F16::from_num(1));
+        assert_eq!((a * 2).0, I16F16::from_num(7));
+        assert_eq!((a / 2).0, I16F16::from_num(1.75));
+        assert_eq!((a << 1u32).0, I16F16::from_num(7));
+        assert_eq!((-a).0, I16F16::from_num(-3.5));
+        let v = [a, b, a];
+        assert_eq!(
+            v.iter().sum::<Unwrapped<I16F16>>().0,
+            I16F16::from_num(5.75)
+        );
+        assert_eq!(
+            v.iter().product::<Unwrapped<I16F16>>().0,
+            I16F16::from_num(-15.3125)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in addition: 32767.99998 + 0.00002")]
+    fn add_overflow() {
+        let _ = Unwrapped(I16F16::max_value()) + Unwrapped(I16F16::from_bits(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in multiplication: 200 * 200")]
+    fn mul_overflow() {
+        let a = Unwrapped(I16F16::from_num(200));
+        let _ = a * a;
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero in division: 1 / 0")]
+    fn div_by_zero() {
+        let _ = Unwrapped(I16F16::from_num(1)) / Unwrapped(I16F16::from_num(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero in remainder: 1 % 0")]
+    fn rem_int_by_zero() {
+        let _ = Unwrapped(I16F16::from_num(1)) % 0;
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in negation: 1")]
+    fn neg_unsigned() {
+        let _ = -Unwrapped(U16F16::from_num(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in left shift: 1 << 32")]
+    fn shl_overflow() {
+        let _ = Unwrapped(I16F16::from_num(1)) << 32;
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in conversion to fixed-point number: 4660")]
+    fn from_num_overflow() {
+        let _ = Unwrapped::<I4F4>::from_num(0x1234);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in conversion from fixed-point number 7.9")]
+    fn to_num_overflow() {
+        let _ = Unwrapped(I4F4::max_value()).to_num::<I2F6>();
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in parsing: \"70000\"")]
+    fn parse_overflow() {
+        let _ = "70000".parse::<Unwrapped<I16F16>>();
+    }
+}
@@ -13,7 +13,7 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
-use crate::helpers::IntHelper;
+use crate::{helpers::IntHelper, int256::U256};
 
 trait DivHalf: Copy {
     fn hi(self) -> Self;
@@ -87,6 +87,64 @@ macro_rules! div_half {
 
 div_half! { u8: 8, u16: 16, u32: 32, u64: 64, u128: 128 }
 
+impl DivHalf for U256 {
+    #[inline]
+    fn hi(self) -> U256 {
+        U256::from_words(0, self.to_words().0)
+    }
+
+    #[inline]
+    fn lo(self) -> U256 {
+        U256::from_words(0, self.to_words().1)
+    }
+
+    #[inline]
+    fn up_lo(self, lo: U256) -> U256 {
+        U256::from_words(self.to_words().1, lo.to_words().1)
+    }
+
+    #[inline]
+    fn div_half(&mut self, d: U256, next_half: U256) -> U256 {
+        let dh = d.to_words().0;
+        let ((q1, q0), rr) = dh.div_rem_from(self.to_words());
+        let mut q = U256::from_words(q1, q0);
+        let m = q.wrapping_mul(d.lo());
+        *self = U256::from_words(rr, next_half.to_words().1);
+        if *self < m {
+            q = q.wrapping_sub(U256::ONE);
+            *self = match self.overflowing_add(d) {
+                (r, false) if r < m => {
+                    q = q.wrapping_sub(U256::ONE);
+                    r.wrapping_add(d)
+                }
+                (r, _) => r,
+            };
+        }
+        *self = self.wrapping_sub(m);
+        q
+    }
+
+    #[inline]
+    fn normalize(&mut self, n1: &mut U256, n0: &mut U256) -> (U256, u32) {
+        assert!(!self.is_zero(), "division by zero");
+        let zeros = self.leading_zeros();
+        if zeros == 0 {
+            (U256::ZERO, 0)
+        } else {
+            *self = *self << zeros;
+            let n2 = *n1 >> (256 - zeros);
+            *n1 = *n1 << zeros | *n0 >> (256 - zeros);
+            *n0 = *n0 << zeros;
+            (n2, zeros)
+        }
+    }
+
+    #[inline]
+    fn unnormalize(self, zeros: u32) -> U256 {
+        self >> zeros
+    }
+}
+
 trait NegAbsHiLo {
     type Abs;
     fn neg_abs(self) -> (bool, Self::Abs);
@@ -166,7 +224,7 @@ macro_rules! signed_wide_div_rem {
     )* };
 }
 
-unsigned_wide_div_rem! { u8, u16, u32, u64, u128 }
+unsigned_wide_div_rem! { u8, u16, u32, u64, u128, U256 }
 signed_wide_div_rem! { i8: u8, i16: u16, i32: u32, i64: u64, i128: u128 }
 
 #[cfg(test)]
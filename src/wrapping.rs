@@ -310,6 +310,28 @@ impl<F: Fixed> Wrapping<F> {
         F::wrapping_from_str_hex(src).map(Wrapping)
     }
 
+    /// Parses a string slice containing digits in the given radix to
+    /// return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range 2 to 36 inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use substrate_fixed::{types::I8F8, Wrapping};
+    /// // 100200.1 in base 3 is 16/3 + 256
+    /// let check = Wrapping(I8F8::from_bits(1365));
+    /// assert_eq!(Wrapping::<I8F8>::from_str_radix("100200.1", 3), Ok(check));
+    /// ```
+    #[inline]
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Wrapping<F>, ParseFixedError> {
+        F::wrapping_from_str_radix(src, radix).map(Wrapping)
+    }
+
     /// Returns the integer part.
     ///
     /// Note that since the numbers are stored in two’s complement,
@@ -961,7 +983,7 @@ impl<F: Fixed> Neg for Wrapping<F> {
     }
 }
 
-impl<'a, F: Fixed> Neg for &'a Wrapping<F> {
+impl<F: Fixed> Neg for &Wrapping<F> {
     type Output = Wrapping<F>;
     #[inline]
     fn neg(self) -> Wrapping<F> {